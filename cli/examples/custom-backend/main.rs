@@ -34,6 +34,7 @@ use jj_lib::backend::Conflict;
 use jj_lib::backend::ConflictId;
 use jj_lib::backend::CopyRecord;
 use jj_lib::backend::FileId;
+use jj_lib::backend::GcStats;
 use jj_lib::backend::SigningFn;
 use jj_lib::backend::SymlinkId;
 use jj_lib::backend::Tree;
@@ -169,6 +170,10 @@ impl Backend for JitBackend {
         self.inner.read_tree(path, id).await
     }
 
+    async fn read_trees(&self, path: &RepoPath, ids: &[TreeId]) -> BackendResult<Vec<Tree>> {
+        self.inner.read_trees(path, ids).await
+    }
+
     async fn write_tree(&self, path: &RepoPath, contents: &Tree) -> BackendResult<TreeId> {
         self.inner.write_tree(path, contents).await
     }
@@ -185,6 +190,10 @@ impl Backend for JitBackend {
         self.inner.read_commit(id).await
     }
 
+    async fn read_commits(&self, ids: &[CommitId]) -> BackendResult<Vec<Commit>> {
+        self.inner.read_commits(ids).await
+    }
+
     async fn write_commit(
         &self,
         contents: Commit,
@@ -205,4 +214,8 @@ impl Backend for JitBackend {
     fn gc(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<()> {
         self.inner.gc(index, keep_newer)
     }
+
+    fn gc_dry_run(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<GcStats> {
+        self.inner.gc_dry_run(index, keep_newer)
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::cell::OnceCell;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
@@ -20,10 +21,13 @@ use std::env;
 use std::ffi::OsString;
 use std::fmt;
 use std::fmt::Debug;
+use std::fs;
 use std::io;
+use std::io::Read as _;
 use std::io::Write as _;
 use std::iter;
 use std::mem;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::ExitCode;
@@ -41,6 +45,7 @@ use clap::builder::TypedValueParser;
 use clap::builder::ValueParserFactory;
 use clap::error::ContextKind;
 use clap::error::ContextValue;
+use clap::parser::ValueSource;
 use clap::ArgAction;
 use clap::ArgMatches;
 use clap::Command;
@@ -63,6 +68,7 @@ use jj_lib::config::ConfigLayer;
 use jj_lib::config::ConfigMigrationRule;
 use jj_lib::config::ConfigNamePathBuf;
 use jj_lib::config::ConfigSource;
+use jj_lib::config::ConfigValue;
 use jj_lib::config::StackedConfig;
 use jj_lib::conflicts::ConflictMarkerStyle;
 use jj_lib::fileset;
@@ -71,6 +77,7 @@ use jj_lib::fileset::FilesetExpression;
 use jj_lib::gitignore::GitIgnoreError;
 use jj_lib::gitignore::GitIgnoreFile;
 use jj_lib::id_prefix::IdPrefixContext;
+use jj_lib::mailmap::Mailmap;
 use jj_lib::matchers::Matcher;
 use jj_lib::merge::MergedTreeValue;
 use jj_lib::merged_tree::MergedTree;
@@ -139,6 +146,7 @@ use tracing_chrome::ChromeLayerBuilder;
 use tracing_subscriber::prelude::*;
 
 use crate::command_error::cli_error;
+use crate::command_error::config_error;
 use crate::command_error::config_error_with_message;
 use crate::command_error::handle_command_result;
 use crate::command_error::internal_error;
@@ -146,6 +154,7 @@ use crate::command_error::internal_error_with_message;
 use crate::command_error::print_parse_diagnostics;
 use crate::command_error::user_error;
 use crate::command_error::user_error_with_hint;
+use crate::command_error::user_error_with_message;
 use crate::command_error::CommandError;
 use crate::commit_templater::CommitTemplateLanguage;
 use crate::commit_templater::CommitTemplateLanguageExtension;
@@ -163,11 +172,15 @@ use crate::diff_util::DiffRenderer;
 use crate::formatter::FormatRecorder;
 use crate::formatter::Formatter;
 use crate::formatter::PlainTextFormatter;
+use crate::hooks;
+use crate::hooks::HookPoint;
 use crate::merge_tools::DiffEditor;
 use crate::merge_tools::MergeEditor;
 use crate::merge_tools::MergeToolConfigError;
 use crate::operation_templater::OperationTemplateLanguage;
 use crate::operation_templater::OperationTemplateLanguageExtension;
+use crate::output_format::check_output_format;
+use crate::output_format::OutputFormat;
 use crate::revset_util;
 use crate::revset_util::RevsetExpressionEvaluator;
 use crate::template_builder;
@@ -175,6 +188,7 @@ use crate::template_builder::TemplateLanguage;
 use crate::template_parser::TemplateAliasesMap;
 use crate::template_parser::TemplateDiagnostics;
 use crate::templater::PropertyPlaceholder;
+use crate::templater::TemplatePredicate;
 use crate::templater::TemplateRenderer;
 use crate::text_util;
 use crate::ui::ColorChoice;
@@ -182,6 +196,10 @@ use crate::ui::Ui;
 
 const SHORT_CHANGE_ID_TEMPLATE_TEXT: &str = "format_short_change_id(self.change_id())";
 
+/// Maximum number of immutable commits listed in the `check_rewritable()`
+/// error.
+const MAX_REPORTED_IMMUTABLE_COMMITS: usize = 10;
+
 #[derive(Clone)]
 struct ChromeTracingFlushGuard {
     _inner: Option<Rc<tracing_chrome::FlushGuard>>,
@@ -608,7 +626,7 @@ impl CommandHelper {
                     )?;
                     let base_repo = repo_loader.load_at(&op_heads[0])?;
                     // TODO: It may be helpful to print each operation we're merging here
-                    let mut tx = start_repo_transaction(&base_repo, &self.data.string_args);
+                    let mut tx = start_repo_transaction(&base_repo, self);
                     for other_op_head in op_heads.into_iter().skip(1) {
                         tx.merge_operation(other_op_head)?;
                         let num_rebased = tx.repo_mut().rebase_descendants()?;
@@ -734,6 +752,19 @@ impl AdvanceBookmarksSettings {
     }
 }
 
+/// Reads the `.mailmap` file at the root of the workspace, if any.
+fn load_mailmap(workspace_root: &Path) -> Result<Mailmap, CommandError> {
+    let path = workspace_root.join(".mailmap");
+    match fs::read(&path) {
+        Ok(content) => Ok(Mailmap::parse(&String::from_utf8_lossy(&content))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Mailmap::empty()),
+        Err(err) => Err(user_error_with_message(
+            format!("Failed to read {}", path.display()),
+            err,
+        )),
+    }
+}
+
 /// Metadata and configuration loaded for a specific workspace.
 pub struct WorkspaceCommandEnvironment {
     command: CommandHelper,
@@ -742,9 +773,13 @@ pub struct WorkspaceCommandEnvironment {
     template_aliases_map: TemplateAliasesMap,
     path_converter: RepoPathUiConverter,
     workspace_id: WorkspaceId,
+    mailmap: Arc<Mailmap>,
     immutable_heads_expression: Rc<UserRevsetExpression>,
     short_prefixes_expression: Option<Rc<UserRevsetExpression>>,
     conflict_marker_style: ConflictMarkerStyle,
+    checkout_parallelism: usize,
+    git_lfs: bool,
+    eol_conversion: bool,
 }
 
 impl WorkspaceCommandEnvironment {
@@ -764,9 +799,13 @@ impl WorkspaceCommandEnvironment {
             template_aliases_map,
             path_converter,
             workspace_id: workspace.workspace_id().to_owned(),
+            mailmap: Arc::new(load_mailmap(workspace.workspace_root())?),
             immutable_heads_expression: RevsetExpression::root(),
             short_prefixes_expression: None,
             conflict_marker_style: settings.get("ui.conflict-marker-style")?,
+            checkout_parallelism: settings.get("core.checkout-parallelism")?,
+            git_lfs: settings.get_bool("git.lfs")?,
+            eol_conversion: settings.get_bool("core.eol-conversion")?,
         };
         env.immutable_heads_expression = env.load_immutable_heads_expression(ui)?;
         env.short_prefixes_expression = env.load_short_prefixes_expression(ui)?;
@@ -796,6 +835,7 @@ impl WorkspaceCommandEnvironment {
         RevsetParseContext::new(
             &self.revset_aliases_map,
             self.settings.user_email(),
+            self.mailmap.clone(),
             now.into(),
             self.command.revset_extensions(),
             Some(workspace_context),
@@ -829,6 +869,21 @@ impl WorkspaceCommandEnvironment {
         self.conflict_marker_style
     }
 
+    /// Number of threads used to write files on checkout
+    pub fn checkout_parallelism(&self) -> usize {
+        self.checkout_parallelism
+    }
+
+    /// Whether to run Git LFS filters on checkout and snapshot
+    pub fn git_lfs(&self) -> bool {
+        self.git_lfs
+    }
+
+    /// Whether to convert line endings according to `.gitattributes`
+    pub fn eol_conversion(&self) -> bool {
+        self.eol_conversion
+    }
+
     fn load_immutable_heads_expression(
         &self,
         ui: &Ui,
@@ -877,25 +932,65 @@ impl WorkspaceCommandEnvironment {
             let root_id = repo.store().root_commit_id();
             return Ok(commits.into_iter().find(|id| *id == root_id).cloned());
         }
+        let immutable_ids = self.find_immutable_commits(repo, commits)?;
+        Ok(immutable_ids.into_iter().next())
+    }
+
+    /// Returns the commits in `commits` which are immutable, regardless of
+    /// `--ignore-immutable`.
+    fn find_immutable_commits<'a>(
+        &self,
+        repo: &dyn Repo,
+        commits: impl IntoIterator<Item = &'a CommitId>,
+    ) -> Result<Vec<CommitId>, CommandError> {
+        let to_rewrite_revset =
+            RevsetExpression::commits(commits.into_iter().cloned().collect_vec());
+        self.evaluate_immutable_expression(repo, self.immutable_expression(), &to_rewrite_revset)
+    }
+
+    /// Returns an immutable head which makes `commit_id` immutable, or `None`
+    /// if the commit is only immutable because it's the root commit.
+    fn find_immutable_head_of(
+        &self,
+        repo: &dyn Repo,
+        commit_id: &CommitId,
+    ) -> Result<Option<CommitId>, CommandError> {
+        let descendants_revset = RevsetExpression::commit(commit_id.clone()).descendants();
+        let head_ids = self.evaluate_immutable_expression(
+            repo,
+            self.immutable_heads_expression.clone(),
+            &descendants_revset,
+        )?;
+        // Prefer the commit itself, then the closest descendant.
+        Ok(head_ids
+            .iter()
+            .find(|id| *id == commit_id)
+            .or(head_ids.last())
+            .cloned())
+    }
 
+    fn evaluate_immutable_expression(
+        &self,
+        repo: &dyn Repo,
+        immutable_expression: Rc<UserRevsetExpression>,
+        other: &Rc<UserRevsetExpression>,
+    ) -> Result<Vec<CommitId>, CommandError> {
         // Not using self.id_prefix_context() because the disambiguation data
         // must not be calculated and cached against arbitrary repo. It's also
         // unlikely that the immutable expression contains short hashes.
         let id_prefix_context = IdPrefixContext::new(self.command.revset_extensions().clone());
-        let to_rewrite_revset =
-            RevsetExpression::commits(commits.into_iter().cloned().collect_vec());
         let mut expression = RevsetExpressionEvaluator::new(
             repo,
             self.command.revset_extensions().clone(),
             &id_prefix_context,
-            self.immutable_expression(),
+            immutable_expression,
         );
-        expression.intersect_with(&to_rewrite_revset);
+        expression.intersect_with(other);
 
-        let mut commit_id_iter = expression.evaluate_to_commit_ids().map_err(|e| {
+        let commit_id_iter = expression.evaluate_to_commit_ids().map_err(|e| {
             config_error_with_message("Invalid `revset-aliases.immutable_heads()`", e)
         })?;
-        Ok(commit_id_iter.next().transpose()?)
+        Ok(commit_id_iter.try_collect()?)
     }
 
     /// Parses template of the given language into evaluation tree.
@@ -921,6 +1016,30 @@ impl WorkspaceCommandEnvironment {
         Ok(template)
     }
 
+    /// Parses boolean template expression of the given language into
+    /// evaluation tree.
+    ///
+    /// `wrap_self` specifies the type of the top-level property, which should
+    /// be one of the `L::wrap_*()` functions.
+    pub fn parse_template_predicate<'a, C: Clone + 'a, L: TemplateLanguage<'a> + ?Sized>(
+        &self,
+        ui: &Ui,
+        language: &L,
+        template_text: &str,
+        wrap_self: impl Fn(PropertyPlaceholder<C>) -> L::Property,
+    ) -> Result<TemplatePredicate<'a, C>, CommandError> {
+        let mut diagnostics = TemplateDiagnostics::new();
+        let predicate = template_builder::parse_predicate(
+            language,
+            &mut diagnostics,
+            template_text,
+            &self.template_aliases_map,
+            wrap_self,
+        )?;
+        print_parse_diagnostics(ui, "In template expression", &diagnostics)?;
+        Ok(predicate)
+    }
+
     /// Creates commit template language environment for this workspace and the
     /// given `repo`.
     pub fn commit_template_language<'a>(
@@ -956,6 +1075,7 @@ pub struct WorkspaceCommandHelper {
     op_summary_template_text: String,
     may_update_working_copy: bool,
     working_copy_shared_with_git: bool,
+    ignore_immutable_warned: Cell<bool>,
 }
 
 enum SnapshotWorkingCopyError {
@@ -1004,6 +1124,7 @@ impl WorkspaceCommandHelper {
             op_summary_template_text,
             may_update_working_copy,
             working_copy_shared_with_git,
+            ignore_immutable_warned: Cell::new(false),
         };
         // Parse commit_summary template early to report error before starting
         // mutable operation.
@@ -1048,7 +1169,11 @@ impl WorkspaceCommandHelper {
         // pointing to the new working-copy commit might not be exported.
         // In that situation, the ref would be conflicted anyway, so export
         // failure is okay.
-        let stats = self.snapshot_working_copy(ui)?;
+        let stats = if self.env.command.global_args().no_snapshot {
+            SnapshotStats::default()
+        } else {
+            self.snapshot_working_copy(ui)?
+        };
 
         // import_git_refs() can rebase the working-copy commit.
         #[cfg(feature = "git")]
@@ -1190,6 +1315,9 @@ impl WorkspaceCommandHelper {
     pub fn checkout_options(&self) -> CheckoutOptions {
         CheckoutOptions {
             conflict_marker_style: self.env.conflict_marker_style(),
+            parallelism: self.env.checkout_parallelism(),
+            git_lfs: self.env.git_lfs(),
+            eol_conversion: self.env.eol_conversion(),
         }
     }
 
@@ -1350,6 +1478,8 @@ to the current parents may contain changes from multiple commits.
             start_tracking_matcher,
             max_new_file_size,
             conflict_marker_style,
+            git_lfs: self.env.git_lfs(),
+            eol_conversion: self.env.eol_conversion(),
         })
     }
 
@@ -1413,6 +1543,7 @@ to the current parents may contain changes from multiple commits.
             self.env.conflict_marker_style(),
             formats,
         )
+        .with_checkout_parallelism(self.env.checkout_parallelism())
     }
 
     /// Loads textual diff renderer from the settings and command arguments.
@@ -1421,7 +1552,10 @@ to the current parents may contain changes from multiple commits.
         args: &DiffFormatArgs,
     ) -> Result<DiffRenderer<'_>, CommandError> {
         let formats = diff_util::diff_formats_for(self.settings(), args)?;
-        Ok(self.diff_renderer(formats))
+        let copy_detection = diff_util::copy_detection_options_for(self.settings(), args)?;
+        Ok(self
+            .diff_renderer(formats)
+            .with_copy_detection(copy_detection))
     }
 
     /// Loads textual diff renderer from the settings and log-like command
@@ -1433,7 +1567,11 @@ to the current parents may contain changes from multiple commits.
         patch: bool,
     ) -> Result<Option<DiffRenderer<'_>>, CommandError> {
         let formats = diff_util::diff_formats_for_log(self.settings(), args, patch)?;
-        Ok((!formats.is_empty()).then(|| self.diff_renderer(formats)))
+        let copy_detection = diff_util::copy_detection_options_for(self.settings(), args)?;
+        Ok((!formats.is_empty()).then(|| {
+            self.diff_renderer(formats)
+                .with_copy_detection(copy_detection)
+        }))
     }
 
     /// Loads diff editor from the settings.
@@ -1533,6 +1671,27 @@ to the current parents may contain changes from multiple commits.
         )
     }
 
+    /// Resolves the merge base of the `commits`, i.e. `fork_point(commits)`.
+    /// Fails if there are several merge bases.
+    pub fn resolve_merge_base(&self, commits: &[Commit]) -> Result<Commit, CommandError> {
+        let ids = commits
+            .iter()
+            .map(|commit| commit.id().clone())
+            .collect_vec();
+        let revision_str = format!(
+            "fork_point({})",
+            ids.iter().map(short_commit_hash).join(" | ")
+        );
+        let expression = self.attach_revset_evaluator(RevsetExpression::commits(ids).fork_point());
+        let should_hint_about_all_prefix = false;
+        revset_util::evaluate_revset_to_single_commit(
+            &revision_str,
+            &expression,
+            || self.commit_summary_template(),
+            should_hint_about_all_prefix,
+        )
+    }
+
     /// Evaluates revset expressions to non-empty set of commits. The returned
     /// set preserves the order of the input expressions.
     ///
@@ -1777,32 +1936,77 @@ to the current parents may contain changes from multiple commits.
         self.commit_summary_template().format(commit, formatter)
     }
 
+    /// Returns an error if any of the `commits` is immutable.
+    ///
+    /// If `--ignore-immutable` is passed, only the root commit is rejected,
+    /// and a warning is printed once if immutable commits are to be rewritten.
     pub fn check_rewritable<'a>(
         &self,
+        ui: &Ui,
         commits: impl IntoIterator<Item = &'a CommitId>,
     ) -> Result<(), CommandError> {
-        let Some(commit_id) = self
+        let repo = self.repo().as_ref();
+        let root_id = repo.store().root_commit_id();
+        let commit_ids = commits.into_iter().collect_vec();
+        if let Some(commit_id) = commit_ids.iter().find(|id| **id == root_id) {
+            return Err(user_error(format!(
+                "The root commit {commit_id:.12} is immutable"
+            )));
+        }
+        let immutable_ids = self
             .env
-            .find_immutable_commit(self.repo().as_ref(), commits)?
-        else {
+            .find_immutable_commits(repo, commit_ids.iter().copied());
+        if self.env.command.global_args().ignore_immutable {
+            // An invalid `immutable_heads()` can also be overridden.
+            let rewrites_immutable = immutable_ids.map_or(true, |ids| !ids.is_empty());
+            if rewrites_immutable && !self.ignore_immutable_warned.replace(true) {
+                writeln!(
+                    ui.warning_default(),
+                    "Rewriting immutable commits because `--ignore-immutable` was passed"
+                )?;
+            }
             return Ok(());
-        };
-        let error = if &commit_id == self.repo().store().root_commit_id() {
-            user_error(format!("The root commit {commit_id:.12} is immutable"))
+        }
+        let immutable_ids = immutable_ids?;
+        if immutable_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut error = if let [commit_id] = &*immutable_ids {
+            user_error(format!("Commit {commit_id:.12} is immutable"))
         } else {
-            let mut error = user_error(format!("Commit {commit_id:.12} is immutable"));
-            let commit = self.repo().store().get_commit(&commit_id)?;
+            user_error(format!("{} commits are immutable", immutable_ids.len()))
+        };
+        for commit_id in immutable_ids.iter().take(MAX_REPORTED_IMMUTABLE_COMMITS) {
+            let commit = repo.store().get_commit(commit_id)?;
             error.add_formatted_hint_with(|formatter| {
                 write!(formatter, "Could not modify commit: ")?;
                 self.write_commit_summary(formatter, &commit)?;
                 Ok(())
             });
-            error.add_hint(
-                "Pass `--ignore-immutable` or configure the set of immutable commits via \
-                 `revset-aliases.immutable_heads()`.",
-            );
-            error
-        };
+            // Commits which aren't immutable heads themselves are protected
+            // because one of their descendants is.
+            if let Some(head_id) = self.env.find_immutable_head_of(repo, commit_id)? {
+                if head_id != *commit_id {
+                    let head = repo.store().get_commit(&head_id)?;
+                    error.add_formatted_hint_with(|formatter| {
+                        write!(formatter, "It is an ancestor of immutable head: ")?;
+                        self.write_commit_summary(formatter, &head)?;
+                        Ok(())
+                    });
+                }
+            }
+        }
+        if immutable_ids.len() > MAX_REPORTED_IMMUTABLE_COMMITS {
+            error.add_hint(format!(
+                "...and {} more immutable commits",
+                immutable_ids.len() - MAX_REPORTED_IMMUTABLE_COMMITS
+            ));
+        }
+        error.add_hint(
+            "Pass `--ignore-immutable` or configure the set of immutable commits via \
+             `revset-aliases.immutable_heads()`.",
+        );
         Err(error)
     }
 
@@ -1900,8 +2104,7 @@ See https://jj-vcs.github.io/jj/latest/working-copy/#stale-working-copy \
                 .map_err(snapshot_command_error)?
         };
         if new_tree_id != *wc_commit.tree_id() {
-            let mut tx =
-                start_repo_transaction(&self.user_repo.repo, self.env.command.string_args());
+            let mut tx = start_repo_transaction(&self.user_repo.repo, &self.env.command);
             tx.set_is_snapshot(true);
             let mut_repo = tx.repo_mut();
             let commit = mut_repo
@@ -1991,7 +2194,7 @@ See https://jj-vcs.github.io/jj/latest/working-copy/#stale-working-copy \
     }
 
     pub fn start_transaction(&mut self) -> WorkspaceCommandTransaction {
-        let tx = start_repo_transaction(self.repo(), self.env.command.string_args());
+        let tx = start_repo_transaction(self.repo(), &self.env.command);
         let id_prefix_context = mem::take(&mut self.user_repo.id_prefix_context);
         WorkspaceCommandTransaction {
             helper: self,
@@ -2048,6 +2251,24 @@ See https://jj-vcs.github.io/jj/latest/working-copy/#stale-working-copy \
             .map(|commit_id| tx.repo().store().get_commit(commit_id))
             .transpose()?;
 
+        let description = description.into();
+        let mut hook_env = vec![
+            (
+                "JJ_ROOT",
+                self.workspace_root().to_string_lossy().into_owned(),
+            ),
+            ("JJ_WORKSPACE", self.workspace_id().as_str().to_owned()),
+            ("JJ_OLD_OPERATION", old_repo.op_id().hex()),
+            ("JJ_OPERATION_DESCRIPTION", description.clone()),
+        ];
+        if let Some(wc_commit) = &maybe_new_wc_commit {
+            hook_env.push(("JJ_CHANGE", wc_commit.change_id().reverse_hex()));
+            hook_env.push(("JJ_COMMIT", wc_commit.id().hex()));
+        }
+        // Run the hook before anything is exported to the colocated Git repo,
+        // so a failing hook leaves no trace of the operation.
+        hooks::run_hook(self.settings(), HookPoint::PreOperation, &hook_env)?;
+
         #[cfg(feature = "git")]
         if self.working_copy_shared_with_git {
             if let Some(wc_commit) = &maybe_new_wc_commit {
@@ -2073,6 +2294,9 @@ See https://jj-vcs.github.io/jj/latest/working-copy/#stale-working-copy \
 
         self.report_repo_changes(ui, &old_repo)?;
 
+        hook_env.push(("JJ_OPERATION", self.repo().op_id().hex()));
+        hooks::run_hook(self.settings(), HookPoint::PostOperation, &hook_env)?;
+
         let settings = self.settings();
         let missing_user_name = settings.user_name().is_empty();
         let missing_user_mail = settings.user_email().is_empty();
@@ -2343,6 +2567,11 @@ impl WorkspaceCommandTransaction<'_> {
         self.tx.repo_mut()
     }
 
+    /// Sets a metadata tag of the operation to be committed.
+    pub fn set_tag(&mut self, key: String, value: String) {
+        self.tx.set_tag(key, value);
+    }
+
     pub fn check_out(&mut self, commit: &Commit) -> Result<Commit, CheckOutCommitError> {
         let workspace_id = self.helper.workspace_id().to_owned();
         self.id_prefix_context.take(); // invalidate
@@ -2480,8 +2709,9 @@ jj git init --colocate",
     }
 }
 
-pub fn start_repo_transaction(repo: &Arc<ReadonlyRepo>, string_args: &[String]) -> Transaction {
+pub fn start_repo_transaction(repo: &Arc<ReadonlyRepo>, command: &CommandHelper) -> Transaction {
     let mut tx = repo.start_transaction();
+    let string_args = command.string_args();
     // TODO: Either do better shell-escaping here or store the values in some list
     // type (which we currently don't have).
     let shell_escape = |arg: &String| {
@@ -2507,9 +2737,30 @@ pub fn start_repo_transaction(repo: &Arc<ReadonlyRepo>, string_args: &[String])
     let mut quoted_strings = vec!["jj".to_string()];
     quoted_strings.extend(string_args.iter().skip(1).map(shell_escape));
     tx.set_tag("args".to_string(), quoted_strings.join(" "));
+    for (key, value) in &command.global_args().op_meta {
+        tx.set_tag(key.clone(), value.clone());
+    }
     tx
 }
 
+/// Parses `--op-meta KEY=VALUE` argument.
+fn parse_op_meta_arg(arg: &str) -> Result<(String, String), String> {
+    let (key, value) = arg
+        .split_once('=')
+        .ok_or_else(|| "Expected KEY=VALUE".to_owned())?;
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(format!("Invalid key {key:?}"));
+    }
+    if key == "args" || key.starts_with("jj.") {
+        return Err(format!("Key {key:?} is reserved for internal use"));
+    }
+    Ok((key.to_owned(), value.to_owned()))
+}
+
 fn update_stale_working_copy(
     mut locked_ws: LockedWorkspace,
     op_id: OperationId,
@@ -2689,6 +2940,8 @@ pub fn print_snapshot_stats(
                 This will increase the maximum file size allowed for new files, in this repository only.
               - Run `jj --config snapshot.max-new-file-size={size} st`
                 This will increase the maximum file size allowed for new files, for this command only.
+              - Run `jj file track --include-large <path>`
+                This will track the given files regardless of their size.
             "
         )?;
     }
@@ -2906,6 +3159,56 @@ impl LogContentFormat {
     }
 }
 
+/// Helper to highlight matches of `--highlight` patterns in log-like output.
+#[derive(Clone, Debug, Default)]
+pub struct LogHighlighter {
+    patterns: Vec<regex::bytes::Regex>,
+}
+
+impl LogHighlighter {
+    pub fn new(patterns: &[regex::bytes::Regex]) -> Self {
+        LogHighlighter {
+            patterns: patterns.to_vec(),
+        }
+    }
+
+    /// Writes the content, labeling the matches of any of the patterns with
+    /// "highlight". Overlapping matches are merged.
+    pub fn write<E: From<io::Error>>(
+        &self,
+        formatter: &mut dyn Formatter,
+        content_fn: impl FnOnce(&mut dyn Formatter) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if self.patterns.is_empty() {
+            return content_fn(formatter);
+        }
+        let mut recorder = FormatRecorder::new();
+        content_fn(&mut recorder)?;
+        let mut ranges = self
+            .patterns
+            .iter()
+            .flat_map(|pattern| pattern.find_iter(recorder.data()))
+            .filter(|m| !m.is_empty())
+            .map(|m| m.range())
+            .collect_vec();
+        ranges.sort_unstable_by_key(|range| range.start);
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        recorder.replay_with_labeled_ranges(formatter, &merged, "highlight")?;
+        Ok(())
+    }
+}
+
+/// Parses a `--highlight` pattern.
+pub fn parse_highlight_pattern(pattern: &str) -> Result<regex::bytes::Regex, regex::Error> {
+    regex::bytes::Regex::new(pattern)
+}
+
 pub fn short_commit_hash(commit_id: &CommitId) -> String {
     format!("{commit_id:.12}")
 }
@@ -3050,11 +3353,25 @@ pub struct GlobalArgs {
     /// implies `--ignore-working-copy`.
     #[arg(long, global = true)]
     pub ignore_working_copy: bool,
+    /// Don't snapshot the working copy, but still update it
+    ///
+    /// Unlike `--ignore-working-copy`, the working-copy commit (`@`) is still
+    /// treated as current, and it's updated at the end of the command if the
+    /// command modified it. The filesystem is not scanned, so changes made on
+    /// disk since the last snapshot are not recorded. This may speed up
+    /// repeated read-only commands when you know nothing has changed on disk.
+    ///
+    /// If files were changed on disk since the last snapshot, the command will
+    /// see stale working-copy contents, and commands that update the working
+    /// copy may overwrite the unrecorded changes.
+    #[arg(long, global = true, conflicts_with = "ignore_working_copy")]
+    pub no_snapshot: bool,
     /// Allow rewriting immutable commits
     ///
     /// By default, Jujutsu prevents rewriting commits in the configured set of
     /// immutable commits. This option disables that check and lets you rewrite
-    /// any commit but the root commit.
+    /// any commit but the root commit. A warning is printed when immutable
+    /// commits are rewritten.
     ///
     /// This option only affects the check. It does not affect the
     /// `immutable_heads()` revset or the `immutable` template keyword.
@@ -3089,6 +3406,32 @@ pub struct GlobalArgs {
         add = ArgValueCandidates::new(complete::operations),
     )]
     pub at_operation: Option<String>,
+    /// Format of the command's primary output
+    ///
+    /// `json` is supported by `jj status`, `jj bookmark list`, and `jj
+    /// operation log`. Other commands fail if it is requested.
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        global = true,
+        value_enum,
+        default_value_t
+    )]
+    pub output_format: OutputFormat,
+    /// Attach custom metadata to the operation (can be repeated)
+    ///
+    /// The metadata is stored in the operations created by the command,
+    /// including the snapshot of the working copy, and can be shown with the
+    /// `tag(key)` and `tags()` operation template methods. Keys may contain
+    /// ASCII alphanumeric characters, `-`, `_`, and `.`. The key `args` and
+    /// keys starting with `jj.` are reserved for internal use.
+    #[arg(
+        long,
+        value_name = "KEY=VALUE",
+        global = true,
+        value_parser = parse_op_meta_arg,
+    )]
+    pub op_meta: Vec<(String, String)>,
     /// Enable debug logging
     #[arg(long, global = true)]
     pub debug: bool,
@@ -3100,6 +3443,10 @@ pub struct GlobalArgs {
 #[derive(clap::Args, Clone, Debug)]
 pub struct EarlyArgs {
     /// When to colorize output (always, never, debug, auto)
+    ///
+    /// With `auto`, which is the default, the output is colorized if stdout
+    /// is a terminal. With `always`, the output is colorized even if it's
+    /// redirected to a file or a pipe.
     #[arg(long, value_name = "WHEN", global = true)]
     pub color: Option<ColorChoice>,
     /// Silence non-primary command output
@@ -3113,6 +3460,9 @@ pub struct EarlyArgs {
     // Option<bool>.
     pub quiet: Option<bool>,
     /// Disable the pager
+    ///
+    /// This takes precedence over the `ui.paginate` setting, including the
+    /// one specified by `--config`.
     #[arg(long, global = true, action = ArgAction::SetTrue)]
     // Parsing with ignore_errors will crash if this is bool, so use
     // Option<bool>.
@@ -3156,6 +3506,64 @@ impl EarlyArgs {
     }
 }
 
+/// `--template-file` argument of commands that accept `-T`/`--template`.
+#[derive(clap::Args, Clone, Debug)]
+pub struct TemplateFileArgs {
+    /// Read the template from the given file
+    ///
+    /// Use `-` to read the template from stdin.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "template",
+        value_hint = clap::ValueHint::FilePath,
+    )]
+    template_file: Option<PathBuf>,
+}
+
+impl TemplateFileArgs {
+    /// Returns the template text specified by `-T` or `--template-file`.
+    pub fn template_text(
+        &self,
+        cwd: &Path,
+        template: Option<&str>,
+    ) -> Result<Option<String>, CommandError> {
+        if let Some(text) = template {
+            return Ok(Some(text.to_owned()));
+        }
+        let Some(path) = &self.template_file else {
+            return Ok(None);
+        };
+        let text = if path.as_os_str() == "-" {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).map_err(|err| {
+                user_error_with_message("Failed to read template from stdin", err)
+            })?;
+            text
+        } else {
+            fs::read_to_string(cwd.join(path)).map_err(|err| {
+                user_error_with_message(
+                    format!("Failed to read template file {}", path.display()),
+                    err,
+                )
+            })?
+        };
+        Ok(Some(text))
+    }
+
+    /// Adds the name of the template file to the error of parsing its content.
+    pub fn annotate_parse_error(&self, err: CommandError) -> CommandError {
+        match &self.template_file {
+            Some(path) if path.as_os_str() == "-" => err.hinted("The template was read from stdin"),
+            Some(path) => err.hinted(format!(
+                "The template was read from file {}",
+                path.display()
+            )),
+            None => err,
+        }
+    }
+}
+
 /// Wrapper around revset expression argument.
 ///
 /// An empty string is rejected early by the CLI value parser, but it's still
@@ -3328,6 +3736,104 @@ fn resolve_aliases(
     }
 }
 
+/// Applies the `command-defaults.<command>` config to the resolved command.
+///
+/// A default is only inserted if the option (or any option conflicting with
+/// it) wasn't specified on the command line.
+fn resolve_command_defaults(
+    ui: &Ui,
+    config: &StackedConfig,
+    app: &Command,
+    mut string_args: Vec<String>,
+) -> Result<Vec<String>, CommandError> {
+    let Ok(matches) = app.clone().try_get_matches_from(&string_args) else {
+        // Let the actual parsing report the error
+        return Ok(string_args);
+    };
+    let mut command = app;
+    let mut matches = &matches;
+    let mut command_names = vec![];
+    while let Some((name, submatches)) = matches.subcommand() {
+        let Some(subcommand) = command.find_subcommand(name) else {
+            return Ok(string_args);
+        };
+        command = subcommand;
+        command_names.push(command.get_name());
+        matches = submatches;
+    }
+    if command_names.is_empty() {
+        return Ok(string_args);
+    }
+
+    let command_key = command_names.join(" ");
+    let table_name = ["command-defaults", &command_key];
+    let specified_on_command_line = |arg: &clap::Arg| {
+        matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+    };
+    let mut default_args = vec![];
+    for key in config.table_keys(table_name).sorted() {
+        let Some(arg) = command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(key) && !arg.is_positional())
+        else {
+            writeln!(
+                ui.warning_default(),
+                "Unknown option '{key}' in command-defaults for '{command_key}'"
+            )?;
+            continue;
+        };
+        let conflicts_with_command_line = command
+            .get_arguments()
+            .filter(|other| specified_on_command_line(other))
+            .any(|other| {
+                let conflicts = |a, b: &clap::Arg| {
+                    command
+                        .get_arg_conflicts_with(a)
+                        .iter()
+                        .any(|c| c.get_id() == b.get_id())
+                };
+                conflicts(arg, other) || conflicts(other, arg)
+            });
+        if specified_on_command_line(arg) || conflicts_with_command_line {
+            continue;
+        }
+        let value = config.get_value(["command-defaults", &command_key, key])?;
+        let values = match value {
+            ConfigValue::Array(items) => items.into_iter().collect(),
+            value => vec![value],
+        };
+        for value in values {
+            match value {
+                ConfigValue::Boolean(flag) => {
+                    if *flag.value() {
+                        default_args.push(format!("--{key}"));
+                    }
+                }
+                ConfigValue::String(value) => {
+                    default_args.push(format!("--{key}={}", value.value()));
+                }
+                ConfigValue::Integer(_) | ConfigValue::Float(_) => {
+                    default_args.push(format!("--{key}={}", value.decorated("", "")));
+                }
+                ConfigValue::Datetime(_) | ConfigValue::Array(_) | ConfigValue::InlineTable(_) => {
+                    return Err(config_error(format!(
+                        "Invalid value for command-defaults.'{command_key}'.{key}: Expected a \
+                         string, number, boolean, or a list of them"
+                    )));
+                }
+            }
+        }
+    }
+
+    // Insert the defaults before "--" so they aren't taken as positional args.
+    let position = string_args
+        .iter()
+        .position(|arg| arg == "--")
+        .unwrap_or(string_args.len());
+    string_args.splice(position..position, default_args);
+    Ok(string_args)
+}
+
 /// Parse args that must be interpreted early, e.g. before printing help.
 fn parse_early_args(
     app: &Command,
@@ -3433,7 +3939,19 @@ pub fn expand_args(
     }
 
     let string_args = resolve_default_command(ui, config, app, string_args)?;
-    resolve_aliases(ui, config, app, string_args)
+    let string_args = resolve_aliases(ui, config, app, string_args)?;
+    resolve_command_defaults(ui, config, app, string_args)
+}
+
+/// Returns the names of the (nested) subcommands selected by `matches`.
+fn subcommand_names(matches: &ArgMatches) -> Vec<&str> {
+    let mut names = vec![];
+    let mut matches = matches;
+    while let Some((name, sub_matches)) = matches.subcommand() {
+        names.push(name);
+        matches = sub_matches;
+    }
+    names
 }
 
 fn parse_args(
@@ -3700,6 +4218,10 @@ impl CliRunner {
         for process_global_args_fn in self.process_global_args_fns {
             process_global_args_fn(ui, &matches)?;
         }
+        check_output_format(
+            &subcommand_names(&matches).join(" "),
+            args.global_args.output_format,
+        )?;
 
         let maybe_workspace_loader = if let Some(path) = &args.global_args.repository {
             // TODO: maybe path should be canonicalized by WorkspaceLoader?
@@ -77,12 +77,70 @@ use crate::ui::Ui;
 pub enum CommandErrorKind {
     User,
     Config,
+    /// Requested config value doesn't exist. Reported like `Config`, but with
+    /// a distinct exit code so scripts can tell it apart from other errors.
+    ConfigNotFound,
     /// Invalid command line. The inner error type may be `clap::Error`.
     Cli,
     BrokenPipe,
+    /// Failed to read or write the repository.
+    Storage,
+    /// The command can't proceed because of conflicts.
+    ///
+    /// Currently only used when `jj git push --rebase-on-reject` produces a
+    /// conflicted commit. Other errors involving conflicts are reported as
+    /// `User` errors.
+    Conflict,
     Internal,
 }
 
+impl CommandErrorKind {
+    /// Returns the exit code the process should report for this kind of
+    /// error.
+    pub fn exit_code(self) -> CommandExitCode {
+        match self {
+            CommandErrorKind::User => CommandExitCode::User,
+            CommandErrorKind::Config => CommandExitCode::User,
+            CommandErrorKind::ConfigNotFound => CommandExitCode::ConfigNotFound,
+            CommandErrorKind::Cli => CommandExitCode::Cli,
+            CommandErrorKind::BrokenPipe => CommandExitCode::BrokenPipe,
+            CommandErrorKind::Storage => CommandExitCode::Storage,
+            CommandErrorKind::Conflict => CommandExitCode::Conflict,
+            CommandErrorKind::Internal => CommandExitCode::Internal,
+        }
+    }
+}
+
+/// Exit codes of the `jj` process.
+///
+/// These are part of the command-line interface, so existing values shouldn't
+/// be changed. See also the "exit codes" section in the FAQ.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum CommandExitCode {
+    Success = 0,
+    /// Generic error, such as a bad revset or no matching revisions.
+    User = 1,
+    /// Invalid command line.
+    Cli = 2,
+    BrokenPipe = 3,
+    /// Requested config value doesn't exist.
+    ConfigNotFound = 4,
+    /// Failed to read or write the repository.
+    Storage = 5,
+    /// The command can't proceed because of conflicts. Currently only used by
+    /// `jj git push --rebase-on-reject`.
+    Conflict = 6,
+    /// Bug or unexpected state of the repository.
+    Internal = 255,
+}
+
+impl From<CommandExitCode> for ExitCode {
+    fn from(code: CommandExitCode) -> Self {
+        ExitCode::from(code as u8)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CommandError {
     pub kind: CommandErrorKind,
@@ -202,6 +260,28 @@ pub fn cli_error(err: impl Into<Box<dyn error::Error + Send + Sync>>) -> Command
     CommandError::new(CommandErrorKind::Cli, err)
 }
 
+pub fn storage_error(err: impl Into<Box<dyn error::Error + Send + Sync>>) -> CommandError {
+    CommandError::new(CommandErrorKind::Storage, err)
+}
+
+pub fn storage_error_with_message(
+    message: impl Into<String>,
+    source: impl Into<Box<dyn error::Error + Send + Sync>>,
+) -> CommandError {
+    CommandError::with_message(CommandErrorKind::Storage, message, source)
+}
+
+pub fn conflict_error(err: impl Into<Box<dyn error::Error + Send + Sync>>) -> CommandError {
+    CommandError::new(CommandErrorKind::Conflict, err)
+}
+
+pub fn conflict_error_with_hint(
+    err: impl Into<Box<dyn error::Error + Send + Sync>>,
+    hint: impl Into<String>,
+) -> CommandError {
+    conflict_error(err).hinted(hint)
+}
+
 pub fn internal_error(err: impl Into<Box<dyn error::Error + Send + Sync>>) -> CommandError {
     CommandError::new(CommandErrorKind::Internal, err)
 }
@@ -322,14 +402,14 @@ impl From<BackendError> for CommandError {
     fn from(err: BackendError) -> Self {
         match &err {
             BackendError::Unsupported(_) => user_error(err),
-            _ => internal_error_with_message("Unexpected error from backend", err),
+            _ => storage_error_with_message("Unexpected error from backend", err),
         }
     }
 }
 
 impl From<OpHeadsStoreError> for CommandError {
     fn from(err: OpHeadsStoreError) -> Self {
-        internal_error_with_message("Unexpected error from operation heads store", err)
+        storage_error_with_message("Unexpected error from operation heads store", err)
     }
 }
 
@@ -396,13 +476,16 @@ impl From<SnapshotError> for CommandError {
 
 impl From<OpStoreError> for CommandError {
     fn from(err: OpStoreError) -> Self {
-        internal_error_with_message("Failed to load an operation", err)
+        match err {
+            OpStoreError::Unsupported(_) => user_error(err),
+            _ => storage_error_with_message("Failed to load an operation", err),
+        }
     }
 }
 
 impl From<RepoLoaderError> for CommandError {
     fn from(err: RepoLoaderError) -> Self {
-        internal_error_with_message("Failed to load the repo", err)
+        storage_error_with_message("Failed to load the repo", err)
     }
 }
 
@@ -489,6 +572,7 @@ mod git {
     use jj_lib::git::GitExportError;
     use jj_lib::git::GitImportError;
     use jj_lib::git::GitRemoteManagementError;
+    use jj_lib::git::GitTagError;
 
     use super::*;
 
@@ -529,6 +613,19 @@ jj currently does not support partial clones. To use jj with this repository, tr
         }
     }
 
+    impl From<GitTagError> for CommandError {
+        fn from(err: GitTagError) -> Self {
+            match err {
+                GitTagError::FailedToUpdate { .. } => {
+                    user_error_with_message("Failed to update tag in the underlying Git repo", err)
+                }
+                GitTagError::AlreadyExists(_)
+                | GitTagError::NotFound(_)
+                | GitTagError::UnexpectedBackend => user_error(err),
+            }
+        }
+    }
+
     impl From<GitRemoteManagementError> for CommandError {
         fn from(err: GitRemoteManagementError) -> Self {
             user_error(err)
@@ -786,51 +883,46 @@ fn template_parse_error_hint(err: &TemplateParseError) -> Option<String> {
     }
 }
 
-const BROKEN_PIPE_EXIT_CODE: u8 = 3;
-
 pub(crate) fn handle_command_result(ui: &mut Ui, result: Result<(), CommandError>) -> ExitCode {
-    try_handle_command_result(ui, result).unwrap_or_else(|_| ExitCode::from(BROKEN_PIPE_EXIT_CODE))
+    try_handle_command_result(ui, result)
+        .unwrap_or(CommandExitCode::BrokenPipe)
+        .into()
 }
 
 fn try_handle_command_result(
     ui: &mut Ui,
     result: Result<(), CommandError>,
-) -> io::Result<ExitCode> {
+) -> io::Result<CommandExitCode> {
     let Err(cmd_err) = &result else {
-        return Ok(ExitCode::SUCCESS);
+        return Ok(CommandExitCode::Success);
     };
     let err = &cmd_err.error;
     let hints = &cmd_err.hints;
     match cmd_err.kind {
-        CommandErrorKind::User => {
+        CommandErrorKind::User | CommandErrorKind::Storage | CommandErrorKind::Conflict => {
             print_error(ui, "Error: ", err, hints)?;
-            Ok(ExitCode::from(1))
         }
-        CommandErrorKind::Config => {
+        CommandErrorKind::Config | CommandErrorKind::ConfigNotFound => {
             print_error(ui, "Config error: ", err, hints)?;
             writeln!(
                 ui.stderr_formatter().labeled("hint"),
                 "For help, see https://jj-vcs.github.io/jj/latest/config/."
             )?;
-            Ok(ExitCode::from(1))
         }
         CommandErrorKind::Cli => {
             if let Some(err) = err.downcast_ref::<clap::Error>() {
-                handle_clap_error(ui, err, hints)
-            } else {
-                print_error(ui, "Error: ", err, hints)?;
-                Ok(ExitCode::from(2))
+                return handle_clap_error(ui, err, hints);
             }
+            print_error(ui, "Error: ", err, hints)?;
         }
         CommandErrorKind::BrokenPipe => {
             // A broken pipe is not an error, but a signal to exit gracefully.
-            Ok(ExitCode::from(BROKEN_PIPE_EXIT_CODE))
         }
         CommandErrorKind::Internal => {
             print_error(ui, "Internal error: ", err, hints)?;
-            Ok(ExitCode::from(255))
         }
     }
+    Ok(cmd_err.kind.exit_code())
 }
 
 fn print_error(
@@ -888,7 +980,11 @@ fn print_error_hints(ui: &Ui, hints: &[ErrorHint]) -> io::Result<()> {
     Ok(())
 }
 
-fn handle_clap_error(ui: &mut Ui, err: &clap::Error, hints: &[ErrorHint]) -> io::Result<ExitCode> {
+fn handle_clap_error(
+    ui: &mut Ui,
+    err: &clap::Error,
+    hints: &[ErrorHint],
+) -> io::Result<CommandExitCode> {
     let clap_str = if ui.color() {
         err.render().ansi().to_string()
     } else {
@@ -905,13 +1001,13 @@ fn handle_clap_error(ui: &mut Ui, err: &clap::Error, hints: &[ErrorHint]) -> io:
     match err.kind() {
         clap::error::ErrorKind::DisplayHelp | clap::error::ErrorKind::DisplayVersion => {
             write!(ui.stdout(), "{clap_str}")?;
-            return Ok(ExitCode::SUCCESS);
+            return Ok(CommandExitCode::Success);
         }
         _ => {}
     }
     write!(ui.stderr(), "{clap_str}")?;
     print_error_hints(ui, hints)?;
-    Ok(ExitCode::from(2))
+    Ok(CommandExitCode::Cli)
 }
 
 /// Prints diagnostic messages emitted during parsing.
@@ -87,7 +87,7 @@ pub(crate) fn cmd_abandon(
         return Ok(());
     }
     let to_abandon_set: HashSet<&CommitId> = to_abandon.iter().ids().collect();
-    workspace_command.check_rewritable(to_abandon_set.iter().copied())?;
+    workspace_command.check_rewritable(ui, to_abandon_set.iter().copied())?;
 
     let mut tx = workspace_command.start_transaction();
     let options = RewriteRefsOptions {
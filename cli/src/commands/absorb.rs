@@ -25,6 +25,7 @@ use crate::cli_util::RevisionArg;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::diff_util::DiffFormat;
+use crate::diff_util::FileListOptions;
 use crate::ui::Ui;
 
 /// Move changes from a revision into the stack of mutable revisions
@@ -90,7 +91,7 @@ pub(crate) fn cmd_absorb(
         writeln!(ui.warning_default(), "Skipping {ui_path}: {reason}")?;
     }
 
-    workspace_command.check_rewritable(selected_trees.target_commits.keys())?;
+    workspace_command.check_rewritable(ui, selected_trees.target_commits.keys())?;
 
     let mut tx = workspace_command.start_transaction();
     let stats = absorb_hunks(tx.repo_mut(), &source, selected_trees.target_commits)?;
@@ -127,7 +128,8 @@ pub(crate) fn cmd_absorb(
             let repo = workspace_command.repo().as_ref();
             if !commit.is_empty(repo)? {
                 writeln!(formatter, "Remaining changes:")?;
-                let diff_renderer = workspace_command.diff_renderer(vec![DiffFormat::Summary]);
+                let diff_renderer = workspace_command
+                    .diff_renderer(vec![DiffFormat::Summary(FileListOptions::default())]);
                 let matcher = &EverythingMatcher; // also print excluded paths
                 let width = ui.term_width();
                 diff_renderer.show_patch(ui, formatter.as_mut(), commit, matcher, width)?;
@@ -18,6 +18,7 @@ use jj_lib::object_id::ObjectId;
 use jj_lib::rewrite::merge_commit_trees;
 use tracing::instrument;
 
+use crate::cli_util::short_commit_hash;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::command_error::CommandError;
@@ -25,6 +26,12 @@ use crate::complete;
 use crate::ui::Ui;
 
 /// Apply the reverse of a revision on top of another revision
+///
+/// A new revision is created for each backed-out revision, with a description
+/// referring to it. If several revisions are backed out, the newest revision
+/// is backed out first, and the new revisions are stacked on top of each
+/// other. If the reverse changes don't apply cleanly, the new revision will
+/// contain conflicts.
 #[derive(clap::Args, Clone, Debug)]
 pub(crate) struct BackoutArgs {
     /// The revision(s) to apply the reverse of
@@ -78,6 +85,7 @@ pub(crate) fn cmd_backout(
         )
     };
     let mut new_base_tree = merge_commit_trees(tx.repo(), &parents)?;
+    let mut backed_out = vec![];
     for commit_to_back_out in to_back_out {
         let commit_to_back_out_subject = commit_to_back_out
             .description()
@@ -98,8 +106,20 @@ pub(crate) fn cmd_backout(
             .new_commit(new_parent_ids, new_tree.id())
             .set_description(new_commit_description)
             .write()?;
-        parents = vec![new_commit];
+        parents = vec![new_commit.clone()];
         new_base_tree = new_tree;
+        backed_out.push((commit_to_back_out, new_commit));
+    }
+    if let Some(mut formatter) = ui.status_formatter() {
+        for (old_commit, new_commit) in &backed_out {
+            write!(
+                formatter,
+                "Backed out {} as ",
+                short_commit_hash(old_commit.id())
+            )?;
+            tx.write_commit_summary(formatter.as_mut(), new_commit)?;
+            writeln!(formatter)?;
+        }
     }
     tx.finish(ui, transaction_description)?;
 
@@ -14,12 +14,17 @@
 
 use clap::builder::NonEmptyStringValueParser;
 use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
 use jj_lib::object_id::ObjectId as _;
 use jj_lib::op_store::RefTarget;
+use jj_lib::repo::Repo as _;
 
 use super::has_tracked_remote_bookmarks;
+use crate::cli_util::short_operation_hash;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::WorkspaceCommandHelper;
+use crate::command_error::user_error;
 use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
 use crate::complete;
@@ -40,6 +45,20 @@ pub struct BookmarkCreateArgs {
     )]
     revision: Option<RevisionArg>,
 
+    /// Recreate the bookmarks where they pointed to at the given operation
+    ///
+    /// This can be used to bring back bookmarks that were deleted since then.
+    /// Unlike the global `--at-operation` flag, the bookmarks are created at
+    /// the current operation.
+    #[arg(
+        long,
+        visible_alias = "from-op",
+        value_name = "OPERATION",
+        conflicts_with = "revision",
+        add = ArgValueCandidates::new(complete::operations),
+    )]
+    from_operation: Option<String>,
+
     /// The bookmarks to create
     #[arg(required = true, value_parser = NonEmptyStringValueParser::new())]
     names: Vec<String>,
@@ -51,28 +70,13 @@ pub fn cmd_bookmark_create(
     args: &BookmarkCreateArgs,
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
+    if let Some(op_str) = &args.from_operation {
+        return create_bookmarks_from_operation(ui, &mut workspace_command, op_str, &args.names);
+    }
     let target_commit = workspace_command
         .resolve_single_rev(ui, args.revision.as_ref().unwrap_or(&RevisionArg::AT))?;
-    let view = workspace_command.repo().view();
     let bookmark_names = &args.names;
-    for name in bookmark_names {
-        if view.get_local_bookmark(name).is_present() {
-            return Err(user_error_with_hint(
-                format!("Bookmark already exists: {name}"),
-                "Use `jj bookmark set` to update it.",
-            ));
-        }
-        if has_tracked_remote_bookmarks(view, name) {
-            return Err(user_error_with_hint(
-                format!("Tracked remote bookmarks exist for deleted bookmark: {name}"),
-                format!(
-                    "Use `jj bookmark set` to recreate the local bookmark. Run `jj bookmark \
-                     untrack 'glob:{name}@*'` to disassociate them."
-                ),
-            ));
-        }
-    }
-
+    check_bookmarks_can_be_created(&workspace_command, bookmark_names)?;
     let mut tx = workspace_command.start_transaction();
     for bookmark_name in bookmark_names {
         tx.repo_mut().set_local_bookmark_target(
@@ -104,3 +108,85 @@ pub fn cmd_bookmark_create(
     )?;
     Ok(())
 }
+
+/// Recreates the `bookmark_names` at the targets they had at the operation
+/// `op_str`.
+fn create_bookmarks_from_operation(
+    ui: &mut Ui,
+    workspace_command: &mut WorkspaceCommandHelper,
+    op_str: &str,
+    bookmark_names: &[String],
+) -> Result<(), CommandError> {
+    let op = workspace_command.resolve_single_op(op_str)?;
+    let op_view = op.view()?;
+    let targets: Vec<(&String, RefTarget)> = bookmark_names
+        .iter()
+        .map(|name| {
+            let target = op_view.get_local_bookmark(name);
+            if target.is_absent() {
+                return Err(user_error(format!(
+                    "Bookmark {name} didn't exist at operation {}",
+                    short_operation_hash(op.id())
+                )));
+            }
+            Ok((name, target.clone()))
+        })
+        .try_collect()?;
+    check_bookmarks_can_be_created(workspace_command, bookmark_names)?;
+
+    let mut tx = workspace_command.start_transaction();
+    for (name, target) in &targets {
+        // The targets may have been abandoned since then.
+        let commits: Vec<_> = target
+            .added_ids()
+            .map(|id| tx.repo().store().get_commit(id))
+            .try_collect()?;
+        tx.repo_mut().add_heads(&commits)?;
+        tx.repo_mut()
+            .set_local_bookmark_target(name, target.clone());
+        if let Some(mut formatter) = ui.status_formatter() {
+            if let [commit] = commits.as_slice() {
+                write!(formatter, "Created bookmark {name} pointing to ")?;
+                tx.write_commit_summary(formatter.as_mut(), commit)?;
+                writeln!(formatter)?;
+            } else {
+                writeln!(formatter, "Created conflicted bookmark {name}")?;
+            }
+        }
+    }
+
+    tx.finish(
+        ui,
+        format!(
+            "create bookmark {names} from operation {op_id}",
+            names = bookmark_names.join(", "),
+            op_id = op.id().hex()
+        ),
+    )?;
+    Ok(())
+}
+
+fn check_bookmarks_can_be_created(
+    workspace_command: &WorkspaceCommandHelper,
+    bookmark_names: &[String],
+) -> Result<(), CommandError> {
+    let view = workspace_command.repo().view();
+    for name in bookmark_names {
+        if view.get_local_bookmark(name).is_present() {
+            return Err(user_error_with_hint(
+                format!("Bookmark already exists: {name}"),
+                "Use `jj bookmark set` to update it.",
+            ));
+        }
+        if has_tracked_remote_bookmarks(view, name) {
+            return Err(user_error_with_hint(
+                format!("Tracked remote bookmarks exist for deleted bookmark: {name}"),
+                format!(
+                    "Use `jj bookmark set` to recreate the local bookmark. Run `jj bookmark \
+                     untrack 'glob:{name}@*'` to disassociate them."
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
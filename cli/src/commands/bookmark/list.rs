@@ -16,15 +16,24 @@ use std::collections::HashSet;
 
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools;
+use jj_lib::backend::MillisSinceEpoch;
+use jj_lib::op_store::BookmarkTarget;
+use jj_lib::op_store::RefTarget;
+use jj_lib::repo::Repo;
 use jj_lib::revset::RevsetExpression;
 use jj_lib::str_util::StringPattern;
+use serde::Serialize;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::TemplateFileArgs;
 use crate::command_error::CommandError;
 use crate::commit_templater::CommitTemplateLanguage;
 use crate::commit_templater::RefName;
 use crate::complete;
+use crate::output_format::write_json;
+use crate::output_format::OutputFormat;
+use crate::output_format::RefTargetJson;
 use crate::ui::Ui;
 
 /// List bookmarks and their targets
@@ -86,6 +95,18 @@ pub struct BookmarkListArgs {
     #[arg(long, short, value_name = "REVSETS")]
     revisions: Option<Vec<RevisionArg>>,
 
+    /// Sort bookmarks by the given key
+    ///
+    /// Date keys use the date of the local target commit, or the latest date
+    /// of the remote target commits if the bookmark was deleted locally.
+    /// Bookmarks with equal keys are sorted by name.
+    #[arg(long, value_enum, default_value_t)]
+    sort: SortKey,
+
+    /// Reverse the order of the bookmarks
+    #[arg(long)]
+    reverse: bool,
+
     /// Render each bookmark using the given template
     ///
     /// All 0-argument methods of the `RefName` type are available as keywords.
@@ -93,6 +114,16 @@ pub struct BookmarkListArgs {
     /// For the syntax, see https://jj-vcs.github.io/jj/latest/templates/
     #[arg(long, short = 'T')]
     template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum SortKey {
+    #[default]
+    Name,
+    CommitterDate,
+    AuthorDate,
 }
 
 pub fn cmd_bookmark_list(
@@ -137,28 +168,55 @@ pub fn cmd_bookmark_list(
 
     let template = {
         let language = workspace_command.commit_template_language();
-        let text = match &args.template {
-            Some(value) => value.to_owned(),
+        let text = match args
+            .template_file
+            .template_text(command.cwd(), args.template.as_deref())?
+        {
+            Some(value) => value,
             None => workspace_command
                 .settings()
                 .get("templates.bookmark_list")?,
         };
         workspace_command
-            .parse_template(ui, &language, &text, CommitTemplateLanguage::wrap_ref_name)?
+            .parse_template(ui, &language, &text, CommitTemplateLanguage::wrap_ref_name)
+            .map_err(|err| args.template_file.annotate_parse_error(err))?
             .labeled("bookmark_list")
     };
 
-    ui.request_pager();
+    let json_output = command.global_args().output_format == OutputFormat::Json;
+    let mut json_bookmarks = vec![];
+    if !json_output {
+        ui.request_pager();
+    }
     let mut formatter = ui.stdout_formatter();
 
     let mut found_deleted_local_bookmark = false;
     let mut found_deleted_tracking_local_bookmark = false;
-    let bookmarks_to_list = view.bookmarks().filter(|(name, target)| {
-        bookmark_names_to_list
-            .as_ref()
-            .map_or(true, |bookmark_names| bookmark_names.contains(name))
-            && (!args.conflicted || target.local_target.has_conflict())
-    });
+    let mut bookmarks_to_list = view
+        .bookmarks()
+        .filter(|(name, target)| {
+            bookmark_names_to_list
+                .as_ref()
+                .map_or(true, |bookmark_names| bookmark_names.contains(name))
+                && (!args.conflicted || target.local_target.has_conflict())
+        })
+        .collect_vec();
+    // Bookmarks are sorted by name, so the stable sort breaks ties by name.
+    if args.sort != SortKey::Name {
+        let keys: Vec<_> = bookmarks_to_list
+            .iter()
+            .map(|(_, target)| bookmark_date(repo.as_ref(), target, args.sort))
+            .try_collect()?;
+        bookmarks_to_list = bookmarks_to_list
+            .into_iter()
+            .zip(keys)
+            .sorted_by_key(|(_, key)| *key)
+            .map(|(bookmark, _)| bookmark)
+            .collect();
+    }
+    if args.reverse {
+        bookmarks_to_list.reverse();
+    }
     for (name, bookmark_target) in bookmarks_to_list {
         let local_target = bookmark_target.local_target;
         let remote_refs = bookmark_target.remote_refs;
@@ -180,17 +238,30 @@ pub fn cmd_bookmark_list(
 
         let include_local_only = !args.tracked && args.remotes.is_none();
         if include_local_only && local_target.is_present() || !tracking_remote_refs.is_empty() {
-            let ref_name = RefName::local(
-                name,
-                local_target.clone(),
-                remote_refs.iter().map(|&(_, remote_ref)| remote_ref),
-            );
-            template.format(&ref_name, formatter.as_mut())?;
+            if json_output {
+                json_bookmarks.push(BookmarkJson::new(name, None, local_target, None));
+            } else {
+                let ref_name = RefName::local(
+                    name,
+                    local_target.clone(),
+                    remote_refs.iter().map(|&(_, remote_ref)| remote_ref),
+                );
+                template.format(&ref_name, formatter.as_mut())?;
+            }
         }
 
         for &(remote, remote_ref) in &tracking_remote_refs {
-            let ref_name = RefName::remote(name, remote, remote_ref.clone(), local_target);
-            template.format(&ref_name, formatter.as_mut())?;
+            if json_output {
+                json_bookmarks.push(BookmarkJson::new(
+                    name,
+                    Some(remote),
+                    &remote_ref.target,
+                    Some(true),
+                ));
+            } else {
+                let ref_name = RefName::remote(name, remote, remote_ref.clone(), local_target);
+                template.format(&ref_name, formatter.as_mut())?;
+            }
         }
 
         if local_target.is_absent() && !tracking_remote_refs.is_empty() {
@@ -202,13 +273,25 @@ pub fn cmd_bookmark_list(
 
         if !args.tracked && (args.all_remotes || args.remotes.is_some()) {
             for &(remote, remote_ref) in &untracked_remote_refs {
-                let ref_name = RefName::remote_only(name, remote, remote_ref.target.clone());
-                template.format(&ref_name, formatter.as_mut())?;
+                if json_output {
+                    json_bookmarks.push(BookmarkJson::new(
+                        name,
+                        Some(remote),
+                        &remote_ref.target,
+                        Some(false),
+                    ));
+                } else {
+                    let ref_name = RefName::remote_only(name, remote, remote_ref.target.clone());
+                    template.format(&ref_name, formatter.as_mut())?;
+                }
             }
         }
     }
 
     drop(formatter);
+    if json_output {
+        write_json(ui, &json_bookmarks)?;
+    }
 
     // Print only one of these hints. It's not important to mention unexported
     // bookmarks, but user might wonder why deleted bookmarks are still listed.
@@ -228,3 +311,56 @@ pub fn cmd_bookmark_list(
 
     Ok(())
 }
+
+/// Returns the latest date of the commits the bookmark points to.
+fn bookmark_date(
+    repo: &dyn Repo,
+    target: &BookmarkTarget,
+    sort: SortKey,
+) -> Result<Option<MillisSinceEpoch>, CommandError> {
+    let ids = if target.local_target.is_present() {
+        target.local_target.added_ids().collect_vec()
+    } else {
+        target
+            .remote_refs
+            .iter()
+            .flat_map(|(_, remote_ref)| remote_ref.target.added_ids())
+            .collect_vec()
+    };
+    let mut latest = None;
+    for id in ids {
+        let commit = repo.store().get_commit(id)?;
+        let signature = match sort {
+            SortKey::Name => unreachable!(),
+            SortKey::CommitterDate => commit.committer(),
+            SortKey::AuthorDate => commit.author(),
+        };
+        latest = latest.max(Some(signature.timestamp.timestamp));
+    }
+    Ok(latest)
+}
+
+#[derive(Serialize)]
+struct BookmarkJson {
+    name: String,
+    remote: Option<String>,
+    present: bool,
+    conflict: bool,
+    target: RefTargetJson,
+    /// Whether the remote bookmark is tracked. Absent for local bookmarks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tracked: Option<bool>,
+}
+
+impl BookmarkJson {
+    fn new(name: &str, remote: Option<&str>, target: &RefTarget, tracked: Option<bool>) -> Self {
+        BookmarkJson {
+            name: name.to_owned(),
+            remote: remote.map(ToOwned::to_owned),
+            present: target.is_present(),
+            conflict: target.has_conflict(),
+            target: RefTargetJson::new(target),
+            tracked,
+        }
+    }
+}
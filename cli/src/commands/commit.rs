@@ -29,6 +29,10 @@ use crate::text_util::parse_author;
 use crate::ui::Ui;
 
 /// Update the description and create a new change on top.
+///
+/// With `--interactive` or file paths, only the selected changes are committed,
+/// similar to `jj split`. The remaining changes are left in the new
+/// working-copy commit.
 #[derive(clap::Args, Clone, Debug)]
 pub(crate) struct CommitArgs {
     /// Interactively choose which changes to include in the first commit
@@ -60,9 +64,11 @@ pub(crate) struct CommitArgs {
     /// Set author to the provided string
     ///
     /// This changes author name and email while retaining author
-    /// timestamp for non-discardable commits.
+    /// timestamp for non-discardable commits. The string must be in the form
+    /// `Name <email>`.
     #[arg(
         long,
+        value_name = "AUTHOR",
         conflicts_with = "reset_author",
         value_parser = parse_author
     )]
@@ -15,12 +15,14 @@
 use std::io::Write as _;
 
 use clap_complete::ArgValueCandidates;
+use jj_lib::config::ConfigGetError;
 use jj_lib::config::ConfigNamePathBuf;
 use jj_lib::config::ConfigValue;
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
 use crate::command_error::CommandError;
+use crate::command_error::CommandErrorKind;
 use crate::complete;
 use crate::ui::Ui;
 
@@ -33,11 +35,27 @@ use crate::ui::Ui;
 /// user.name="Martin von Zweigbergk"
 /// $ jj config get user.name
 /// Martin von Zweigbergk
+///
+/// If the config option doesn't exist, the command exits with status 4.
 #[derive(clap::Args, Clone, Debug)]
 #[command(verbatim_doc_comment)]
 pub struct ConfigGetArgs {
     #[arg(required = true, add = ArgValueCandidates::new(complete::leaf_config_keys))]
     name: ConfigNamePathBuf,
+    /// Require the value to be of the given type
+    ///
+    /// Booleans are printed as `true` or `false`, and lists are printed one
+    /// item per line.
+    #[arg(long = "type", value_enum)]
+    value_type: Option<ConfigValueType>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+enum ConfigValueType {
+    String,
+    Bool,
+    Int,
+    List,
 }
 
 #[instrument(skip_all)]
@@ -46,24 +64,62 @@ pub fn cmd_config_get(
     command: &CommandHelper,
     args: &ConfigGetArgs,
 ) -> Result<(), CommandError> {
-    let stringified = command
+    let output = command
         .settings()
-        .get_value_with(&args.name, |value| match value {
-            // Remove extra formatting from a string value
-            ConfigValue::String(v) => Ok(v.into_value()),
-            // Print other values in TOML syntax (but whitespace trimmed)
-            ConfigValue::Integer(_)
-            | ConfigValue::Float(_)
-            | ConfigValue::Boolean(_)
-            | ConfigValue::Datetime(_) => Ok(value.decorated("", "").to_string()),
-            // TODO: maybe okay to just print array or table in TOML syntax?
-            ConfigValue::Array(_) => {
-                Err("Expected a value convertible to a string, but is an array")
-            }
-            ConfigValue::InlineTable(_) => {
-                Err("Expected a value convertible to a string, but is a table")
+        .get_value_with(&args.name, |value| match args.value_type {
+            None => stringify_value(value).map(|line| line + "\n"),
+            Some(value_type) => format_typed_value(value, value_type),
+        })
+        .map_err(|err| {
+            let not_found = matches!(err, ConfigGetError::NotFound { .. });
+            let mut cmd_err = CommandError::from(err);
+            if not_found {
+                cmd_err.kind = CommandErrorKind::ConfigNotFound;
             }
+            cmd_err
         })?;
-    writeln!(ui.stdout(), "{stringified}")?;
+    write!(ui.stdout(), "{output}")?;
     Ok(())
 }
+
+fn stringify_value(value: ConfigValue) -> Result<String, &'static str> {
+    match value {
+        // Remove extra formatting from a string value
+        ConfigValue::String(v) => Ok(v.into_value()),
+        // Print other values in TOML syntax (but whitespace trimmed)
+        ConfigValue::Integer(_)
+        | ConfigValue::Float(_)
+        | ConfigValue::Boolean(_)
+        | ConfigValue::Datetime(_) => Ok(value.decorated("", "").to_string()),
+        // TODO: maybe okay to just print array or table in TOML syntax?
+        ConfigValue::Array(_) => Err("Expected a value convertible to a string, but is an array"),
+        ConfigValue::InlineTable(_) => {
+            Err("Expected a value convertible to a string, but is a table")
+        }
+    }
+}
+
+/// Formats the value as lines of output, failing if it isn't of the given
+/// type.
+fn format_typed_value(
+    value: ConfigValue,
+    value_type: ConfigValueType,
+) -> Result<String, &'static str> {
+    let line = match (value_type, value) {
+        (ConfigValueType::String, ConfigValue::String(v)) => v.into_value(),
+        (ConfigValueType::String, _) => return Err("Expected a string"),
+        (ConfigValueType::Bool, ConfigValue::Boolean(v)) => v.into_value().to_string(),
+        (ConfigValueType::Bool, _) => return Err("Expected a boolean"),
+        (ConfigValueType::Int, ConfigValue::Integer(v)) => v.into_value().to_string(),
+        (ConfigValueType::Int, _) => return Err("Expected an integer"),
+        (ConfigValueType::List, ConfigValue::Array(items)) => {
+            return items
+                .into_iter()
+                .map(|item| stringify_value(item).map(|line| line + "\n"))
+                .collect::<Result<String, _>>()
+                .map_err(|_| "Expected a list of values convertible to strings");
+        }
+        (ConfigValueType::List, _) => return Err("Expected a list"),
+    };
+    Ok(line + "\n")
+}
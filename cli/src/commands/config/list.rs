@@ -20,6 +20,7 @@ use tracing::instrument;
 
 use super::ConfigLevelArgs;
 use crate::cli_util::CommandHelper;
+use crate::cli_util::TemplateFileArgs;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::config::resolved_config_values;
@@ -42,9 +43,14 @@ pub struct ConfigListArgs {
     /// Allow printing overridden values.
     #[arg(long)]
     pub include_overridden: bool,
+    /// Annotate each variable with the config layer it comes from.
+    ///
+    /// The variables are rendered with the `templates.config_list_with_source`
+    /// template instead of `templates.config_list`.
+    #[arg(long, conflicts_with = "template")]
+    pub show_source: bool,
     #[command(flatten)]
     pub level: ConfigLevelArgs,
-    // TODO(#1047): Support --show-origin using StackedConfig.
     /// Render each variable using the given template
     ///
     /// The following keywords are defined:
@@ -52,10 +58,16 @@ pub struct ConfigListArgs {
     /// * `name: String`: Config name.
     /// * `value: ConfigValue`: Value to be formatted in TOML syntax.
     /// * `overridden: Boolean`: True if the value is shadowed by other.
+    /// * `source: String`: Config layer the value comes from: `default`,
+    ///   `env-base`, `user`, `repo`, `env-overrides`, or `cli`.
+    /// * `path: String`: Path to the config file the value comes from, or empty
+    ///   if the value doesn't come from a file.
     ///
     /// For the syntax, see https://jj-vcs.github.io/jj/latest/templates/
     #[arg(long, short = 'T', verbatim_doc_comment)]
     template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
 }
 
 #[instrument(skip_all)]
@@ -66,12 +78,19 @@ pub fn cmd_config_list(
 ) -> Result<(), CommandError> {
     let template = {
         let language = config_template_language(command.settings());
-        let text = match &args.template {
-            Some(value) => value.to_owned(),
+        let text = match args
+            .template_file
+            .template_text(command.cwd(), args.template.as_deref())?
+        {
+            Some(value) => value,
+            None if args.show_source => command
+                .settings()
+                .get_string("templates.config_list_with_source")?,
             None => command.settings().get_string("templates.config_list")?,
         };
         command
-            .parse_template(ui, &language, &text, GenericTemplateLanguage::wrap_self)?
+            .parse_template(ui, &language, &text, GenericTemplateLanguage::wrap_self)
+            .map_err(|err| args.template_file.annotate_parse_error(err))?
             .labeled("config_list")
     };
 
@@ -126,5 +145,29 @@ fn config_template_language(
         let out_property = self_property.map(|annotated| annotated.is_overridden);
         Ok(L::wrap_boolean(out_property))
     });
+    language.add_keyword("source", |self_property| {
+        let out_property = self_property.map(|annotated| source_name(annotated.source).to_owned());
+        Ok(L::wrap_string(out_property))
+    });
+    language.add_keyword("path", |self_property| {
+        let out_property = self_property.map(|annotated| {
+            annotated
+                .path
+                .as_ref()
+                .map_or_else(String::new, |path| path.display().to_string())
+        });
+        Ok(L::wrap_string(out_property))
+    });
     language
 }
+
+fn source_name(source: ConfigSource) -> &'static str {
+    match source {
+        ConfigSource::Default => "default",
+        ConfigSource::EnvBase => "env-base",
+        ConfigSource::User => "user",
+        ConfigSource::Repo => "repo",
+        ConfigSource::EnvOverrides => "env-overrides",
+        ConfigSource::CommandArg => "cli",
+    }
+}
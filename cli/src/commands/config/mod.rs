@@ -40,6 +40,7 @@ use self::unset::cmd_config_unset;
 use self::unset::ConfigUnsetArgs;
 use crate::cli_util::CommandHelper;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
 use crate::config::ConfigEnv;
 use crate::ui::Ui;
@@ -87,13 +88,16 @@ impl ConfigLevelArgs {
         let pick_one = |mut files: Vec<ConfigFile>, not_found_error: &str| {
             if files.len() > 1 {
                 // TODO: prompt or pick the last?
-                return Err(user_error(format!(
-                    "Cannot determine config file to edit:\n{}",
-                    files
-                        .iter()
-                        .map(|file| format!("  {}", file.path().display()))
-                        .join("\n")
-                )));
+                return Err(user_error_with_hint(
+                    format!(
+                        "Cannot determine config file to edit:\n{}",
+                        files
+                            .iter()
+                            .map(|file| format!("  {}", file.path().display()))
+                            .join("\n")
+                    ),
+                    "Edit one of the files directly, or point `JJ_CONFIG` at a single file.",
+                ));
             }
             files.pop().ok_or_else(|| user_error(not_found_error))
         };
@@ -15,9 +15,13 @@
 use std::fmt::Debug;
 use std::io::Write as _;
 
+use itertools::Itertools as _;
 use jj_lib::default_index::AsCompositeIndex as _;
+use jj_lib::default_index::DefaultIndexStore;
 use jj_lib::default_index::DefaultReadonlyIndex;
 
+use super::reindex::cmd_debug_reindex;
+use super::reindex::DebugReindexArgs;
 use crate::cli_util::CommandHelper;
 use crate::command_error::internal_error;
 use crate::command_error::user_error;
@@ -26,13 +30,20 @@ use crate::ui::Ui;
 
 /// Show commit index stats
 #[derive(clap::Args, Clone, Debug)]
-pub struct DebugIndexArgs {}
+pub struct DebugIndexArgs {
+    /// Rebuild the index from scratch, same as `jj debug reindex`
+    #[arg(long)]
+    rebuild: bool,
+}
 
 pub fn cmd_debug_index(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &DebugIndexArgs,
+    args: &DebugIndexArgs,
 ) -> Result<(), CommandError> {
+    if args.rebuild {
+        return cmd_debug_reindex(ui, command, &DebugReindexArgs {});
+    }
     // Resolve the operation without loading the repo, so this command won't
     // update the index.
     let workspace = command.load_workspace()?;
@@ -42,8 +53,17 @@ pub fn cmd_debug_index(
     let index = index_store
         .get_index_at_op(&op, repo_loader.store())
         .map_err(internal_error)?;
-    if let Some(default_index) = index.as_any().downcast_ref::<DefaultReadonlyIndex>() {
+    let default_index_store = index_store.as_any().downcast_ref::<DefaultIndexStore>();
+    if let (Some(default_index_store), Some(default_index)) = (
+        default_index_store,
+        index.as_any().downcast_ref::<DefaultReadonlyIndex>(),
+    ) {
         let stats = default_index.as_composite().stats();
+        let level_sizes: Vec<u64> = stats
+            .levels
+            .iter()
+            .map(|level| default_index_store.segment_file_size(level.name.as_ref().unwrap()))
+            .try_collect()?;
         writeln!(ui.stdout(), "Number of commits: {}", stats.num_commits)?;
         writeln!(ui.stdout(), "Number of merges: {}", stats.num_merges)?;
         writeln!(
@@ -53,11 +73,17 @@ pub fn cmd_debug_index(
         )?;
         writeln!(ui.stdout(), "Number of heads: {}", stats.num_heads)?;
         writeln!(ui.stdout(), "Number of changes: {}", stats.num_changes)?;
+        writeln!(
+            ui.stdout(),
+            "Size on disk: {} bytes",
+            level_sizes.iter().sum::<u64>()
+        )?;
         writeln!(ui.stdout(), "Stats per level:")?;
-        for (i, level) in stats.levels.iter().enumerate() {
+        for (i, (level, size)) in stats.levels.iter().zip(&level_sizes).enumerate() {
             writeln!(ui.stdout(), "  Level {i}:")?;
             writeln!(ui.stdout(), "    Number of commits: {}", level.num_commits)?;
             writeln!(ui.stdout(), "    Name: {}", level.name.as_ref().unwrap())?;
+            writeln!(ui.stdout(), "    Size on disk: {size} bytes")?;
         }
     } else {
         return Err(user_error(format!(
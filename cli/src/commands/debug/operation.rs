@@ -18,19 +18,30 @@ use std::io::Write as _;
 use clap_complete::ArgValueCandidates;
 use jj_lib::object_id::ObjectId;
 use jj_lib::op_walk;
+use jj_lib::simple_op_store::SimpleOpStore;
 
 use crate::cli_util::CommandHelper;
+use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::ui::Ui;
 
 /// Show information about an operation and its view
+///
+/// The operation is read directly from the operation store without loading the
+/// repo, so this can be used to inspect a corrupted operation log.
 #[derive(clap::Args, Clone, Debug)]
 pub struct DebugOperationArgs {
     #[arg(default_value = "@", add = ArgValueCandidates::new(complete::operations))]
     operation: String,
     #[arg(long, value_enum, default_value = "all")]
     display: OperationDisplay,
+    /// Dump the raw stored bytes of the operation or the view
+    ///
+    /// Requires `--display=operation` or `--display=view`.
+    #[arg(long)]
+    binary: bool,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
@@ -54,6 +65,34 @@ pub fn cmd_debug_operation(
     // even if e.g. the view object is broken.
     let workspace = command.load_workspace()?;
     let repo_loader = workspace.repo_loader();
+    if args.binary {
+        let op_store = repo_loader.op_store();
+        let Some(simple_op_store) = op_store.as_any().downcast_ref::<SimpleOpStore>() else {
+            return Err(user_error(format!(
+                "Cannot read raw objects from the {} operation store",
+                op_store.name()
+            )));
+        };
+        let bytes = match args.display {
+            // Don't decode the operation, which may be broken
+            OperationDisplay::Operation => {
+                let op_id = op_walk::resolve_op_id_for_load(repo_loader, &args.operation)?;
+                simple_op_store.read_raw_operation(&op_id)?
+            }
+            OperationDisplay::View => {
+                let op = op_walk::resolve_op_for_load(repo_loader, &args.operation)?;
+                simple_op_store.read_raw_view(op.view_id())?
+            }
+            OperationDisplay::Id | OperationDisplay::All => {
+                return Err(user_error_with_hint(
+                    "--binary can only dump a single object",
+                    "Use --display=operation or --display=view",
+                ));
+            }
+        };
+        ui.stdout().write_all(&bytes)?;
+        return Ok(());
+    }
     let op = op_walk::resolve_op_for_load(repo_loader, &args.operation)?;
     if args.display == OperationDisplay::Id {
         writeln!(ui.stdout(), "{}", op.id().hex())?;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::fmt::Debug;
+use std::io::Write as _;
 
 use crate::cli_util::CommandHelper;
 use crate::command_error::CommandError;
@@ -20,14 +21,31 @@ use crate::ui::Ui;
 
 /// Trigger a snapshot in the op log
 #[derive(clap::Args, Clone, Debug)]
-pub struct DebugSnapshotArgs {}
+pub struct DebugSnapshotArgs {
+    /// Print statistics about the snapshot
+    ///
+    /// The number of files scanned and hashed, the number of bytes read, and
+    /// the time spent in each phase of the snapshot are printed.
+    #[arg(long)]
+    stats: bool,
+}
 
 pub fn cmd_debug_snapshot(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &DebugSnapshotArgs,
+    args: &DebugSnapshotArgs,
 ) -> Result<(), CommandError> {
     // workspace helper will snapshot as needed
-    command.workspace_helper(ui)?;
+    let (_workspace_command, stats) = command.workspace_helper_with_stats(ui)?;
+    if args.stats {
+        let mut formatter = ui.stdout_formatter();
+        writeln!(formatter, "Files scanned: {}", stats.files_scanned)?;
+        writeln!(formatter, "Files hashed: {}", stats.files_hashed)?;
+        writeln!(formatter, "Bytes read: {}", stats.bytes_read)?;
+        writeln!(formatter, "Phases:")?;
+        for (phase, duration) in &stats.phase_durations {
+            writeln!(formatter, "  {phase}: {duration:?}")?;
+        }
+    }
     Ok(())
 }
@@ -35,8 +35,13 @@ use crate::ui::Ui;
 pub enum DebugWatchmanCommand {
     /// Check whether `watchman` is enabled and whether it's correctly installed
     Status,
+    /// Print the clock watchman returns for the working copy
     QueryClock,
+    /// Print the files watchman reports as changed since the last snapshot
+    #[command(visible_alias = "query")]
     QueryChangedFiles,
+    /// Reset the stored watchman clock so the next snapshot scans all files
+    #[command(visible_alias = "reset")]
     ResetClock,
 }
 
@@ -54,6 +59,20 @@ pub fn cmd_debug_watchman(
         // The value is likely irrelevant here. TODO(ilyagr): confirm
         register_trigger: false,
     };
+    // Querying or resetting the clock of a disabled watchman would only report
+    // state that isn't used by snapshots.
+    if !matches!(subcommand, DebugWatchmanCommand::Status)
+        && matches!(
+            workspace_command.settings().fsmonitor_settings()?,
+            FsmonitorSettings::None
+        )
+    {
+        writeln!(
+            ui.status(),
+            r#"Watchman is disabled, nothing to do. Set `core.fsmonitor="watchman"` to enable."#
+        )?;
+        return Ok(());
+    }
     match subcommand {
         DebugWatchmanCommand::Status => {
             // TODO(ilyagr): It would be nice to add colors here
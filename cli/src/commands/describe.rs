@@ -86,9 +86,11 @@ pub(crate) struct DescribeArgs {
     /// Set author to the provided string
     ///
     /// This changes author name and email while retaining author
-    /// timestamp for non-discardable commits.
+    /// timestamp for non-discardable commits. The string must be in the form
+    /// `Name <email>`.
     #[arg(
         long,
+        value_name = "AUTHOR",
         conflicts_with = "reset_author",
         value_parser = parse_author
     )]
@@ -114,7 +116,7 @@ pub(crate) fn cmd_describe(
         writeln!(ui.status(), "No revisions to describe.")?;
         return Ok(());
     }
-    workspace_command.check_rewritable(commits.iter().ids())?;
+    workspace_command.check_rewritable(ui, commits.iter().ids())?;
     let text_editor = workspace_command.text_editor()?;
 
     let mut tx = workspace_command.start_transaction();
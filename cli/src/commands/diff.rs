@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::mem;
+
 use clap_complete::ArgValueCandidates;
 use clap_complete::ArgValueCompleter;
-use itertools::Itertools;
+use jj_lib::commit::Commit;
 use jj_lib::copies::CopyRecords;
-use jj_lib::repo::Repo;
 use jj_lib::rewrite::merge_commit_trees;
 use tracing::instrument;
 
@@ -25,7 +26,6 @@ use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::command_error::CommandError;
 use crate::complete;
-use crate::diff_util::get_copy_records;
 use crate::diff_util::DiffFormatArgs;
 use crate::ui::Ui;
 
@@ -41,8 +41,10 @@ use crate::ui::Ui;
 /// commit. For example, `jj diff --from main` shows the changes from "main"
 /// (perhaps a bookmark name) to the working-copy commit.
 #[derive(clap::Args, Clone, Debug)]
+#[command(mut_arg("context", |a| a.short('U')))]
 #[command(mut_arg("ignore_all_space", |a| a.short('w')))]
 #[command(mut_arg("ignore_space_change", |a| a.short('b')))]
+#[command(mut_arg("null_terminated", |a| a.short('z')))]
 pub(crate) struct DiffArgs {
     /// Show changes in this revision, compared to its parent(s)
     ///
@@ -74,6 +76,18 @@ pub(crate) struct DiffArgs {
         add = ArgValueCandidates::new(complete::all_revisions)
     )]
     to: Option<RevisionArg>,
+    /// Show changes from the merge base of this revision and the target
+    ///
+    /// The target is the revision given by `-r` or `--to`, or @ by default.
+    /// This shows the changes made since the target forked from this revision,
+    /// like `git diff REV...`.
+    #[arg(
+        long,
+        conflicts_with = "from",
+        value_name = "REVSET",
+        add = ArgValueCandidates::new(complete::all_revisions)
+    )]
+    merge_base: Option<RevisionArg>,
     /// Restrict the diff to these paths
     #[arg(
         value_name = "FILESETS",
@@ -81,6 +95,12 @@ pub(crate) struct DiffArgs {
         add = ArgValueCompleter::new(complete::modified_revision_or_range_files),
     )]
     paths: Vec<String>,
+    /// Swap the two sides of the diff
+    ///
+    /// Additions are shown as removals and vice versa, which is useful for
+    /// generating a patch that reverts the changes.
+    #[arg(long)]
+    reverse: bool,
     #[command(flatten)]
     format: DiffFormatArgs,
 }
@@ -99,30 +119,44 @@ pub(crate) fn cmd_diff(
         workspace_command.resolve_single_rev(ui, r.as_ref().unwrap_or(&RevisionArg::AT))
     };
 
-    let from_tree;
-    let to_tree;
+    let diff_renderer = workspace_command.diff_renderer_for(&args.format)?;
+    let mut from_tree;
+    let mut to_tree;
     let mut copy_records = CopyRecords::default();
-    if args.from.is_some() || args.to.is_some() {
+    // Copies are detected in the direction the diff is shown.
+    let mut add_copy_records = |from: &Commit, to: &Commit| {
+        let (from, to) = if args.reverse { (to, from) } else { (from, to) };
+        diff_renderer.add_copy_records(&mut copy_records, from.id(), to.id(), &matcher)
+    };
+    if let Some(merge_base_arg) = &args.merge_base {
+        let to = resolve_revision(&args.to.clone().or_else(|| args.revision.clone()))?;
+        let other = workspace_command.resolve_single_rev(ui, merge_base_arg)?;
+        let from = workspace_command.resolve_merge_base(&[other, to.clone()])?;
+        from_tree = from.tree()?;
+        to_tree = to.tree()?;
+
+        add_copy_records(&from, &to)?;
+    } else if args.from.is_some() || args.to.is_some() {
         let from = resolve_revision(&args.from)?;
         let to = resolve_revision(&args.to)?;
         from_tree = from.tree()?;
         to_tree = to.tree()?;
 
-        let records = get_copy_records(repo.store(), from.id(), to.id(), &matcher)?;
-        copy_records.add_records(records)?;
+        add_copy_records(&from, &to)?;
     } else {
         let to = resolve_revision(&args.revision)?;
-        let parents: Vec<_> = to.parents().try_collect()?;
+        let parents = to.store().get_commits(to.parent_ids())?;
         from_tree = merge_commit_trees(repo.as_ref(), &parents)?;
         to_tree = to.tree()?;
 
         for p in &parents {
-            let records = get_copy_records(repo.store(), p.id(), to.id(), &matcher)?;
-            copy_records.add_records(records)?;
+            add_copy_records(p, &to)?;
         }
     }
+    if args.reverse {
+        mem::swap(&mut from_tree, &mut to_tree);
+    }
 
-    let diff_renderer = workspace_command.diff_renderer_for(&args.format)?;
     ui.request_pager();
     diff_renderer.show_diff(
         ui,
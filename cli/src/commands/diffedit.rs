@@ -12,17 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs;
+use std::io;
+use std::io::Read as _;
 use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
 
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools;
+use jj_lib::backend::MergedTreeId;
+use jj_lib::backend::TreeValue;
 use jj_lib::matchers::EverythingMatcher;
+use jj_lib::merge::Merge;
+use jj_lib::merged_tree::MergedTree;
+use jj_lib::merged_tree::MergedTreeBuilder;
 use jj_lib::object_id::ObjectId;
+use jj_lib::patch::apply_hunks;
+use jj_lib::patch::parse_patch;
+use jj_lib::repo_path::RepoPathBuf;
 use jj_lib::rewrite::merge_commit_trees;
+use pollster::FutureExt as _;
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
+use crate::command_error::user_error;
+use crate::command_error::user_error_with_message;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::ui::Ui;
@@ -43,6 +59,11 @@ use crate::ui::Ui;
 /// updated. Unless `--restore-descendants` is used, descendants will be
 /// rebased on top as usual, which may result in conflicts.
 ///
+/// With the `--apply` option, applies a patch in the unified diff format (as
+/// produced by `jj diff --git` or `git diff`) to the revision instead of
+/// starting a diff editor. Hunks which don't apply cleanly are reported and
+/// skipped, the others are still applied.
+///
 /// See `jj restore` if you want to move entire files from one revision to
 /// another. For moving changes between revisions, see `jj squash -i`.
 #[derive(clap::Args, Clone, Debug)]
@@ -80,6 +101,12 @@ pub(crate) struct DiffeditArgs {
     /// Specify diff editor to be used
     #[arg(long, value_name = "NAME")]
     tool: Option<String>,
+    /// Apply a patch instead of starting a diff editor
+    ///
+    /// Paths in the patch are relative to the workspace root. Use `-` to read
+    /// the patch from stdin.
+    #[arg(long, value_name = "PATH", conflicts_with = "tool", value_hint = clap::ValueHint::FilePath)]
+    apply: Option<PathBuf>,
     /// Preserve the content (not the diff) when rebasing descendants
     ///
     /// When rebasing a descendant on top of the rewritten revision, its diff
@@ -114,9 +141,13 @@ pub(crate) fn cmd_diffedit(
         base_commits = target_commit.parents().try_collect()?;
         diff_description = "The diff initially shows the commit's changes.".to_string();
     };
-    workspace_command.check_rewritable([target_commit.id()])?;
+    workspace_command.check_rewritable(ui, [target_commit.id()])?;
 
-    let diff_editor = workspace_command.diff_editor(ui, args.tool.as_deref())?;
+    let diff_editor = if args.apply.is_none() {
+        Some(workspace_command.diff_editor(ui, args.tool.as_deref())?)
+    } else {
+        None
+    };
     let mut tx = workspace_command.start_transaction();
     let format_instructions = || {
         format!(
@@ -132,7 +163,20 @@ don't make any changes, then the operation will be aborted.",
     };
     let base_tree = merge_commit_trees(tx.repo(), base_commits.as_slice())?;
     let tree = target_commit.tree()?;
-    let tree_id = diff_editor.edit(&base_tree, &tree, &EverythingMatcher, format_instructions)?;
+    let tree_id = if let Some(diff_editor) = &diff_editor {
+        diff_editor.edit(&base_tree, &tree, &EverythingMatcher, format_instructions)?
+    } else {
+        let patch_path = args.apply.as_ref().unwrap();
+        let patch = read_patch(command.cwd(), patch_path)?;
+        let (tree_id, num_rejected, num_hunks) = apply_patch(ui, &tree, &patch)?;
+        if num_rejected > 0 {
+            writeln!(
+                ui.warning_default(),
+                "Failed to apply {num_rejected} of {num_hunks} hunks"
+            )?;
+        }
+        tree_id
+    };
     if tree_id == *target_commit.tree_id() {
         writeln!(ui.status(), "Nothing changed.")?;
     } else {
@@ -166,3 +210,113 @@ don't make any changes, then the operation will be aborted.",
     }
     Ok(())
 }
+
+fn read_patch(cwd: &Path, path: &Path) -> Result<Vec<u8>, CommandError> {
+    let mut patch = vec![];
+    if path.as_os_str() == "-" {
+        io::stdin()
+            .read_to_end(&mut patch)
+            .map_err(|err| user_error_with_message("Failed to read patch from stdin", err))?;
+    } else {
+        patch = fs::read(cwd.join(path)).map_err(|err| {
+            user_error_with_message(format!("Failed to read patch {}", path.display()), err)
+        })?;
+    }
+    Ok(patch)
+}
+
+/// Applies the `patch` to the `tree`. Returns the new tree id, the number of
+/// rejected hunks, and the total number of hunks.
+fn apply_patch(
+    ui: &Ui,
+    tree: &MergedTree,
+    patch: &[u8],
+) -> Result<(MergedTreeId, usize, usize), CommandError> {
+    let store = tree.store();
+    let file_patches = parse_patch(patch).map_err(|err| user_error(err.to_string()))?;
+    let parse_path = |path: &str| {
+        RepoPathBuf::from_relative_path(path)
+            .map_err(|err| user_error_with_message(format!("Invalid path in patch: {path}"), err))
+    };
+    let mut tree_builder = MergedTreeBuilder::new(tree.id());
+    let mut num_rejected = 0;
+    let mut num_hunks = 0;
+    for file_patch in &file_patches {
+        num_hunks += file_patch.hunks.len();
+        let old_path = file_patch.old_path.as_deref().map(parse_path).transpose()?;
+        let new_path = file_patch.new_path.as_deref().map(parse_path).transpose()?;
+        // parse_patch() rejects files without any path
+        let Some(target_path) = new_path.clone().or_else(|| old_path.clone()) else {
+            return Err(user_error("Invalid patch: file without path"));
+        };
+        let display_path = target_path.as_internal_file_string();
+
+        let (old_content, old_executable) = if let Some(old_path) = &old_path {
+            match tree.path_value(old_path)?.into_resolved() {
+                Ok(Some(TreeValue::File { id, executable })) => {
+                    let mut content = vec![];
+                    store.read_file(old_path, &id)?.read_to_end(&mut content)?;
+                    (content, executable)
+                }
+                Ok(None) => {
+                    writeln!(
+                        ui.warning_default(),
+                        "Cannot apply patch to {display_path}: the file doesn't exist"
+                    )?;
+                    num_rejected += file_patch.hunks.len();
+                    continue;
+                }
+                _ => {
+                    writeln!(
+                        ui.warning_default(),
+                        "Cannot apply patch to {display_path}: not a regular file or conflicted"
+                    )?;
+                    num_rejected += file_patch.hunks.len();
+                    continue;
+                }
+            }
+        } else {
+            if tree.path_value(&target_path)?.is_present() {
+                writeln!(
+                    ui.warning_default(),
+                    "Cannot add {display_path}: the file already exists"
+                )?;
+                num_rejected += file_patch.hunks.len();
+                continue;
+            }
+            (vec![], false)
+        };
+        let executable = file_patch.executable.unwrap_or(old_executable);
+
+        let patched = apply_hunks(&old_content, &file_patch.hunks);
+        for &index in &patched.rejected_hunks {
+            writeln!(
+                ui.warning_default(),
+                "Rejected hunk #{} ({}) in {display_path}",
+                index + 1,
+                file_patch.hunks[index]
+            )?;
+        }
+        num_rejected += patched.rejected_hunks.len();
+
+        // Keep a file to be deleted if some of its hunks couldn't be removed.
+        if new_path.is_none() && patched.rejected_hunks.is_empty() {
+            tree_builder.set_or_remove(target_path, Merge::absent());
+            continue;
+        }
+        if let Some(old_path) = old_path {
+            if old_path != target_path {
+                tree_builder.set_or_remove(old_path, Merge::absent());
+            }
+        }
+        let id = store
+            .write_file(&target_path, &mut patched.content.as_slice())
+            .block_on()?;
+        tree_builder.set_or_remove(
+            target_path,
+            Merge::normal(TreeValue::File { id, executable }),
+        );
+    }
+    let tree_id = tree_builder.write_tree(store)?;
+    Ok((tree_id, num_rejected, num_hunks))
+}
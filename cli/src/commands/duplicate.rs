@@ -135,7 +135,7 @@ pub(crate) fn cmd_duplicate(
             .into_iter()
             .collect_vec();
         children_commit_ids = children_commits.iter().ids().cloned().collect();
-        workspace_command.check_rewritable(&children_commit_ids)?;
+        workspace_command.check_rewritable(ui, &children_commit_ids)?;
         let children_expression = RevsetExpression::commits(children_commit_ids.clone());
         let parents_expression = RevsetExpression::commits(parent_commit_ids.clone());
         ensure_no_commit_loop(
@@ -149,7 +149,7 @@ pub(crate) fn cmd_duplicate(
             .into_iter()
             .collect_vec();
         children_commit_ids = children_commits.iter().ids().cloned().collect();
-        workspace_command.check_rewritable(&children_commit_ids)?;
+        workspace_command.check_rewritable(ui, &children_commit_ids)?;
         let children_expression = RevsetExpression::commits(children_commit_ids.clone());
         let parents_expression = children_expression.parents();
         ensure_no_commit_loop(
@@ -178,7 +178,7 @@ pub(crate) fn cmd_duplicate(
             .map_err(|err| err.expect_backend_error())?
             .iter()
             .try_collect()?;
-        workspace_command.check_rewritable(&children_commit_ids)?;
+        workspace_command.check_rewritable(ui, &children_commit_ids)?;
         ensure_no_commit_loop(
             workspace_command.repo(),
             &children_expression,
@@ -19,7 +19,8 @@ use itertools::Itertools;
 use jj_lib::backend::BackendError;
 use jj_lib::backend::CommitId;
 use jj_lib::commit::Commit;
-use jj_lib::dag_walk::topo_order_reverse_ok;
+use jj_lib::commit::CommitByCommitterTimestamp;
+use jj_lib::dag_walk::topo_order_reverse_lazy_ok;
 use jj_lib::graph::reverse_graph;
 use jj_lib::graph::GraphEdge;
 use jj_lib::graph::GraphNode;
@@ -31,6 +32,7 @@ use crate::cli_util::format_template;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::LogContentFormat;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::TemplateFileArgs;
 use crate::command_error::CommandError;
 use crate::commit_templater::CommitTemplateLanguage;
 use crate::complete;
@@ -66,6 +68,8 @@ pub(crate) struct EvologArgs {
     /// For the syntax, see https://jj-vcs.github.io/jj/latest/templates/
     #[arg(long, short = 'T')]
     template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
     /// Show patch compared to the previous version of this change
     ///
     /// If the previous version has different parents, it will be temporarily
@@ -95,8 +99,11 @@ pub(crate) fn cmd_evolog(
     let node_template;
     {
         let language = workspace_command.commit_template_language();
-        let template_string = match &args.template {
-            Some(value) => value.to_string(),
+        let template_string = match args
+            .template_file
+            .template_text(command.cwd(), args.template.as_deref())?
+        {
+            Some(value) => value,
             None => workspace_command.settings().get_string("templates.log")?,
         };
         template = workspace_command
@@ -105,7 +112,8 @@ pub(crate) fn cmd_evolog(
                 &language,
                 &template_string,
                 CommitTemplateLanguage::wrap_commit,
-            )?
+            )
+            .map_err(|err| args.template_file.annotate_parse_error(err))?
             .labeled("log");
         node_template = workspace_command
             .parse_template(
@@ -121,28 +129,32 @@ pub(crate) fn cmd_evolog(
     let mut formatter = ui.stdout_formatter();
     let formatter = formatter.as_mut();
 
-    let mut commits = topo_order_reverse_ok(
-        vec![Ok(start_commit)],
-        |commit: &Commit| commit.id().clone(),
-        |commit: &Commit| {
-            let mut predecessors = commit.predecessors().collect_vec();
-            // Predecessors don't need to follow any defined order. However in
-            // practice, if there are multiple predecessors, then usually the
-            // first predecessor is the previous version of the same change, and
-            // the other predecessors are commits that were squashed into it. If
-            // multiple commits are squashed at once, then they are usually
-            // recorded in chronological order. We want to show squashed commits
-            // in reverse chronological order, and we also want to show squashed
-            // commits before the squash destination (since the destination's
-            // subgraph may contain earlier squashed commits as well), so we
-            // visit the predecessors in reverse order.
-            predecessors.reverse();
-            predecessors
+    // Walk lazily so that --limit doesn't have to load the whole history.
+    let commits_iter = topo_order_reverse_lazy_ok(
+        [Ok(CommitByCommitterTimestamp(start_commit))],
+        |CommitByCommitterTimestamp(commit)| commit.id().clone(),
+        |CommitByCommitterTimestamp(commit)| {
+            // The committer timestamps order the linear parts of the walk, but
+            // where the graph branches, predecessors are visited in the order
+            // returned here. If there are multiple predecessors, the first one
+            // is usually the previous version of the same change, and the
+            // others are commits squashed into it in chronological order. We
+            // want squashed commits in reverse chronological order, and before
+            // the squash destination (whose subgraph may contain earlier
+            // squashed commits), so visit the predecessors in reverse order.
+            commit
+                .predecessor_ids()
+                .iter()
+                .rev()
+                .map(|id| commit.store().get_commit(id))
+                .map_ok(CommitByCommitterTimestamp)
+                .collect_vec()
         },
-    )?;
-    if let Some(n) = args.limit {
-        commits.truncate(n);
-    }
+    )
+    .map_ok(|CommitByCommitterTimestamp(commit)| commit);
+    let mut commits: Vec<Commit> = commits_iter
+        .take(args.limit.unwrap_or(usize::MAX))
+        .try_collect()?;
     if !args.no_graph {
         let mut raw_output = formatter.raw()?;
         let mut graph = get_graphlog(graph_style, raw_output.as_mut());
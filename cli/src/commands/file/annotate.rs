@@ -23,6 +23,7 @@ use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::TemplateFileArgs;
 use crate::command_error::user_error;
 use crate::command_error::CommandError;
 use crate::complete;
@@ -34,7 +35,11 @@ use crate::ui::Ui;
 /// Annotates a revision line by line. Each line includes the source change that
 /// introduced the associated line. A path to the desired file must be provided.
 /// The per-line prefix for each line can be customized via
-/// template with the `templates.annotate_commit_summary` config variable.
+/// template with the `templates.annotate_commit_summary` config variable, or
+/// with the `--template` option.
+///
+/// If the file was renamed from another path, the history of the original
+/// path is followed when the rename can be detected.
 #[derive(clap::Args, Clone, Debug)]
 pub(crate) struct FileAnnotateArgs {
     /// the file to annotate
@@ -51,6 +56,14 @@ pub(crate) struct FileAnnotateArgs {
         add = ArgValueCandidates::new(complete::all_revisions)
     )]
     revision: Option<RevisionArg>,
+    /// Render the prefix of each line using the given template
+    ///
+    /// The template is evaluated for the commit which introduced the line.
+    /// For the syntax, see https://jj-vcs.github.io/jj/latest/templates/
+    #[arg(long, short = 'T')]
+    template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
 }
 
 #[instrument(skip_all)]
@@ -75,10 +88,18 @@ pub(crate) fn cmd_file_annotate(
         )));
     }
 
-    let annotate_commit_summary_text = workspace_command
-        .settings()
-        .get_string("templates.annotate_commit_summary")?;
-    let template = workspace_command.parse_commit_template(ui, &annotate_commit_summary_text)?;
+    let annotate_commit_summary_text = match args
+        .template_file
+        .template_text(command.cwd(), args.template.as_deref())?
+    {
+        Some(value) => value,
+        None => workspace_command
+            .settings()
+            .get_string("templates.annotate_commit_summary")?,
+    };
+    let template = workspace_command
+        .parse_commit_template(ui, &annotate_commit_summary_text)
+        .map_err(|err| args.template_file.annotate_parse_error(err))?;
 
     // TODO: Should we add an option to limit the domain to e.g. recent commits?
     // Note that this is probably different from "--skip REVS", which won't
@@ -29,19 +29,21 @@ use crate::ui::Ui;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
 enum ChmodMode {
-    /// Make a path non-executable (alias: normal)
+    /// Make a path non-executable (aliases: normal, -x)
     // We use short names for enum values so that errors say that the possible values are `n, x`.
-    #[value(name = "n", alias("normal"))]
+    #[value(name = "n", aliases(["normal", "-x"]))]
     Normal,
-    /// Make a path executable (alias: executable)
-    #[value(name = "x", alias("executable"))]
+    /// Make a path executable (aliases: executable, +x)
+    #[value(name = "x", aliases(["executable", "+x"]))]
     Executable,
 }
 
 /// Sets or removes the executable bit for paths in the repo
 ///
 /// Unlike the POSIX `chmod`, `jj file chmod` also works on Windows, on
-/// conflicted files, and on arbitrary revisions.
+/// conflicted files, and on arbitrary revisions. The mode can also be written
+/// as `+x` or `-x`. Since `-x` looks like a flag, it must come after `--`, e.g.
+/// `jj file chmod -- -x script.sh`.
 #[derive(clap::Args, Clone, Debug)]
 pub(crate) struct FileChmodArgs {
     mode: ChmodMode,
@@ -76,7 +78,7 @@ pub(crate) fn cmd_file_chmod(
 
     let mut workspace_command = command.workspace_helper(ui)?;
     let commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
-    workspace_command.check_rewritable([commit.id()])?;
+    workspace_command.check_rewritable(ui, [commit.id()])?;
     let tree = commit.tree()?;
     // TODO: No need to add special case for empty paths when switching to
     // parse_union_filesets(). paths = [] should be "none()" if supported.
@@ -0,0 +1,162 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap_complete::ArgValueCandidates;
+use clap_complete::ArgValueCompleter;
+use jj_lib::copies::find_copy_source;
+use jj_lib::fileset::FilesetExpression;
+use jj_lib::matchers::FilesMatcher;
+use jj_lib::repo::Repo as _;
+use jj_lib::revset::RevsetExpression;
+use jj_lib::revset::RevsetFilterPredicate;
+use jj_lib::revset::RevsetIteratorExt as _;
+use tracing::instrument;
+
+use crate::cli_util::CommandHelper;
+use crate::cli_util::LogContentFormat;
+use crate::cli_util::RevisionArg;
+use crate::cli_util::TemplateFileArgs;
+use crate::command_error::CommandError;
+use crate::commit_templater::CommitTemplateLanguage;
+use crate::complete;
+use crate::diff_util::DiffFormatArgs;
+use crate::ui::Ui;
+
+/// Show the revisions that modified a file, following renames
+///
+/// Lists the revisions which modified the file, starting at the given revision
+/// and going back in history. If the file was renamed or copied from another
+/// path, the revisions which modified the previous path are listed too.
+/// Renames are detected if the backend records them. The Git backend detects
+/// them by comparing the contents of removed and added files.
+///
+/// Unlike `jj log <FILE>`, the revisions are rendered as a flat list.
+#[derive(clap::Args, Clone, Debug)]
+pub(crate) struct FileLogArgs {
+    /// The file to show the history of
+    #[arg(
+        value_hint = clap::ValueHint::AnyPath,
+        add = ArgValueCompleter::new(complete::all_revision_files),
+    )]
+    path: String,
+    /// The revision to start at
+    #[arg(
+        long, short,
+        default_value = "@",
+        value_name = "REVSET",
+        add = ArgValueCandidates::new(complete::all_revisions),
+    )]
+    revision: RevisionArg,
+    /// Don't follow renames
+    #[arg(long)]
+    no_follow: bool,
+    /// Limit number of revisions to show
+    #[arg(long, short = 'n')]
+    limit: Option<usize>,
+    /// Render each revision using the given template
+    ///
+    /// For the syntax, see https://jj-vcs.github.io/jj/latest/templates/.
+    ///
+    /// If not specified, this defaults to the `templates.log` setting.
+    #[arg(long, short = 'T')]
+    template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
+    /// Show the changes to the file in each revision
+    #[arg(long, short = 'p')]
+    patch: bool,
+    #[command(flatten)]
+    diff_format: DiffFormatArgs,
+}
+
+#[instrument(skip_all)]
+pub(crate) fn cmd_file_log(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &FileLogArgs,
+) -> Result<(), CommandError> {
+    let workspace_command = command.workspace_helper(ui)?;
+    let settings = workspace_command.settings();
+    let repo = workspace_command.repo();
+    let store = repo.store();
+    let start_commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
+    let file_path = workspace_command.parse_file_path(&args.path)?;
+
+    let diff_renderer = workspace_command.diff_renderer_for_log(&args.diff_format, args.patch)?;
+    let with_content_format = LogContentFormat::new(ui, settings)?;
+    let template = {
+        let language = workspace_command.commit_template_language();
+        let template_string = match args
+            .template_file
+            .template_text(command.cwd(), args.template.as_deref())?
+        {
+            Some(value) => value,
+            None => settings.get_string("templates.log")?,
+        };
+        workspace_command
+            .parse_template(
+                ui,
+                &language,
+                &template_string,
+                CommitTemplateLanguage::wrap_commit,
+            )
+            .map_err(|err| args.template_file.annotate_parse_error(err))?
+            .labeled("log")
+    };
+
+    ui.request_pager();
+    let mut formatter = ui.stdout_formatter();
+    let formatter = formatter.as_mut();
+    let mut remaining = args.limit.unwrap_or(usize::MAX);
+    // Like `git log --follow`, a single path is followed at a time. When a
+    // revision renamed the file, the walk continues from the parent with the
+    // previous path.
+    let mut next = Some((start_commit.id().clone(), file_path));
+    while let Some((start_id, path)) = next.take() {
+        let predicate = RevsetFilterPredicate::File(FilesetExpression::file_path(path.clone()));
+        let expression = RevsetExpression::commit(start_id)
+            .ancestors()
+            .intersection(&RevsetExpression::filter(predicate));
+        let revset = workspace_command
+            .attach_revset_evaluator(expression)
+            .evaluate()?;
+        for commit in revset.iter().commits(store) {
+            if remaining == 0 {
+                return Ok(());
+            }
+            remaining -= 1;
+            let commit = commit?;
+            let source = if args.no_follow {
+                None
+            } else {
+                find_copy_source(&commit, &path)?
+            };
+            with_content_format
+                .write(formatter, |formatter| template.format(&commit, formatter))?;
+            if let Some(renderer) = &diff_renderer {
+                let matcher = match &source {
+                    Some((_, source_path)) => FilesMatcher::new([&path, source_path]),
+                    None => FilesMatcher::new([&path]),
+                };
+                let width = ui.term_width();
+                renderer.show_patch(ui, formatter, &commit, &matcher, width)?;
+            }
+            if let Some((parent, source_path)) = source {
+                next = Some((parent.id().clone(), source_path));
+                break;
+            }
+        }
+    }
+    Ok(())
+}
@@ -18,11 +18,13 @@ use std::io::Write;
 use clap_complete::ArgValueCandidates;
 use clap_complete::ArgValueCompleter;
 use jj_lib::backend::BackendResult;
+use jj_lib::backend::TreeValue;
 use jj_lib::conflicts::materialize_merge_result;
 use jj_lib::conflicts::materialize_tree_value;
 use jj_lib::conflicts::MaterializedTreeValue;
 use jj_lib::fileset::FilePattern;
 use jj_lib::fileset::FilesetExpression;
+use jj_lib::merge::Merge;
 use jj_lib::merge::MergedTreeValue;
 use jj_lib::repo::Repo;
 use jj_lib::repo_path::RepoPath;
@@ -42,6 +44,9 @@ use crate::ui::Ui;
 ///
 /// If the given path is a directory, files in the directory will be visited
 /// recursively.
+///
+/// Conflicted files are printed with conflict markers, unless `--conflict-side`
+/// is used to pick the content of one side of the conflict.
 #[derive(clap::Args, Clone, Debug)]
 pub(crate) struct FileShowArgs {
     /// The revision to get the file contents from
@@ -60,6 +65,12 @@ pub(crate) struct FileShowArgs {
         add = ArgValueCompleter::new(complete::all_revision_files),
     )]
     paths: Vec<String>,
+    /// Print the given side of conflicted files instead of conflict markers
+    ///
+    /// Sides are numbered from 1, in the same order as in the conflict
+    /// markers.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    conflict_side: Option<u32>,
 }
 
 #[instrument(skip_all)]
@@ -84,7 +95,12 @@ pub(crate) fn cmd_file_show(
         }
         if !value.is_tree() {
             ui.request_pager();
-            write_tree_entries(ui, &workspace_command, [(path, Ok(value))])?;
+            write_tree_entries(
+                ui,
+                &workspace_command,
+                [(path, Ok(value))],
+                args.conflict_side,
+            )?;
             return Ok(());
         }
     }
@@ -95,6 +111,7 @@ pub(crate) fn cmd_file_show(
         ui,
         &workspace_command,
         tree.entries_matching(matcher.as_ref()),
+        args.conflict_side,
     )?;
     print_unmatched_explicit_paths(ui, &workspace_command, &fileset_expression, [&tree])?;
     Ok(())
@@ -116,10 +133,40 @@ fn write_tree_entries<P: AsRef<RepoPath>>(
     ui: &Ui,
     workspace_command: &WorkspaceCommandHelper,
     entries: impl IntoIterator<Item = (P, BackendResult<MergedTreeValue>)>,
+    conflict_side: Option<u32>,
 ) -> Result<(), CommandError> {
     let repo = workspace_command.repo();
     for (path, result) in entries {
         let value = result?;
+        let value = match conflict_side {
+            Some(side) if !value.is_resolved() => {
+                let ui_path = workspace_command.format_file_path(path.as_ref());
+                let Some(side_value) = value.get_add(side as usize - 1) else {
+                    return Err(user_error(format!(
+                        "The conflict at '{ui_path}' has only {} sides",
+                        value.num_sides()
+                    )));
+                };
+                match side_value {
+                    None => {
+                        writeln!(
+                            ui.warning_default(),
+                            "Path '{ui_path}' doesn't exist on side #{side} of the conflict"
+                        )?;
+                        continue;
+                    }
+                    Some(TreeValue::Tree(_)) => {
+                        writeln!(
+                            ui.warning_default(),
+                            "Path '{ui_path}' is not a file on side #{side} of the conflict"
+                        )?;
+                        continue;
+                    }
+                    Some(_) => Merge::resolved(side_value.clone()),
+                }
+            }
+            _ => value,
+        };
         let materialized = materialize_tree_value(repo.store(), path.as_ref(), value).block_on()?;
         match materialized {
             MaterializedTreeValue::Absent => panic!("absent values should be excluded"),
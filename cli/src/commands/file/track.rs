@@ -35,6 +35,10 @@ pub(crate) struct FileTrackArgs {
     /// Paths to track
     #[arg(required = true, value_name = "FILESETS", value_hint = clap::ValueHint::AnyPath)]
     paths: Vec<String>,
+    /// Track the specified files even if they exceed
+    /// `snapshot.max-new-file-size`
+    #[arg(long)]
+    include_large: bool,
 }
 
 #[instrument(skip_all)]
@@ -47,7 +51,10 @@ pub(crate) fn cmd_file_track(
     let matcher = workspace_command
         .parse_file_patterns(ui, &args.paths)?
         .to_matcher();
-    let options = workspace_command.snapshot_options_with_start_tracking_matcher(&matcher)?;
+    let mut options = workspace_command.snapshot_options_with_start_tracking_matcher(&matcher)?;
+    if args.include_large {
+        options.max_new_file_size = u64::MAX;
+    }
 
     let mut tx = workspace_command.start_transaction().into_inner();
     let (mut locked_ws, _wc_commit) = workspace_command.start_working_copy_mutation()?;
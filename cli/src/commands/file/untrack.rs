@@ -112,5 +112,29 @@ Make sure they're ignored, then try again.",
     let repo = tx.commit("untrack paths")?;
     locked_ws.finish(repo.op_id().clone())?;
     print_snapshot_stats(ui, &stats, workspace_command.env().path_converter())?;
+    // Untracking doesn't rewrite history, so files that were already committed
+    // will show up as deleted in the working-copy commit.
+    let parent_tree = wc_commit.parent_tree(repo.as_ref())?;
+    let committed_paths = parent_tree
+        .entries_matching(matcher.as_ref())
+        .map(|(path, _value)| path)
+        .collect_vec();
+    if !committed_paths.is_empty() {
+        writeln!(
+            ui.warning_default(),
+            "The following untracked files exist in the parent commit and will show as deleted in \
+             the working-copy commit:"
+        )?;
+        let mut formatter = ui.stderr_formatter();
+        for path in &committed_paths {
+            writeln!(formatter, "  {}", workspace_command.format_file_path(path))?;
+        }
+        drop(formatter);
+        writeln!(
+            ui.hint_default(),
+            "Untracking only affects the working-copy commit going forward. The files are not \
+             removed from existing commits."
+        )?;
+    }
     Ok(())
 }
@@ -80,7 +80,8 @@ use crate::ui::Ui;
 ///    which will be replaced with the repo-relative path of the file being
 ///    fixed. It is useful to provide the path to tools that include the path in
 ///    error messages, or behave differently based on the directory or file
-///    name.
+///    name. The same path is also available to the tool in the `JJ_FIX_PATH`
+///    environment variable, which is useful for wrapper scripts.
 ///  - `patterns`: Determines which files the tool will affect. If this list is
 ///    empty, no files will be affected by the tool. If there are multiple
 ///    patterns, the tool is applied only once to each file in the union of the
@@ -146,7 +147,7 @@ pub(crate) fn cmd_fix(
     }
     .evaluate_to_commit_ids()?
     .try_collect()?;
-    workspace_command.check_rewritable(root_commits.iter())?;
+    workspace_command.check_rewritable(ui, root_commits.iter())?;
     let matcher = workspace_command
         .parse_file_patterns(ui, &args.paths)?
         .to_matcher();
@@ -385,6 +386,10 @@ fn run_tool(
     vars.insert("path", tool_input.repo_path.as_internal_file_string());
     let mut child = tool_command
         .to_command_with_variables(&vars)
+        .env(
+            "JJ_FIX_PATH",
+            tool_input.repo_path.as_internal_file_string(),
+        )
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .spawn()
@@ -18,6 +18,8 @@ use std::io::Write;
 use std::num::NonZeroU32;
 use std::path::Path;
 
+use jj_lib::config::ConfigFile;
+use jj_lib::config::ConfigSource;
 use jj_lib::git;
 use jj_lib::git::GitFetchError;
 use jj_lib::git::GitFetchStats;
@@ -37,9 +39,20 @@ use crate::git_util::absolute_git_url;
 use crate::git_util::get_git_repo;
 use crate::git_util::map_git_error;
 use crate::git_util::print_git_import_stats;
+use crate::git_util::with_network_retries;
 use crate::git_util::with_remote_git_callbacks;
 use crate::ui::Ui;
 
+/// Layout of the colocated Git repo
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum GitLayout {
+    /// Git repo sharing the working copy with jj
+    #[default]
+    NonBare,
+    /// Bare Git repo without a working tree
+    Bare,
+}
+
 /// Create a new repo backed by a clone of a Git repo
 ///
 /// The Git repo will be a bare git repo stored inside the `.jj/` directory.
@@ -62,6 +75,20 @@ pub struct GitCloneArgs {
     /// Whether or not to colocate the Jujutsu repo with the git repo
     #[arg(long)]
     colocate: bool,
+    /// Layout of the colocated Git repo
+    ///
+    /// With `bare`, the `.git` directory is a bare Git repo. Git doesn't see
+    /// the working copy, and the repos aren't kept in sync automatically. Use
+    /// `jj git import` and `jj git export` to sync them.
+    #[arg(long, value_enum, default_value_t, requires = "colocate")]
+    git_layout: GitLayout,
+    /// Copy relevant settings from the Git config into the repo config
+    ///
+    /// `user.name`, `user.email`, and `remote.pushDefault` are imported as
+    /// `user.name`, `user.email`, and `git.push` respectively. Only the
+    /// repo-local Git config is read, not the global or system config.
+    #[arg(long)]
+    import_git_config: bool,
     /// Create a shallow clone of the given depth
     #[arg(long)]
     depth: Option<NonZeroU32>,
@@ -117,7 +144,13 @@ pub fn cmd_git_clone(
         .map_err(|err| user_error_with_message(format!("Failed to create {wc_path_str}"), err))?;
 
     let clone_result = (|| -> Result<_, CommandError> {
-        let mut workspace_command = init_workspace(ui, command, &canonical_wc_path, args.colocate)?;
+        let mut workspace_command = init_workspace(
+            ui,
+            command,
+            &canonical_wc_path,
+            args.colocate,
+            args.git_layout,
+        )?;
         let stats = fetch_new_remote(ui, &mut workspace_command, remote_name, &source, args.depth)?;
         Ok((workspace_command, stats))
     })();
@@ -144,6 +177,9 @@ pub fn cmd_git_clone(
     }
 
     let (mut workspace_command, stats) = clone_result?;
+    if args.import_git_config {
+        import_git_config(ui, &workspace_command)?;
+    }
     if let Some(default_branch) = &stats.default_branch {
         write_repository_level_trunk_alias(
             ui,
@@ -176,9 +212,14 @@ fn init_workspace(
     command: &CommandHelper,
     wc_path: &Path,
     colocate: bool,
+    git_layout: GitLayout,
 ) -> Result<WorkspaceCommandHelper, CommandError> {
     let settings = command.settings_for_new_workspace(wc_path)?;
-    let (workspace, repo) = if colocate {
+    let (workspace, repo) = if colocate && git_layout == GitLayout::Bare {
+        let git_repo_path = wc_path.join(".git");
+        git2::Repository::init_bare(&git_repo_path).map_err(map_git_error)?;
+        Workspace::init_external_git(&settings, wc_path, &git_repo_path)?
+    } else if colocate {
         Workspace::init_colocated_git(&settings, wc_path)?
     } else {
         Workspace::init_internal_git(&settings, wc_path)?
@@ -188,6 +229,46 @@ fn init_workspace(
     Ok(workspace_command)
 }
 
+/// Copies the user and remote settings from the Git config to the repo config.
+fn import_git_config(
+    ui: &Ui,
+    workspace_command: &WorkspaceCommandHelper,
+) -> Result<(), CommandError> {
+    const KEYS: [(&str, &str); 3] = [
+        ("user.name", "user.name"),
+        ("user.email", "user.email"),
+        ("remote.pushDefault", "git.push"),
+    ];
+    let git_repo = get_git_repo(workspace_command.repo().store())?;
+    // Only the repo-local config is imported, so the user's global Git
+    // settings aren't copied into every cloned repo.
+    let git_config = git_repo
+        .config()
+        .and_then(|config| config.open_level(git2::ConfigLevel::Local))
+        .map_err(map_git_error)?;
+    let config_path = workspace_command.repo_path().join("config.toml");
+    let mut file = ConfigFile::load_or_empty(ConfigSource::Repo, config_path)?;
+    let mut num_imported = 0;
+    for (git_key, jj_key) in KEYS {
+        let Ok(value) = git_config.get_string(git_key) else {
+            continue;
+        };
+        file.set_value(jj_key, &value)
+            .map_err(|err| user_error_with_message(format!("Failed to set {jj_key}"), err))?;
+        writeln!(
+            ui.status(),
+            r#"Imported Git config {git_key} as {jj_key} = "{value}""#
+        )?;
+        num_imported += 1;
+    }
+    if num_imported == 0 {
+        writeln!(ui.status(), "No Git config to import")?;
+    } else {
+        file.save()?;
+    }
+    Ok(())
+}
+
 fn fetch_new_remote(
     ui: &Ui,
     workspace_command: &mut WorkspaceCommandHelper,
@@ -205,16 +286,18 @@ fn fetch_new_remote(
     let git_settings = workspace_command.settings().git_settings()?;
     let mut fetch_tx = workspace_command.start_transaction();
 
-    let stats = with_remote_git_callbacks(ui, None, |cb| {
-        git::fetch(
-            fetch_tx.repo_mut(),
-            &git_repo,
-            remote_name,
-            &[StringPattern::everything()],
-            cb,
-            &git_settings,
-            depth,
-        )
+    let stats = with_network_retries(ui, &git_settings, GitFetchError::is_transient, || {
+        with_remote_git_callbacks(ui, None, |cb| {
+            git::fetch(
+                fetch_tx.repo_mut(),
+                &git_repo,
+                remote_name,
+                &[StringPattern::everything()],
+                cb,
+                &git_settings,
+                depth,
+            )
+        })
     })
     .map_err(|err| match err {
         GitFetchError::NoSuchRemote(_) => {
@@ -12,19 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io;
+
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools;
 use jj_lib::config::ConfigGetResultExt as _;
+use jj_lib::git::GitImportStats;
+use jj_lib::git::RefName;
 use jj_lib::repo::Repo;
 use jj_lib::settings::UserSettings;
 use jj_lib::str_util::StringPattern;
 
 use crate::cli_util::CommandHelper;
+use crate::command_error::user_error;
 use crate::command_error::CommandError;
 use crate::commands::git::get_single_remote;
 use crate::complete;
 use crate::git_util::get_git_repo;
 use crate::git_util::git_fetch;
+use crate::git_util::git_fetch_remote;
+use crate::git_util::print_git_import_stats;
+use crate::git_util::warn_if_branches_not_found;
 use crate::ui::Ui;
 
 /// Fetch from a Git remote
@@ -58,6 +66,12 @@ pub struct GitFetchArgs {
     )]
     remotes: Vec<String>,
     /// Fetch from all remotes
+    ///
+    /// Each remote is fetched in turn, and the number of new, updated, and
+    /// deleted bookmarks is reported per remote. If fetching from a remote
+    /// fails, the other remotes are still fetched and recorded in the same
+    /// operation, and the command exits with an error listing the failed
+    /// remotes.
     #[arg(long, conflicts_with = "remotes")]
     all_remotes: bool,
 }
@@ -78,17 +92,84 @@ pub fn cmd_git_fetch(
         args.remotes.clone()
     };
     let mut tx = workspace_command.start_transaction();
-    git_fetch(ui, &mut tx, &git_repo, &remotes, &args.branch)?;
+    if !args.all_remotes {
+        git_fetch(ui, &mut tx, &git_repo, &remotes, &args.branch)?;
+        tx.finish(
+            ui,
+            format!("fetch from git remote(s) {}", remotes.iter().join(",")),
+        )?;
+        return Ok(());
+    }
+
+    // Fetch from each remote in turn, but don't let a failing remote prevent
+    // the others from being fetched.
+    let mut fetched_remotes = vec![];
+    let mut failed_remotes = vec![];
+    for remote in &remotes {
+        match git_fetch_remote(ui, &mut tx, &git_repo, remote, &args.branch) {
+            Ok(stats) => {
+                print_git_import_stats(ui, tx.repo(), &stats, true)?;
+                print_remote_summary(ui, remote, &stats)?;
+                fetched_remotes.push(remote.as_str());
+            }
+            Err(err) => {
+                writeln!(
+                    ui.warning_default(),
+                    "Failed to fetch from remote {remote}: {}",
+                    err.error
+                )?;
+                failed_remotes.push(remote.as_str());
+            }
+        }
+    }
+    warn_if_branches_not_found(
+        ui,
+        &tx,
+        &args.branch,
+        &fetched_remotes
+            .iter()
+            .map(|remote| StringPattern::exact(*remote))
+            .collect_vec(),
+    )?;
     tx.finish(
         ui,
-        format!("fetch from git remote(s) {}", remotes.iter().join(",")),
+        format!("fetch from git remote(s) {}", fetched_remotes.join(",")),
     )?;
-    Ok(())
+    if failed_remotes.is_empty() {
+        Ok(())
+    } else {
+        Err(user_error(format!(
+            "Failed to fetch from {} remote(s): {}",
+            failed_remotes.len(),
+            failed_remotes.join(", ")
+        )))
+    }
+}
+
+/// Prints the number of bookmarks created, updated, and deleted on `remote`.
+fn print_remote_summary(ui: &Ui, remote: &str, stats: &GitImportStats) -> io::Result<()> {
+    let (mut new, mut updated, mut deleted) = (0, 0, 0);
+    for (ref_name, (old_remote_ref, new_target)) in &stats.changed_remote_refs {
+        if !matches!(ref_name, RefName::RemoteBranch { .. }) {
+            continue;
+        }
+        if old_remote_ref.target.is_absent() {
+            new += 1;
+        } else if new_target.is_absent() {
+            deleted += 1;
+        } else {
+            updated += 1;
+        }
+    }
+    writeln!(
+        ui.status(),
+        "Fetched from {remote}: {new} new, {updated} updated, {deleted} deleted bookmarks"
+    )
 }
 
 const DEFAULT_REMOTE: &str = "origin";
 
-fn get_default_fetch_remotes(
+pub(super) fn get_default_fetch_remotes(
     ui: &Ui,
     settings: &UserSettings,
     git_repo: &git2::Repository,
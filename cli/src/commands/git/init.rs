@@ -167,7 +167,7 @@ fn do_init(
             // Import refs first so all the reachable commits are indexed in
             // chronological order.
             let colocated = is_colocated_git_workspace(&workspace, &repo);
-            let repo = init_git_refs(ui, repo, command.string_args(), colocated)?;
+            let repo = init_git_refs(ui, repo, command, colocated)?;
             let mut workspace_command = command.for_workable_repo(ui, workspace, repo)?;
             maybe_add_gitignore(&workspace_command)?;
             workspace_command.maybe_snapshot(ui)?;
@@ -200,11 +200,11 @@ fn do_init(
 fn init_git_refs(
     ui: &mut Ui,
     repo: Arc<ReadonlyRepo>,
-    string_args: &[String],
+    command: &CommandHelper,
     colocated: bool,
 ) -> Result<Arc<ReadonlyRepo>, CommandError> {
     let mut git_settings = repo.settings().git_settings()?;
-    let mut tx = start_repo_transaction(&repo, string_args);
+    let mut tx = start_repo_transaction(&repo, command);
     // There should be no old refs to abandon, but enforce it.
     git_settings.abandon_unreachable_commits = false;
     let stats = git::import_some_refs(
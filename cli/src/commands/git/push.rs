@@ -17,6 +17,8 @@ use std::collections::HashSet;
 use std::fmt;
 use std::io;
 use std::io::Write;
+use std::iter;
+use std::slice;
 
 use clap::ArgGroup;
 use clap_complete::ArgValueCandidates;
@@ -31,12 +33,18 @@ use jj_lib::git::GitBranchPushTargets;
 use jj_lib::git::GitPushError;
 use jj_lib::object_id::ObjectId;
 use jj_lib::op_store::RefTarget;
+use jj_lib::op_store::RemoteRef;
+use jj_lib::op_store::RemoteRefState;
 use jj_lib::refs::classify_bookmark_push_action;
 use jj_lib::refs::BookmarkPushAction;
 use jj_lib::refs::BookmarkPushUpdate;
 use jj_lib::refs::LocalAndRemoteRef;
 use jj_lib::repo::Repo;
 use jj_lib::revset::RevsetExpression;
+use jj_lib::revset::RevsetIteratorExt as _;
+use jj_lib::rewrite::move_commits;
+use jj_lib::rewrite::MoveCommitsTarget;
+use jj_lib::rewrite::RebaseOptions;
 use jj_lib::settings::UserSettings;
 use jj_lib::signing::SignBehavior;
 use jj_lib::str_util::StringPattern;
@@ -48,14 +56,18 @@ use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::cli_util::WorkspaceCommandHelper;
 use crate::cli_util::WorkspaceCommandTransaction;
+use crate::command_error::conflict_error_with_hint;
 use crate::command_error::user_error;
 use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
 use crate::commands::git::get_single_remote;
+use crate::commands::operation::PUSHED_BOOKMARKS_TAG;
 use crate::complete;
 use crate::formatter::Formatter;
 use crate::git_util::get_git_repo;
+use crate::git_util::git_fetch;
 use crate::git_util::map_git_error;
+use crate::git_util::with_network_retries;
 use crate::git_util::with_remote_git_callbacks;
 use crate::git_util::GitSidebandProgressMessageWriter;
 use crate::ui::Ui;
@@ -65,7 +77,9 @@ use crate::ui::Ui;
 /// By default, pushes tracking bookmarks pointing to
 /// `remote_bookmarks(remote=<remote>)..@`. Use `--bookmark` to push specific
 /// bookmarks. Use `--all` to push all bookmarks. Use `--change` to generate
-/// bookmark names based on the change IDs of specific commits.
+/// bookmark names based on the change IDs of specific commits. Use `--tags` to
+/// push tags instead of bookmarks. Use `--named` to push commits to remote
+/// bookmarks without creating local bookmarks.
 ///
 /// Unlike in Git, the remote to push to is not derived from the tracked remote
 /// bookmarks. Use `--remote` to select the remote Git repository by name. There
@@ -84,7 +98,7 @@ use crate::ui::Ui;
 
 #[derive(clap::Args, Clone, Debug)]
 #[command(group(ArgGroup::new("specific").args(&["bookmark", "change", "revisions"]).multiple(true)))]
-#[command(group(ArgGroup::new("what").args(&["all", "deleted", "tracked"]).conflicts_with("specific")))]
+#[command(group(ArgGroup::new("what").args(&["all", "deleted", "tracked", "tags"]).conflicts_with("specific")))]
 pub struct GitPushArgs {
     /// The remote to push to (only named remotes are supported)
     ///
@@ -122,6 +136,32 @@ pub struct GitPushArgs {
     /// correspond to missing local bookmarks.
     #[arg(long)]
     deleted: bool,
+    /// Push all local tags instead of bookmarks
+    ///
+    /// Tags are never moved on the remote. If a tag already exists on the
+    /// remote with a different target, the push is rejected.
+    #[arg(long)]
+    tags: bool,
+    /// Also push annotated tags reachable from the pushed bookmarks
+    ///
+    /// Only tags which the remote doesn't have yet are pushed. Lightweight
+    /// tags are never pushed by this option.
+    #[arg(long, conflicts_with = "tags")]
+    follow_tags: bool,
+    /// Push a commit to the remote bookmark NAME without creating a local
+    /// bookmark (can be repeated)
+    ///
+    /// The revset must resolve to a single commit. If it's empty, i.e.
+    /// `--named NAME=`, the remote bookmark is deleted. The remote bookmark
+    /// isn't tracked, so later pushes won't update it unless `--named` is used
+    /// again.
+    #[arg(
+        long,
+        value_name = "NAME=REVSET",
+        value_parser = parse_named_push,
+        conflicts_with_all = ["specific", "what", "follow_tags"],
+    )]
+    named: Vec<(String, String)>,
     /// Allow pushing new bookmarks
     ///
     /// Newly-created remote bookmarks will be tracked automatically.
@@ -171,6 +211,13 @@ pub struct GitPushArgs {
     /// Only display what will change on the remote
     #[arg(long)]
     dry_run: bool,
+    /// If the remote rejects the push because a bookmark moved, fetch it,
+    /// rebase the local commits onto the new remote target, and push again
+    ///
+    /// The push is retried only once. If the rebase produces conflicts, it is
+    /// aborted and nothing is pushed.
+    #[arg(long, conflicts_with_all = ["dry_run", "tags", "named"])]
+    rebase_on_reject: bool,
 }
 
 fn make_bookmark_term(bookmark_names: &[impl fmt::Display]) -> String {
@@ -203,6 +250,13 @@ pub fn cmd_git_push(
         get_default_push_remote(ui, workspace_command.settings(), &git_repo)?
     };
 
+    if args.tags {
+        return push_tags(ui, &workspace_command, &git_repo, &remote, args.dry_run);
+    }
+    if !args.named.is_empty() {
+        return push_named(ui, &mut workspace_command, &git_repo, &remote, args);
+    }
+
     let mut tx = workspace_command.start_transaction();
     let view = tx.repo().view();
     let tx_description;
@@ -357,9 +411,19 @@ pub fn cmd_git_push(
         }
     }
 
+    let tags_to_follow = if args.follow_tags {
+        find_tags_to_follow(ui, tx.repo(), &git_repo, &remote, &bookmark_updates)?
+    } else {
+        vec![]
+    };
+
     if let Some(mut formatter) = ui.status_formatter() {
         writeln!(formatter, "Changes to push to {remote}:")?;
         print_commits_ready_to_push(formatter.as_mut(), tx.repo(), &bookmark_updates)?;
+        if !tags_to_follow.is_empty() {
+            writeln!(formatter, "Tags to push to {remote}:")?;
+            print_tags_ready_to_push(formatter.as_mut(), &tags_to_follow)?;
+        }
     }
 
     if args.dry_run {
@@ -367,6 +431,10 @@ pub fn cmd_git_push(
         return Ok(());
     }
 
+    let pushed_bookmarks = bookmark_updates
+        .iter()
+        .map(|(bookmark, _)| bookmark.as_str())
+        .join("\n");
     let targets = GitBranchPushTargets {
         branch_updates: bookmark_updates,
     };
@@ -374,10 +442,44 @@ pub fn cmd_git_push(
     let mut sideband_progress_callback = |progress_message: &[u8]| {
         _ = writer.write(ui, progress_message);
     };
-    with_remote_git_callbacks(ui, Some(&mut sideband_progress_callback), |cb| {
-        git::push_branches(tx.repo_mut(), &git_repo, &remote, &targets, cb)
-    })
-    .map_err(|err| match err {
+    let git_settings = tx.settings().git_settings()?;
+    let push_result = with_network_retries(ui, &git_settings, GitPushError::is_transient, || {
+        with_remote_git_callbacks(ui, Some(&mut sideband_progress_callback), |cb| {
+            git::push_branches(tx.repo_mut(), &git_repo, &remote, &targets, cb)
+        })
+    });
+    writer.flush(ui)?;
+    match push_result {
+        Err(GitPushError::RefInUnexpectedLocation(refs)) if args.rebase_on_reject => {
+            drop(tx);
+            writeln!(
+                ui.status(),
+                "The remote rejected the push because some bookmarks moved; rebasing onto them."
+            )?;
+            rebase_rejected_bookmarks(ui, &mut workspace_command, &git_repo, &remote, &refs)?;
+            drop(workspace_command);
+            let args = GitPushArgs {
+                rebase_on_reject: false,
+                ..args.clone()
+            };
+            return cmd_git_push(ui, command, &args);
+        }
+        result => result.map_err(map_push_error)?,
+    }
+    tx.set_tag(PUSHED_BOOKMARKS_TAG.to_owned(), pushed_bookmarks);
+    tx.finish(ui, tx_description)?;
+    if !tags_to_follow.is_empty() {
+        let tag_names = tags_to_follow
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect_vec();
+        push_tag_names(ui, command.settings(), &git_repo, &remote, &tag_names)?;
+    }
+    Ok(())
+}
+
+fn map_push_error(err: GitPushError) -> CommandError {
+    match err {
         GitPushError::InternalGitError(err) => map_git_error(err),
         GitPushError::RefInUnexpectedLocation(refs) => user_error_with_hint(
             format!(
@@ -389,12 +491,384 @@ pub fn cmd_git_push(
              be, and push again.",
         ),
         _ => user_error(err),
+    }
+}
+
+/// Fetches the bookmarks that the remote rejected, and rebases the local
+/// commits of each bookmark onto its updated remote target.
+fn rebase_rejected_bookmarks(
+    ui: &mut Ui,
+    workspace_command: &mut WorkspaceCommandHelper,
+    git_repo: &git2::Repository,
+    remote: &str,
+    rejected_refs: &[String],
+) -> Result<(), CommandError> {
+    let bookmark_names = rejected_refs
+        .iter()
+        .filter_map(|full_name| full_name.strip_prefix("refs/heads/"))
+        .map(ToOwned::to_owned)
+        .collect_vec();
+    // Fetching would turn the moved local bookmarks into conflicts, so restore
+    // them to where they pointed to before.
+    let local_targets = bookmark_names
+        .iter()
+        .map(|name| {
+            workspace_command
+                .repo()
+                .view()
+                .get_local_bookmark(name)
+                .clone()
+        })
+        .collect_vec();
+
+    let mut tx = workspace_command.start_transaction();
+    let patterns = bookmark_names
+        .iter()
+        .map(StringPattern::exact)
+        .collect_vec();
+    git_fetch(ui, &mut tx, git_repo, &[remote.to_owned()], &patterns)?;
+    for (name, local_target) in iter::zip(&bookmark_names, &local_targets) {
+        tx.repo_mut()
+            .set_local_bookmark_target(name, local_target.clone());
+    }
+    tx.finish(ui, format!("fetch from git remote(s) {remote}"))?;
+
+    let mut tx = workspace_command.start_transaction();
+    let mut rebased_heads = vec![];
+    for (name, local_target) in iter::zip(&bookmark_names, local_targets) {
+        let remote_target = tx
+            .repo()
+            .view()
+            .get_remote_bookmark(name, remote)
+            .target
+            .clone();
+        let (Some(local_id), Some(remote_id)) =
+            (local_target.as_normal(), remote_target.as_normal())
+        else {
+            return Err(user_error(format!(
+                "Cannot rebase bookmark {name} onto {name}@{remote} because one of them is \
+                 conflicted or absent"
+            )));
+        };
+        let local_id = local_id.clone();
+        let remote_id = remote_id.clone();
+        let store = tx.repo().store().clone();
+        let roots: Vec<Commit> = RevsetExpression::commit(remote_id.clone())
+            .range(&RevsetExpression::commit(local_id.clone()))
+            .roots()
+            .evaluate(tx.repo())?
+            .iter()
+            .commits(&store)
+            .try_collect()?;
+        tx.base_workspace_helper()
+            .check_rewritable(ui, roots.iter().ids())?;
+        if !roots.is_empty() {
+            move_commits(
+                tx.repo_mut(),
+                slice::from_ref(&remote_id),
+                &[],
+                &MoveCommitsTarget::Roots(roots),
+                &RebaseOptions::default(),
+            )?;
+        }
+        let new_local_id = tx
+            .repo()
+            .view()
+            .get_local_bookmark(name)
+            .as_normal()
+            .cloned()
+            .unwrap_or(local_id);
+        rebased_heads.push((name, remote_id, new_local_id));
+    }
+
+    for (name, remote_id, local_id) in &rebased_heads {
+        let store = tx.repo().store().clone();
+        let rebased_commits = RevsetExpression::commit(remote_id.clone())
+            .range(&RevsetExpression::commit(local_id.clone()))
+            .evaluate(tx.repo())?
+            .iter()
+            .commits(&store);
+        let mut conflicted = None;
+        for commit in rebased_commits {
+            let commit = commit?;
+            if commit.has_conflict()? {
+                conflicted = Some(commit);
+                break;
+            }
+        }
+        if let Some(commit) = conflicted {
+            return Err(conflict_error_with_hint(
+                format!(
+                    "Rebasing bookmark {name} onto {name}@{remote} resulted in conflicts in \
+                     commit {}, not pushing",
+                    short_commit_hash(commit.id())
+                ),
+                format!("Rebase bookmark {name} onto {name}@{remote} and resolve the conflicts."),
+            ));
+        }
+    }
+
+    tx.finish(
+        ui,
+        format!(
+            "rebase {} onto git remote {remote}",
+            make_bookmark_term(&bookmark_names)
+        ),
+    )?;
+    Ok(())
+}
+
+fn parse_named_push(s: &str) -> Result<(String, String), String> {
+    let Some((name, revision)) = s.split_once('=') else {
+        return Err("expected NAME=REVSET".to_owned());
+    };
+    if !git2::Reference::is_valid_name(&format!("refs/heads/{name}")) {
+        return Err(format!("invalid bookmark name: {name:?}"));
+    }
+    Ok((name.to_owned(), revision.to_owned()))
+}
+
+/// Pushes the `--named` commits to remote bookmarks, without creating local
+/// bookmarks. The updated remote bookmarks are left untracked.
+fn push_named(
+    ui: &Ui,
+    workspace_command: &mut WorkspaceCommandHelper,
+    git_repo: &git2::Repository,
+    remote: &str,
+    args: &GitPushArgs,
+) -> Result<(), CommandError> {
+    let view = workspace_command.repo().view();
+    let mut bookmark_updates = vec![];
+    for (name, revision) in &args.named {
+        if view.get_local_bookmark(name).is_present() {
+            return Err(user_error_with_hint(
+                format!("Bookmark {name} already exists locally"),
+                format!("Use `jj git push --bookmark {name}` to push it."),
+            ));
+        }
+        let remote_ref = view.get_remote_bookmark(name, remote);
+        let Some(old_target) = remote_ref.target.as_resolved() else {
+            return Err(user_error(format!(
+                "Bookmark {name}@{remote} is conflicted"
+            )));
+        };
+        let new_target = if revision.is_empty() {
+            None
+        } else {
+            let commit =
+                workspace_command.resolve_single_rev(ui, &RevisionArg::from(revision.clone()))?;
+            Some(commit.id().clone())
+        };
+        if *old_target == new_target {
+            writeln!(
+                ui.status(),
+                "Bookmark {name}@{remote} already matches {revision}"
+            )?;
+            continue;
+        }
+        let update = BookmarkPushUpdate {
+            old_target: old_target.clone(),
+            new_target,
+        };
+        bookmark_updates.push((name.clone(), update));
+    }
+    if bookmark_updates.is_empty() {
+        writeln!(ui.status(), "Nothing changed.")?;
+        return Ok(());
+    }
+
+    let mut tx = workspace_command.start_transaction();
+    validate_commits_ready_to_push(ui, &bookmark_updates, remote, &tx, args, None)?;
+    if let Some(mut formatter) = ui.status_formatter() {
+        writeln!(formatter, "Changes to push to {remote}:")?;
+        print_commits_ready_to_push(formatter.as_mut(), tx.repo(), &bookmark_updates)?;
+    }
+    if args.dry_run {
+        writeln!(ui.status(), "Dry-run requested, not pushing.")?;
+        return Ok(());
+    }
+
+    let ref_updates = bookmark_updates
+        .iter()
+        .map(|(name, update)| git::GitRefUpdate {
+            qualified_name: format!("refs/heads/{name}"),
+            expected_current_target: update.old_target.clone(),
+            new_target: update.new_target.clone(),
+        })
+        .collect_vec();
+    let mut writer = GitSidebandProgressMessageWriter::new(ui);
+    let mut sideband_progress_callback = |progress_message: &[u8]| {
+        _ = writer.write(ui, progress_message);
+    };
+    let git_settings = tx.settings().git_settings()?;
+    with_network_retries(ui, &git_settings, GitPushError::is_transient, || {
+        with_remote_git_callbacks(ui, Some(&mut sideband_progress_callback), |cb| {
+            git::push_updates(tx.repo(), git_repo, remote, &ref_updates, cb)
+        })
+    })
+    .map_err(map_push_error)?;
+    writer.flush(ui)?;
+
+    for (name, update) in &bookmark_updates {
+        let target = RefTarget::resolved(update.new_target.clone());
+        let remote_ref = RemoteRef {
+            target: target.clone(),
+            state: RemoteRefState::New,
+        };
+        tx.repo_mut()
+            .set_git_ref_target(&format!("refs/remotes/{remote}/{name}"), target);
+        tx.repo_mut().set_remote_bookmark(name, remote, remote_ref);
+    }
+    let names = bookmark_updates
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect_vec();
+    tx.set_tag(PUSHED_BOOKMARKS_TAG.to_owned(), names.join("\n"));
+    tx.finish(
+        ui,
+        format!("push {} to git remote {remote}", make_bookmark_term(&names)),
+    )?;
+    Ok(())
+}
+
+/// Pushes all resolved local tags to the remote.
+fn push_tags(
+    ui: &Ui,
+    workspace_command: &WorkspaceCommandHelper,
+    git_repo: &git2::Repository,
+    remote: &str,
+    dry_run: bool,
+) -> Result<(), CommandError> {
+    let view = workspace_command.repo().view();
+    let mut tags = vec![];
+    for (name, target) in view.tags() {
+        if let Some(id) = target.as_normal() {
+            tags.push((name.clone(), id.clone()));
+        } else {
+            writeln!(
+                ui.warning_default(),
+                "Tag {name} is conflicted and will not be pushed"
+            )?;
+        }
+    }
+    if tags.is_empty() {
+        writeln!(ui.status(), "Nothing changed.")?;
+        return Ok(());
+    }
+
+    if let Some(mut formatter) = ui.status_formatter() {
+        writeln!(formatter, "Changes to push to {remote}:")?;
+        print_tags_ready_to_push(formatter.as_mut(), &tags)?;
+    }
+
+    if dry_run {
+        writeln!(ui.status(), "Dry-run requested, not pushing.")?;
+        return Ok(());
+    }
+
+    let tag_names = tags.iter().map(|(name, _)| name.clone()).collect_vec();
+    push_tag_names(
+        ui,
+        workspace_command.settings(),
+        git_repo,
+        remote,
+        &tag_names,
+    )
+}
+
+/// Pushes the named local tags to the remote, without moving existing tags.
+fn push_tag_names(
+    ui: &Ui,
+    settings: &UserSettings,
+    git_repo: &git2::Repository,
+    remote: &str,
+    tag_names: &[String],
+) -> Result<(), CommandError> {
+    let mut writer = GitSidebandProgressMessageWriter::new(ui);
+    let mut sideband_progress_callback = |progress_message: &[u8]| {
+        _ = writer.write(ui, progress_message);
+    };
+    let git_settings = settings.git_settings()?;
+    with_network_retries(ui, &git_settings, GitPushError::is_transient, || {
+        with_remote_git_callbacks(ui, Some(&mut sideband_progress_callback), |cb| {
+            git::push_tags(git_repo, remote, tag_names, cb)
+        })
+    })
+    .map_err(|err| match err {
+        GitPushError::InternalGitError(err) => map_git_error(err),
+        GitPushError::RefUpdateRejected(refs) => user_error_with_hint(
+            format!(
+                "The remote rejected some tags: {}",
+                refs.iter()
+                    .map(|name| name.strip_prefix("refs/tags/").unwrap_or(name))
+                    .join(", ")
+            ),
+            "Tags can't be moved on the remote. Delete the tag on the remote, or create a tag \
+             with a different name.",
+        ),
+        _ => user_error(err),
     })?;
     writer.flush(ui)?;
-    tx.finish(ui, tx_description)?;
     Ok(())
 }
 
+fn print_tags_ready_to_push(
+    formatter: &mut dyn Formatter,
+    tags: &[(String, CommitId)],
+) -> io::Result<()> {
+    for (name, id) in tags {
+        writeln!(formatter, "  Add tag {name} to {}", short_commit_hash(id))?;
+    }
+    Ok(())
+}
+
+/// Finds the annotated tags reachable from the pushed bookmarks which the
+/// remote doesn't have yet.
+fn find_tags_to_follow(
+    ui: &Ui,
+    repo: &dyn Repo,
+    git_repo: &git2::Repository,
+    remote: &str,
+    bookmark_updates: &[(String, BookmarkPushUpdate)],
+) -> Result<Vec<(String, CommitId)>, CommandError> {
+    let pushed_heads = bookmark_updates
+        .iter()
+        .filter_map(|(_, update)| update.new_target.as_ref())
+        .collect_vec();
+    let index = repo.index();
+    let mut tags = repo
+        .view()
+        .tags()
+        .iter()
+        .filter_map(|(name, target)| Some((name, target.as_normal()?)))
+        .filter(|(name, _)| is_annotated_git_tag(git_repo, name))
+        .filter(|(_, id)| pushed_heads.iter().any(|head| index.is_ancestor(id, head)))
+        .map(|(name, id)| (name.clone(), id.clone()))
+        .collect_vec();
+    if tags.is_empty() {
+        return Ok(tags);
+    }
+    let remote_tags: HashSet<String> =
+        with_remote_git_callbacks(ui, None, |cb| git::list_remote_tags(git_repo, remote, cb))
+            .map_err(|err| match err {
+                GitPushError::InternalGitError(err) => map_git_error(err),
+                _ => user_error(err),
+            })?
+            .into_iter()
+            .collect();
+    tags.retain(|(name, _)| !remote_tags.contains(name));
+    Ok(tags)
+}
+
+fn is_annotated_git_tag(git_repo: &git2::Repository, name: &str) -> bool {
+    git_repo
+        .find_reference(&format!("refs/tags/{name}"))
+        .ok()
+        .and_then(|reference| reference.target())
+        .and_then(|oid| git_repo.find_object(oid, None).ok())
+        .is_some_and(|object| object.kind() == Some(git2::ObjectType::Tag))
+}
+
 /// Validates that the commits that will be pushed are ready (have authorship
 /// information, are not conflicted, etc.).
 ///
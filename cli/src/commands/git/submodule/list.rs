@@ -0,0 +1,85 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use clap_complete::ArgValueCandidates;
+use jj_lib::object_id::ObjectId as _;
+
+use super::load_submodules;
+use super::open_submodule_repo;
+use crate::cli_util::short_commit_hash;
+use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
+use crate::command_error::CommandError;
+use crate::complete;
+use crate::ui::Ui;
+
+/// List the submodules recorded in a revision
+///
+/// Each submodule is printed with its path, the recorded commit, and its URL
+/// from `.gitmodules`. If the submodule isn't checked out at the recorded
+/// commit in the working copy, its state is printed as well.
+#[derive(clap::Args, Clone, Debug)]
+pub struct GitSubmoduleListArgs {
+    /// The revision to list the submodules of
+    #[arg(
+        long,
+        short,
+        default_value = "@",
+        value_name = "REVSET",
+        add = ArgValueCandidates::new(complete::all_revisions),
+    )]
+    revision: RevisionArg,
+}
+
+pub fn cmd_git_submodule_list(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &GitSubmoduleListArgs,
+) -> Result<(), CommandError> {
+    let workspace_command = command.workspace_helper(ui)?;
+    let commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
+    let submodules = load_submodules(workspace_command.repo().as_ref(), &commit)?;
+    if submodules.is_empty() {
+        writeln!(ui.status(), "No submodules.")?;
+        return Ok(());
+    }
+    let workspace_root = workspace_command.workspace_root();
+    for submodule in &submodules {
+        let state = match open_submodule_repo(workspace_root, submodule) {
+            None => Some("not checked out".to_owned()),
+            Some(submodule_repo) => {
+                let head_id = submodule_repo.head().ok().and_then(|head| head.target());
+                match head_id {
+                    Some(oid) if oid.as_bytes() == submodule.commit_id.as_bytes() => None,
+                    Some(oid) => Some(format!("checked out at {:.12}", oid.to_string())),
+                    None => Some("no commit checked out".to_owned()),
+                }
+            }
+        };
+        write!(
+            ui.stdout(),
+            "{path} {commit} {url}",
+            path = submodule.path.as_internal_file_string(),
+            commit = short_commit_hash(&submodule.commit_id),
+            url = submodule.url.as_deref().unwrap_or("<no URL>"),
+        )?;
+        if let Some(state) = state {
+            write!(ui.stdout(), " ({state})")?;
+        }
+        writeln!(ui.stdout())?;
+    }
+    Ok(())
+}
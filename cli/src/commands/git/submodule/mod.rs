@@ -0,0 +1,139 @@
+// Copyright 2020-2023 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod list;
+mod print_gitmodules;
+mod update;
+
+use std::path::Path;
+
+use clap::Subcommand;
+use jj_lib::backend::CommitId;
+use jj_lib::backend::TreeValue;
+use jj_lib::commit::Commit;
+use jj_lib::git::parse_gitmodules;
+use jj_lib::repo::Repo;
+use jj_lib::repo_path::RepoPath;
+use jj_lib::repo_path::RepoPathBuf;
+
+use self::list::cmd_git_submodule_list;
+use self::list::GitSubmoduleListArgs;
+use self::print_gitmodules::cmd_git_submodule_print_gitmodules;
+use self::print_gitmodules::GitSubmodulePrintGitmodulesArgs;
+use self::update::cmd_git_submodule_update;
+use self::update::GitSubmoduleUpdateArgs;
+use crate::cli_util::CommandHelper;
+use crate::command_error::user_error;
+use crate::command_error::CommandError;
+use crate::ui::Ui;
+
+/// Interact with Git submodules
+///
+/// Jujutsu doesn't manage the contents of submodules. These commands only
+/// inspect the submodule commits recorded in a revision, and check them out in
+/// the working copy.
+#[derive(Subcommand, Clone, Debug)]
+pub enum GitSubmoduleCommand {
+    List(GitSubmoduleListArgs),
+    /// Print the relevant contents from .gitmodules. For debugging purposes
+    /// only.
+    PrintGitmodules(GitSubmodulePrintGitmodulesArgs),
+    Update(GitSubmoduleUpdateArgs),
+}
+
+pub fn cmd_git_submodule(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    subcommand: &GitSubmoduleCommand,
+) -> Result<(), CommandError> {
+    match subcommand {
+        GitSubmoduleCommand::List(args) => cmd_git_submodule_list(ui, command, args),
+        GitSubmoduleCommand::PrintGitmodules(args) => {
+            cmd_git_submodule_print_gitmodules(ui, command, args)
+        }
+        GitSubmoduleCommand::Update(args) => cmd_git_submodule_update(ui, command, args),
+    }
+}
+
+/// Submodule recorded in a revision.
+struct Submodule {
+    path: RepoPathBuf,
+    /// Commit recorded in the tree of the revision.
+    commit_id: CommitId,
+    /// URL configured in `.gitmodules`, if any.
+    url: Option<String>,
+}
+
+/// Loads the submodules recorded in the tree of `commit`.
+fn load_submodules(repo: &dyn Repo, commit: &Commit) -> Result<Vec<Submodule>, CommandError> {
+    let tree = commit.tree()?;
+    let gitmodules_path = RepoPath::from_internal_string(".gitmodules");
+    let configs = match tree.path_value(gitmodules_path)?.into_resolved() {
+        Ok(None) => Default::default(),
+        Ok(Some(TreeValue::File { id, .. })) => {
+            let mut gitmodules_file = repo.store().read_file(gitmodules_path, &id)?;
+            parse_gitmodules(&mut gitmodules_file)?
+        }
+        _ => {
+            return Err(user_error(".gitmodules is not a file."));
+        }
+    };
+
+    let mut submodules = vec![];
+    for (path, value) in tree.entries() {
+        // Conflicted submodules can't be checked out.
+        let Ok(Some(TreeValue::GitSubmodule(commit_id))) = value?.into_resolved() else {
+            continue;
+        };
+        let url = configs
+            .values()
+            .find(|config| config.path == path.as_internal_file_string())
+            .map(|config| config.url.clone());
+        submodules.push(Submodule {
+            path,
+            commit_id,
+            url,
+        });
+    }
+    Ok(submodules)
+}
+
+/// Resolves a relative submodule URL such as `../sub.git` against the URL (or
+/// path) of the superproject.
+fn resolve_submodule_url(base: &str, url: &str) -> String {
+    if !url.starts_with("./") && !url.starts_with("../") {
+        return url.to_owned();
+    }
+    let mut base = base.trim_end_matches('/');
+    let mut rest = url;
+    loop {
+        if let Some(stripped) = rest.strip_prefix("./") {
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix("../") {
+            base = base.rsplit_once('/').map_or("", |(parent, _)| parent);
+            rest = stripped;
+        } else {
+            break;
+        }
+    }
+    format!("{base}/{rest}")
+}
+
+/// Opens the repository of a checked out submodule.
+fn open_submodule_repo(workspace_root: &Path, submodule: &Submodule) -> Option<git2::Repository> {
+    let path = submodule.path.to_fs_path_unchecked(workspace_root);
+    git2::Repository::open(path)
+        .ok()
+        .filter(|repo| repo.workdir().is_some())
+}
@@ -14,7 +14,6 @@
 
 use std::io::Write;
 
-use clap::Subcommand;
 use jj_lib::backend::TreeValue;
 use jj_lib::git::parse_gitmodules;
 use jj_lib::repo::Repo;
@@ -26,40 +25,19 @@ use crate::command_error::user_error;
 use crate::command_error::CommandError;
 use crate::ui::Ui;
 
-/// FOR INTERNAL USE ONLY Interact with git submodules
-#[derive(Subcommand, Clone, Debug)]
-pub enum GitSubmoduleCommand {
-    /// Print the relevant contents from .gitmodules. For debugging purposes
-    /// only.
-    PrintGitmodules(PrintArgs),
-}
-
-pub fn cmd_git_submodule(
-    ui: &mut Ui,
-    command: &CommandHelper,
-    subcommand: &GitSubmoduleCommand,
-) -> Result<(), CommandError> {
-    match subcommand {
-        GitSubmoduleCommand::PrintGitmodules(args) => cmd_submodule_print(ui, command, args),
-    }
-}
-
-// TODO: break everything below into a separate file as soon as there is more
-// than one subcommand here.
-
 /// Print debugging info about Git submodules
 #[derive(clap::Args, Clone, Debug)]
 #[command(hide = true)]
-pub struct PrintArgs {
+pub struct GitSubmodulePrintGitmodulesArgs {
     /// Read .gitmodules from the given revision.
     #[arg(long, short = 'r', default_value = "@", value_name = "REVSET")]
     revisions: RevisionArg,
 }
 
-fn cmd_submodule_print(
+pub fn cmd_git_submodule_print_gitmodules(
     ui: &mut Ui,
     command: &CommandHelper,
-    args: &PrintArgs,
+    args: &GitSubmodulePrintGitmodulesArgs,
 ) -> Result<(), CommandError> {
     let workspace_command = command.workspace_helper(ui)?;
     let repo = workspace_command.repo();
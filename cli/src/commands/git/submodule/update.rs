@@ -0,0 +1,145 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use jj_lib::git;
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::Repo;
+
+use super::load_submodules;
+use super::open_submodule_repo;
+use super::resolve_submodule_url;
+use super::Submodule;
+use crate::cli_util::short_commit_hash;
+use crate::cli_util::CommandHelper;
+use crate::command_error::user_error;
+use crate::command_error::CommandError;
+use crate::commands::git::fetch::get_default_fetch_remotes;
+use crate::git_util::get_git_repo;
+use crate::git_util::with_remote_git_callbacks;
+use crate::ui::Ui;
+
+/// Check out the submodule commits recorded in the working-copy commit
+///
+/// Submodules which aren't checked out yet are cloned from the URL configured
+/// in `.gitmodules`. If the recorded commit isn't available locally, it is
+/// fetched from that URL. Submodules which can't be updated are reported and
+/// skipped.
+#[derive(clap::Args, Clone, Debug)]
+pub struct GitSubmoduleUpdateArgs {}
+
+pub fn cmd_git_submodule_update(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    _args: &GitSubmoduleUpdateArgs,
+) -> Result<(), CommandError> {
+    let workspace_command = command.workspace_helper(ui)?;
+    let Some(wc_commit_id) = workspace_command.get_wc_commit_id() else {
+        return Err(user_error("This command requires a working copy"));
+    };
+    let wc_commit = workspace_command.repo().store().get_commit(wc_commit_id)?;
+    let submodules = load_submodules(workspace_command.repo().as_ref(), &wc_commit)?;
+    if submodules.is_empty() {
+        writeln!(ui.status(), "No submodules.")?;
+        return Ok(());
+    }
+
+    let workspace_root = workspace_command.workspace_root();
+    let git_repo = get_git_repo(workspace_command.repo().store())?;
+    // Relative submodule URLs are resolved against the superproject's
+    // default fetch remote, or against the workspace if there is no such
+    // remote.
+    let remotes = get_default_fetch_remotes(ui, workspace_command.settings(), &git_repo)?;
+    let base_url = remotes
+        .first()
+        .and_then(|name| git_repo.find_remote(name).ok())
+        .and_then(|remote| remote.url().map(|url| url.to_owned()))
+        .unwrap_or_else(|| workspace_root.to_string_lossy().into_owned());
+
+    let mut num_failed = 0;
+    for submodule in &submodules {
+        let path = submodule.path.as_internal_file_string();
+        match update_submodule(ui, workspace_root, &base_url, submodule) {
+            Ok(()) => {
+                writeln!(
+                    ui.status(),
+                    "Checked out submodule {path} at {}",
+                    short_commit_hash(&submodule.commit_id)
+                )?;
+            }
+            Err(message) => {
+                num_failed += 1;
+                writeln!(
+                    ui.warning_default(),
+                    "Failed to update submodule {path}: {message}"
+                )?;
+            }
+        }
+    }
+    if num_failed > 0 {
+        return Err(user_error(format!(
+            "Failed to update {num_failed} of {} submodules",
+            submodules.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Checks out the recorded commit of `submodule`, fetching it if needed.
+///
+/// Errors are returned as messages to be reported to the user.
+fn update_submodule(
+    ui: &Ui,
+    workspace_root: &std::path::Path,
+    base_url: &str,
+    submodule: &Submodule,
+) -> Result<(), String> {
+    let Some(url) = &submodule.url else {
+        return Err("no URL is configured in .gitmodules".to_owned());
+    };
+    let url = resolve_submodule_url(base_url, url);
+    let submodule_repo = match open_submodule_repo(workspace_root, submodule) {
+        Some(submodule_repo) => submodule_repo,
+        None => {
+            let fs_path = submodule
+                .path
+                .to_fs_path(workspace_root)
+                .map_err(|err| err.to_string())?;
+            git2::Repository::init(fs_path).map_err(|err| err.message().to_owned())?
+        }
+    };
+    let oid = git2::Oid::from_bytes(submodule.commit_id.as_bytes()).unwrap();
+    if submodule_repo.find_commit(oid).is_err() {
+        with_remote_git_callbacks(ui, None, |callbacks| {
+            git::fetch_submodule(&submodule_repo, &url, callbacks)
+        })
+        .map_err(|err| format!("could not fetch from {url}: {}", err.message()))?;
+    }
+    let target = submodule_repo.find_commit(oid).map_err(|_| {
+        format!(
+            "commit {} is not available from {url}",
+            short_commit_hash(&submodule.commit_id)
+        )
+    })?;
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.safe();
+    submodule_repo
+        .checkout_tree(target.as_object(), Some(&mut checkout))
+        .map_err(|err| err.message().to_owned())?;
+    submodule_repo
+        .set_head_detached(oid)
+        .map_err(|err| err.message().to_owned())?;
+    Ok(())
+}
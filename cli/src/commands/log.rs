@@ -12,8 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::slice;
+use std::sync::Arc;
+
 use clap_complete::ArgValueCandidates;
 use clap_complete::ArgValueCompleter;
+use itertools::Itertools as _;
+use jj_lib::backend::BackendResult;
 use jj_lib::backend::CommitId;
 use jj_lib::config::ConfigGetError;
 use jj_lib::config::ConfigGetResultExt as _;
@@ -22,21 +29,30 @@ use jj_lib::graph::GraphEdge;
 use jj_lib::graph::GraphEdgeType;
 use jj_lib::graph::TopoGroupedGraphIterator;
 use jj_lib::repo::Repo;
+use jj_lib::revset::Revset;
 use jj_lib::revset::RevsetEvaluationError;
 use jj_lib::revset::RevsetExpression;
 use jj_lib::revset::RevsetFilterPredicate;
 use jj_lib::revset::RevsetIteratorExt;
+use jj_lib::revset::UserRevsetExpression;
 use jj_lib::settings::UserSettings;
+use jj_lib::store::Store;
+use jj_lib::str_util::StringPattern;
 use tracing::instrument;
 
 use crate::cli_util::format_template;
+use crate::cli_util::parse_highlight_pattern;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::LogContentFormat;
+use crate::cli_util::LogHighlighter;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::TemplateFileArgs;
+use crate::cli_util::WorkspaceCommandHelper;
 use crate::command_error::CommandError;
 use crate::commit_templater::CommitTemplateLanguage;
 use crate::complete;
 use crate::diff_util::DiffFormatArgs;
+use crate::graphlog::get_compact_graphlog;
 use crate::graphlog::get_graphlog;
 use crate::graphlog::GraphStyle;
 use crate::ui::Ui;
@@ -50,19 +66,28 @@ use crate::ui::Ui;
 /// https://jj-vcs.github.io/jj/latest/revsets/) for information about the
 /// syntax.
 ///
+/// Use `--date-order` to sort the revisions by committer date instead of
+/// topologically. Since the revisions are then no longer grouped by branch,
+/// `--date-order` implies `--no-graph`.
+///
 /// Spans of revisions that are not included in the graph per `--revisions` are
-/// rendered as a synthetic node labeled "(elided revisions)".
+/// rendered as a synthetic node labeled "(elided revisions)". Use
+/// `--elide-summary` to show the number of elided revisions, or `--no-elide`
+/// to show them instead.
 ///
 /// The working-copy commit is indicated by a `@` symbol in the graph. Immutable
 /// revisions (https://jj-vcs.github.io/jj/latest/config/#set-of-immutable-commits)
 /// have a `◆` symbol. Other commits have a `○` symbol. To customize these
 /// symbols, see https://jj-vcs.github.io/jj/latest/config/#node-style.
 #[derive(clap::Args, Clone, Debug)]
+#[command(mut_arg("context", |a| a.short('U')))]
 pub(crate) struct LogArgs {
     /// Which revisions to show
     ///
     /// If no paths nor revisions are specified, this defaults to the
-    /// `revsets.log` setting.
+    /// `revsets.log` setting. If the option is repeated, the union of the
+    /// revsets is shown in topological order, as if they were joined with `|`.
+    /// The `revsets.log` setting isn't added to the union.
     #[arg(
         long,
         short,
@@ -70,6 +95,27 @@ pub(crate) struct LogArgs {
         add = ArgValueCandidates::new(complete::all_revisions)
     )]
     revisions: Vec<RevisionArg>,
+    /// Show the ancestors of the revisions, including themselves
+    ///
+    /// Equivalent to `-r '::REVSETS'`. Defaults to the ancestors of @ if no
+    /// revisions are specified. The `revsets.log` setting isn't used.
+    #[arg(long, conflicts_with = "descendants")]
+    ancestors: bool,
+    /// Show the descendants of the revisions, including themselves
+    ///
+    /// Equivalent to `-r 'REVSETS::'`. Defaults to the descendants of @ if no
+    /// revisions are specified. The `revsets.log` setting isn't used.
+    #[arg(long)]
+    descendants: bool,
+    /// Show the branch the working copy is on
+    ///
+    /// Shows the revisions from where the nearest bookmark forked off
+    /// `trunk()`, up to that bookmark and @. The nearest bookmark is the
+    /// closest ancestor of @ with a local bookmark which isn't an ancestor of
+    /// `trunk()`. If there's no such bookmark, the `revsets.log` setting is
+    /// used instead.
+    #[arg(long, conflicts_with_all = ["revisions", "ancestors", "descendants"])]
+    current_branch: bool,
     /// Show revisions modifying the given paths
     #[arg(
         value_name = "FILESETS",
@@ -80,14 +126,60 @@ pub(crate) struct LogArgs {
     /// Show revisions in the opposite order (older revisions first)
     #[arg(long)]
     reversed: bool,
+    /// Show revisions in topological order (the default)
+    ///
+    /// Children are shown before their parents, and each branch is kept
+    /// together.
+    #[arg(long, conflicts_with = "date_order")]
+    topo_order: bool,
+    /// Show revisions sorted by committer date, newest first
+    ///
+    /// Revisions with the same committer date are kept in topological order.
+    /// Implies `--no-graph`.
+    #[arg(long, visible_alias = "committer-date-order")]
+    date_order: bool,
     /// Limit number of revisions to show
     ///
     /// Applied after revisions are filtered and reordered.
     #[arg(long, short = 'n')]
     limit: Option<usize>,
+    /// Limit number of revisions to show per branch
+    ///
+    /// Each head of the revisions, and each revision with a local bookmark,
+    /// starts a branch. Any other revision belongs to the branch of its first
+    /// child in the log order. Revisions beyond the limit are elided.
+    #[arg(long, value_name = "N")]
+    limit_per_branch: Option<usize>,
     /// Don't show the graph, show a flat list of revisions
     #[arg(long)]
     no_graph: bool,
+    /// Render a narrower graph with one character per column
+    ///
+    /// If not specified, this defaults to the `ui.log-compact-graph` setting.
+    #[arg(long, conflicts_with_all = ["no_graph", "date_order"])]
+    compact_graph: bool,
+    /// Show the revisions connecting the selected revisions instead of eliding
+    /// them
+    ///
+    /// The connecting revisions are rendered dimmed.
+    #[arg(long, conflicts_with_all = ["no_graph", "date_order", "elide_summary"])]
+    no_elide: bool,
+    /// Show the number of elided revisions in place of "(elided revisions)"
+    ///
+    /// Implies `ui.log-synthetic-elided-nodes`.
+    #[arg(long, conflicts_with_all = ["no_graph", "date_order"])]
+    elide_summary: bool,
+    /// Limit the graph to this many columns (implies --compact-graph)
+    ///
+    /// Columns beyond the limit, such as the ones of deeply nested merges, are
+    /// collapsed into an indicator.
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = ["no_graph", "date_order"],
+        value_parser = clap::value_parser!(u16).range(1..),
+    )]
+    graph_width: Option<u16>,
     /// Render each revision using the given template
     ///
     /// Run `jj log -T` to list the built-in templates.
@@ -98,6 +190,15 @@ pub(crate) struct LogArgs {
     /// If not specified, this defaults to the `templates.log` setting.
     #[arg(long, short = 'T')]
     template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
+    /// Highlight the matches of the given regular expression in the output
+    ///
+    /// The matches are labeled with `highlight`, which can be styled in the
+    /// `colors` config. The option can be repeated to highlight several
+    /// patterns.
+    #[arg(long, value_name = "PATTERN", value_parser = parse_highlight_pattern)]
+    highlight: Vec<regex::bytes::Regex>,
     /// Show patch
     #[arg(long, short = 'p')]
     patch: bool,
@@ -116,12 +217,43 @@ pub(crate) fn cmd_log(
 
     let fileset_expression = workspace_command.parse_file_patterns(ui, &args.paths)?;
     let revset_expression = {
+        let has_range = args.ancestors || args.descendants;
+        let current_branch = if args.current_branch {
+            let expression = current_branch_expression(ui, &workspace_command)?;
+            if expression.is_none() {
+                writeln!(
+                    ui.warning_default(),
+                    "No bookmark found between the working copy and trunk(), showing the default \
+                     revset instead."
+                )?;
+            }
+            expression
+        } else {
+            None
+        };
         // only use default revset if neither revset nor path are specified
-        let mut expression = if args.revisions.is_empty() && args.paths.is_empty() {
+        let mut expression = if let Some(expression) = current_branch {
+            workspace_command.attach_revset_evaluator(expression)
+        } else if args.revisions.is_empty()
+            && (args.paths.is_empty() || args.current_branch)
+            && !has_range
+        {
             let revset_string = settings.get_string("revsets.log")?;
             workspace_command.parse_revset(ui, &RevisionArg::from(revset_string))?
-        } else if !args.revisions.is_empty() {
-            workspace_command.parse_union_revsets(ui, &args.revisions)?
+        } else if !args.revisions.is_empty() || has_range {
+            let revisions = if args.revisions.is_empty() {
+                slice::from_ref(&RevisionArg::AT)
+            } else {
+                &args.revisions
+            };
+            let expression = workspace_command.parse_union_revsets(ui, revisions)?;
+            if args.ancestors {
+                workspace_command.attach_revset_evaluator(expression.expression().ancestors())
+            } else if args.descendants {
+                workspace_command.attach_revset_evaluator(expression.expression().descendants())
+            } else {
+                expression
+            }
         } else {
             // a path was specified so we use all() and add path filter later
             workspace_command.attach_revset_evaluator(RevsetExpression::all())
@@ -137,21 +269,46 @@ pub(crate) fn cmd_log(
 
     let repo = workspace_command.repo();
     let matcher = fileset_expression.to_matcher();
-    let revset = revset_expression.evaluate()?;
+    let mut revset_expression = revset_expression;
+    let mut revset = revset_expression.evaluate()?;
+    if let Some(limit) = args.limit_per_branch {
+        let commit_ids = limit_commits_per_branch(repo.as_ref(), revset.as_ref(), limit)?;
+        revset_expression.intersect_with(&RevsetExpression::commits(commit_ids));
+        revset = revset_expression.evaluate()?;
+    }
+    // With --no-elide, the graph also includes the revisions in between.
+    let graph_revset = if args.no_elide {
+        let expression = revset_expression.expression().connected();
+        Some(
+            workspace_command
+                .attach_revset_evaluator(expression)
+                .evaluate()?,
+        )
+    } else {
+        None
+    };
 
     let store = repo.store();
     let diff_renderer = workspace_command.diff_renderer_for_log(&args.diff_format, args.patch)?;
     let graph_style = GraphStyle::from_settings(settings)?;
+    let compact_graph = args.compact_graph
+        || args.graph_width.is_some()
+        || settings.get_bool("ui.log-compact-graph")?;
 
-    let use_elided_nodes = settings.get_bool("ui.log-synthetic-elided-nodes")?;
+    let use_elided_nodes =
+        args.elide_summary || settings.get_bool("ui.log-synthetic-elided-nodes")?;
     let with_content_format = LogContentFormat::new(ui, settings)?;
+    let highlighter = LogHighlighter::new(&args.highlight);
 
     let template;
     let node_template;
     {
         let language = workspace_command.commit_template_language();
-        let template_string = match &args.template {
-            Some(value) => value.to_string(),
+        let template_string = match args
+            .template_file
+            .template_text(command.cwd(), args.template.as_deref())?
+        {
+            Some(value) => value,
             None => settings.get_string("templates.log")?,
         };
         template = workspace_command
@@ -160,7 +317,8 @@ pub(crate) fn cmd_log(
                 &language,
                 &template_string,
                 CommitTemplateLanguage::wrap_commit,
-            )?
+            )
+            .map_err(|err| args.template_file.annotate_parse_error(err))?
             .labeled("log");
         node_template = workspace_command
             .parse_template(
@@ -179,15 +337,22 @@ pub(crate) fn cmd_log(
 
         let limit = args.limit.unwrap_or(usize::MAX);
 
-        if !args.no_graph {
+        if !args.no_graph && !args.date_order {
             let mut raw_output = formatter.raw()?;
-            let mut graph = get_graphlog(graph_style, raw_output.as_mut());
+            let mut graph = if compact_graph {
+                let max_width = args.graph_width.map(usize::from);
+                get_compact_graphlog(graph_style, max_width, raw_output.as_mut())
+            } else {
+                get_graphlog(graph_style, raw_output.as_mut())
+            };
+            let is_selected = revset.containing_fn();
+            let graph_revset = graph_revset.as_ref().unwrap_or(&revset);
             let iter: Box<dyn Iterator<Item = _>> = {
-                let mut forward_iter = TopoGroupedGraphIterator::new(revset.iter_graph());
+                let mut forward_iter = TopoGroupedGraphIterator::new(graph_revset.iter_graph());
                 // Emit the working-copy branch first, which is usually most
                 // interesting. This also helps stabilize output order.
                 if let Some(id) = workspace_command.get_wc_commit_id() {
-                    let has_commit = revset.containing_fn();
+                    let has_commit = graph_revset.containing_fn();
                     if has_commit(id)? {
                         forward_iter.prioritize_branch(id.clone());
                     }
@@ -234,8 +399,17 @@ pub(crate) fn cmd_log(
                 let commit = store.get_commit(&key.0)?;
                 let within_graph =
                     with_content_format.sub_width(graph.width(&key, &graphlog_edges));
+                let is_connecting = args.no_elide && !is_selected(commit.id())?;
                 within_graph.write(ui.new_formatter(&mut buffer).as_mut(), |formatter| {
-                    template.format(&commit, formatter)
+                    highlighter.write(formatter, |formatter| {
+                        if is_connecting {
+                            formatter.with_label("elided", |formatter| {
+                                template.format(&commit, formatter)
+                            })
+                        } else {
+                            template.format(&commit, formatter)
+                        }
+                    })
                 })?;
                 if !buffer.ends_with(b"\n") {
                     buffer.push(b'\n');
@@ -259,6 +433,16 @@ pub(crate) fn cmd_log(
                     &String::from_utf8_lossy(&buffer),
                 )?;
                 for elided_target in elided_targets {
+                    let num_elided = if args.elide_summary {
+                        Some(count_elided_revisions(
+                            repo.as_ref(),
+                            &is_selected,
+                            &key.0,
+                            &elided_target,
+                        )?)
+                    } else {
+                        None
+                    };
                     let elided_key = (elided_target, true);
                     let real_key = (elided_key.0.clone(), false);
                     let edges = [GraphEdge::direct(real_key)];
@@ -266,7 +450,13 @@ pub(crate) fn cmd_log(
                     let within_graph =
                         with_content_format.sub_width(graph.width(&elided_key, &edges));
                     within_graph.write(ui.new_formatter(&mut buffer).as_mut(), |formatter| {
-                        writeln!(formatter.labeled("elided"), "(elided revisions)")
+                        match num_elided {
+                            Some(1) => writeln!(formatter.labeled("elided"), "(1 elided revision)"),
+                            Some(n) => {
+                                writeln!(formatter.labeled("elided"), "({n} elided revisions)")
+                            }
+                            None => writeln!(formatter.labeled("elided"), "(elided revisions)"),
+                        }
                     })?;
                     let node_symbol = format_template(ui, &None, &node_template);
                     graph.add_node(
@@ -279,15 +469,23 @@ pub(crate) fn cmd_log(
             }
         } else {
             let iter: Box<dyn Iterator<Item = Result<CommitId, RevsetEvaluationError>>> =
-                if args.reversed {
+                if args.date_order {
+                    let ids: Vec<_> = revset.iter().try_collect()?;
+                    let mut ids = sort_by_committer_date(store, ids, |id| id)?;
+                    if args.reversed {
+                        ids.reverse();
+                    }
+                    Box::new(ids.into_iter().map(Ok))
+                } else if args.reversed {
                     Box::new(revset.iter().reversed()?)
                 } else {
                     Box::new(revset.iter())
                 };
             for commit_or_error in iter.commits(store).take(limit) {
                 let commit = commit_or_error?;
-                with_content_format
-                    .write(formatter, |formatter| template.format(&commit, formatter))?;
+                with_content_format.write(formatter, |formatter| {
+                    highlighter.write(formatter, |formatter| template.format(&commit, formatter))
+                })?;
                 if let Some(renderer) = &diff_renderer {
                     let width = ui.term_width();
                     renderer.show_patch(ui, formatter, &commit, matcher.as_ref(), width)?;
@@ -323,6 +521,114 @@ pub(crate) fn cmd_log(
     Ok(())
 }
 
+/// Builds the revset of the branch the working copy is on, or returns `None`
+/// if no bookmark is found between `@` and `trunk()`.
+fn current_branch_expression(
+    ui: &Ui,
+    workspace_command: &WorkspaceCommandHelper,
+) -> Result<Option<Rc<UserRevsetExpression>>, CommandError> {
+    let trunk = workspace_command
+        .parse_revset(ui, &RevisionArg::from("present(trunk())".to_owned()))?
+        .expression()
+        .clone();
+    let working_copy = workspace_command
+        .parse_revset(ui, &RevisionArg::AT)?
+        .expression()
+        .clone();
+    // The nearest bookmarks are the heads of the bookmarked ancestors of @
+    // which haven't been merged into trunk yet.
+    let nearest_bookmarks = working_copy
+        .ancestors()
+        .intersection(&RevsetExpression::bookmarks(StringPattern::everything()))
+        .minus(&trunk.ancestors())
+        .heads();
+    let bookmark_ids: Vec<CommitId> = workspace_command
+        .attach_revset_evaluator(nearest_bookmarks)
+        .evaluate_to_commit_ids()?
+        .try_collect()?;
+    if bookmark_ids.is_empty() {
+        return Ok(None);
+    }
+    let heads = RevsetExpression::commits(bookmark_ids).union(&working_copy);
+    let merge_base = heads.union(&trunk).fork_point();
+    Ok(Some(merge_base.dag_range_to(&heads)))
+}
+
+/// Selects up to `limit` commits of each branch in the `revset`.
+fn limit_commits_per_branch(
+    repo: &dyn Repo,
+    revset: &dyn Revset,
+    limit: usize,
+) -> Result<Vec<CommitId>, RevsetEvaluationError> {
+    // Maps commit to the head of the branch it belongs to.
+    let mut branch_heads: HashMap<CommitId, CommitId> = HashMap::new();
+    let mut branch_sizes: HashMap<CommitId, usize> = HashMap::new();
+    let mut commit_ids = vec![];
+    for node in revset.iter_graph() {
+        let (commit_id, edges) = node?;
+        let has_bookmark = repo
+            .view()
+            .local_bookmarks_for_commit(&commit_id)
+            .next()
+            .is_some();
+        let branch_head = match branch_heads.remove(&commit_id) {
+            Some(head) if !has_bookmark => head,
+            _ => commit_id.clone(),
+        };
+        let size = branch_sizes.entry(branch_head.clone()).or_default();
+        *size += 1;
+        if *size <= limit {
+            commit_ids.push(commit_id);
+        }
+        for edge in edges {
+            if edge.edge_type != GraphEdgeType::Missing {
+                branch_heads
+                    .entry(edge.target)
+                    .or_insert_with(|| branch_head.clone());
+            }
+        }
+    }
+    Ok(commit_ids)
+}
+
+/// Sorts the `items` by committer date, newest first. The sort is stable, so
+/// items with the same date keep their original order.
+fn sort_by_committer_date<T>(
+    store: &Arc<Store>,
+    items: Vec<T>,
+    as_id: impl Fn(&T) -> &CommitId,
+) -> BackendResult<Vec<T>> {
+    let mut keyed_items = items
+        .into_iter()
+        .map(|item| {
+            let commit = store.get_commit(as_id(&item))?;
+            Ok((commit.committer().timestamp.timestamp, item))
+        })
+        .collect::<BackendResult<Vec<_>>>()?;
+    keyed_items.sort_by(|(a, _), (b, _)| b.cmp(a));
+    Ok(keyed_items.into_iter().map(|(_, item)| item).collect())
+}
+
+/// Counts the revisions on the paths from `descendant` to `ancestor` which
+/// aren't selected to be shown.
+fn count_elided_revisions(
+    repo: &dyn Repo,
+    is_selected: &dyn Fn(&CommitId) -> Result<bool, RevsetEvaluationError>,
+    descendant: &CommitId,
+    ancestor: &CommitId,
+) -> Result<usize, RevsetEvaluationError> {
+    let between = RevsetExpression::commit(ancestor.clone())
+        .dag_range_to(&RevsetExpression::commit(descendant.clone()))
+        .evaluate(repo)?;
+    let mut count = 0;
+    for commit_id in between.iter() {
+        if !is_selected(&commit_id?)? {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
 pub fn get_node_template(
     style: GraphStyle,
     settings: &UserSettings,
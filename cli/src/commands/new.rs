@@ -135,7 +135,7 @@ pub(crate) fn cmd_new(
             .into_iter()
             .collect_vec();
         let children_commit_ids = children_commits.iter().ids().cloned().collect();
-        workspace_command.check_rewritable(&children_commit_ids)?;
+        workspace_command.check_rewritable(ui, &children_commit_ids)?;
         let children_expression = RevsetExpression::commits(children_commit_ids);
         let parents_expression = children_expression.parents();
         ensure_no_commit_loop(
@@ -187,7 +187,7 @@ pub(crate) fn cmd_new(
                 workspace_command.get_advanceable_bookmarks(parent_commits[0].parent_ids())?;
         }
     };
-    workspace_command.check_rewritable(children_commits.iter().ids())?;
+    workspace_command.check_rewritable(ui, children_commits.iter().ids())?;
 
     let parent_commit_ids_set: HashSet<CommitId> = parent_commit_ids.iter().cloned().collect();
 
@@ -14,7 +14,6 @@
 
 use std::collections::HashMap;
 use std::convert::Infallible;
-use std::sync::Arc;
 
 use clap_complete::ArgValueCandidates;
 use indexmap::IndexMap;
@@ -31,10 +30,10 @@ use jj_lib::op_store::RemoteRef;
 use jj_lib::op_store::RemoteRefState;
 use jj_lib::refs::diff_named_ref_targets;
 use jj_lib::refs::diff_named_remote_refs;
-use jj_lib::repo::ReadonlyRepo;
 use jj_lib::repo::Repo;
 use jj_lib::revset;
 use jj_lib::revset::RevsetIteratorExt as _;
+use jj_lib::view::View;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::LogContentFormat;
@@ -124,8 +123,10 @@ pub fn cmd_op_diff(
         let formats = diff_formats_for_log(settings, &args.diff_format, args.patch)?;
         let path_converter = workspace_env.path_converter();
         let conflict_marker_style = workspace_env.conflict_marker_style();
-        (!formats.is_empty())
-            .then(|| DiffRenderer::new(merged_repo, path_converter, conflict_marker_style, formats))
+        (!formats.is_empty()).then(|| {
+            DiffRenderer::new(merged_repo, path_converter, conflict_marker_style, formats)
+                .with_checkout_parallelism(workspace_env.checkout_parallelism())
+        })
     };
     let id_prefix_context = workspace_env.new_id_prefix_context();
     let commit_summary_template = {
@@ -148,8 +149,8 @@ pub fn cmd_op_diff(
         ui,
         formatter.as_mut(),
         merged_repo,
-        &from_repo,
-        &to_repo,
+        from_repo.view(),
+        to_repo.view(),
         &commit_summary_template,
         (!args.no_graph).then_some(graph_style),
         &with_content_format,
@@ -158,7 +159,7 @@ pub fn cmd_op_diff(
 }
 
 /// Computes and shows the differences between two operations, using the given
-/// `ReadonlyRepo`s for the operations.
+/// views of the operations.
 /// `current_repo` should contain a `Repo` with the indices of both repos merged
 /// into it.
 #[allow(clippy::too_many_arguments)]
@@ -166,14 +167,14 @@ pub fn show_op_diff(
     ui: &Ui,
     formatter: &mut dyn Formatter,
     current_repo: &dyn Repo,
-    from_repo: &Arc<ReadonlyRepo>,
-    to_repo: &Arc<ReadonlyRepo>,
+    from_view: &View,
+    to_view: &View,
     commit_summary_template: &TemplateRenderer<Commit>,
     graph_style: Option<GraphStyle>,
     with_content_format: &LogContentFormat,
     diff_renderer: Option<&DiffRenderer>,
 ) -> Result<(), CommandError> {
-    let changes = compute_operation_commits_diff(current_repo, from_repo, to_repo)?;
+    let changes = compute_operation_commits_diff(current_repo, from_view, to_view)?;
 
     let commit_id_change_id_map: HashMap<CommitId, ChangeId> = changes
         .iter()
@@ -277,11 +278,9 @@ pub fn show_op_diff(
         }
     }
 
-    let changed_local_bookmarks = diff_named_ref_targets(
-        from_repo.view().local_bookmarks(),
-        to_repo.view().local_bookmarks(),
-    )
-    .collect_vec();
+    let changed_local_bookmarks =
+        diff_named_ref_targets(from_view.local_bookmarks(), to_view.local_bookmarks())
+            .collect_vec();
     if !changed_local_bookmarks.is_empty() {
         writeln!(formatter)?;
         with_content_format.write(formatter, |formatter| {
@@ -310,8 +309,7 @@ pub fn show_op_diff(
         }
     }
 
-    let changed_tags =
-        diff_named_ref_targets(from_repo.view().tags(), to_repo.view().tags()).collect_vec();
+    let changed_tags = diff_named_ref_targets(from_view.tags(), to_view.tags()).collect_vec();
     if !changed_tags.is_empty() {
         writeln!(formatter)?;
         with_content_format.write(formatter, |formatter| writeln!(formatter, "Changed tags:"))?;
@@ -340,8 +338,8 @@ pub fn show_op_diff(
     }
 
     let changed_remote_bookmarks = diff_named_remote_refs(
-        from_repo.view().all_remote_bookmarks(),
-        to_repo.view().all_remote_bookmarks(),
+        from_view.all_remote_bookmarks(),
+        to_view.all_remote_bookmarks(),
     )
     // Skip updates to the local git repo, since they should typically be covered in
     // local branches.
@@ -497,13 +495,13 @@ struct ModifiedChange {
 /// and removed commits for the change ID.
 fn compute_operation_commits_diff(
     repo: &dyn Repo,
-    from_repo: &ReadonlyRepo,
-    to_repo: &ReadonlyRepo,
+    from_view: &View,
+    to_view: &View,
 ) -> Result<IndexMap<ChangeId, ModifiedChange>, CommandError> {
     let mut changes: IndexMap<ChangeId, ModifiedChange> = IndexMap::new();
 
-    let from_heads = from_repo.view().heads().iter().cloned().collect_vec();
-    let to_heads = to_repo.view().heads().iter().cloned().collect_vec();
+    let from_heads = from_view.heads().iter().cloned().collect_vec();
+    let to_heads = to_view.heads().iter().cloned().collect_vec();
 
     // Find newly added commits in `to_repo` which were not present in
     // `from_repo`.
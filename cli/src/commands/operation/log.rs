@@ -12,33 +12,57 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::iter::Peekable;
 use std::slice;
 
+use clap_complete::ArgValueCandidates;
 use itertools::Itertools as _;
 use jj_lib::config::ConfigGetError;
 use jj_lib::config::ConfigGetResultExt as _;
 use jj_lib::graph::reverse_graph;
 use jj_lib::graph::GraphEdge;
+use jj_lib::graph::GraphNode;
+use jj_lib::object_id::ObjectId as _;
 use jj_lib::op_store::OpStoreError;
+use jj_lib::op_store::OperationId;
 use jj_lib::op_walk;
 use jj_lib::operation::Operation;
 use jj_lib::repo::RepoLoader;
 use jj_lib::settings::UserSettings;
+use serde::Serialize;
+use unicode_width::UnicodeWidthStr as _;
 
 use super::diff::show_op_diff;
+use super::PUSHED_BOOKMARKS_TAG;
 use crate::cli_util::format_template;
+use crate::cli_util::parse_highlight_pattern;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::LogContentFormat;
+use crate::cli_util::LogHighlighter;
+use crate::cli_util::TemplateFileArgs;
 use crate::cli_util::WorkspaceCommandEnvironment;
+use crate::command_error::user_error;
+use crate::command_error::user_error_with_message;
 use crate::command_error::CommandError;
 use crate::commit_templater::CommitTemplateLanguage;
+use crate::complete;
 use crate::diff_util::diff_formats_for_log;
 use crate::diff_util::DiffFormatArgs;
 use crate::diff_util::DiffRenderer;
 use crate::formatter::Formatter;
+use crate::formatter::PlainTextFormatter;
 use crate::graphlog::get_graphlog;
 use crate::graphlog::GraphStyle;
 use crate::operation_templater::OperationTemplateLanguage;
+use crate::output_format::write_json;
+use crate::output_format::OutputFormat;
+use crate::templater::TemplatePredicate;
+use crate::templater::TemplateRenderer;
+use crate::time_util::format_rfc3339_timestamp;
 use crate::ui::Ui;
 
 /// Show the operation log
@@ -64,7 +88,64 @@ pub struct OperationLogArgs {
     /// For the syntax, see https://jj-vcs.github.io/jj/latest/templates/
     #[arg(long, short = 'T')]
     template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
+    /// Highlight the matches of the given regular expression in the output
+    ///
+    /// The matches are labeled with `highlight`, which can be styled in the
+    /// `colors` config. The option can be repeated to highlight several
+    /// patterns.
+    #[arg(long, value_name = "PATTERN", value_parser = parse_highlight_pattern)]
+    highlight: Vec<regex::bytes::Regex>,
+    /// Show only operations matching the given template expression
+    ///
+    /// The expression should evaluate to a Boolean, e.g. `!snapshot`. If not
+    /// specified, the `op-log.default-filter` setting is used.
+    #[arg(long, value_name = "TEMPLATE")]
+    filter: Option<String>,
+    /// Show all operations, ignoring the `op-log.default-filter` setting
+    #[arg(long, conflicts_with = "filter")]
+    no_filter: bool,
+    /// Show only operations made since the bookmark was last pushed
+    ///
+    /// The most recent `jj git push` operation which pushed the bookmark is
+    /// looked up in the operation history. That operation and its ancestors
+    /// are not shown.
+    #[arg(
+        long,
+        value_name = "BOOKMARK",
+        add = ArgValueCandidates::new(complete::local_bookmarks),
+    )]
+    since_push: Option<String>,
+    /// Show only the latest common ancestor of the two operations
+    ///
+    /// This is useful to find where concurrent operations diverged. Use
+    /// `--op-diff` to also show the changes made by the operation.
+    #[arg(
+        long,
+        num_args = 2,
+        value_names = ["OP1", "OP2"],
+        conflicts_with = "since_push",
+        add = ArgValueCandidates::new(complete::operations),
+    )]
+    merge_base: Vec<String>,
+    /// Show only the ancestors of the given operation
+    ///
+    /// The operation itself isn't shown. Combined with `--limit`, this can be
+    /// used to page through a long operation log by passing the last operation
+    /// of the previous page. The order is the one before `--reversed` is
+    /// applied.
+    #[arg(
+        long,
+        value_name = "OP",
+        add = ArgValueCandidates::new(complete::operations),
+    )]
+    after_op: Option<String>,
     /// Show changes to the repository at each operation
+    ///
+    /// Each operation is compared to its parent operation, so this is like
+    /// running `jj op show` on every displayed operation. Only the operations
+    /// selected by `--limit` are diffed.
     #[arg(long)]
     op_diff: bool,
     /// Show patch of modifications to changes (implies --op-diff)
@@ -87,7 +168,14 @@ pub fn cmd_op_log(
         let workspace_command = command.workspace_helper(ui)?;
         let current_op = workspace_command.repo().operation();
         let repo_loader = workspace_command.workspace().repo_loader();
-        do_op_log(ui, workspace_command.env(), repo_loader, current_op, args)
+        do_op_log(
+            ui,
+            command,
+            workspace_command.env(),
+            repo_loader,
+            current_op,
+            args,
+        )
     } else {
         // Don't load the repo so that the operation history can be inspected
         // even with a corrupted repo state. For example, you can find the first
@@ -96,31 +184,45 @@ pub fn cmd_op_log(
         let workspace_env = command.workspace_environment(ui, &workspace)?;
         let repo_loader = workspace.repo_loader();
         let current_op = command.resolve_operation(ui, workspace.repo_loader())?;
-        do_op_log(ui, &workspace_env, repo_loader, &current_op, args)
+        do_op_log(ui, command, &workspace_env, repo_loader, &current_op, args)
     }
 }
 
 fn do_op_log(
     ui: &mut Ui,
+    command: &CommandHelper,
     workspace_env: &WorkspaceCommandEnvironment,
     repo_loader: &RepoLoader,
     current_op: &Operation,
     args: &OperationLogArgs,
 ) -> Result<(), CommandError> {
     let settings = repo_loader.settings();
+    let filter = parse_op_filter(ui, workspace_env, repo_loader, current_op, args)?;
+    let (head_op, excluded_root_ops) = resolve_op_log_range(repo_loader, current_op, args)?;
+    let excluded = RefCell::new(ExcludedOps::new(&excluded_root_ops));
+    let start_ops = resolve_start_ops(repo_loader, current_op, head_op, args)?;
+    if command.global_args().output_format == OutputFormat::Json {
+        let iter = walk_listed_ops(&start_ops, &excluded);
+        return write_op_log_json(ui, current_op, iter, filter.as_ref(), args);
+    }
     let graph_style = GraphStyle::from_settings(settings)?;
     let with_content_format = LogContentFormat::new(ui, settings)?;
+    let highlighter = LogHighlighter::new(&args.highlight);
 
     let template;
     let op_node_template;
+    let time_column_template;
     {
         let language = OperationTemplateLanguage::new(
             repo_loader,
             Some(current_op.id()),
             workspace_env.operation_template_extensions(),
         );
-        let text = match &args.template {
-            Some(value) => value.to_owned(),
+        let text = match args
+            .template_file
+            .template_text(command.cwd(), args.template.as_deref())?
+        {
+            Some(value) => value,
             None => settings.get_string("templates.op_log")?,
         };
         template = workspace_env
@@ -129,7 +231,8 @@ fn do_op_log(
                 &language,
                 &text,
                 OperationTemplateLanguage::wrap_operation,
-            )?
+            )
+            .map_err(|err| args.template_file.annotate_parse_error(err))?
             .labeled("operation")
             .labeled("op_log");
         op_node_template = workspace_env
@@ -140,6 +243,19 @@ fn do_op_log(
                 OperationTemplateLanguage::wrap_operation,
             )?
             .labeled("node");
+        time_column_template = settings
+            .get_string("templates.op_log_time_column")
+            .optional()?
+            .map(|text| {
+                workspace_env.parse_template(
+                    ui,
+                    &language,
+                    &text,
+                    OperationTemplateLanguage::wrap_operation,
+                )
+            })
+            .transpose()?
+            .map(|template| template.labeled("operation").labeled("op_log"));
     }
 
     let diff_formats = diff_formats_for_log(settings, &args.diff_format, args.patch)?;
@@ -174,14 +290,15 @@ fn do_op_log(
                     conflict_marker_style,
                     diff_formats.clone(),
                 )
+                .with_checkout_parallelism(workspace_env.checkout_parallelism())
             });
 
             show_op_diff(
                 ui,
                 formatter,
                 repo.as_ref(),
-                &parent_repo,
-                &repo,
+                parent_repo.view(),
+                repo.view(),
                 &commit_summary_template,
                 (!args.no_graph).then_some(graph_style),
                 with_content_format,
@@ -197,29 +314,57 @@ fn do_op_log(
     let mut formatter = ui.stdout_formatter();
     let formatter = formatter.as_mut();
     let limit = args.limit.unwrap_or(usize::MAX);
-    let iter = op_walk::walk_ancestors(slice::from_ref(current_op));
+    let iter = walk_listed_ops(&start_ops, &excluded);
 
     if !args.no_graph {
         let mut raw_output = formatter.raw()?;
         let mut graph = get_graphlog(graph_style, raw_output.as_mut());
-        let iter = iter.map(|op| -> Result<_, OpStoreError> {
-            let op = op?;
-            let ids = op.parent_ids();
-            let edges = ids.iter().cloned().map(GraphEdge::direct).collect();
-            Ok((op, edges))
-        });
+        let iter: Box<dyn Iterator<Item = _>> = if let Some(filter) = &filter {
+            Box::new(filtered_op_graph(iter, filter, &excluded))
+        } else {
+            Box::new(iter.map(|op| -> Result<_, CommandError> {
+                let op = op?;
+                let ids = op.parent_ids();
+                let edges = ids
+                    .iter()
+                    .map(|id| {
+                        if excluded.borrow().contains_id(id) {
+                            GraphEdge::missing(id.clone())
+                        } else {
+                            GraphEdge::direct(id.clone())
+                        }
+                    })
+                    .collect();
+                Ok((op, edges))
+            }))
+        };
         let iter_nodes: Box<dyn Iterator<Item = _>> = if args.reversed {
             Box::new(reverse_graph(iter, Operation::id)?.into_iter().map(Ok))
         } else {
             Box::new(iter)
         };
-        for node in iter_nodes.take(limit) {
+        let iter_nodes = iter_nodes.take(limit);
+        // The time column is aligned across all operations, so they have to be
+        // collected first.
+        let (iter_nodes, time_column): (Box<dyn Iterator<Item = _>>, _) =
+            if let Some(column_template) = &time_column_template {
+                let nodes: Vec<_> = iter_nodes.try_collect()?;
+                let time_column =
+                    TimeColumn::new(ui, column_template, nodes.iter().map(|(op, _)| op))?;
+                (Box::new(nodes.into_iter().map(Ok)), Some(time_column))
+            } else {
+                (Box::new(iter_nodes), None)
+            };
+        for (index, node) in iter_nodes.enumerate() {
             let (op, edges) = node?;
             let mut buffer = vec![];
             let within_graph = with_content_format.sub_width(graph.width(op.id(), &edges));
             within_graph.write(ui.new_formatter(&mut buffer).as_mut(), |formatter| {
-                template.format(&op, formatter)
+                highlighter.write(formatter, |formatter| template.format(&op, formatter))
             })?;
+            if let Some(time_column) = &time_column {
+                time_column.append(&mut buffer, index, &within_graph, &op, &template)?;
+            }
             if !buffer.ends_with(b"\n") {
                 buffer.push(b'\n');
             }
@@ -236,14 +381,35 @@ fn do_op_log(
             )?;
         }
     } else {
+        let iter = filter_operations(iter, filter.as_ref());
         let iter: Box<dyn Iterator<Item = _>> = if args.reversed {
             Box::new(iter.collect_vec().into_iter().rev())
         } else {
             Box::new(iter)
         };
-        for op in iter.take(limit) {
+        let iter = iter.take(limit);
+        let (iter, time_column): (Box<dyn Iterator<Item = _>>, _) =
+            if let Some(column_template) = &time_column_template {
+                let ops: Vec<_> = iter.try_collect()?;
+                let time_column = TimeColumn::new(ui, column_template, ops.iter())?;
+                (Box::new(ops.into_iter().map(Ok)), Some(time_column))
+            } else {
+                (Box::new(iter), None)
+            };
+        for (index, op) in iter.enumerate() {
             let op = op?;
-            with_content_format.write(formatter, |formatter| template.format(&op, formatter))?;
+            if let Some(time_column) = &time_column {
+                let mut buffer = vec![];
+                with_content_format.write(ui.new_formatter(&mut buffer).as_mut(), |formatter| {
+                    highlighter.write(formatter, |formatter| template.format(&op, formatter))
+                })?;
+                time_column.append(&mut buffer, index, &with_content_format, &op, &template)?;
+                formatter.write_all(&buffer)?;
+            } else {
+                with_content_format.write(formatter, |formatter| {
+                    highlighter.write(formatter, |formatter| template.format(&op, formatter))
+                })?;
+            }
             if let Some(show) = &maybe_show_op_diff {
                 show(ui, formatter, &op, &with_content_format)?;
             }
@@ -253,6 +419,389 @@ fn do_op_log(
     Ok(())
 }
 
+/// Parses the operation filter specified by `--filter` or the
+/// `op-log.default-filter` setting.
+fn parse_op_filter(
+    ui: &Ui,
+    workspace_env: &WorkspaceCommandEnvironment,
+    repo_loader: &RepoLoader,
+    current_op: &Operation,
+    args: &OperationLogArgs,
+) -> Result<Option<TemplatePredicate<'static, Operation>>, CommandError> {
+    let text = if args.no_filter {
+        None
+    } else if let Some(text) = &args.filter {
+        Some(text.clone())
+    } else {
+        repo_loader
+            .settings()
+            .get_string("op-log.default-filter")
+            .optional()?
+            .filter(|text| !text.is_empty())
+    };
+    let Some(text) = text else {
+        return Ok(None);
+    };
+    let language = OperationTemplateLanguage::new(
+        repo_loader,
+        Some(current_op.id()),
+        workspace_env.operation_template_extensions(),
+    );
+    let filter = workspace_env.parse_template_predicate(
+        ui,
+        &language,
+        &text,
+        OperationTemplateLanguage::wrap_operation,
+    )?;
+    Ok(Some(filter))
+}
+
+/// Returns the operation to start listing from, and the operations whose
+/// ancestors (including themselves) shouldn't be listed.
+///
+/// With `--merge-base`, only the merge base of the two operations is listed.
+/// With `--since-push`, the most recent push operation of the bookmark and all
+/// of its ancestors are hidden.
+fn resolve_op_log_range(
+    repo_loader: &RepoLoader,
+    current_op: &Operation,
+    args: &OperationLogArgs,
+) -> Result<(Operation, Vec<Operation>), CommandError> {
+    if let [op_str1, op_str2] = args.merge_base.as_slice() {
+        let op_store = repo_loader.op_store();
+        let op1 = op_walk::resolve_op_at(op_store, slice::from_ref(current_op), op_str1)?;
+        let op2 = op_walk::resolve_op_at(op_store, slice::from_ref(current_op), op_str2)?;
+        let merge_base = find_merge_base(repo_loader, &op1, &op2)?;
+        let parents = merge_base.parents().try_collect()?;
+        return Ok((merge_base, parents));
+    }
+    let Some(bookmark) = &args.since_push else {
+        return Ok((current_op.clone(), vec![]));
+    };
+    let pushed = |op: &Operation| {
+        op.metadata()
+            .tags
+            .get(PUSHED_BOOKMARKS_TAG)
+            .is_some_and(|names| names.split('\n').any(|name| name == bookmark))
+    };
+    let push_op = op_walk::walk_ancestors(slice::from_ref(current_op))
+        .find(|op| op.as_ref().map_or(true, pushed))
+        .transpose()?
+        .ok_or_else(|| {
+            user_error(format!(
+                "No push operation found for bookmark \"{bookmark}\""
+            ))
+        })?;
+    Ok((current_op.clone(), vec![push_op]))
+}
+
+/// Ancestors of the operations which shouldn't be listed.
+///
+/// The ancestors are collected lazily, only as far back as the oldest operation
+/// tested so far. The walk relies on timestamps like `op_walk::walk_ancestors()`
+/// does, so this works so long as the operation history is mostly linear.
+struct ExcludedOps {
+    ancestors: Peekable<Box<dyn Iterator<Item = Result<Operation, OpStoreError>>>>,
+    ids: HashSet<OperationId>,
+}
+
+impl ExcludedOps {
+    fn new(root_ops: &[Operation]) -> Self {
+        let ancestors: Box<dyn Iterator<Item = _>> = Box::new(op_walk::walk_ancestors(root_ops));
+        ExcludedOps {
+            ancestors: ancestors.peekable(),
+            ids: HashSet::new(),
+        }
+    }
+
+    /// Returns whether the `op` is excluded.
+    fn contains(&mut self, op: &Operation) -> Result<bool, OpStoreError> {
+        let end_time = &op.metadata().end_time;
+        while let Some(ancestor) = self.ancestors.next_if(|ancestor| {
+            ancestor
+                .as_ref()
+                .map_or(true, |ancestor| ancestor.metadata().end_time >= *end_time)
+        }) {
+            self.ids.insert(ancestor?.id().clone());
+        }
+        Ok(self.ids.contains(op.id()))
+    }
+
+    /// Returns whether the operation of the `id` is excluded. The operation
+    /// must have been tested by `contains()`.
+    fn contains_id(&self, id: &OperationId) -> bool {
+        self.ids.contains(id)
+    }
+}
+
+/// Returns the operations whose ancestors (including themselves) are listed.
+///
+/// With `--after-op`, the walk starts from the parents of the given operation
+/// instead of the `head_op`.
+fn resolve_start_ops(
+    repo_loader: &RepoLoader,
+    current_op: &Operation,
+    head_op: Operation,
+    args: &OperationLogArgs,
+) -> Result<Vec<Operation>, CommandError> {
+    let Some(op_str) = &args.after_op else {
+        return Ok(vec![head_op]);
+    };
+    let op_store = repo_loader.op_store();
+    let after_op = op_walk::resolve_op_at(op_store, slice::from_ref(current_op), op_str)?;
+    Ok(after_op.parents().try_collect()?)
+}
+
+/// Walks the ancestors of the `start_ops` to be listed, children first. The
+/// `excluded` operations are skipped.
+fn walk_listed_ops<'a>(
+    start_ops: &[Operation],
+    excluded: &'a RefCell<ExcludedOps>,
+) -> impl Iterator<Item = Result<Operation, OpStoreError>> + 'a {
+    op_walk::walk_ancestors_excluding(start_ops, |op| excluded.borrow_mut().contains(op))
+}
+
+/// Finds the latest common ancestor of `op1` and `op2`. Falls back to the root
+/// operation if there's none.
+fn find_merge_base(
+    repo_loader: &RepoLoader,
+    op1: &Operation,
+    op2: &Operation,
+) -> Result<Operation, CommandError> {
+    // Walk both sides together, newest operation first, so the first operation
+    // reached from both sides is the latest common ancestor.
+    let mut walk1 = op_walk::walk_ancestors(slice::from_ref(op1)).peekable();
+    let mut walk2 = op_walk::walk_ancestors(slice::from_ref(op2)).peekable();
+    let mut visited1 = HashSet::new();
+    let mut visited2 = HashSet::new();
+    loop {
+        let from_first = match (walk1.peek(), walk2.peek()) {
+            (None, None) => break,
+            (Some(Err(_)), _) | (Some(_), None) => true,
+            (_, Some(Err(_))) | (None, Some(_)) => false,
+            (Some(Ok(op1)), Some(Ok(op2))) => op1.metadata().end_time >= op2.metadata().end_time,
+        };
+        let (walk, visited, other_visited) = if from_first {
+            (&mut walk1, &mut visited1, &visited2)
+        } else {
+            (&mut walk2, &mut visited2, &visited1)
+        };
+        let op = walk.next().unwrap()?;
+        if other_visited.contains(op.id()) {
+            return Ok(op);
+        }
+        visited.insert(op.id().clone());
+    }
+    Ok(repo_loader.root_operation())
+}
+
+/// Evaluates the `filter` for the operation, mapping errors to
+/// `CommandError`.
+fn test_op_filter(
+    filter: &TemplatePredicate<Operation>,
+    op: &Operation,
+) -> Result<bool, CommandError> {
+    filter
+        .test(op)
+        .map_err(|err| user_error_with_message("Failed to evaluate operation filter", err.0))
+}
+
+/// Drops operations not matching the `filter` from the `ops` iterator.
+fn filter_operations<'a>(
+    ops: impl Iterator<Item = Result<Operation, OpStoreError>> + 'a,
+    filter: Option<&'a TemplatePredicate<Operation>>,
+) -> impl Iterator<Item = Result<Operation, CommandError>> + 'a {
+    ops.filter_map(move |op| {
+        let op = match op {
+            Ok(op) => op,
+            Err(err) => return Some(Err(err.into())),
+        };
+        match filter.map_or(Ok(true), |filter| test_op_filter(filter, &op)) {
+            Ok(true) => Some(Ok(op)),
+            Ok(false) => None,
+            Err(err) => Some(Err(err)),
+        }
+    })
+}
+
+/// Builds the graph of the operations matching the `filter`. Operations whose
+/// parents are filtered out get indirect edges to their nearest matching
+/// ancestors. `excluded` parents are outside of the listed range, and get
+/// missing edges.
+///
+/// The graph is built lazily. Only the filtered-out ancestors of the emitted
+/// operations are visited ahead of the `ops` iterator.
+fn filtered_op_graph<'a>(
+    ops: impl Iterator<Item = Result<Operation, OpStoreError>> + 'a,
+    filter: &'a TemplatePredicate<Operation>,
+    excluded: &'a RefCell<ExcludedOps>,
+) -> impl Iterator<Item = Result<GraphNode<Operation, OperationId>, CommandError>> + 'a {
+    let mut graph_filter = OpGraphFilter {
+        filter,
+        excluded,
+        visible: HashMap::new(),
+        hidden_edges: HashMap::new(),
+    };
+    ops.map(move |op| {
+        let op = op?;
+        if !graph_filter.is_visible(&op)? {
+            return Ok(None);
+        }
+        let edges = graph_filter.edges_to_parents(&op)?;
+        Ok(Some((op, edges)))
+    })
+    .filter_map(Result::transpose)
+}
+
+struct OpGraphFilter<'a> {
+    filter: &'a TemplatePredicate<'a, Operation>,
+    excluded: &'a RefCell<ExcludedOps>,
+    /// Whether the operation matches the filter.
+    visible: HashMap<OperationId, bool>,
+    /// Edges of filtered-out operations to their nearest matching ancestors.
+    hidden_edges: HashMap<OperationId, Vec<GraphEdge<OperationId>>>,
+}
+
+impl OpGraphFilter<'_> {
+    fn is_visible(&mut self, op: &Operation) -> Result<bool, CommandError> {
+        if let Some(&visible) = self.visible.get(op.id()) {
+            return Ok(visible);
+        }
+        let visible = test_op_filter(self.filter, op)?;
+        self.visible.insert(op.id().clone(), visible);
+        Ok(visible)
+    }
+
+    /// Returns the edges from `op` to its nearest matching ancestors.
+    fn edges_to_parents(
+        &mut self,
+        op: &Operation,
+    ) -> Result<Vec<GraphEdge<OperationId>>, CommandError> {
+        // Resolve the filtered-out ancestors first, without recursion
+        // since long runs of operations may be filtered out.
+        let mut pending = vec![(op.clone(), false)];
+        while let Some((op, parents_visited)) = pending.pop() {
+            if parents_visited {
+                let edges = self.resolved_edges(op.parent_ids());
+                self.hidden_edges.insert(op.id().clone(), edges);
+                continue;
+            }
+            pending.push((op.clone(), true));
+            for parent in op.parents() {
+                let parent = parent?;
+                if self.hidden_edges.contains_key(parent.id())
+                    || self.excluded.borrow_mut().contains(&parent)?
+                {
+                    continue;
+                }
+                if !self.is_visible(&parent)? {
+                    pending.push((parent, false));
+                }
+            }
+        }
+        Ok(self.hidden_edges.remove(op.id()).unwrap())
+    }
+
+    /// Builds edges to the `parent_ids`, whose filtered-out ancestors have
+    /// been resolved.
+    fn resolved_edges(&self, parent_ids: &[OperationId]) -> Vec<GraphEdge<OperationId>> {
+        parent_ids
+            .iter()
+            .flat_map(|id| {
+                if self.excluded.borrow().contains_id(id) {
+                    // The parent is outside of the listed range.
+                    vec![GraphEdge::missing(id.clone())]
+                } else if let Some(edges) = self.hidden_edges.get(id) {
+                    edges
+                        .iter()
+                        .map(|edge| GraphEdge::indirect(edge.target.clone()))
+                        .collect()
+                } else {
+                    vec![GraphEdge::direct(id.clone())]
+                }
+            })
+            .unique()
+            .collect_vec()
+    }
+}
+
+/// Column rendered right-aligned at the end of the first line of each
+/// operation, configured by `templates.op_log_time_column`.
+struct TimeColumn {
+    /// Rendered cell of each displayed operation and its width.
+    cells: Vec<(Vec<u8>, usize)>,
+    /// Width of the widest cell.
+    width: usize,
+}
+
+impl TimeColumn {
+    fn new<'a>(
+        ui: &Ui,
+        template: &TemplateRenderer<Operation>,
+        ops: impl IntoIterator<Item = &'a Operation>,
+    ) -> Result<Self, CommandError> {
+        let cells: Vec<_> = ops
+            .into_iter()
+            .map(|op| {
+                let mut cell = vec![];
+                template.format(op, ui.new_formatter(&mut cell).as_mut())?;
+                let width = first_line_width(template, op, None)?;
+                Ok::<_, CommandError>((cell, width))
+            })
+            .try_collect()?;
+        let width = cells.iter().map(|(_, width)| *width).max().unwrap_or(0);
+        Ok(TimeColumn { cells, width })
+    }
+
+    /// Inserts the cell at `index` at the end of the first line of the
+    /// `buffer`, which contains the `op` rendered by the `template`.
+    fn append(
+        &self,
+        buffer: &mut Vec<u8>,
+        index: usize,
+        content_format: &LogContentFormat,
+        op: &Operation,
+        template: &TemplateRenderer<Operation>,
+    ) -> Result<(), CommandError> {
+        let (cell, cell_width) = &self.cells[index];
+        let cell = cell.split(|&b| b == b'\n').next().unwrap();
+        let line_width = first_line_width(template, op, Some(content_format))?;
+        // If the line is too long to fit the column, separate it by a single
+        // space instead of aligning it.
+        let padding = content_format
+            .width()
+            .saturating_sub(line_width + self.width)
+            .max(1)
+            + (self.width - cell_width);
+        let line_end = buffer
+            .iter()
+            .position(|&b| b == b'\n')
+            .unwrap_or(buffer.len());
+        let mut inserted = vec![b' '; padding];
+        inserted.extend_from_slice(cell);
+        buffer.splice(line_end..line_end, inserted);
+        Ok(())
+    }
+}
+
+/// Returns the display width of the first line of the `op` rendered by the
+/// `template`.
+fn first_line_width(
+    template: &TemplateRenderer<Operation>,
+    op: &Operation,
+    content_format: Option<&LogContentFormat>,
+) -> Result<usize, CommandError> {
+    let mut output = vec![];
+    let mut formatter = PlainTextFormatter::new(&mut output);
+    if let Some(content_format) = content_format {
+        content_format.write(&mut formatter, |formatter| template.format(op, formatter))?;
+    } else {
+        template.format(op, &mut formatter)?;
+    }
+    let first_line = output.split(|&b| b == b'\n').next().unwrap();
+    Ok(String::from_utf8_lossy(first_line).width())
+}
+
 fn get_node_template(style: GraphStyle, settings: &UserSettings) -> Result<String, ConfigGetError> {
     let symbol = settings.get_string("templates.op_log_node").optional()?;
     let default = if style.is_ascii() {
@@ -262,3 +811,57 @@ fn get_node_template(style: GraphStyle, settings: &UserSettings) -> Result<Strin
     };
     Ok(symbol.unwrap_or_else(|| default.to_owned()))
 }
+
+#[derive(Serialize)]
+struct OperationJson {
+    id: String,
+    parents: Vec<String>,
+    start_time: String,
+    end_time: String,
+    description: String,
+    hostname: String,
+    username: String,
+    is_snapshot: bool,
+    tags: BTreeMap<String, String>,
+    current: bool,
+}
+
+impl OperationJson {
+    fn new(op: &Operation, current_op: &Operation) -> Result<Self, CommandError> {
+        let metadata = op.metadata();
+        let format_time = |timestamp| format_rfc3339_timestamp(timestamp).map_err(user_error);
+        Ok(OperationJson {
+            id: op.id().hex(),
+            parents: op.parent_ids().iter().map(|id| id.hex()).collect(),
+            start_time: format_time(&metadata.start_time)?,
+            end_time: format_time(&metadata.end_time)?,
+            description: metadata.description.clone(),
+            hostname: metadata.hostname.clone(),
+            username: metadata.username.clone(),
+            is_snapshot: metadata.is_snapshot,
+            tags: metadata.tags.clone().into_iter().collect(),
+            current: op.id() == current_op.id(),
+        })
+    }
+}
+
+fn write_op_log_json(
+    ui: &Ui,
+    current_op: &Operation,
+    ops: impl Iterator<Item = Result<Operation, OpStoreError>>,
+    filter: Option<&TemplatePredicate<Operation>>,
+    args: &OperationLogArgs,
+) -> Result<(), CommandError> {
+    let limit = args.limit.unwrap_or(usize::MAX);
+    let iter = filter_operations(ops, filter);
+    let iter: Box<dyn Iterator<Item = _>> = if args.reversed {
+        Box::new(iter.collect_vec().into_iter().rev())
+    } else {
+        Box::new(iter)
+    };
+    let ops: Vec<_> = iter
+        .take(limit)
+        .map(|op| OperationJson::new(&op?, current_op))
+        .try_collect()?;
+    write_json(ui, &ops)
+}
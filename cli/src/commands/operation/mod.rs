@@ -15,25 +15,37 @@
 mod abandon;
 mod diff;
 mod log;
+pub mod redo;
 mod restore;
 mod show;
+mod snapshot;
 pub mod undo;
 
 use abandon::cmd_op_abandon;
 use abandon::OperationAbandonArgs;
 use clap::Subcommand;
+use clap::ValueEnum as _;
 use diff::cmd_op_diff;
 use diff::OperationDiffArgs;
+use itertools::Itertools as _;
+use jj_lib::op_store::OperationId;
+use jj_lib::operation::Operation;
+use jj_lib::repo::RepoLoader;
 use log::cmd_op_log;
 use log::OperationLogArgs;
+use redo::cmd_op_redo;
+use redo::OperationRedoArgs;
 use restore::cmd_op_restore;
 use restore::OperationRestoreArgs;
 use show::cmd_op_show;
 use show::OperationShowArgs;
+use snapshot::cmd_op_snapshot;
+use snapshot::OperationSnapshotArgs;
 use undo::cmd_op_undo;
 use undo::OperationUndoArgs;
 
 use crate::cli_util::CommandHelper;
+use crate::command_error::internal_error_with_message;
 use crate::command_error::CommandError;
 use crate::ui::Ui;
 
@@ -46,8 +58,10 @@ pub enum OperationCommand {
     Abandon(OperationAbandonArgs),
     Diff(OperationDiffArgs),
     Log(OperationLogArgs),
+    Redo(OperationRedoArgs),
     Restore(OperationRestoreArgs),
     Show(OperationShowArgs),
+    Snapshot(OperationSnapshotArgs),
     Undo(OperationUndoArgs),
 }
 
@@ -60,21 +74,128 @@ pub fn cmd_operation(
         OperationCommand::Abandon(args) => cmd_op_abandon(ui, command, args),
         OperationCommand::Diff(args) => cmd_op_diff(ui, command, args),
         OperationCommand::Log(args) => cmd_op_log(ui, command, args),
+        OperationCommand::Redo(args) => cmd_op_redo(ui, command, args),
         OperationCommand::Restore(args) => cmd_op_restore(ui, command, args),
         OperationCommand::Show(args) => cmd_op_show(ui, command, args),
+        OperationCommand::Snapshot(args) => cmd_op_snapshot(ui, command, args),
         OperationCommand::Undo(args) => cmd_op_undo(ui, command, args),
     }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
 enum UndoWhatToRestore {
-    /// The jj repo state and local bookmarks
+    /// The jj repo state and local bookmarks (equivalent to `commits`,
+    /// `bookmarks`, and `working-copy` together)
     Repo,
+    /// The visible commits
+    Commits,
+    /// The local bookmarks and tags
+    Bookmarks,
+    /// The working-copy commits of the workspaces
+    WorkingCopy,
     /// The remote-tracking bookmarks. Do not restore these if you'd like to
     /// push after the undo
     RemoteTracking,
 }
 
+impl UndoWhatToRestore {
+    /// Returns true if this portion is restored by any of the `what`
+    /// arguments.
+    fn is_selected(self, what: &[UndoWhatToRestore]) -> bool {
+        what.iter().any(|&item| {
+            item == self
+                || item == UndoWhatToRestore::Repo
+                    && matches!(
+                        self,
+                        UndoWhatToRestore::Commits
+                            | UndoWhatToRestore::Bookmarks
+                            | UndoWhatToRestore::WorkingCopy
+                    )
+        })
+    }
+}
+
+/// Operation metadata key recording the id of the operation undone by an undo
+/// operation.
+const UNDO_OF_TAG: &str = "jj.undo-of";
+/// Operation metadata key recording the id of the undo operation reverted by a
+/// redo operation.
+const REDO_OF_TAG: &str = "jj.redo-of";
+/// Operation metadata key recording the names of the bookmarks pushed by a
+/// `jj git push` operation, separated by newlines.
+pub(crate) const PUSHED_BOOKMARKS_TAG: &str = "jj.pushed-bookmarks";
+
+/// Loads the operation whose id is stored in the metadata `key` of `op`.
+fn load_tagged_operation(
+    repo_loader: &RepoLoader,
+    op: &Operation,
+    key: &str,
+) -> Result<Option<Operation>, CommandError> {
+    let Some(hex) = op.metadata().tags.get(key) else {
+        return Ok(None);
+    };
+    let id = OperationId::try_from_hex(hex).map_err(|err| {
+        internal_error_with_message(format!("Invalid operation id in `{key}` metadata"), err)
+    })?;
+    Ok(Some(repo_loader.load_operation(&id)?))
+}
+
+/// Returns the only parent of `op`, or `None` if it has zero or several.
+fn single_parent(op: &Operation) -> Result<Option<Operation>, CommandError> {
+    let parents: Vec<_> = op.parents().try_collect()?;
+    Ok(match <[_; 1]>::try_from(parents) {
+        Ok([parent]) => Some(parent),
+        Err(_) => None,
+    })
+}
+
+/// Finds the operation `jj undo` should undo when the repo is at `op`.
+///
+/// Undo and redo operations are skipped so that repeated `jj undo` steps back
+/// through the history like an editor's undo stack.
+fn find_undo_target(repo_loader: &RepoLoader, op: &Operation) -> Result<Operation, CommandError> {
+    let mut op = op.clone();
+    loop {
+        if let Some(undone_op) = load_tagged_operation(repo_loader, &op, UNDO_OF_TAG)? {
+            // Continue from the state before the undone operation.
+            match single_parent(&undone_op)? {
+                Some(parent_op) => op = parent_op,
+                None => return Ok(undone_op),
+            }
+        } else if let Some(undo_op) = load_tagged_operation(repo_loader, &op, REDO_OF_TAG)? {
+            // The redo reapplied the operation undone by `undo_op`.
+            match load_tagged_operation(repo_loader, &undo_op, UNDO_OF_TAG)? {
+                Some(undone_op) => op = undone_op,
+                None => return Ok(op),
+            }
+        } else {
+            return Ok(op);
+        }
+    }
+}
+
+/// Finds the undo operation `jj redo` should revert when the repo is at `op`.
+fn find_redo_target(
+    repo_loader: &RepoLoader,
+    op: &Operation,
+) -> Result<Option<Operation>, CommandError> {
+    let mut op = op.clone();
+    loop {
+        if op.metadata().tags.contains_key(UNDO_OF_TAG) {
+            return Ok(Some(op));
+        }
+        // After a redo, the remaining undo operations are the ones which could
+        // be redone before the reverted undo.
+        let Some(undo_op) = load_tagged_operation(repo_loader, &op, REDO_OF_TAG)? else {
+            return Ok(None);
+        };
+        let Some(parent_op) = single_parent(&undo_op)? else {
+            return Ok(None);
+        };
+        op = parent_op;
+    }
+}
+
 const DEFAULT_UNDO_WHAT: [UndoWhatToRestore; 2] =
     [UndoWhatToRestore::Repo, UndoWhatToRestore::RemoteTracking];
 
@@ -84,23 +205,56 @@ fn view_with_desired_portions_restored(
     current_view: &jj_lib::op_store::View,
     what: &[UndoWhatToRestore],
 ) -> jj_lib::op_store::View {
-    let repo_source = if what.contains(&UndoWhatToRestore::Repo) {
-        view_being_restored
-    } else {
-        current_view
-    };
-    let remote_source = if what.contains(&UndoWhatToRestore::RemoteTracking) {
-        view_being_restored
-    } else {
-        current_view
+    let source = |portion: UndoWhatToRestore| {
+        if portion.is_selected(what) {
+            view_being_restored
+        } else {
+            current_view
+        }
     };
+    let commits_source = source(UndoWhatToRestore::Commits);
+    let bookmarks_source = source(UndoWhatToRestore::Bookmarks);
+    let wc_source = source(UndoWhatToRestore::WorkingCopy);
+    let remote_source = source(UndoWhatToRestore::RemoteTracking);
+    // Commits referenced by the restored refs must stay visible even if the
+    // heads themselves aren't restored. Redundant heads are pruned when the
+    // view invariants are enforced.
+    let mut head_ids = commits_source.head_ids.clone();
+    head_ids.extend(
+        itertools::chain(
+            bookmarks_source.local_bookmarks.values(),
+            bookmarks_source.tags.values(),
+        )
+        .flat_map(|target| target.added_ids())
+        .chain(wc_source.wc_commit_ids.values())
+        .cloned(),
+    );
     jj_lib::op_store::View {
-        head_ids: repo_source.head_ids.clone(),
-        local_bookmarks: repo_source.local_bookmarks.clone(),
-        tags: repo_source.tags.clone(),
+        head_ids,
+        local_bookmarks: bookmarks_source.local_bookmarks.clone(),
+        tags: bookmarks_source.tags.clone(),
         remote_views: remote_source.remote_views.clone(),
         git_refs: current_view.git_refs.clone(),
         git_head: current_view.git_head.clone(),
-        wc_commit_ids: repo_source.wc_commit_ids.clone(),
+        wc_commit_ids: wc_source.wc_commit_ids.clone(),
+    }
+}
+
+/// Describes the restored portions if only some of them were selected.
+fn describe_partially_restored_portions(what: &[UndoWhatToRestore]) -> Option<String> {
+    let portions = [
+        UndoWhatToRestore::Commits,
+        UndoWhatToRestore::Bookmarks,
+        UndoWhatToRestore::WorkingCopy,
+        UndoWhatToRestore::RemoteTracking,
+    ];
+    if portions.iter().all(|portion| portion.is_selected(what)) {
+        return None;
     }
+    let names = portions
+        .iter()
+        .filter(|portion| portion.is_selected(what))
+        .map(|portion| portion.to_possible_value().unwrap().get_name().to_owned())
+        .join(", ");
+    Some(names)
 }
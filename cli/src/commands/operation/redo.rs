@@ -0,0 +1,70 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use jj_lib::object_id::ObjectId;
+
+use super::find_redo_target;
+use super::load_tagged_operation;
+use super::single_parent;
+use super::undo::revert_operation;
+use super::DEFAULT_UNDO_WHAT;
+use super::REDO_OF_TAG;
+use super::UNDO_OF_TAG;
+use crate::cli_util::CommandHelper;
+use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
+use crate::command_error::CommandError;
+use crate::ui::Ui;
+
+/// Redo the most recently undone operation
+///
+/// This reverts the most recent `jj undo`. Running `jj redo` repeatedly
+/// reapplies the operations undone by successive `jj undo` commands, most
+/// recently undone first. Once another operation is made, the undone
+/// operations can no longer be redone.
+#[derive(clap::Args, Clone, Debug)]
+pub struct OperationRedoArgs {}
+
+pub fn cmd_op_redo(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    _args: &OperationRedoArgs,
+) -> Result<(), CommandError> {
+    let mut workspace_command = command.workspace_helper(ui)?;
+    let repo = workspace_command.repo();
+    let repo_loader = repo.loader();
+    let Some(undo_op) = find_redo_target(repo_loader, repo.operation())? else {
+        return Err(user_error_with_hint(
+            "Nothing to redo",
+            "Only operations undone by `jj undo` can be redone",
+        ));
+    };
+    let parent_op =
+        single_parent(&undo_op)?.ok_or_else(|| user_error("Cannot redo a merge operation"))?;
+    let undone_op = load_tagged_operation(repo_loader, &undo_op, UNDO_OF_TAG)?
+        .unwrap_or_else(|| parent_op.clone());
+
+    let mut tx = workspace_command.start_transaction();
+    revert_operation(&mut tx, &undo_op, &parent_op, &DEFAULT_UNDO_WHAT)?;
+    tx.set_tag(REDO_OF_TAG.to_owned(), undo_op.id().hex());
+    if let Some(mut formatter) = ui.status_formatter() {
+        write!(formatter, "Redid operation: ")?;
+        let template = tx.base_workspace_helper().operation_summary_template();
+        template.format(&undone_op, formatter.as_mut())?;
+        writeln!(formatter)?;
+    }
+    tx.finish(ui, format!("redo operation {}", undone_op.id().hex()))?;
+
+    Ok(())
+}
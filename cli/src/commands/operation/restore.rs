@@ -15,6 +15,7 @@
 use clap_complete::ArgValueCandidates;
 use jj_lib::object_id::ObjectId;
 
+use super::describe_partially_restored_portions;
 use super::view_with_desired_portions_restored;
 use super::UndoWhatToRestore;
 use super::DEFAULT_UNDO_WHAT;
@@ -63,6 +64,9 @@ pub fn cmd_op_restore(
         let template = tx.base_workspace_helper().operation_summary_template();
         template.format(&target_op, formatter.as_mut())?;
         writeln!(formatter)?;
+        if let Some(portions) = describe_partially_restored_portions(&args.what) {
+            writeln!(formatter, "Restored only: {portions}")?;
+        }
     }
     tx.finish(ui, format!("restore to operation {}", target_op.id().hex()))?;
 
@@ -82,6 +82,7 @@ pub fn cmd_op_show(
                 conflict_marker_style,
                 formats,
             )
+            .with_checkout_parallelism(workspace_env.checkout_parallelism())
         })
     };
 
@@ -101,8 +102,8 @@ pub fn cmd_op_show(
         ui,
         formatter.as_mut(),
         repo.as_ref(),
-        &parent_repo,
-        &repo,
+        parent_repo.view(),
+        repo.view(),
         &commit_summary_template,
         (!args.no_graph).then_some(graph_style),
         &with_content_format,
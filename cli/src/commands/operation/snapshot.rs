@@ -0,0 +1,61 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write as _;
+
+use crate::cli_util::short_operation_hash;
+use crate::cli_util::CommandHelper;
+use crate::command_error::user_error;
+use crate::command_error::CommandError;
+use crate::ui::Ui;
+
+/// Snapshot the working copy and record it as an operation
+///
+/// Most commands snapshot the working copy before doing anything else. This
+/// command only snapshots it, which is useful to record a checkpoint that can
+/// be restored with `jj op restore`, e.g. before running external tools that
+/// modify the working copy.
+///
+/// The id of the new operation is printed. If nothing changed in the working
+/// copy, no operation is recorded.
+#[derive(clap::Args, Clone, Debug)]
+pub struct OperationSnapshotArgs {}
+
+pub fn cmd_op_snapshot(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    _args: &OperationSnapshotArgs,
+) -> Result<(), CommandError> {
+    let global_args = command.global_args();
+    if global_args.ignore_working_copy || global_args.no_snapshot {
+        return Err(user_error(
+            "Cannot snapshot the working copy with --ignore-working-copy or --no-snapshot",
+        ));
+    }
+    if !command.is_at_head_operation() {
+        return Err(user_error(
+            "Cannot snapshot the working copy at a non-head operation",
+        ));
+    }
+    let mut workspace_command = command.workspace_helper_no_snapshot(ui)?;
+    let old_op_id = workspace_command.repo().op_id().clone();
+    workspace_command.maybe_snapshot(ui)?;
+    let new_op_id = workspace_command.repo().op_id();
+    if *new_op_id == old_op_id {
+        writeln!(ui.status(), "Nothing changed.")?;
+    } else {
+        writeln!(ui.stdout(), "{}", short_operation_hash(new_op_id))?;
+    }
+    Ok(())
+}
@@ -13,35 +13,59 @@
 // limitations under the License.
 
 use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
 use jj_lib::object_id::ObjectId;
+use jj_lib::operation::Operation;
 use jj_lib::repo::Repo;
+use jj_lib::transaction::Transaction;
 
+use super::describe_partially_restored_portions;
+use super::diff::show_op_diff;
+use super::find_undo_target;
 use super::view_with_desired_portions_restored;
 use super::UndoWhatToRestore;
 use super::DEFAULT_UNDO_WHAT;
+use super::UNDO_OF_TAG;
 use crate::cli_util::CommandHelper;
+use crate::cli_util::LogContentFormat;
+use crate::cli_util::WorkspaceCommandHelper;
+use crate::cli_util::WorkspaceCommandTransaction;
 use crate::command_error::user_error;
 use crate::command_error::CommandError;
+use crate::commit_templater::CommitTemplateLanguage;
 use crate::complete;
+use crate::graphlog::GraphStyle;
 use crate::ui::Ui;
 
 /// Create a new operation that undoes an earlier operation
 ///
 /// This undoes an individual operation by applying the inverse of the
 /// operation.
+///
+/// If no operation is specified, the most recent operation that hasn't been
+/// undone yet is undone. Running `jj undo` repeatedly steps back through the
+/// operation history, and `jj redo` reapplies the undone operations.
+///
+/// With `--dry-run`, the commits and refs that would be changed by the undo are
+/// shown in the same format as `jj op diff`, and no operation is recorded.
 #[derive(clap::Args, Clone, Debug)]
 pub struct OperationUndoArgs {
     /// The operation to undo
     ///
-    /// Use `jj op log` to find an operation to undo.
-    #[arg(default_value = "@", add = ArgValueCandidates::new(complete::operations))]
-    operation: String,
+    /// Use `jj op log` to find an operation to undo. If specified, the
+    /// operation is undone even if it is itself an undo operation.
+    #[arg(add = ArgValueCandidates::new(complete::operations))]
+    operation: Option<String>,
 
     /// What portions of the local state to restore (can be repeated)
     ///
     /// This option is EXPERIMENTAL.
     #[arg(long, value_enum, default_values_t = DEFAULT_UNDO_WHAT)]
     what: Vec<UndoWhatToRestore>,
+
+    /// Show what would be changed without undoing the operation
+    #[arg(long)]
+    dry_run: bool,
 }
 
 pub fn cmd_op_undo(
@@ -50,7 +74,13 @@ pub fn cmd_op_undo(
     args: &OperationUndoArgs,
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
-    let bad_op = workspace_command.resolve_single_op(&args.operation)?;
+    let bad_op = match &args.operation {
+        Some(op_str) => workspace_command.resolve_single_op(op_str)?,
+        None => {
+            let repo = workspace_command.repo();
+            find_undo_target(repo.loader(), repo.operation())?
+        }
+    };
     let mut parent_ops = bad_op.parents();
     let Some(parent_op) = parent_ops.next().transpose()? else {
         return Err(user_error("Cannot undo repo initialization"));
@@ -60,23 +90,103 @@ pub fn cmd_op_undo(
     }
 
     let mut tx = workspace_command.start_transaction();
+    revert_operation(&mut tx, &bad_op, &parent_op, &args.what)?;
+    tx.set_tag(UNDO_OF_TAG.to_owned(), bad_op.id().hex());
+    if args.dry_run {
+        let tx = tx.into_inner();
+        return show_undo_preview(ui, &workspace_command, tx, &bad_op);
+    }
+    if let Some(mut formatter) = ui.status_formatter() {
+        write!(formatter, "Undid operation: ")?;
+        let template = tx.base_workspace_helper().operation_summary_template();
+        template.format(&bad_op, formatter.as_mut())?;
+        writeln!(formatter)?;
+        if let Some(portions) = describe_partially_restored_portions(&args.what) {
+            writeln!(formatter, "Restored only: {portions}")?;
+        }
+    }
+    tx.finish(ui, format!("undo operation {}", bad_op.id().hex()))?;
+
+    Ok(())
+}
+
+/// Applies the inverse of `bad_op` to the repo. Only the portions of the view
+/// selected by `what` are restored.
+pub(super) fn revert_operation(
+    tx: &mut WorkspaceCommandTransaction,
+    bad_op: &Operation,
+    parent_op: &Operation,
+    what: &[UndoWhatToRestore],
+) -> Result<(), CommandError> {
     let repo_loader = tx.base_repo().loader();
-    let bad_repo = repo_loader.load_at(&bad_op)?;
-    let parent_repo = repo_loader.load_at(&parent_op)?;
+    let bad_repo = repo_loader.load_at(bad_op)?;
+    let parent_repo = repo_loader.load_at(parent_op)?;
     tx.repo_mut().merge(&bad_repo, &parent_repo)?;
     let new_view = view_with_desired_portions_restored(
         tx.repo().view().store_view(),
         tx.base_repo().view().store_view(),
-        &args.what,
+        what,
     );
     tx.repo_mut().set_view(new_view);
+    Ok(())
+}
+
+/// Prints the changes the undo transaction `tx` would make to the repo.
+fn show_undo_preview(
+    ui: &mut Ui,
+    workspace_command: &WorkspaceCommandHelper,
+    tx: Transaction,
+    bad_op: &Operation,
+) -> Result<(), CommandError> {
     if let Some(mut formatter) = ui.status_formatter() {
-        write!(formatter, "Undid operation: ")?;
-        let template = tx.base_workspace_helper().operation_summary_template();
-        template.format(&bad_op, formatter.as_mut())?;
+        write!(formatter, "Would undo operation: ")?;
+        let template = workspace_command.operation_summary_template();
+        template.format(bad_op, formatter.as_mut())?;
         writeln!(formatter)?;
     }
-    tx.finish(ui, format!("undo operation {}", bad_op.id().hex()))?;
+    if !tx.repo().has_changes() {
+        writeln!(ui.status(), "Nothing would change.")?;
+        return Ok(());
+    }
+    let base_view = tx.base_repo().view();
+    let new_conflicted_bookmarks = tx
+        .repo()
+        .view()
+        .local_bookmarks()
+        .filter(|(name, target)| {
+            target.has_conflict() && !base_view.get_local_bookmark(name).has_conflict()
+        })
+        .map(|(name, _)| name.to_owned())
+        .collect_vec();
 
+    let workspace_env = workspace_command.env();
+    let settings = workspace_command.settings();
+    // Nothing is written to the stores. The transaction's repo is used to
+    // resolve the changed commits.
+    let to_repo = tx.repo();
+    let id_prefix_context = workspace_env.new_id_prefix_context();
+    let commit_summary_template = {
+        let language = workspace_env.commit_template_language(to_repo, &id_prefix_context);
+        let text = settings.get_string("templates.commit_summary")?;
+        workspace_env.parse_template(ui, &language, &text, CommitTemplateLanguage::wrap_commit)?
+    };
+    show_op_diff(
+        ui,
+        ui.stdout_formatter().as_mut(),
+        to_repo,
+        tx.base_repo().view(),
+        to_repo.view(),
+        &commit_summary_template,
+        Some(GraphStyle::from_settings(settings)?),
+        &LogContentFormat::new(ui, settings)?,
+        None,
+    )?;
+    if !new_conflicted_bookmarks.is_empty() {
+        writeln!(
+            ui.warning_default(),
+            "The undo would make these bookmarks conflicted: {}",
+            new_conflicted_bookmarks.join(", ")
+        )?;
+    }
     Ok(())
 }
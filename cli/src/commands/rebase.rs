@@ -23,11 +23,13 @@ use jj_lib::backend::CommitId;
 use jj_lib::commit::Commit;
 use jj_lib::commit::CommitIteratorExt;
 use jj_lib::object_id::ObjectId;
+use jj_lib::op_store::RefTarget;
 use jj_lib::repo::ReadonlyRepo;
 use jj_lib::repo::Repo;
 use jj_lib::revset::ResolvedRevsetExpression;
 use jj_lib::revset::RevsetExpression;
 use jj_lib::revset::RevsetIteratorExt;
+use jj_lib::rewrite::duplicate_commits;
 use jj_lib::rewrite::move_commits;
 use jj_lib::rewrite::EmptyBehaviour;
 use jj_lib::rewrite::MoveCommitsStats;
@@ -36,12 +38,15 @@ use jj_lib::rewrite::RebaseOptions;
 use jj_lib::rewrite::RewriteRefsOptions;
 use tracing::instrument;
 
+use crate::cli_util::short_change_hash;
 use crate::cli_util::short_commit_hash;
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::cli_util::WorkspaceCommandHelper;
+use crate::cli_util::WorkspaceCommandTransaction;
 use crate::command_error::cli_error;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::ui::Ui;
@@ -228,6 +233,28 @@ pub(crate) struct RebaseArgs {
     /// parents.
     #[arg(long)]
     skip_emptied: bool,
+
+    /// Like --skip-emptied, but keep commits with a meaningful description
+    ///
+    /// A commit which becomes empty is only abandoned if its description is
+    /// empty or equal to the `ui.default-description` setting, ignoring
+    /// leading and trailing whitespace. Commits which were already empty, and
+    /// emptied commits with any other description, are kept.
+    #[arg(long, conflicts_with = "skip_emptied")]
+    skip_emptied_undescribed: bool,
+
+    /// Create a bookmark at the copy of the revisions on each destination of
+    /// --onto-each
+    ///
+    /// The bookmarks are named by the prefix followed by the name of the first
+    /// bookmark on the destination, or by its change id if it has none.
+    #[arg(
+        long,
+        requires = "onto_each",
+        conflicts_with_all = ["destination", "insert_after", "insert_before"],
+        value_name = "PREFIX"
+    )]
+    bookmark_prefix: Option<String>,
 }
 
 #[derive(clap::Args, Clone, Debug)]
@@ -264,6 +291,20 @@ pub struct RebaseDestinationArgs {
         add = ArgValueCandidates::new(complete::mutable_revisions),
     )]
     insert_before: Option<Vec<RevisionArg>>,
+    /// Duplicate the revisions onto each of the given revisions (can be
+    /// repeated)
+    ///
+    /// Instead of moving the revisions, a copy with new change ids is created
+    /// on top of each destination, and the original revisions are left in
+    /// place. With `-b`, the revisions which aren't already ancestors of a
+    /// destination are copied onto it.
+    #[arg(
+        long,
+        conflicts_with_all = ["destination", "insert_after", "insert_before"],
+        value_name = "REVSETS",
+        add = ArgValueCandidates::new(complete::all_revisions),
+    )]
+    onto_each: Option<Vec<RevisionArg>>,
 }
 
 #[instrument(skip_all)]
@@ -278,18 +319,27 @@ pub(crate) fn cmd_rebase(
         ));
     }
 
+    let mut workspace_command = command.workspace_helper(ui)?;
+    let empty = if args.skip_emptied_undescribed {
+        EmptyBehaviour::AbandonNewlyEmptyUndescribed
+    } else if args.skip_emptied {
+        EmptyBehaviour::AbandonNewlyEmpty
+    } else {
+        EmptyBehaviour::Keep
+    };
     let rebase_options = RebaseOptions {
-        empty: match args.skip_emptied {
-            true => EmptyBehaviour::AbandonNewlyEmpty,
-            false => EmptyBehaviour::Keep,
-        },
+        empty,
         rewrite_refs: RewriteRefsOptions {
             delete_abandoned_bookmarks: false,
         },
         simplify_ancestor_merge: false,
+        default_description: workspace_command
+            .settings()
+            .get_string("ui.default-description")?,
     };
-    let mut workspace_command = command.workspace_helper(ui)?;
-    if !args.revisions.is_empty() {
+    if let Some(destinations) = &args.destination.onto_each {
+        rebase_onto_each(ui, &mut workspace_command, args, destinations)?;
+    } else if !args.revisions.is_empty() {
         rebase_revisions(
             ui,
             &mut workspace_command,
@@ -328,7 +378,7 @@ fn rebase_revisions(
         .parse_union_revsets(ui, revisions)?
         .evaluate_to_commits()?
         .try_collect()?; // in reverse topological order
-    workspace_command.check_rewritable(target_commits.iter().ids())?;
+    workspace_command.check_rewritable(ui, target_commits.iter().ids())?;
 
     let (new_parents, new_children) =
         compute_rebase_destination(ui, workspace_command, rebase_destination)?;
@@ -363,7 +413,7 @@ fn rebase_source(
         .resolve_some_revsets_default_single(ui, source)?
         .into_iter()
         .collect_vec();
-    workspace_command.check_rewritable(source_commits.iter().ids())?;
+    workspace_command.check_rewritable(ui, source_commits.iter().ids())?;
 
     let (new_parents, new_children) =
         compute_rebase_destination(ui, workspace_command, rebase_destination)?;
@@ -413,7 +463,7 @@ fn rebase_branch(
         .iter()
         .commits(workspace_command.repo().store())
         .try_collect()?;
-    workspace_command.check_rewritable(root_commits.iter().ids())?;
+    workspace_command.check_rewritable(ui, root_commits.iter().ids())?;
     if rebase_destination.destination.is_some() && new_children.is_empty() {
         for commit in &root_commits {
             check_rebase_destinations(workspace_command.repo(), &new_parents, commit)?;
@@ -463,10 +513,126 @@ fn rebase_descendants_transaction(
         &MoveCommitsTarget::Roots(target_roots),
         rebase_options,
     )?;
-    print_move_commits_stats(ui, &stats)?;
+    print_move_commits_stats(ui, &tx, &stats)?;
     tx.finish(ui, tx_description)
 }
 
+/// Duplicates the revisions selected by `args` onto each of the
+/// `destinations`.
+fn rebase_onto_each(
+    ui: &mut Ui,
+    workspace_command: &mut WorkspaceCommandHelper,
+    args: &RebaseArgs,
+    destinations: &[RevisionArg],
+) -> Result<(), CommandError> {
+    let destination_commits = workspace_command
+        .resolve_some_revsets_default_single(ui, destinations)?
+        .into_iter()
+        .collect_vec();
+    let target_expression = if !args.revisions.is_empty() {
+        let commit_ids: Vec<_> = workspace_command
+            .parse_union_revsets(ui, &args.revisions)?
+            .evaluate_to_commit_ids()?
+            .try_collect()?;
+        RevsetExpression::commits(commit_ids)
+    } else if !args.source.is_empty() {
+        let source_commits =
+            workspace_command.resolve_some_revsets_default_single(ui, &args.source)?;
+        RevsetExpression::commits(source_commits.iter().ids().cloned().collect()).descendants()
+    } else {
+        let branch_commits = if args.branch.is_empty() {
+            vec![workspace_command.resolve_single_rev(ui, &RevisionArg::AT)?]
+        } else {
+            workspace_command
+                .resolve_some_revsets_default_single(ui, &args.branch)?
+                .into_iter()
+                .collect_vec()
+        };
+        RevsetExpression::commits(branch_commits.iter().ids().cloned().collect())
+    };
+
+    // Commits to duplicate, and their heads, for each destination
+    let repo = workspace_command.repo().as_ref();
+    let mut targets = vec![];
+    for destination in &destination_commits {
+        let expression = if args.revisions.is_empty() && args.source.is_empty() {
+            RevsetExpression::commit(destination.id().clone()).range(&target_expression)
+        } else {
+            target_expression.clone()
+        };
+        let commit_ids: Vec<CommitId> = expression.clone().evaluate(repo)?.iter().try_collect()?;
+        let head_ids: Vec<CommitId> = expression.heads().evaluate(repo)?.iter().try_collect()?;
+        targets.push((destination, commit_ids, head_ids));
+    }
+
+    let mut tx = workspace_command.start_transaction();
+    let mut results = vec![];
+    for (destination, commit_ids, head_ids) in targets {
+        if commit_ids.is_empty() {
+            writeln!(
+                ui.warning_default(),
+                "No revisions to rebase onto {}",
+                short_commit_hash(destination.id())
+            )?;
+            continue;
+        }
+        let stats =
+            duplicate_commits(tx.repo_mut(), &commit_ids, &[destination.id().clone()], &[])?;
+        let tips = head_ids
+            .iter()
+            .map(|id| stats.duplicated_commits[id].clone())
+            .collect_vec();
+        if let Some(prefix) = &args.bookmark_prefix {
+            let suffix = tx
+                .base_repo()
+                .view()
+                .local_bookmarks_for_commit(destination.id())
+                .map(|(name, _)| name.to_owned())
+                .next()
+                .unwrap_or_else(|| short_change_hash(destination.change_id()));
+            let name = format!("{prefix}{suffix}");
+            if let [tip] = tips.as_slice() {
+                if tx.repo().view().get_local_bookmark(&name).is_present() {
+                    return Err(user_error_with_hint(
+                        format!("Bookmark already exists: {name}"),
+                        "Use a different --bookmark-prefix.",
+                    ));
+                }
+                tx.repo_mut()
+                    .set_local_bookmark_target(&name, RefTarget::normal(tip.id().clone()));
+            } else {
+                writeln!(
+                    ui.warning_default(),
+                    "Not creating bookmark {name} because the revisions have {} heads",
+                    tips.len()
+                )?;
+            }
+        }
+        results.push((destination, commit_ids.len(), tips));
+    }
+
+    if let Some(mut formatter) = ui.status_formatter() {
+        for (destination, num_duplicated, tips) in &results {
+            write!(formatter, "Duplicated {num_duplicated} commits onto ")?;
+            tx.write_commit_summary(formatter.as_mut(), destination)?;
+            writeln!(formatter)?;
+            for tip in tips {
+                write!(formatter, "  Tip: ")?;
+                tx.write_commit_summary(formatter.as_mut(), tip)?;
+                writeln!(formatter)?;
+            }
+        }
+    }
+    tx.finish(
+        ui,
+        format!(
+            "duplicate commits onto each of {} destinations",
+            results.len()
+        ),
+    )?;
+    Ok(())
+}
+
 /// Computes the new parents and children for the given
 /// [`RebaseDestinationArgs`].
 fn compute_rebase_destination(
@@ -524,7 +690,7 @@ fn compute_rebase_destination(
     };
 
     if !new_children.is_empty() {
-        workspace_command.check_rewritable(new_children.iter().ids())?;
+        workspace_command.check_rewritable(ui, new_children.iter().ids())?;
         ensure_no_commit_loop(
             workspace_command.repo().as_ref(),
             &RevsetExpression::commits(new_children.iter().ids().cloned().collect_vec()),
@@ -567,7 +733,7 @@ fn rebase_revisions_transaction(
         &MoveCommitsTarget::Commits(target_commits),
         rebase_options,
     )?;
-    print_move_commits_stats(ui, &stats)?;
+    print_move_commits_stats(ui, &tx, &stats)?;
     tx.finish(ui, tx_description)
 }
 
@@ -612,7 +778,11 @@ fn check_rebase_destinations(
 }
 
 /// Print details about the provided [`MoveCommitsStats`].
-fn print_move_commits_stats(ui: &Ui, stats: &MoveCommitsStats) -> std::io::Result<()> {
+fn print_move_commits_stats(
+    ui: &Ui,
+    tx: &WorkspaceCommandTransaction,
+    stats: &MoveCommitsStats,
+) -> std::io::Result<()> {
     let Some(mut formatter) = ui.status_formatter() else {
         return Ok(());
     };
@@ -620,7 +790,7 @@ fn print_move_commits_stats(ui: &Ui, stats: &MoveCommitsStats) -> std::io::Resul
         num_rebased_targets,
         num_rebased_descendants,
         num_skipped_rebases,
-        num_abandoned,
+        ref abandoned_commits,
     } = stats;
     if num_skipped_rebases > 0 {
         writeln!(
@@ -640,8 +810,18 @@ fn print_move_commits_stats(ui: &Ui, stats: &MoveCommitsStats) -> std::io::Resul
             "Rebased {num_rebased_descendants} descendant commits"
         )?;
     }
-    if num_abandoned > 0 {
-        writeln!(formatter, "Abandoned {num_abandoned} newly emptied commits")?;
+    if !abandoned_commits.is_empty() {
+        writeln!(
+            formatter,
+            "Abandoned {} newly emptied commits:",
+            abandoned_commits.len()
+        )?;
+        let template = tx.base_workspace_helper().commit_summary_template();
+        for commit in abandoned_commits {
+            write!(formatter, "  ")?;
+            template.format(commit, formatter.as_mut())?;
+            writeln!(formatter)?;
+        }
     }
     Ok(())
 }
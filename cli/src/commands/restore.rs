@@ -84,6 +84,17 @@ pub(crate) struct RestoreArgs {
         add = ArgValueCandidates::new(complete::all_revisions),
     )]
     changes_in: Option<RevisionArg>,
+    /// Restore from the merge base of this revision and the destination
+    ///
+    /// This undoes the changes made in the destination (`--into`, or @ by
+    /// default) and its ancestors since they forked from this revision.
+    #[arg(
+        long,
+        value_name = "REVSET",
+        conflicts_with_all = ["from", "changes_in"],
+        add = ArgValueCandidates::new(complete::all_revisions),
+    )]
+    merge_base: Option<RevisionArg>,
     /// Prints an error. DO NOT USE.
     ///
     /// If we followed the pattern of `jj diff` and `jj diffedit`, we would use
@@ -119,7 +130,14 @@ pub(crate) fn cmd_restore(
              revision,\nuse `--into` or `--changes-in`.",
         ));
     }
-    if args.from.is_some() || args.into.is_some() {
+    if let Some(merge_base_arg) = &args.merge_base {
+        to_commit = workspace_command
+            .resolve_single_rev(ui, args.into.as_ref().unwrap_or(&RevisionArg::AT))?;
+        let other = workspace_command.resolve_single_rev(ui, merge_base_arg)?;
+        let from_commit = workspace_command.resolve_merge_base(&[other, to_commit.clone()])?;
+        from_tree = from_commit.tree()?;
+        from_commits = vec![from_commit];
+    } else if args.from.is_some() || args.into.is_some() {
         to_commit = workspace_command
             .resolve_single_rev(ui, args.into.as_ref().unwrap_or(&RevisionArg::AT))?;
         let from_commit = workspace_command
@@ -132,7 +150,7 @@ pub(crate) fn cmd_restore(
         from_tree = to_commit.parent_tree(workspace_command.repo().as_ref())?;
         from_commits = to_commit.parents().try_collect()?;
     }
-    workspace_command.check_rewritable([to_commit.id()])?;
+    workspace_command.check_rewritable(ui, [to_commit.id()])?;
 
     let matcher = workspace_command
         .parse_file_patterns(ui, &args.paths)?
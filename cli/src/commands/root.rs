@@ -22,20 +22,51 @@ use crate::command_error::CommandError;
 use crate::ui::Ui;
 
 /// Show the current workspace root directory
+///
+/// Only the workspace metadata is read, so this also works in a read-only
+/// checkout whose files can't be scanned.
 #[derive(clap::Args, Clone, Debug)]
-pub(crate) struct RootArgs {}
+pub(crate) struct RootArgs {
+    /// Show the path to the backing Git repository instead
+    ///
+    /// This is the `.git` directory of a colocated workspace, or the internal
+    /// Git repository otherwise.
+    #[arg(long)]
+    git: bool,
+}
 
 #[instrument(skip_all)]
 pub(crate) fn cmd_root(
     ui: &mut Ui,
     command: &CommandHelper,
-    RootArgs {}: &RootArgs,
+    args: &RootArgs,
 ) -> Result<(), CommandError> {
-    let root = command
-        .workspace_loader()?
-        .workspace_root()
+    print_root(ui, command, args.git)
+}
+
+/// Prints the workspace root, or the Git repository path if `git` is set.
+pub(crate) fn print_root(ui: &Ui, command: &CommandHelper, git: bool) -> Result<(), CommandError> {
+    let root = if git {
+        git_repo_path(command)?
+    } else {
+        command.workspace_loader()?.workspace_root().to_owned()
+    };
+    let root = root
         .to_str()
         .ok_or_else(|| user_error("The workspace root is not valid UTF-8"))?;
     writeln!(ui.stdout(), "{root}")?;
     Ok(())
 }
+
+#[cfg(feature = "git")]
+fn git_repo_path(command: &CommandHelper) -> Result<std::path::PathBuf, CommandError> {
+    let workspace = command.load_workspace()?;
+    let store = workspace.repo_loader().store();
+    let git_backend = crate::git_util::get_git_backend(store)?;
+    Ok(git_backend.git_repo_path().to_owned())
+}
+
+#[cfg(not(feature = "git"))]
+fn git_repo_path(_command: &CommandHelper) -> Result<std::path::PathBuf, CommandError> {
+    Err(user_error("The repo is not backed by a git repo"))
+}
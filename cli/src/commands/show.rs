@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use clap_complete::ArgValueCandidates;
+use jj_lib::copies::CopyRecords;
 use jj_lib::matchers::EverythingMatcher;
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
+use crate::cli_util::TemplateFileArgs;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::diff_util::DiffFormatArgs;
@@ -25,6 +27,7 @@ use crate::ui::Ui;
 
 /// Show commit description and changes in a revision
 #[derive(clap::Args, Clone, Debug)]
+#[command(mut_arg("null_terminated", |a| a.short('z')))]
 pub(crate) struct ShowArgs {
     /// Show changes in this revision, compared to its parent(s)
     #[arg(
@@ -42,6 +45,11 @@ pub(crate) struct ShowArgs {
     #[arg(long, short = 'T')]
     template: Option<String>,
     #[command(flatten)]
+    template_file: TemplateFileArgs,
+    /// Show the changes in reverse, as if the revision were reverted
+    #[arg(long)]
+    reverse: bool,
+    #[command(flatten)]
     format: DiffFormatArgs,
 }
 
@@ -53,16 +61,42 @@ pub(crate) fn cmd_show(
 ) -> Result<(), CommandError> {
     let workspace_command = command.workspace_helper(ui)?;
     let commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
-    let template_string = match &args.template {
-        Some(value) => value.to_string(),
+    let template_string = match args
+        .template_file
+        .template_text(command.cwd(), args.template.as_deref())?
+    {
+        Some(value) => value,
         None => workspace_command.settings().get_string("templates.show")?,
     };
-    let template = workspace_command.parse_commit_template(ui, &template_string)?;
+    let template = workspace_command
+        .parse_commit_template(ui, &template_string)
+        .map_err(|err| args.template_file.annotate_parse_error(err))?;
     let diff_renderer = workspace_command.diff_renderer_for(&args.format)?;
     ui.request_pager();
     let mut formatter = ui.stdout_formatter();
     let formatter = formatter.as_mut();
     template.format(&commit, formatter)?;
-    diff_renderer.show_patch(ui, formatter, &commit, &EverythingMatcher, ui.term_width())?;
+    if args.reverse {
+        let mut copy_records = CopyRecords::default();
+        for parent_id in commit.parent_ids() {
+            diff_renderer.add_copy_records(
+                &mut copy_records,
+                commit.id(),
+                parent_id,
+                &EverythingMatcher,
+            )?;
+        }
+        diff_renderer.show_diff(
+            ui,
+            formatter,
+            &commit.tree()?,
+            &commit.parent_tree(workspace_command.repo().as_ref())?,
+            &EverythingMatcher,
+            &copy_records,
+            ui.term_width(),
+        )?;
+    } else {
+        diff_renderer.show_patch(ui, formatter, &commit, &EverythingMatcher, ui.term_width())?;
+    }
     Ok(())
 }
@@ -0,0 +1,146 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::io::Write as _;
+
+use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
+use jj_lib::backend::CommitId;
+use jj_lib::commit::Commit;
+use jj_lib::commit::CommitIteratorExt as _;
+use jj_lib::repo::Repo as _;
+use jj_lib::signing::SignBehavior;
+
+use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
+use crate::cli_util::WorkspaceCommandTransaction;
+use crate::command_error::user_error_with_hint;
+use crate::command_error::CommandError;
+use crate::complete;
+use crate::ui::Ui;
+
+/// Cryptographically sign revisions
+///
+/// The selected revisions are rewritten with a signature made by the
+/// configured `signing.backend`. Commits that are already signed are signed
+/// again. Since this changes the commit IDs, descendants are rebased onto the
+/// signed commits and bookmarks are updated.
+#[derive(clap::Args, Clone, Debug)]
+pub(crate) struct SignArgs {
+    /// The revision(s) to sign
+    #[arg(
+        long,
+        short,
+        value_name = "REVSETS",
+        default_value = "@",
+        add = ArgValueCandidates::new(complete::mutable_revisions)
+    )]
+    revisions: Vec<RevisionArg>,
+    /// The key to sign with, instead of the `signing.key` setting
+    #[arg(long)]
+    key: Option<String>,
+}
+
+pub(crate) fn cmd_sign(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &SignArgs,
+) -> Result<(), CommandError> {
+    let mut workspace_command = command.workspace_helper(ui)?;
+    if !workspace_command.repo().store().signer().can_sign() {
+        return Err(user_error_with_hint(
+            "No signing backend is configured",
+            "Set `signing.backend` to the backend to sign with",
+        ));
+    }
+    let to_sign: Vec<Commit> = workspace_command
+        .parse_union_revsets(ui, &args.revisions)?
+        .evaluate_to_commits()?
+        .try_collect()?;
+    workspace_command.check_rewritable(ui, to_sign.iter().ids())?;
+
+    let mut tx = workspace_command.start_transaction();
+    let (signed_commits, num_rebased) =
+        rewrite_signatures(&mut tx, &to_sign, SignBehavior::Force, args.key.clone())?;
+
+    let user_email = tx.settings().user_email().to_owned();
+    let num_foreign = signed_commits
+        .iter()
+        .filter(|commit| commit.author().email != user_email)
+        .count();
+    if num_foreign > 0 {
+        writeln!(
+            ui.warning_default(),
+            "Signed {num_foreign} commits not authored by you"
+        )?;
+    }
+    print_rewritten_commits(ui, &tx, "Signed", &signed_commits, num_rebased)?;
+    tx.finish(ui, format!("sign {} commits", signed_commits.len()))?;
+    Ok(())
+}
+
+/// Rewrites `commits` with the given `sign_behavior` and rebases their
+/// descendants. Returns the rewritten commits and the number of rebased
+/// descendants.
+pub(crate) fn rewrite_signatures(
+    tx: &mut WorkspaceCommandTransaction,
+    commits: &[Commit],
+    sign_behavior: SignBehavior,
+    sign_key: Option<String>,
+) -> Result<(Vec<Commit>, usize), CommandError> {
+    let commit_ids: HashSet<&CommitId> = commits.iter().ids().collect();
+    let mut rewritten_commits = Vec::new();
+    let mut num_rebased = 0;
+    tx.repo_mut().transform_descendants(
+        commits.iter().ids().cloned().collect_vec(),
+        |rewriter| {
+            if commit_ids.contains(rewriter.old_commit().id()) {
+                let commit = rewriter
+                    .reparent()
+                    .set_sign_behavior(sign_behavior)
+                    .set_sign_key(sign_key.clone())
+                    .write()?;
+                rewritten_commits.push(commit);
+            } else {
+                rewriter.reparent().write()?;
+                num_rebased += 1;
+            }
+            Ok(())
+        },
+    )?;
+    Ok((rewritten_commits, num_rebased))
+}
+
+pub(crate) fn print_rewritten_commits(
+    ui: &Ui,
+    tx: &WorkspaceCommandTransaction,
+    verb: &str,
+    commits: &[Commit],
+    num_rebased: usize,
+) -> Result<(), CommandError> {
+    if let Some(mut formatter) = ui.status_formatter() {
+        let template = tx.commit_summary_template();
+        writeln!(formatter, "{verb} {} commits:", commits.len())?;
+        for commit in commits {
+            write!(formatter, "  ")?;
+            template.format(commit, formatter.as_mut())?;
+            writeln!(formatter)?;
+        }
+        if num_rebased > 0 {
+            writeln!(formatter, "Rebased {num_rebased} descendant commits")?;
+        }
+    }
+    Ok(())
+}
@@ -74,7 +74,7 @@ pub(crate) fn cmd_simplify_parents(
         .attach_revset_evaluator(revs)
         .evaluate_to_commit_ids()?
         .try_collect()?;
-    workspace_command.check_rewritable(&commit_ids)?;
+    workspace_command.check_rewritable(ui, &commit_ids)?;
     let commit_ids_set: HashSet<_> = commit_ids.iter().cloned().collect();
     let num_orig_commits = commit_ids.len();
 
@@ -15,9 +15,14 @@
 use std::io::Write as _;
 use std::path::Path;
 
+use jj_lib::matchers::Matcher as _;
+use jj_lib::matchers::PrefixMatcher;
+use jj_lib::repo::Repo as _;
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
+use crate::cli_util::WorkspaceCommandHelper;
+use crate::command_error::user_error;
 use crate::command_error::CommandError;
 use crate::ui::Ui;
 
@@ -27,15 +32,24 @@ use crate::ui::Ui;
 /// matching all files from the repo root. That pattern is rendered as `.` (a
 /// single period).
 #[derive(clap::Args, Clone, Debug)]
-pub struct SparseListArgs {}
+pub struct SparseListArgs {
+    /// List the files in the working-copy commit instead of the patterns,
+    /// showing whether each file is present on disk, excluded by the
+    /// patterns, or missing from disk despite matching the patterns
+    #[arg(long)]
+    materialized: bool,
+}
 
 #[instrument(skip_all)]
 pub fn cmd_sparse_list(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &SparseListArgs,
+    args: &SparseListArgs,
 ) -> Result<(), CommandError> {
     let workspace_command = command.workspace_helper(ui)?;
+    if args.materialized {
+        return list_materialized_files(ui, &workspace_command);
+    }
     for path in workspace_command.working_copy().sparse_patterns()? {
         writeln!(
             ui.stdout(),
@@ -45,3 +59,29 @@ pub fn cmd_sparse_list(
     }
     Ok(())
 }
+
+fn list_materialized_files(
+    ui: &mut Ui,
+    workspace_command: &WorkspaceCommandHelper,
+) -> Result<(), CommandError> {
+    let wc_commit_id = workspace_command
+        .get_wc_commit_id()
+        .ok_or_else(|| user_error("This command requires a working copy"))?;
+    let wc_commit = workspace_command.repo().store().get_commit(wc_commit_id)?;
+    let sparse_matcher = PrefixMatcher::new(workspace_command.working_copy().sparse_patterns()?);
+    ui.request_pager();
+    let mut formatter = ui.stdout_formatter();
+    let workspace_root = workspace_command.workspace_root();
+    for (path, _value) in wc_commit.tree()?.entries() {
+        let fs_path = path.to_fs_path_unchecked(workspace_root);
+        if fs_path.symlink_metadata().is_ok() {
+            write!(formatter.labeled("present"), "present ")?;
+        } else if sparse_matcher.matches(&path) {
+            write!(formatter.labeled("missing"), "missing ")?;
+        } else {
+            write!(formatter.labeled("excluded"), "excluded")?;
+        }
+        writeln!(formatter, " {}", workspace_command.format_file_path(&path))?;
+    }
+    Ok(())
+}
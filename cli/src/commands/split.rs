@@ -91,7 +91,7 @@ pub(crate) fn cmd_split(
         ));
     }
 
-    workspace_command.check_rewritable([commit.id()])?;
+    workspace_command.check_rewritable(ui, [commit.id()])?;
     let matcher = workspace_command
         .parse_file_patterns(ui, &args.paths)?
         .to_matcher();
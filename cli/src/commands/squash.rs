@@ -18,6 +18,7 @@ use indoc::formatdoc;
 use itertools::Itertools as _;
 use jj_lib::commit::Commit;
 use jj_lib::commit::CommitIteratorExt;
+use jj_lib::matchers::EverythingMatcher;
 use jj_lib::matchers::Matcher;
 use jj_lib::object_id::ObjectId;
 use jj_lib::repo::Repo;
@@ -35,6 +36,9 @@ use crate::command_error::CommandError;
 use crate::complete;
 use crate::description_util::combine_messages;
 use crate::description_util::join_message_paragraphs;
+use crate::diff_util;
+use crate::diff_util::DiffFormatArgs;
+use crate::diff_util::DiffRenderer;
 use crate::ui::Ui;
 
 /// Move changes from a revision into another revision
@@ -58,6 +62,10 @@ use crate::ui::Ui;
 ///
 /// If a working-copy commit gets abandoned, it will be given a new, empty
 /// commit. This is true in general; it is not specific to this command.
+///
+/// With `--dry-run`, the destination revision as it would look after squashing
+/// is shown, and nothing is changed. The destination keeps its description in
+/// the preview instead of asking for a combined one.
 #[derive(clap::Args, Clone, Debug)]
 pub(crate) struct SquashArgs {
     /// Revision to squash into its parent (default: @)
@@ -109,6 +117,9 @@ pub(crate) struct SquashArgs {
     /// The source revision will not be abandoned
     #[arg(long, short)]
     keep_emptied: bool,
+    /// Show the diff of the resulting revision without squashing
+    #[arg(long)]
+    dry_run: bool,
 }
 
 #[instrument(skip_all)]
@@ -159,8 +170,10 @@ pub(crate) fn cmd_squash(
         workspace_command.diff_selector(ui, args.tool.as_deref(), args.interactive)?;
     let text_editor = workspace_command.text_editor()?;
     let description = SquashedDescription::from_args(args);
-    workspace_command
-        .check_rewritable(sources.iter().chain(std::iter::once(&destination)).ids())?;
+    workspace_command.check_rewritable(
+        ui,
+        sources.iter().chain(std::iter::once(&destination)).ids(),
+    )?;
 
     let mut tx = workspace_command.start_transaction();
     let tx_description = format!("squash commits into {}", destination.id().hex());
@@ -173,6 +186,9 @@ pub(crate) fn cmd_squash(
         |abandoned_commits| match description {
             SquashedDescription::Exact(description) => Ok(description),
             SquashedDescription::UseDestination => Ok(destination.description().to_owned()),
+            SquashedDescription::Combine if args.dry_run => {
+                Ok(destination.description().to_owned())
+            }
             SquashedDescription::Combine => {
                 let abandoned_commits = abandoned_commits.iter().map(|c| &c.commit).collect_vec();
                 combine_messages(&text_editor, &abandoned_commits, &destination)
@@ -183,6 +199,10 @@ pub(crate) fn cmd_squash(
             if diff_selector.is_interactive() {
                 return Err(user_error("No changes selected"));
             }
+            if args.dry_run {
+                writeln!(ui.status(), "Nothing would change.")?;
+                return Ok(());
+            }
 
             if let [only_path] = &*args.paths {
                 let no_rev_arg =
@@ -201,12 +221,49 @@ pub(crate) fn cmd_squash(
                 }
             }
         }
-        rewrite::SquashResult::NewCommit(_) => {}
+        rewrite::SquashResult::NewCommit(commit) => {
+            if args.dry_run {
+                tx.repo_mut().rebase_descendants()?;
+                return show_squash_preview(ui, &tx, &commit);
+            }
+        }
     }
     tx.finish(ui, tx_description)?;
     Ok(())
 }
 
+/// Shows the squashed `commit` which only exists in the uncommitted `tx`.
+fn show_squash_preview(
+    ui: &mut Ui,
+    tx: &WorkspaceCommandTransaction,
+    commit: &Commit,
+) -> Result<(), CommandError> {
+    if let Some(mut formatter) = ui.status_formatter() {
+        write!(formatter, "Would squash into: ")?;
+        tx.write_commit_summary(formatter.as_mut(), commit)?;
+        writeln!(formatter)?;
+    }
+    let workspace_command = tx.base_workspace_helper();
+    let formats = diff_util::diff_formats_for(tx.settings(), &DiffFormatArgs::default())?;
+    let diff_renderer = DiffRenderer::new(
+        tx.repo(),
+        workspace_command.path_converter(),
+        workspace_command.env().conflict_marker_style(),
+        formats,
+    )
+    .with_checkout_parallelism(workspace_command.env().checkout_parallelism());
+    ui.request_pager();
+    let mut formatter = ui.stdout_formatter();
+    diff_renderer.show_patch(
+        ui,
+        formatter.as_mut(),
+        commit,
+        &EverythingMatcher,
+        ui.term_width(),
+    )?;
+    Ok(())
+}
+
 enum SquashedDescription {
     // Use this exact description.
     Exact(String),
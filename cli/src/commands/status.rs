@@ -14,18 +14,34 @@
 
 use std::io;
 
+use futures::StreamExt as _;
+use futures::TryStreamExt as _;
 use itertools::Itertools;
+use jj_lib::commit::Commit;
 use jj_lib::copies::CopyRecords;
+use jj_lib::matchers::FilesMatcher;
+use jj_lib::matchers::Matcher;
+use jj_lib::merged_tree::MergedTree;
+use jj_lib::merged_tree::TreeDiffEntry;
 use jj_lib::repo::Repo;
+use jj_lib::repo_path::RepoPathBuf;
 use jj_lib::revset::RevsetExpression;
 use jj_lib::revset::RevsetFilterPredicate;
+use jj_lib::working_copy::SnapshotStats;
+use pollster::FutureExt as _;
+use serde::Serialize;
 use tracing::instrument;
 
 use crate::cli_util::print_conflicted_paths;
 use crate::cli_util::CommandHelper;
+use crate::cli_util::WorkspaceCommandHelper;
 use crate::command_error::CommandError;
 use crate::diff_util::get_copy_records;
 use crate::diff_util::DiffFormat;
+use crate::diff_util::FileListOptions;
+use crate::output_format::write_json;
+use crate::output_format::CommitJson;
+use crate::output_format::OutputFormat;
 use crate::ui::Ui;
 
 /// Show high-level repo status
@@ -35,12 +51,37 @@ use crate::ui::Ui;
 ///  * The working copy commit and its (first) parent, and a summary of the
 ///    changes between them
 ///  * Conflicted bookmarks (see https://jj-vcs.github.io/jj/latest/bookmarks/)
+///
+/// With the global `--no-snapshot` option, the working copy isn't snapshotted,
+/// so the status reflects the working-copy commit as of the last snapshot. This
+/// is faster in large repos, but the status may be out of date.
+///
+/// With `--json` (or `--output-format=json`), the status is printed as a JSON
+/// document meant for tools like editor integrations and shell prompts. The
+/// document has a `version` field, which is incremented if fields are removed
+/// or their meaning changes.
 #[derive(clap::Args, Clone, Debug)]
 #[command(visible_alias = "st")]
 pub(crate) struct StatusArgs {
     /// Restrict the status display to these paths
     #[arg(value_name = "FILESETS", value_hint = clap::ValueHint::AnyPath)]
     paths: Vec<String>,
+    /// Only show changes of these types
+    ///
+    /// Untracked paths are not shown if this option is used.
+    #[arg(long, value_delimiter = ',', value_name = "TYPES")]
+    types: Vec<StatusType>,
+    /// Print the status as JSON (shorthand for `--output-format=json`)
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+enum StatusType {
+    Added,
+    Modified,
+    Deleted,
+    Conflicted,
 }
 
 #[instrument(skip_all)]
@@ -49,15 +90,45 @@ pub(crate) fn cmd_status(
     command: &CommandHelper,
     args: &StatusArgs,
 ) -> Result<(), CommandError> {
-    let (workspace_command, snapshot_stats) = command.workspace_helper_with_stats(ui)?;
+    let (workspace_command, mut snapshot_stats) = command.workspace_helper_with_stats(ui)?;
+    if command.global_args().no_snapshot {
+        writeln!(
+            ui.warning_default(),
+            "The working copy was not snapshotted, the status may be out of date"
+        )?;
+    }
     let repo = workspace_command.repo();
     let maybe_wc_commit = workspace_command
         .get_wc_commit_id()
         .map(|id| repo.store().get_commit(id))
         .transpose()?;
-    let matcher = workspace_command
+    let mut matcher = workspace_command
         .parse_file_patterns(ui, &args.paths)?
         .to_matcher();
+    let show_conflicts = args.types.is_empty() || args.types.contains(&StatusType::Conflicted);
+    if !args.types.is_empty() {
+        snapshot_stats.untracked_paths.clear();
+        if let Some(wc_commit) = &maybe_wc_commit {
+            let parent_tree = wc_commit.parent_tree(repo.as_ref())?;
+            let tree = wc_commit.tree()?;
+            matcher = Box::new(matcher_for_types(
+                &parent_tree,
+                &tree,
+                matcher.as_ref(),
+                &args.types,
+            )?);
+        }
+    }
+    if args.json || command.global_args().output_format == OutputFormat::Json {
+        return write_status_json(
+            ui,
+            &workspace_command,
+            maybe_wc_commit.as_ref(),
+            &matcher,
+            &snapshot_stats,
+            show_conflicts,
+        );
+    }
     ui.request_pager();
     let mut formatter = ui.stdout_formatter();
     let formatter = formatter.as_mut();
@@ -71,14 +142,22 @@ pub(crate) fn cmd_status(
         if !wc_has_changes && !wc_has_untracked {
             writeln!(formatter, "The working copy is clean")?;
         } else {
-            if wc_has_changes {
+            let show_changes = wc_has_changes
+                && (args.types.is_empty()
+                    || parent_tree
+                        .diff_stream(&tree, matcher.as_ref())
+                        .next()
+                        .block_on()
+                        .is_some());
+            if show_changes {
                 writeln!(formatter, "Working copy changes:")?;
                 let mut copy_records = CopyRecords::default();
                 for parent in wc_commit.parent_ids() {
                     let records = get_copy_records(repo.store(), parent, wc_commit.id(), &matcher)?;
                     copy_records.add_records(records)?;
                 }
-                let diff_renderer = workspace_command.diff_renderer(vec![DiffFormat::Summary]);
+                let diff_renderer = workspace_command
+                    .diff_renderer(vec![DiffFormat::Summary(FileListOptions::default())]);
                 let width = ui.term_width();
                 diff_renderer.show_diff(
                     ui,
@@ -108,7 +187,7 @@ pub(crate) fn cmd_status(
         // TODO: Conflicts should also be filtered by the `matcher`. See the related
         // TODO on `MergedTree::conflicts()`.
         let conflicts = wc_commit.tree()?.conflicts().collect_vec();
-        if !conflicts.is_empty() {
+        if show_conflicts && !conflicts.is_empty() {
             writeln!(
                 formatter.labeled("conflict"),
                 "There are unresolved conflicts at these paths:"
@@ -208,3 +287,135 @@ pub(crate) fn cmd_status(
 
     Ok(())
 }
+
+/// Returns a matcher for the paths matched by `matcher` whose change between
+/// `parent_tree` and `tree` is one of the `types`.
+fn matcher_for_types(
+    parent_tree: &MergedTree,
+    tree: &MergedTree,
+    matcher: &dyn Matcher,
+    types: &[StatusType],
+) -> Result<FilesMatcher, CommandError> {
+    let paths: Vec<RepoPathBuf> = parent_tree
+        .diff_stream(tree, matcher)
+        .map(|TreeDiffEntry { path, values }| {
+            let (before, after) = values?;
+            let status_type = if before.is_absent() {
+                StatusType::Added
+            } else if after.is_absent() {
+                StatusType::Deleted
+            } else {
+                StatusType::Modified
+            };
+            let matches = types.contains(&status_type)
+                || (types.contains(&StatusType::Conflicted) && !after.is_resolved());
+            Ok::<_, CommandError>(matches.then_some(path))
+        })
+        .try_filter_map(|path| async { Ok(path) })
+        .try_collect()
+        .block_on()?;
+    Ok(FilesMatcher::new(paths))
+}
+
+/// Version of the `StatusJson` schema.
+const STATUS_JSON_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct StatusJson {
+    version: u32,
+    working_copy: Option<CommitJson>,
+    parents: Vec<CommitJson>,
+    changes: Vec<ChangedPathJson>,
+    untracked: Vec<String>,
+    conflicts: Vec<String>,
+    conflict_count: usize,
+    /// Local bookmarks pointing to the working-copy commit or its parents.
+    bookmarks: Vec<String>,
+    conflicted_bookmarks: Vec<String>,
+    conflicted_remote_bookmarks: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ChangedPathJson {
+    path: String,
+    status: &'static str,
+}
+
+fn write_status_json(
+    ui: &Ui,
+    workspace_command: &WorkspaceCommandHelper,
+    maybe_wc_commit: Option<&Commit>,
+    matcher: &dyn Matcher,
+    snapshot_stats: &SnapshotStats,
+    show_conflicts: bool,
+) -> Result<(), CommandError> {
+    let repo = workspace_command.repo();
+    let mut status = StatusJson {
+        version: STATUS_JSON_VERSION,
+        working_copy: maybe_wc_commit.map(CommitJson::new),
+        parents: vec![],
+        changes: vec![],
+        untracked: snapshot_stats
+            .untracked_paths
+            .keys()
+            .map(|path| path.as_internal_file_string().to_owned())
+            .collect(),
+        conflicts: vec![],
+        conflict_count: 0,
+        bookmarks: vec![],
+        conflicted_bookmarks: repo
+            .view()
+            .local_bookmarks()
+            .filter(|(_, target)| target.has_conflict())
+            .map(|(bookmark_name, _)| bookmark_name.to_owned())
+            .collect(),
+        conflicted_remote_bookmarks: repo
+            .view()
+            .all_remote_bookmarks()
+            .filter(|(_, remote_ref)| remote_ref.target.has_conflict())
+            .map(|((bookmark_name, remote_name), _)| format!("{bookmark_name}@{remote_name}"))
+            .collect(),
+    };
+    if let Some(wc_commit) = maybe_wc_commit {
+        let parent_tree = wc_commit.parent_tree(repo.as_ref())?;
+        let tree = wc_commit.tree()?;
+        status.parents = wc_commit
+            .parents()
+            .map_ok(|parent| CommitJson::new(&parent))
+            .try_collect()?;
+        status.changes = parent_tree
+            .diff_stream(&tree, matcher)
+            .map(|TreeDiffEntry { path, values }| {
+                let (before, after) = values?;
+                let status = if before.is_absent() {
+                    "added"
+                } else if after.is_absent() {
+                    "removed"
+                } else {
+                    "modified"
+                };
+                let path = path.as_internal_file_string().to_owned();
+                Ok::<_, CommandError>(ChangedPathJson { path, status })
+            })
+            .try_collect()
+            .block_on()?;
+        if show_conflicts {
+            status.conflicts = tree
+                .conflicts()
+                .map(|(path, _)| path.as_internal_file_string().to_owned())
+                .collect();
+            status.conflict_count = status.conflicts.len();
+        }
+        let current_ids = [wc_commit.id()]
+            .into_iter()
+            .chain(wc_commit.parent_ids())
+            .collect_vec();
+        status.bookmarks = repo
+            .view()
+            .local_bookmarks()
+            .filter(|(_, target)| target.added_ids().any(|id| current_ids.contains(&id)))
+            .map(|(bookmark_name, _)| bookmark_name.to_owned())
+            .collect();
+    }
+    write_json(ui, &status)
+}
@@ -12,21 +12,83 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use clap::builder::NonEmptyStringValueParser;
+use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
+#[cfg(feature = "git")]
+use jj_lib::git;
+use jj_lib::object_id::ObjectId as _;
 use jj_lib::str_util::StringPattern;
 
 use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
+use crate::cli_util::TemplateFileArgs;
+use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
 use crate::commit_templater::CommitTemplateLanguage;
 use crate::commit_templater::RefName;
+use crate::complete;
 use crate::ui::Ui;
 
 /// Manage tags.
 #[derive(clap::Subcommand, Clone, Debug)]
 pub enum TagCommand {
+    #[cfg(feature = "git")]
+    #[command(visible_alias("c"))]
+    Create(TagCreateArgs),
+    #[cfg(feature = "git")]
+    #[command(visible_alias("d"))]
+    Delete(TagDeleteArgs),
     #[command(visible_alias("l"))]
     List(TagListArgs),
 }
 
+/// Create a new tag
+///
+/// Tags are immutable: once created, a tag can't be moved to another revision.
+/// Delete it and create it again instead.
+///
+/// If a message is given, an annotated tag is created. Otherwise, the tag is a
+/// lightweight tag.
+#[derive(clap::Args, Clone, Debug)]
+pub struct TagCreateArgs {
+    /// The tag's target revision
+    #[arg(
+        long,
+        short,
+        value_name = "REVSET",
+        default_value = "@",
+        add = ArgValueCandidates::new(complete::all_revisions),
+    )]
+    revision: RevisionArg,
+    /// The message of the annotated tag
+    #[arg(long = "message", short, value_name = "MESSAGE")]
+    message: Option<String>,
+    /// The name of the tag to create
+    #[arg(value_parser = NonEmptyStringValueParser::new())]
+    name: String,
+}
+
+/// Delete existing tags
+///
+/// The tags are deleted from the underlying Git repo as well. Tags which have
+/// already been pushed are not deleted from remotes.
+#[derive(clap::Args, Clone, Debug)]
+pub struct TagDeleteArgs {
+    /// The tags to delete
+    ///
+    /// By default, the specified name matches exactly. Use `glob:` prefix to
+    /// select tags by wildcard pattern. For details, see
+    /// https://jj-vcs.github.io/jj/latest/revsets/#string-patterns.
+    #[arg(
+        required = true,
+        value_parser = StringPattern::parse,
+        add = ArgValueCandidates::new(complete::tags),
+    )]
+    names: Vec<StringPattern>,
+}
+
 /// List tags.
 #[derive(clap::Args, Clone, Debug)]
 pub struct TagListArgs {
@@ -35,7 +97,7 @@ pub struct TagListArgs {
     /// By default, the specified name matches exactly. Use `glob:` prefix to
     /// select tags by wildcard pattern. For details, see
     /// https://jj-vcs.github.io/jj/latest/revsets/#string-patterns.
-    #[arg(value_parser = StringPattern::parse)]
+    #[arg(value_parser = StringPattern::parse, add = ArgValueCandidates::new(complete::tags))]
     pub names: Vec<StringPattern>,
     /// Render each tag using the given template
     ///
@@ -44,6 +106,8 @@ pub struct TagListArgs {
     /// For the syntax, see https://jj-vcs.github.io/jj/latest/templates/
     #[arg(long, short = 'T')]
     template: Option<String>,
+    #[command(flatten)]
+    template_file: TemplateFileArgs,
 }
 
 pub fn cmd_tag(
@@ -52,10 +116,95 @@ pub fn cmd_tag(
     subcommand: &TagCommand,
 ) -> Result<(), CommandError> {
     match subcommand {
+        #[cfg(feature = "git")]
+        TagCommand::Create(args) => cmd_tag_create(ui, command, args),
+        #[cfg(feature = "git")]
+        TagCommand::Delete(args) => cmd_tag_delete(ui, command, args),
         TagCommand::List(args) => cmd_tag_list(ui, command, args),
     }
 }
 
+#[cfg(feature = "git")]
+fn cmd_tag_create(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &TagCreateArgs,
+) -> Result<(), CommandError> {
+    let mut workspace_command = command.workspace_helper(ui)?;
+    let target_commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
+    let name = &args.name;
+    if workspace_command.repo().view().get_tag(name).is_present() {
+        return Err(user_error_with_hint(
+            format!("Tag already exists: {name}"),
+            "Tags can't be moved. Use `jj tag delete` to delete it first.",
+        ));
+    }
+
+    let mut tx = workspace_command.start_transaction();
+    let tagger = tx.settings().signature();
+    let annotation = args.message.as_deref().map(|message| (message, &tagger));
+    git::create_tag(tx.repo_mut(), name, target_commit.id(), annotation)?;
+    if let Some(mut formatter) = ui.status_formatter() {
+        let kind = if annotation.is_some() {
+            "annotated"
+        } else {
+            "lightweight"
+        };
+        write!(formatter, "Created {kind} tag {name} pointing to ")?;
+        tx.write_commit_summary(formatter.as_mut(), &target_commit)?;
+        writeln!(formatter)?;
+    }
+    tx.finish(
+        ui,
+        format!(
+            "create tag {name} pointing to commit {id}",
+            id = target_commit.id().hex()
+        ),
+    )?;
+    Ok(())
+}
+
+#[cfg(feature = "git")]
+fn cmd_tag_delete(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &TagDeleteArgs,
+) -> Result<(), CommandError> {
+    let mut workspace_command = command.workspace_helper(ui)?;
+    let repo = workspace_command.repo().clone();
+    let mut matched_tags = vec![];
+    let mut unmatched_patterns = vec![];
+    for pattern in &args.names {
+        let mut matches = repo.view().tags_matching(pattern).peekable();
+        if matches.peek().is_none() {
+            unmatched_patterns.push(pattern);
+        }
+        matched_tags.extend(matches.map(|(name, _)| name));
+    }
+    match &unmatched_patterns[..] {
+        [] => {}
+        [pattern] if pattern.is_exact() => {
+            return Err(user_error(format!("No such tag: {pattern}")));
+        }
+        patterns => {
+            return Err(user_error(format!(
+                "No matching tags for patterns: {}",
+                patterns.iter().join(", ")
+            )));
+        }
+    }
+    matched_tags.sort_unstable();
+    matched_tags.dedup();
+
+    let mut tx = workspace_command.start_transaction();
+    for name in &matched_tags {
+        git::delete_tag(tx.repo_mut(), name)?;
+    }
+    writeln!(ui.status(), "Deleted {} tags.", matched_tags.len())?;
+    tx.finish(ui, format!("delete tag {}", matched_tags.iter().join(", ")))?;
+    Ok(())
+}
+
 fn cmd_tag_list(
     ui: &mut Ui,
     command: &CommandHelper,
@@ -67,12 +216,16 @@ fn cmd_tag_list(
 
     let template = {
         let language = workspace_command.commit_template_language();
-        let text = match &args.template {
-            Some(value) => value.to_owned(),
+        let text = match args
+            .template_file
+            .template_text(command.cwd(), args.template.as_deref())?
+        {
+            Some(value) => value,
             None => workspace_command.settings().get("templates.tag_list")?,
         };
         workspace_command
-            .parse_template(ui, &language, &text, CommitTemplateLanguage::wrap_ref_name)?
+            .parse_template(ui, &language, &text, CommitTemplateLanguage::wrap_ref_name)
+            .map_err(|err| args.template_file.annotate_parse_error(err))?
             .labeled("tag_list")
     };
 
@@ -0,0 +1,72 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write as _;
+
+use clap_complete::ArgValueCandidates;
+use itertools::Itertools as _;
+use jj_lib::commit::Commit;
+use jj_lib::commit::CommitIteratorExt as _;
+use jj_lib::signing::SignBehavior;
+
+use super::sign::print_rewritten_commits;
+use super::sign::rewrite_signatures;
+use crate::cli_util::CommandHelper;
+use crate::cli_util::RevisionArg;
+use crate::command_error::CommandError;
+use crate::complete;
+use crate::ui::Ui;
+
+/// Drop the cryptographic signatures of revisions
+///
+/// The selected revisions that are signed are rewritten without their
+/// signature. Descendants are rebased onto the rewritten commits and bookmarks
+/// are updated. Descendants keep their own signatures.
+#[derive(clap::Args, Clone, Debug)]
+pub(crate) struct UnsignArgs {
+    /// The revision(s) to unsign
+    #[arg(
+        long,
+        short,
+        value_name = "REVSETS",
+        default_value = "@",
+        add = ArgValueCandidates::new(complete::mutable_revisions)
+    )]
+    revisions: Vec<RevisionArg>,
+}
+
+pub(crate) fn cmd_unsign(
+    ui: &mut Ui,
+    command: &CommandHelper,
+    args: &UnsignArgs,
+) -> Result<(), CommandError> {
+    let mut workspace_command = command.workspace_helper(ui)?;
+    let to_unsign: Vec<Commit> = workspace_command
+        .parse_union_revsets(ui, &args.revisions)?
+        .evaluate_to_commits()?
+        .filter_ok(|commit| commit.is_signed())
+        .try_collect()?;
+    if to_unsign.is_empty() {
+        writeln!(ui.status(), "No signed revisions to unsign.")?;
+        return Ok(());
+    }
+    workspace_command.check_rewritable(ui, to_unsign.iter().ids())?;
+
+    let mut tx = workspace_command.start_transaction();
+    let (unsigned_commits, num_rebased) =
+        rewrite_signatures(&mut tx, &to_unsign, SignBehavior::Drop, None)?;
+    print_rewritten_commits(ui, &tx, "Unsigned", &unsigned_commits, num_rebased)?;
+    tx.finish(ui, format!("unsign {} commits", unsigned_commits.len()))?;
+    Ok(())
+}
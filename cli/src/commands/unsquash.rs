@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use clap_complete::ArgValueCandidates;
 use jj_lib::matchers::EverythingMatcher;
 use jj_lib::object_id::ObjectId;
 use tracing::instrument;
@@ -20,6 +21,7 @@ use crate::cli_util::CommandHelper;
 use crate::cli_util::RevisionArg;
 use crate::command_error::user_error;
 use crate::command_error::CommandError;
+use crate::complete;
 use crate::description_util::combine_messages;
 use crate::ui::Ui;
 
@@ -38,7 +40,13 @@ use crate::ui::Ui;
 /// commit. This is true in general; it is not specific to this command.
 #[derive(clap::Args, Clone, Debug)]
 pub(crate) struct UnsquashArgs {
-    #[arg(long, short, default_value = "@", value_name = "REVSET")]
+    #[arg(
+        long,
+        short,
+        default_value = "@",
+        value_name = "REVSET",
+        add = ArgValueCandidates::new(complete::mutable_revisions)
+    )]
     revision: RevisionArg,
     /// Interactively choose which parts to unsquash
     // TODO: It doesn't make much sense to run this without -i. We should make that
@@ -67,12 +75,12 @@ pub(crate) fn cmd_unsquash(
     )?;
     let mut workspace_command = command.workspace_helper(ui)?;
     let commit = workspace_command.resolve_single_rev(ui, &args.revision)?;
-    workspace_command.check_rewritable([commit.id()])?;
+    workspace_command.check_rewritable(ui, [commit.id()])?;
     if commit.parent_ids().len() > 1 {
         return Err(user_error("Cannot unsquash merge commits"));
     }
     let parent = commit.parents().next().unwrap()?;
-    workspace_command.check_rewritable([parent.id()])?;
+    workspace_command.check_rewritable(ui, [parent.id()])?;
     let interactive_editor = if args.tool.is_some() || args.interactive {
         Some(workspace_command.diff_editor(ui, args.tool.as_deref())?)
     } else {
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::repo::Repo as _;
+
 use crate::cli_util::CommandHelper;
 use crate::command_error::user_error;
 use crate::command_error::user_error_with_message;
@@ -59,6 +62,18 @@ use crate::ui::Ui;
 /// # This last empty string will become "$0" in bash, so your actual arguments
 /// # are all included in "$@" and start at "$1" as expected.
 /// ```
+///
+/// If jj is run inside a workspace, the following environment variables are
+/// set for the external command. The working copy isn't snapshotted to
+/// compute them.
+///
+///   JJ_ROOT       The workspace root directory
+///   JJ_WORKSPACE  The name of the workspace
+///   JJ_OPERATION  The id of the current operation
+///   JJ_CHANGE     The change id of the working-copy commit (@)
+///   JJ_COMMIT     The commit id of the working-copy commit (@)
+///
+/// Outside of a workspace, these variables are unset.
 #[derive(clap::Args, Clone, Debug)]
 #[command(verbatim_doc_comment)]
 pub(crate) struct UtilExecArgs {
@@ -68,20 +83,34 @@ pub(crate) struct UtilExecArgs {
     args: Vec<String>,
 }
 
+/// Names of the environment variables set for the external command.
+const ENV_VAR_NAMES: &[&str] = &[
+    "JJ_ROOT",
+    "JJ_WORKSPACE",
+    "JJ_OPERATION",
+    "JJ_CHANGE",
+    "JJ_COMMIT",
+];
+
 pub fn cmd_util_exec(
-    _ui: &mut Ui,
-    _command: &CommandHelper,
+    ui: &mut Ui,
+    command: &CommandHelper,
     args: &UtilExecArgs,
 ) -> Result<(), CommandError> {
-    let status = std::process::Command::new(&args.command)
-        .args(&args.args)
-        .status()
-        .map_err(|err| {
-            user_error_with_message(
-                format!("Failed to execute external command '{}'", &args.command),
-                err,
-            )
-        })?;
+    let mut process = std::process::Command::new(&args.command);
+    process.args(&args.args);
+    for name in ENV_VAR_NAMES {
+        process.env_remove(name);
+    }
+    if command.workspace_loader().is_ok() {
+        process.envs(workspace_env_vars(ui, command)?);
+    }
+    let status = process.status().map_err(|err| {
+        user_error_with_message(
+            format!("Failed to execute external command '{}'", &args.command),
+            err,
+        )
+    })?;
     if !status.success() {
         let error_msg = if let Some(exit_code) = status.code() {
             format!("External command exited with {exit_code}")
@@ -93,3 +122,30 @@ pub fn cmd_util_exec(
     }
     Ok(())
 }
+
+/// Returns the environment variables describing the current workspace state.
+fn workspace_env_vars(
+    ui: &Ui,
+    command: &CommandHelper,
+) -> Result<Vec<(&'static str, String)>, CommandError> {
+    let workspace_command = command.workspace_helper_no_snapshot(ui)?;
+    let repo = workspace_command.repo();
+    let root = workspace_command
+        .workspace_root()
+        .to_str()
+        .ok_or_else(|| user_error("The workspace root is not valid UTF-8"))?;
+    let mut vars = vec![
+        ("JJ_ROOT", root.to_owned()),
+        (
+            "JJ_WORKSPACE",
+            workspace_command.workspace_id().as_str().to_owned(),
+        ),
+        ("JJ_OPERATION", repo.op_id().hex()),
+    ];
+    if let Some(wc_commit_id) = workspace_command.get_wc_commit_id() {
+        let wc_commit = repo.store().get_commit(wc_commit_id)?;
+        vars.push(("JJ_CHANGE", wc_commit.change_id().reverse_hex()));
+        vars.push(("JJ_COMMIT", wc_commit_id.hex()));
+    }
+    Ok(vars)
+}
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Write as _;
 use std::slice;
 use std::time::Duration;
 use std::time::SystemTime;
 
+use jj_lib::fmt_util::binary_prefix;
 use jj_lib::repo::Repo as _;
 
 use crate::cli_util::CommandHelper;
@@ -42,6 +44,14 @@ pub struct UtilGcArgs {
     /// release.
     #[arg(long)]
     expire: Option<String>,
+
+    /// Report what would be removed without removing anything
+    ///
+    /// Commits, trees, and blobs are counted by walking everything reachable
+    /// from the repository's refs, so the numbers are approximate. The
+    /// backend's own garbage collection may keep some of these objects.
+    #[arg(long)]
+    dry_run: bool,
 }
 
 pub fn cmd_util_gc(
@@ -62,6 +72,42 @@ pub fn cmd_util_gc(
     let workspace_command = command.workspace_helper(ui)?;
 
     let repo = workspace_command.repo();
+    if args.dry_run {
+        let op_stats = repo
+            .op_store()
+            .gc_dry_run(slice::from_ref(repo.op_id()), keep_newer)?;
+        let stats = repo.store().gc_dry_run(repo.index(), keep_newer)?;
+        let format_size = |bytes: u64| {
+            let (scaled, prefix) = binary_prefix(bytes as f32);
+            if prefix.is_empty() {
+                format!("{bytes} B")
+            } else {
+                format!("{scaled:.1} {prefix}B")
+            }
+        };
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        writeln!(
+            ui.status(),
+            "Would remove {} operation{} and {} view{} ({})",
+            op_stats.operations,
+            plural(op_stats.operations),
+            op_stats.views,
+            plural(op_stats.views),
+            format_size(op_stats.bytes)
+        )?;
+        writeln!(
+            ui.status(),
+            "Would remove {} commit{}, {} tree{}, and {} blob{} (approximately {})",
+            stats.commits,
+            plural(stats.commits),
+            stats.trees,
+            plural(stats.trees),
+            stats.blobs,
+            plural(stats.blobs),
+            format_size(stats.bytes)
+        )?;
+        return Ok(());
+    }
     repo.op_store()
         .gc(slice::from_ref(repo.op_id()), keep_newer)?;
     repo.store().gc(repo.index(), keep_newer)?;
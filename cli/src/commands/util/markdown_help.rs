@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::io::Write as _;
+use std::mem;
 
 use crate::cli_util::CommandHelper;
 use crate::command_error::CommandError;
@@ -20,16 +21,37 @@ use crate::ui::Ui;
 
 /// Print the CLI help for all subcommands in Markdown
 #[derive(clap::Args, Clone, Debug)]
-pub struct UtilMarkdownHelp {}
+pub struct UtilMarkdownHelp {
+    /// Also document hidden commands and arguments
+    ///
+    /// These are usually deprecated aliases or commands meant for debugging,
+    /// so they are not part of the generated reference by default.
+    #[arg(long)]
+    include_hidden: bool,
+}
 
 pub fn cmd_util_markdown_help(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &UtilMarkdownHelp,
+    args: &UtilMarkdownHelp,
 ) -> Result<(), CommandError> {
     // If we ever need more flexibility, the code of `clap_markdown` is simple and
     // readable. We could reimplement the parts we need without trouble.
-    let markdown = clap_markdown::help_markdown_command(command.app()).into_bytes();
-    ui.stdout().write_all(&markdown)?;
+    let markdown = if args.include_hidden {
+        clap_markdown::help_markdown_command(&unhide_all(command.app().clone()))
+    } else {
+        clap_markdown::help_markdown_command(command.app())
+    };
+    ui.stdout().write_all(markdown.as_bytes())?;
     Ok(())
 }
+
+/// Clears the `hide` flag of the command, its arguments, and all of its
+/// subcommands recursively.
+fn unhide_all(command: clap::Command) -> clap::Command {
+    let mut command = command.hide(false).mut_args(|arg| arg.hide(false));
+    for subcommand in command.get_subcommands_mut() {
+        *subcommand = unhide_all(mem::take(subcommand));
+    }
+    command
+}
@@ -14,11 +14,17 @@
 
 use clap_complete::ArgValueCandidates;
 use itertools::Itertools;
+use jj_lib::backend::Timestamp;
 use jj_lib::op_store::WorkspaceId;
+use jj_lib::repo::Repo as _;
+use jj_lib::workspace::get_recorded_workspace_root;
+use jj_lib::workspace::remove_recorded_workspace_root;
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
+use crate::cli_util::WorkspaceCommandHelper;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
 use crate::complete;
 use crate::ui::Ui;
@@ -33,6 +39,18 @@ pub struct WorkspaceForgetArgs {
     /// workspace.
     #[arg(add = ArgValueCandidates::new(complete::workspaces))]
     workspaces: Vec<String>,
+    /// Forget all workspaces that look abandoned
+    ///
+    /// A workspace is considered abandoned if its directory no longer exists,
+    /// or if its working-copy commit hasn't been updated in
+    /// `workspace.stale-after-days` days. The current workspace is never
+    /// forgotten by this option.
+    #[arg(long, conflicts_with = "workspaces")]
+    all_stale: bool,
+    /// Don't ask for confirmation before forgetting workspaces with
+    /// `--all-stale`
+    #[arg(long, short, requires = "all_stale")]
+    yes: bool,
 }
 
 #[instrument(skip_all)]
@@ -43,7 +61,38 @@ pub fn cmd_workspace_forget(
 ) -> Result<(), CommandError> {
     let mut workspace_command = command.workspace_helper(ui)?;
 
-    let wss: Vec<WorkspaceId> = if args.workspaces.is_empty() {
+    let wss: Vec<WorkspaceId> = if args.all_stale {
+        let wss = find_stale_workspaces(&workspace_command)?;
+        if wss.is_empty() {
+            writeln!(ui.status(), "No stale workspaces found.")?;
+            return Ok(());
+        }
+        if let Some(mut formatter) = ui.status_formatter() {
+            let repo = workspace_command.repo();
+            let template = workspace_command.commit_summary_template();
+            writeln!(formatter, "The following workspaces will be forgotten:")?;
+            for ws in &wss {
+                let commit_id = repo.view().get_wc_commit_id(ws).unwrap();
+                let commit = repo.store().get_commit(commit_id)?;
+                write!(formatter, "  {}: ", ws.as_str())?;
+                template.format(&commit, formatter.as_mut())?;
+                writeln!(formatter)?;
+            }
+        }
+        if !args.yes {
+            if !Ui::can_prompt() {
+                return Err(user_error_with_hint(
+                    "Refusing to forget workspaces without confirmation",
+                    "Use --yes to forget them non-interactively.",
+                ));
+            }
+            if !ui.prompt_yes_no("Forget these workspaces?", Some(false))? {
+                writeln!(ui.status(), "Nothing changed.")?;
+                return Ok(());
+            }
+        }
+        wss
+    } else if args.workspaces.is_empty() {
         vec![workspace_command.workspace_id().clone()]
     } else {
         args.workspaces
@@ -78,5 +127,44 @@ pub fn cmd_workspace_forget(
     };
 
     tx.finish(ui, description)?;
+    for ws in &wss {
+        // The record is only a hint for `--all-stale`, so a leftover file is
+        // harmless.
+        remove_recorded_workspace_root(workspace_command.repo_path(), ws).ok();
+    }
     Ok(())
 }
+
+/// Returns the workspaces other than the current one whose directory no
+/// longer exists, or whose working-copy commit is older than the configured
+/// threshold.
+///
+/// Workspaces created before their root was recorded are only checked by age.
+fn find_stale_workspaces(
+    workspace_command: &WorkspaceCommandHelper,
+) -> Result<Vec<WorkspaceId>, CommandError> {
+    let days = workspace_command
+        .settings()
+        .get_int("workspace.stale-after-days")?;
+    let threshold_millis = days.saturating_mul(24 * 60 * 60 * 1000);
+    let now = Timestamp::now().timestamp.0;
+    let repo = workspace_command.repo();
+    let mut wss = vec![];
+    for (ws, commit_id) in repo.view().wc_commit_ids().iter().sorted() {
+        if ws == workspace_command.workspace_id() {
+            continue;
+        }
+        if let Some(root) = get_recorded_workspace_root(workspace_command.repo_path(), ws)? {
+            if !root.exists() {
+                wss.push(ws.clone());
+                continue;
+            }
+        }
+        let commit = repo.store().get_commit(commit_id)?;
+        let updated = commit.committer().timestamp.timestamp.0;
+        if now.saturating_sub(updated) > threshold_millis {
+            wss.push(ws.clone());
+        }
+    }
+    Ok(wss)
+}
@@ -12,19 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use clap_complete::ArgValueCandidates;
 use jj_lib::op_store::WorkspaceId;
+use jj_lib::workspace::record_workspace_root;
+use jj_lib::workspace::remove_recorded_workspace_root;
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
 use crate::command_error::user_error;
+use crate::command_error::user_error_with_hint;
 use crate::command_error::CommandError;
+use crate::complete;
 use crate::ui::Ui;
 
 /// Renames the current workspace
+///
+/// The working-copy commit of the workspace is preserved. Only the workspace
+/// you are currently in can be renamed, since its on-disk metadata has to be
+/// updated as well.
 #[derive(clap::Args, Clone, Debug)]
 pub struct WorkspaceRenameArgs {
-    /// The name of the workspace to update to.
-    new_workspace_name: String,
+    /// The name of the workspace to update to, optionally preceded by the
+    /// current name of the workspace (`[OLD] NEW`)
+    #[arg(
+        value_name = "WORKSPACE_NAME",
+        required = true,
+        num_args = 1..=2,
+        add = ArgValueCandidates::new(complete::workspaces),
+    )]
+    workspace_names: Vec<String>,
 }
 
 #[instrument(skip_all)]
@@ -33,14 +49,35 @@ pub fn cmd_workspace_rename(
     command: &CommandHelper,
     args: &WorkspaceRenameArgs,
 ) -> Result<(), CommandError> {
-    if args.new_workspace_name.is_empty() {
+    let (old_workspace_name, new_workspace_name) = match args.workspace_names.as_slice() {
+        [new] => (None, new),
+        [old, new] => (Some(old), new),
+        _ => unreachable!("clap should reject unexpected number of names"),
+    };
+    if new_workspace_name.is_empty() {
         return Err(user_error("New workspace name cannot be empty"));
     }
 
     let mut workspace_command = command.workspace_helper(ui)?;
 
     let old_workspace_id = workspace_command.working_copy().workspace_id().clone();
-    let new_workspace_id = WorkspaceId::new(args.new_workspace_name.clone());
+    if let Some(name) = old_workspace_name {
+        if name != old_workspace_id.as_str() {
+            if workspace_command
+                .repo()
+                .view()
+                .get_wc_commit_id(&WorkspaceId::new(name.clone()))
+                .is_none()
+            {
+                return Err(user_error(format!("No such workspace: {name}")));
+            }
+            return Err(user_error_with_hint(
+                format!("Cannot rename workspace '{name}' from another workspace"),
+                format!("Run this command from within the '{name}' workspace."),
+            ));
+        }
+    }
+    let new_workspace_id = WorkspaceId::new(new_workspace_name.clone());
     if new_workspace_id == old_workspace_id {
         writeln!(ui.status(), "Nothing changed.")?;
         return Ok(());
@@ -66,13 +103,21 @@ pub fn cmd_workspace_rename(
         .rename_workspace(new_workspace_id.clone());
 
     tx.repo_mut()
-        .rename_workspace(&old_workspace_id, new_workspace_id)?;
+        .rename_workspace(&old_workspace_id, new_workspace_id.clone())?;
     let repo = tx.commit(format!(
         "Renamed workspace '{}' to '{}'",
         old_workspace_id.as_str(),
-        args.new_workspace_name
+        new_workspace_name
     ))?;
     locked_ws.finish(repo.op_id().clone())?;
 
+    let repo_path = workspace_command.repo_path();
+    record_workspace_root(
+        repo_path,
+        &new_workspace_id,
+        workspace_command.workspace_root(),
+    )?;
+    remove_recorded_workspace_root(repo_path, &old_workspace_id)?;
+
     Ok(())
 }
@@ -12,30 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io::Write;
-
 use tracing::instrument;
 
 use crate::cli_util::CommandHelper;
-use crate::command_error::user_error;
 use crate::command_error::CommandError;
+use crate::commands::root::print_root;
 use crate::ui::Ui;
 
 /// Show the current workspace root directory
+///
+/// Like `jj root`, this doesn't scan the working-copy files.
 #[derive(clap::Args, Clone, Debug)]
-pub struct WorkspaceRootArgs {}
+pub struct WorkspaceRootArgs {
+    /// Show the path to the backing Git repository instead
+    #[arg(long)]
+    git: bool,
+}
 
 #[instrument(skip_all)]
 pub fn cmd_workspace_root(
     ui: &mut Ui,
     command: &CommandHelper,
-    _args: &WorkspaceRootArgs,
+    args: &WorkspaceRootArgs,
 ) -> Result<(), CommandError> {
-    let root = command
-        .workspace_loader()?
-        .workspace_root()
-        .to_str()
-        .ok_or_else(|| user_error("The workspace root is not valid UTF-8"))?;
-    writeln!(ui.stdout(), "{root}")?;
-    Ok(())
+    print_root(ui, command, args.git)
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::cell::RefCell;
 use std::cmp::max;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -20,11 +21,13 @@ use std::io;
 use std::rc::Rc;
 
 use futures::stream::BoxStream;
+use futures::StreamExt as _;
 use itertools::Itertools as _;
 use jj_lib::backend::BackendResult;
 use jj_lib::backend::ChangeId;
 use jj_lib::backend::CommitId;
 use jj_lib::commit::Commit;
+use jj_lib::config::ConfigGetResultExt as _;
 use jj_lib::conflicts::ConflictMarkerStyle;
 use jj_lib::copies::CopiesTreeDiffEntry;
 use jj_lib::copies::CopyRecords;
@@ -32,11 +35,13 @@ use jj_lib::extensions_map::ExtensionsMap;
 use jj_lib::fileset;
 use jj_lib::fileset::FilesetDiagnostics;
 use jj_lib::fileset::FilesetExpression;
+use jj_lib::git;
 use jj_lib::id_prefix::IdPrefixContext;
 use jj_lib::id_prefix::IdPrefixIndex;
 use jj_lib::matchers::Matcher;
 use jj_lib::merged_tree::MergedTree;
 use jj_lib::object_id::ObjectId as _;
+use jj_lib::op_store::OperationId;
 use jj_lib::op_store::RefTarget;
 use jj_lib::op_store::RemoteRef;
 use jj_lib::op_store::WorkspaceId;
@@ -56,6 +61,7 @@ use jj_lib::signing::SignResult;
 use jj_lib::signing::Verification;
 use jj_lib::store::Store;
 use once_cell::unsync::OnceCell;
+use pollster::FutureExt as _;
 
 use crate::diff_util;
 use crate::formatter::Formatter;
@@ -569,9 +575,15 @@ pub struct CommitKeywordCache<'repo> {
     bookmarks_index: OnceCell<Rc<RefNamesIndex>>,
     tags_index: OnceCell<Rc<RefNamesIndex>>,
     git_refs_index: OnceCell<Rc<RefNamesIndex>>,
+    remote_heads_index: OnceCell<Rc<RefNamesIndex>>,
     is_immutable_fn: OnceCell<Rc<RevsetContainingFn<'repo>>>,
+    revset_containing_fns: RefCell<HashMap<RevsetCacheKey, Rc<RevsetContainingFn<'repo>>>>,
+    touched_files: Rc<RefCell<HashMap<(CommitId, String), bool>>>,
 }
 
+/// Operation id and text of a revset evaluated by template.
+type RevsetCacheKey = (OperationId, String);
+
 impl<'repo> CommitKeywordCache<'repo> {
     pub fn bookmarks_index(&self, repo: &dyn Repo) -> &Rc<RefNamesIndex> {
         self.bookmarks_index
@@ -588,6 +600,16 @@ impl<'repo> CommitKeywordCache<'repo> {
             .get_or_init(|| Rc::new(build_ref_names_index(repo.view().git_refs())))
     }
 
+    pub fn remote_heads_index(&self, repo: &dyn Repo) -> &Rc<RefNamesIndex> {
+        self.remote_heads_index
+            .get_or_init(|| Rc::new(build_remote_heads_index(repo)))
+    }
+
+    /// Results of `commit.touches(files)`, keyed by commit and `files` text.
+    pub fn touched_files(&self) -> &Rc<RefCell<HashMap<(CommitId, String), bool>>> {
+        &self.touched_files
+    }
+
     pub fn is_immutable_fn(
         &self,
         language: &CommitTemplateLanguage<'repo>,
@@ -602,6 +624,32 @@ impl<'repo> CommitKeywordCache<'repo> {
             Ok(revset.containing_fn().into())
         })
     }
+
+    /// Returns function that tests if a commit is contained in the `revset`.
+    ///
+    /// The same revset may be used by more than one template call (e.g.
+    /// `contained_in("trunk()")`), so the evaluated revset is shared between
+    /// them. The repo and the revset aliases don't change while templates are
+    /// evaluated, so the same text always evaluates to the same set, and
+    /// there's no need to invalidate the cache.
+    pub fn revset_containing_fn(
+        &self,
+        language: &CommitTemplateLanguage<'repo>,
+        diagnostics: &mut TemplateDiagnostics,
+        span: pest::Span<'_>,
+        revset: &str,
+    ) -> TemplateParseResult<Rc<RevsetContainingFn<'repo>>> {
+        let key = (language.repo.base_repo().op_id().clone(), revset.to_owned());
+        if let Some(containing_fn) = self.revset_containing_fns.borrow().get(&key) {
+            return Ok(containing_fn.clone());
+        }
+        let revset = evaluate_user_revset(language, diagnostics, span, revset)?;
+        let containing_fn: Rc<RevsetContainingFn<'repo>> = revset.containing_fn().into();
+        self.revset_containing_fns
+            .borrow_mut()
+            .insert(key, containing_fn.clone());
+        Ok(containing_fn)
+    }
 }
 
 fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Commit> {
@@ -647,9 +695,11 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
     );
     map.insert(
         "author",
-        |_language, _diagnostics, _build_ctx, self_property, function| {
+        |language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let out_property = self_property.map(|commit| commit.author().clone());
+            let mailmap = language.revset_parse_context.mailmap().clone();
+            let out_property = self_property
+                .map(move |commit| mailmap.resolve_signature(commit.author()).into_owned());
             Ok(L::wrap_signature(out_property))
         },
     );
@@ -665,8 +715,13 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
         "mine",
         |language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
-            let user_email = language.revset_parse_context.user_email().to_owned();
-            let out_property = self_property.map(move |commit| commit.author().email == user_email);
+            let mailmap = language.revset_parse_context.mailmap().clone();
+            let (_, user_email) = mailmap.resolve("", language.revset_parse_context.user_email());
+            let user_email = user_email.to_owned();
+            let out_property = self_property.map(move |commit| {
+                let author = commit.author();
+                mailmap.resolve(&author.name, &author.email).1 == user_email
+            });
             Ok(L::wrap_boolean(out_property))
         },
     );
@@ -699,6 +754,20 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
             Ok(L::wrap_boolean(out_property))
         },
     );
+    map.insert(
+        "is_working_copy",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let repo = language.repo;
+            let out_property = self_property.map(|commit| {
+                repo.view()
+                    .wc_commit_ids()
+                    .values()
+                    .any(|id| id == commit.id())
+            });
+            Ok(L::wrap_boolean(out_property))
+        },
+    );
     map.insert(
         "bookmarks",
         |language, diagnostics, _build_ctx, self_property, function| {
@@ -800,6 +869,18 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
             Ok(L::wrap_ref_name_list(out_property))
         },
     );
+    map.insert(
+        "remote_heads",
+        |language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let index = language
+                .keyword_cache
+                .remote_heads_index(language.repo)
+                .clone();
+            let out_property = self_property.map(move |commit| index.get(commit.id()).to_vec());
+            Ok(L::wrap_ref_name_list(out_property))
+        },
+    );
     map.insert(
         "git_head",
         |language, _diagnostics, _build_ctx, self_property, function| {
@@ -856,7 +937,9 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
 
             let is_contained =
                 template_parser::expect_string_literal_with(revset_node, |revset, span| {
-                    Ok(evaluate_user_revset(language, diagnostics, span, revset)?.containing_fn())
+                    language
+                        .keyword_cache
+                        .revset_containing_fn(language, diagnostics, span, revset)
                 })?;
 
             let out_property = self_property.and_then(move |commit| Ok(is_contained(commit.id())?));
@@ -898,6 +981,32 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
             Ok(L::wrap_tree_diff(out_property))
         },
     );
+    map.insert(
+        "touches",
+        |language, diagnostics, _build_ctx, self_property, function| {
+            let [files_node] = function.expect_exact_arguments()?;
+            let files_text =
+                template_parser::expect_string_literal_with(files_node, |text, _span| {
+                    Ok(text.to_owned())
+                })?;
+            let files = expect_fileset_literal(diagnostics, files_node, language.path_converter)?;
+            let repo = language.repo;
+            let matcher = files.to_matcher();
+            // The same commit may be evaluated by more than one template (e.g.
+            // the node and the log templates), so share the results.
+            let touched_files = language.keyword_cache.touched_files().clone();
+            let out_property = self_property.and_then(move |commit| {
+                let key = (commit.id().clone(), files_text.clone());
+                if let Some(&touched) = touched_files.borrow().get(&key) {
+                    return Ok(touched);
+                }
+                let touched = commit_touches_files(repo, &commit, matcher.as_ref())?;
+                touched_files.borrow_mut().insert(key, touched);
+                Ok(touched)
+            });
+            Ok(L::wrap_boolean(out_property))
+        },
+    );
     map.insert(
         "root",
         |language, _diagnostics, _build_ctx, self_property, function| {
@@ -911,6 +1020,22 @@ fn builtin_commit_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, Comm
     map
 }
 
+/// Returns true if the commit changes any of the files matched by `matcher`.
+fn commit_touches_files(
+    repo: &dyn Repo,
+    commit: &Commit,
+    matcher: &dyn Matcher,
+) -> BackendResult<bool> {
+    let from_tree = commit.parent_tree(repo)?;
+    let to_tree = commit.tree()?;
+    // The first changed file is enough to tell.
+    let mut diff_stream = from_tree.diff_stream(&to_tree, matcher);
+    match diff_stream.next().block_on() {
+        Some(entry) => entry.values.map(|_| true),
+        None => Ok(false),
+    }
+}
+
 // TODO: return Vec<String>
 fn extract_working_copies(repo: &dyn Repo, commit: &Commit) -> String {
     let wc_commit_ids = repo.view().wc_commit_ids();
@@ -1336,6 +1461,17 @@ fn build_ref_names_index<'a>(
     index
 }
 
+fn build_remote_heads_index(repo: &dyn Repo) -> RefNamesIndex {
+    let mut index = RefNamesIndex::default();
+    for (full_name, target) in repo.view().git_refs() {
+        if let Some(remote_name) = git::parse_git_remote_head_ref(full_name) {
+            let ref_name = RefName::remote_only("HEAD", remote_name, target.clone());
+            index.insert(target.added_ids(), ref_name);
+        }
+    }
+    index
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CommitOrChangeId {
     Commit(CommitId),
@@ -1435,6 +1571,22 @@ fn builtin_commit_or_change_id_methods<'repo>(
                     )
                 })
                 .transpose()?;
+            let get_min_len = |name: &'static str| {
+                language
+                    .settings()
+                    .get::<usize>(name)
+                    .optional()
+                    .map(Option::unwrap_or_default)
+                    .map_err(|err| {
+                        TemplateParseError::expression(
+                            "Failed to get config value",
+                            function.name_span,
+                        )
+                        .with_source(err)
+                    })
+            };
+            let commit_min_len = get_min_len("ui.commit-id-length")?;
+            let change_min_len = get_min_len("ui.change-id-length")?;
             let repo = language.repo;
             let index = match language.id_prefix_context.populate(repo) {
                 Ok(index) => index,
@@ -1451,8 +1603,13 @@ fn builtin_commit_or_change_id_methods<'repo>(
                     IdPrefixIndex::empty()
                 }
             };
-            let out_property = (self_property, len_property)
-                .map(move |(id, len)| id.shortest(repo, &index, len.unwrap_or(0)));
+            let out_property = (self_property, len_property).map(move |(id, len)| {
+                let min_len = match id {
+                    CommitOrChangeId::Commit(_) => commit_min_len,
+                    CommitOrChangeId::Change(_) => change_min_len,
+                };
+                id.shortest(repo, &index, max(len.unwrap_or(0), min_len))
+            });
             Ok(L::wrap_shortest_id_prefix(out_property))
         },
     );
@@ -1618,8 +1775,10 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                         context: context.unwrap_or(diff_util::DEFAULT_CONTEXT_LINES),
                         line_diff: diff_util::LineDiffOptions {
                             compare_mode: diff_util::LineCompareMode::Exact,
+                            ignore_blank_lines: false,
                         },
                         max_inline_alternation: Some(3),
+                        max_lines: None,
                     };
                     diff.into_formatted(move |formatter, store, tree_diff| {
                         diff_util::show_color_words_diff(
@@ -1657,7 +1816,9 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                         context: context.unwrap_or(diff_util::DEFAULT_CONTEXT_LINES),
                         line_diff: diff_util::LineDiffOptions {
                             compare_mode: diff_util::LineCompareMode::Exact,
+                            ignore_blank_lines: false,
                         },
+                        max_lines: None,
                     };
                     diff.into_formatted(move |formatter, store, tree_diff| {
                         diff_util::show_git_diff(
@@ -1690,7 +1851,9 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
                     let options = diff_util::DiffStatOptions {
                         line_diff: diff_util::LineDiffOptions {
                             compare_mode: diff_util::LineCompareMode::Exact,
+                            ignore_blank_lines: false,
                         },
+                        width: None,
                     };
                     diff.into_formatted(move |formatter, store, tree_diff| {
                         diff_util::show_diff_stat(
@@ -1716,7 +1879,12 @@ fn builtin_tree_diff_methods<'repo>() -> CommitTemplateBuildMethodFnMap<'repo, T
             let template = self_property
                 .map(move |diff| {
                     diff.into_formatted(move |formatter, _store, tree_diff| {
-                        diff_util::show_diff_summary(formatter, tree_diff, path_converter)
+                        diff_util::show_diff_summary(
+                            formatter,
+                            tree_diff,
+                            path_converter,
+                            &diff_util::FileListOptions::default(),
+                        )
                     })
                 })
                 .into_template();
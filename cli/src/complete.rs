@@ -181,6 +181,27 @@ pub fn bookmarks() -> Vec<CompletionCandidate> {
     })
 }
 
+pub fn tags() -> Vec<CompletionCandidate> {
+    with_jj(|jj, _| {
+        let output = jj
+            .build()
+            .arg("tag")
+            .arg("list")
+            .arg("--config")
+            .arg(BOOKMARK_HELP_TEMPLATE)
+            .arg("--template")
+            .arg(r#"name ++ bookmark_help() ++ "\n""#)
+            .output()
+            .map_err(user_error)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(split_help_text)
+            .map(|(name, help)| CompletionCandidate::new(name).help(help))
+            .collect())
+    })
+}
+
 pub fn git_remotes() -> Vec<CompletionCandidate> {
     with_jj(|jj, _| {
         let output = jj
@@ -96,7 +96,8 @@ pub struct AnnotatedValue {
     pub value: ConfigValue,
     /// Source of the configuration value.
     pub source: ConfigSource,
-    // TODO: add source file path
+    /// Source file path of the configuration value if any.
+    pub path: Option<PathBuf>,
     /// True if this value is overridden in higher precedence layers.
     pub is_overridden: bool,
 }
@@ -151,6 +152,7 @@ pub fn resolved_config_values(
                     name,
                     value,
                     source: layer.source,
+                    path: layer.path.clone(),
                     is_overridden,
                 });
             }
@@ -261,6 +263,7 @@ impl UnresolvedConfigEnv {
 pub struct ConfigEnv {
     home_dir: Option<PathBuf>,
     repo_path: Option<PathBuf>,
+    repo_remote_urls: Vec<String>,
     user_config_path: ConfigPath,
     repo_config_path: ConfigPath,
 }
@@ -279,6 +282,7 @@ impl ConfigEnv {
         Ok(ConfigEnv {
             home_dir,
             repo_path: None,
+            repo_remote_urls: vec![],
             user_config_path: env.resolve()?,
             repo_config_path: ConfigPath::Unavailable,
         })
@@ -344,6 +348,7 @@ impl ConfigEnv {
     /// is usually `.jj/repo`.
     pub fn reset_repo_path(&mut self, path: &Path) {
         self.repo_path = Some(path.to_owned());
+        self.repo_remote_urls = load_repo_remote_urls(path);
         self.repo_config_path = ConfigPath::new(Some(path.join("config.toml")));
     }
 
@@ -398,11 +403,41 @@ impl ConfigEnv {
         let context = ConfigResolutionContext {
             home_dir: self.home_dir.as_deref(),
             repo_path: self.repo_path.as_deref(),
+            remote_urls: &self.repo_remote_urls,
         };
         jj_lib::config::resolve(config.as_ref(), &context)
     }
 }
 
+/// Looks up the remote URLs of the Git repository backing the repo at
+/// `repo_path`. Returns an empty list if the repo isn't backed by Git.
+#[cfg(feature = "git")]
+fn load_repo_remote_urls(repo_path: &Path) -> Vec<String> {
+    let store_path = repo_path.join("store");
+    let Ok(git_target) = std::fs::read_to_string(store_path.join("git_target")) else {
+        return vec![];
+    };
+    let git_repo_path = store_path.join(git_target);
+    let options = gix::open::Options::isolated().open_path_as_is(true);
+    let Ok(git_repo) = gix::open_opts(git_repo_path, options) else {
+        return vec![];
+    };
+    git_repo
+        .remote_names()
+        .iter()
+        .filter_map(|name| git_repo.find_remote(name.as_ref()).ok())
+        .filter_map(|remote| {
+            let url = remote.url(gix::remote::Direction::Fetch)?;
+            Some(url.to_bstring().to_string())
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "git"))]
+fn load_repo_remote_urls(_repo_path: &Path) -> Vec<String> {
+    vec![]
+}
+
 fn config_files_for(
     config: &RawConfig,
     source: ConfigSource,
@@ -944,6 +979,7 @@ mod tests {
                     },
                 ),
                 source: EnvBase,
+                path: None,
                 is_overridden: false,
             },
             AnnotatedValue {
@@ -971,6 +1007,7 @@ mod tests {
                     },
                 ),
                 source: EnvBase,
+                path: None,
                 is_overridden: true,
             },
             AnnotatedValue {
@@ -998,6 +1035,7 @@ mod tests {
                     },
                 ),
                 source: Repo,
+                path: None,
                 is_overridden: false,
             },
         ]
@@ -1046,6 +1084,7 @@ mod tests {
                     },
                 ),
                 source: User,
+                path: None,
                 is_overridden: false,
             },
             AnnotatedValue {
@@ -1073,6 +1112,7 @@ mod tests {
                     },
                 ),
                 source: Repo,
+                path: None,
                 is_overridden: false,
             },
         ]
@@ -1367,6 +1407,7 @@ mod tests {
             Ok(ConfigEnv {
                 home_dir,
                 repo_path: None,
+                repo_remote_urls: vec![],
                 user_config_path: env.resolve()?,
                 repo_config_path: ConfigPath::Unavailable,
             })
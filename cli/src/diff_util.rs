@@ -16,6 +16,7 @@ use std::borrow::Borrow;
 use std::cmp::max;
 use std::collections::HashSet;
 use std::io;
+use std::io::Write;
 use std::mem;
 use std::ops::Range;
 use std::path::Path;
@@ -39,7 +40,9 @@ use jj_lib::conflicts::materialized_diff_stream;
 use jj_lib::conflicts::ConflictMarkerStyle;
 use jj_lib::conflicts::MaterializedTreeDiffEntry;
 use jj_lib::conflicts::MaterializedTreeValue;
+use jj_lib::copies::detect_copies;
 use jj_lib::copies::CopiesTreeDiffEntry;
+use jj_lib::copies::CopyDetectionOptions;
 use jj_lib::copies::CopyOperation;
 use jj_lib::copies::CopyRecords;
 use jj_lib::diff::find_line_ranges;
@@ -83,17 +86,31 @@ use crate::ui::Ui;
 
 pub const DEFAULT_CONTEXT_LINES: usize = 3;
 
-#[derive(clap::Args, Clone, Debug)]
+#[derive(clap::Args, Clone, Debug, Default)]
 #[command(next_help_heading = "Diff Formatting Options")]
 #[command(group(clap::ArgGroup::new("short-format").args(&["summary", "stat", "types", "name_only"])))]
+#[command(group(clap::ArgGroup::new("file-list").args(&["summary", "name_only"]).multiple(true)))]
 #[command(group(clap::ArgGroup::new("long-format").args(&["git", "color_words", "tool"])))]
 pub struct DiffFormatArgs {
     /// For each path, show only whether it was modified, added, or deleted
-    #[arg(long, short)]
+    ///
+    /// The path is prefixed with a status letter: 'M' for modified, 'A' for
+    /// added, 'D' for deleted, 'R' for renamed, and 'C' for copied.
+    #[arg(long, short, visible_alias = "name-status")]
     pub summary: bool,
     /// Show a histogram of the changes
     #[arg(long)]
     pub stat: bool,
+    /// Width of the `--stat` histogram, including the file names
+    ///
+    /// Defaults to the width of the terminal, minus the graph in `jj log`.
+    /// Ignored if no histogram is shown.
+    #[arg(
+        long,
+        value_name = "WIDTH",
+        value_parser = clap::value_parser!(u16).range(1..),
+    )]
+    pub stat_width: Option<u16>,
     /// For each path, show only its type before and after
     ///
     /// The diff is shown as two letters. The first letter indicates the type
@@ -109,6 +126,13 @@ pub struct DiffFormatArgs {
     ///    `jj diff -r @- --name-only | xargs perl -pi -e's/OLD/NEW/g`
     #[arg(long)]
     pub name_only: bool,
+    /// Terminate each path with NUL instead of newline
+    ///
+    /// Applies to `--summary` and `--name-only`. Paths are printed verbatim,
+    /// and the source and target paths of renamed or copied files are printed
+    /// as separate entries.
+    #[arg(long, requires = "file-list")] // short = 'z'
+    pub null_terminated: bool,
     /// Show a Git-format diff
     #[arg(long)]
     pub git: bool,
@@ -119,7 +143,7 @@ pub struct DiffFormatArgs {
     #[arg(long)]
     pub tool: Option<String>,
     /// Number of lines of context to show
-    #[arg(long)]
+    #[arg(long, value_name = "N")] // short = 'U'
     context: Option<usize>,
 
     // Short flags are set by command to avoid future conflicts.
@@ -129,15 +153,81 @@ pub struct DiffFormatArgs {
     /// Ignore changes in amount of whitespace when comparing lines.
     #[arg(long, conflicts_with = "ignore_all_space")] // short = 'b'
     ignore_space_change: bool,
+    /// Ignore changes whose lines are all blank.
+    #[arg(long)]
+    ignore_blank_lines: bool,
+    /// Show at most N lines of diff per file
+    ///
+    /// The rest of the file diff is omitted with a notice, and isn't
+    /// formatted at all.
+    #[arg(long, value_name = "N")]
+    max_diff_lines: Option<usize>,
+    /// Detect renames of files at least N% similar to the deleted file
+    ///
+    /// If N isn't specified, 50% is used. Without this option, renames
+    /// recorded by the backend are shown.
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "50",
+        value_parser = parse_similarity_threshold,
+    )]
+    find_renames: Option<u8>,
+    /// Detect copies and renames of files at least N% similar to a modified or
+    /// deleted file
+    ///
+    /// If N isn't specified, 50% is used. Implies `--find-renames` with the
+    /// same threshold unless specified.
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "50",
+        value_parser = parse_similarity_threshold,
+    )]
+    find_copies: Option<u8>,
+}
+
+fn parse_similarity_threshold(value: &str) -> Result<u8, String> {
+    let number = value.strip_suffix('%').unwrap_or(value);
+    match number.parse() {
+        Ok(threshold @ 0..=100) => Ok(threshold),
+        _ => Err(format!(
+            "expected a percentage between 0 and 100, got {value:?}"
+        )),
+    }
+}
+
+/// Loads the rename and copy detection options from the settings and command
+/// arguments.
+pub fn copy_detection_options_for(
+    settings: &UserSettings,
+    args: &DiffFormatArgs,
+) -> Result<CopyDetectionOptions, ConfigGetError> {
+    let rename_threshold = match args.find_renames {
+        Some(threshold) => Some(threshold),
+        None => settings.get("diff.find-renames").optional()?,
+    };
+    let copy_threshold = match args.find_copies {
+        Some(threshold) => Some(threshold),
+        None => settings.get("diff.find-copies").optional()?,
+    };
+    Ok(CopyDetectionOptions {
+        rename_threshold,
+        copy_threshold,
+    })
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DiffFormat {
     // Non-trivial parameters are boxed in order to keep the variants small
-    Summary,
+    Summary(FileListOptions),
     Stat(Box<DiffStatOptions>),
     Types,
-    NameOnly,
+    NameOnly(FileListOptions),
     Git(Box<UnifiedDiffOptions>),
     ColorWords(Box<ColorWordsDiffOptions>),
     Tool(Box<ExternalMergeTool>),
@@ -165,7 +255,7 @@ pub fn diff_formats_for_log(
 ) -> Result<Vec<DiffFormat>, ConfigGetError> {
     let mut formats = diff_formats_from_args(settings, args)?;
     // --patch implies default if no format other than --summary is specified
-    if patch && matches!(formats.as_slice(), [] | [DiffFormat::Summary]) {
+    if patch && matches!(formats.as_slice(), [] | [DiffFormat::Summary(_)]) {
         formats.push(default_diff_format(settings, args)?);
         formats.dedup();
     }
@@ -177,14 +267,15 @@ fn diff_formats_from_args(
     args: &DiffFormatArgs,
 ) -> Result<Vec<DiffFormat>, ConfigGetError> {
     let mut formats = Vec::new();
+    let file_list_options = FileListOptions::from_args(args);
     if args.summary {
-        formats.push(DiffFormat::Summary);
+        formats.push(DiffFormat::Summary(file_list_options));
     }
     if args.types {
         formats.push(DiffFormat::Types);
     }
     if args.name_only {
-        formats.push(DiffFormat::NameOnly);
+        formats.push(DiffFormat::NameOnly(file_list_options));
     }
     if args.git {
         let options = UnifiedDiffOptions::from_settings_and_args(settings, args)?;
@@ -228,9 +319,9 @@ fn default_diff_format(
         "color-words".to_owned()
     };
     match name.as_ref() {
-        "summary" => Ok(DiffFormat::Summary),
+        "summary" => Ok(DiffFormat::Summary(FileListOptions::default())),
         "types" => Ok(DiffFormat::Types),
-        "name-only" => Ok(DiffFormat::NameOnly),
+        "name-only" => Ok(DiffFormat::NameOnly(FileListOptions::default())),
         "git" => {
             let options = UnifiedDiffOptions::from_settings_and_args(settings, args)?;
             Ok(DiffFormat::Git(Box::new(options)))
@@ -274,6 +365,8 @@ pub struct DiffRenderer<'a> {
     path_converter: &'a RepoPathUiConverter,
     conflict_marker_style: ConflictMarkerStyle,
     formats: Vec<DiffFormat>,
+    copy_detection: CopyDetectionOptions,
+    checkout_parallelism: usize,
 }
 
 impl<'a> DiffRenderer<'a> {
@@ -288,6 +381,40 @@ impl<'a> DiffRenderer<'a> {
             path_converter,
             conflict_marker_style,
             formats,
+            copy_detection: CopyDetectionOptions::default(),
+            checkout_parallelism: 0,
+        }
+    }
+
+    /// Detects copies and renames by comparing file contents instead of using
+    /// the ones recorded by the backend.
+    pub fn with_copy_detection(mut self, options: CopyDetectionOptions) -> Self {
+        self.copy_detection = options;
+        self
+    }
+
+    /// Sets the number of threads used to check out the trees for external
+    /// diff tools. Zero picks the number based on the available CPUs.
+    pub fn with_checkout_parallelism(mut self, parallelism: usize) -> Self {
+        self.checkout_parallelism = parallelism;
+        self
+    }
+
+    /// Adds the copies and renames between the `root` and `head` commits to
+    /// `copy_records`.
+    pub fn add_copy_records(
+        &self,
+        copy_records: &mut CopyRecords,
+        root: &CommitId,
+        head: &CommitId,
+        matcher: &dyn Matcher,
+    ) -> BackendResult<()> {
+        let store = self.repo.store();
+        if self.copy_detection.is_enabled() {
+            let records = detect_copies(store, root, head, matcher, &self.copy_detection)?;
+            copy_records.add_records(records.into_iter().map(Ok))
+        } else {
+            copy_records.add_records(get_copy_records(store, root, head, matcher)?)
         }
     }
 
@@ -331,10 +458,10 @@ impl<'a> DiffRenderer<'a> {
         let path_converter = self.path_converter;
         for format in &self.formats {
             match format {
-                DiffFormat::Summary => {
+                DiffFormat::Summary(options) => {
                     let tree_diff =
                         from_tree.diff_stream_with_copies(to_tree, matcher, copy_records);
-                    show_diff_summary(formatter, tree_diff, path_converter)?;
+                    show_diff_summary(formatter, tree_diff, path_converter, options)?;
                 }
                 DiffFormat::Stat(options) => {
                     let tree_diff =
@@ -354,10 +481,10 @@ impl<'a> DiffRenderer<'a> {
                         from_tree.diff_stream_with_copies(to_tree, matcher, copy_records);
                     show_types(formatter, tree_diff, path_converter)?;
                 }
-                DiffFormat::NameOnly => {
+                DiffFormat::NameOnly(options) => {
                     let tree_diff =
                         from_tree.diff_stream_with_copies(to_tree, matcher, copy_records);
-                    show_names(formatter, tree_diff, path_converter)?;
+                    show_names(formatter, tree_diff, path_converter, options)?;
                 }
                 DiffFormat::Git(options) => {
                     let tree_diff =
@@ -407,6 +534,7 @@ impl<'a> DiffRenderer<'a> {
                                 matcher,
                                 tool,
                                 self.conflict_marker_style,
+                                self.checkout_parallelism,
                             )
                             .map_err(DiffRenderError::DiffGenerate)
                         }
@@ -456,8 +584,7 @@ impl<'a> DiffRenderer<'a> {
         let to_tree = commit.tree()?;
         let mut copy_records = CopyRecords::default();
         for parent_id in commit.parent_ids() {
-            let records = get_copy_records(self.repo.store(), parent_id, commit.id(), matcher)?;
-            copy_records.add_records(records)?;
+            self.add_copy_records(&mut copy_records, parent_id, commit.id(), matcher)?;
         }
         self.show_diff(
             ui,
@@ -487,7 +614,8 @@ pub fn get_copy_records<'a>(
 pub struct LineDiffOptions {
     /// How equivalence of lines is tested.
     pub compare_mode: LineCompareMode,
-    // TODO: add --ignore-blank-lines, etc. which aren't mutually exclusive.
+    /// Whether changes of blank lines are ignored.
+    pub ignore_blank_lines: bool,
 }
 
 impl LineDiffOptions {
@@ -499,7 +627,10 @@ impl LineDiffOptions {
         } else {
             LineCompareMode::Exact
         };
-        LineDiffOptions { compare_mode }
+        LineDiffOptions {
+            compare_mode,
+            ignore_blank_lines: args.ignore_blank_lines,
+        }
     }
 }
 
@@ -517,11 +648,7 @@ fn diff_by_line<'input, T: AsRef<[u8]> + ?Sized + 'input>(
     inputs: impl IntoIterator<Item = &'input T>,
     options: &LineDiffOptions,
 ) -> Diff<'input> {
-    // TODO: If we add --ignore-blank-lines, its tokenizer will have to attach
-    // blank lines to the preceding range. Maybe it can also be implemented as a
-    // post-process (similar to refine_changed_regions()) that expands unchanged
-    // regions across blank lines.
-    match options.compare_mode {
+    let mut diff = match options.compare_mode {
         LineCompareMode::Exact => {
             Diff::for_tokenizer(inputs, find_line_ranges, CompareBytesExactly)
         }
@@ -531,7 +658,11 @@ fn diff_by_line<'input, T: AsRef<[u8]> + ?Sized + 'input>(
         LineCompareMode::IgnoreSpaceChange => {
             Diff::for_tokenizer(inputs, find_line_ranges, CompareBytesIgnoreWhitespaceAmount)
         }
+    };
+    if options.ignore_blank_lines {
+        diff.ignore_blank_changes();
     }
+    diff
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -542,6 +673,8 @@ pub struct ColorWordsDiffOptions {
     pub line_diff: LineDiffOptions,
     /// Maximum number of removed/added word alternation to inline.
     pub max_inline_alternation: Option<usize>,
+    /// Maximum number of lines to show per file.
+    pub max_lines: Option<usize>,
 }
 
 impl ColorWordsDiffOptions {
@@ -567,6 +700,7 @@ impl ColorWordsDiffOptions {
             context,
             line_diff: LineDiffOptions::from_args(args),
             max_inline_alternation,
+            max_lines: args.max_diff_lines,
         })
     }
 }
@@ -578,45 +712,51 @@ fn show_color_words_diff_hunks(
     options: &ColorWordsDiffOptions,
 ) -> io::Result<()> {
     let line_diff = diff_by_line([left, right], &options.line_diff);
-    let mut line_number = DiffLineNumber { left: 1, right: 1 };
-    // Matching entries shouldn't appear consecutively in diff of two inputs.
-    // However, if the inputs have conflicts, there may be a hunk that can be
-    // resolved, resulting [matching, resolved, matching] sequence.
-    let mut contexts = Vec::new();
-    let mut emitted = false;
-
-    for hunk in line_diff.hunks() {
-        match hunk.kind {
-            DiffHunkKind::Matching => contexts.push(hunk.contents),
-            DiffHunkKind::Different => {
-                let num_after = if emitted { options.context } else { 0 };
-                line_number = show_color_words_context_lines(
-                    formatter,
-                    &contexts,
-                    line_number,
-                    options,
-                    num_after,
-                    options.context,
-                )?;
-                contexts.clear();
-                emitted = true;
-                line_number =
-                    show_color_words_diff_lines(formatter, &hunk.contents, line_number, options)?;
+    with_max_lines(formatter, options.max_lines, |formatter| {
+        let mut line_number = DiffLineNumber { left: 1, right: 1 };
+        // Matching entries shouldn't appear consecutively in diff of two inputs.
+        // However, if the inputs have conflicts, there may be a hunk that can be
+        // resolved, resulting [matching, resolved, matching] sequence.
+        let mut contexts = Vec::new();
+        let mut emitted = false;
+
+        for hunk in line_diff.hunks() {
+            match hunk.kind {
+                DiffHunkKind::Matching => contexts.push(hunk.contents),
+                DiffHunkKind::Different => {
+                    let num_after = if emitted { options.context } else { 0 };
+                    line_number = show_color_words_context_lines(
+                        formatter,
+                        &contexts,
+                        line_number,
+                        options,
+                        num_after,
+                        options.context,
+                    )?;
+                    contexts.clear();
+                    emitted = true;
+                    line_number = show_color_words_diff_lines(
+                        formatter,
+                        &hunk.contents,
+                        line_number,
+                        options,
+                    )?;
+                }
             }
         }
-    }
 
-    if emitted {
-        show_color_words_context_lines(
-            formatter,
-            &contexts,
-            line_number,
-            options,
-            options.context,
-            0,
-        )?;
-    }
-    Ok(())
+        if emitted {
+            show_color_words_context_lines(
+                formatter,
+                &contexts,
+                line_number,
+                options,
+                options.context,
+                0,
+            )?;
+        }
+        Ok(())
+    })
 }
 
 /// Prints `num_after` lines, ellipsis, and `num_before` lines.
@@ -698,7 +838,10 @@ fn show_color_words_diff_lines(
     mut line_number: DiffLineNumber,
     options: &ColorWordsDiffOptions,
 ) -> io::Result<DiffLineNumber> {
-    let word_diff_hunks = Diff::by_word(contents).hunks().collect_vec();
+    let word_diff_hunks = ignore_word_space_changes(
+        Diff::by_word(contents).hunks().collect(),
+        options.line_diff.compare_mode,
+    );
     let can_inline = match options.max_inline_alternation {
         None => true,     // unlimited
         Some(0) => false, // no need to count alternation
@@ -739,6 +882,47 @@ fn show_color_words_diff_lines(
     Ok(line_number)
 }
 
+/// Turns whitespace-only word changes into matching hunks as requested by the
+/// line `compare_mode`. Changes across newlines are kept as the line structure
+/// of the both sides has to be preserved.
+fn ignore_word_space_changes(
+    mut hunks: Vec<DiffHunk<'_>>,
+    compare_mode: LineCompareMode,
+) -> Vec<DiffHunk<'_>> {
+    let is_space = |text: &[u8]| text.iter().all(|&b| b.is_ascii_whitespace() && b != b'\n');
+    let ends_with_space = |hunk: Option<&DiffHunk>| {
+        hunk.is_some_and(|hunk| hunk.contents[0].last().is_some_and(u8::is_ascii_whitespace))
+    };
+    let starts_with_space = |hunk: Option<&DiffHunk>| {
+        hunk.is_some_and(|hunk| {
+            hunk.contents[0]
+                .first()
+                .is_some_and(u8::is_ascii_whitespace)
+        })
+    };
+    for i in 0..hunks.len() {
+        let hunk = &hunks[i];
+        if hunk.kind != DiffHunkKind::Different || !hunk.contents.iter().all(|c| is_space(c)) {
+            continue;
+        }
+        let ignored = match compare_mode {
+            LineCompareMode::Exact => false,
+            LineCompareMode::IgnoreAllSpace => true,
+            LineCompareMode::IgnoreSpaceChange => {
+                hunk.contents.iter().all(|c| !c.is_empty())
+                    || ends_with_space(i.checked_sub(1).map(|j| &hunks[j]))
+                    || starts_with_space(hunks.get(i + 1))
+            }
+        };
+        if ignored {
+            // Show the new whitespace as if it were unchanged.
+            let right = hunk.contents[1];
+            hunks[i] = DiffHunk::matching([right, right]);
+        }
+    }
+    hunks
+}
+
 fn show_color_words_line_number(
     formatter: &mut dyn Formatter,
     left_line_number: Option<u32>,
@@ -1249,6 +1433,8 @@ pub struct UnifiedDiffOptions {
     pub context: usize,
     /// How lines are tokenized and compared.
     pub line_diff: LineDiffOptions,
+    /// Maximum number of lines to show per file.
+    pub max_lines: Option<usize>,
 }
 
 impl UnifiedDiffOptions {
@@ -1262,6 +1448,7 @@ impl UnifiedDiffOptions {
         Ok(UnifiedDiffOptions {
             context,
             line_diff: LineDiffOptions::from_args(args),
+            max_lines: args.max_diff_lines,
         })
     }
 }
@@ -1329,10 +1516,13 @@ fn unified_diff_hunks<'content>(
     while let Some(hunk) = diff_hunks.next() {
         match hunk.kind {
             DiffHunkKind::Matching => {
-                // Just use the right (i.e. new) content. We could count the
-                // number of skipped lines separately, but the number of the
-                // context lines should match the displayed content.
-                let [_, right] = hunk.contents[..].try_into().unwrap();
+                // Just use the right (i.e. new) content. The number of the
+                // context lines should match the displayed content, but the
+                // left side may have a different number of lines if blank
+                // lines are ignored.
+                let [left, right] = hunk.contents[..].try_into().unwrap();
+                let left_line_end = current_hunk.left_line_range.end
+                    + left.split_inclusive(|b| *b == b'\n').count();
                 let mut lines = right.split_inclusive(|b| *b == b'\n').fuse();
                 if !current_hunk.lines.is_empty() {
                     // The previous hunk line should be either removed/added.
@@ -1345,7 +1535,7 @@ fn unified_diff_hunks<'content>(
                 };
                 let num_skip_lines = lines.count();
                 if num_skip_lines > 0 {
-                    let left_start = current_hunk.left_line_range.end + num_skip_lines;
+                    let left_start = left_line_end.saturating_sub(before_lines.len());
                     let right_start = current_hunk.right_line_range.end + num_skip_lines;
                     if !current_hunk.lines.is_empty() {
                         hunks.push(current_hunk);
@@ -1358,6 +1548,7 @@ fn unified_diff_hunks<'content>(
                 }
                 // The next hunk should be of DiffHunk::Different type if any.
                 current_hunk.extend_context_lines(before_lines.into_iter().rev());
+                current_hunk.left_line_range.end = left_line_end;
             }
             DiffHunkKind::Different => {
                 let (left_lines, right_lines) =
@@ -1451,32 +1642,35 @@ fn show_unified_diff_hunks(
         }
     }
 
-    for hunk in unified_diff_hunks(left_content, right_content, options) {
-        writeln!(
-            formatter.labeled("hunk_header"),
-            "@@ -{},{} +{},{} @@",
-            to_line_number(hunk.left_line_range.clone()),
-            hunk.left_line_range.len(),
-            to_line_number(hunk.right_line_range.clone()),
-            hunk.right_line_range.len()
-        )?;
-        for (line_type, tokens) in &hunk.lines {
-            let (label, sigil) = match line_type {
-                DiffLineType::Context => ("context", " "),
-                DiffLineType::Removed => ("removed", "-"),
-                DiffLineType::Added => ("added", "+"),
-            };
-            formatter.with_label(label, |formatter| {
-                write!(formatter, "{sigil}")?;
-                show_diff_line_tokens(formatter, tokens)
-            })?;
-            let (_, content) = tokens.last().expect("hunk line must not be empty");
-            if !content.ends_with(b"\n") {
-                write!(formatter, "\n\\ No newline at end of file\n")?;
+    let hunks = unified_diff_hunks(left_content, right_content, options);
+    with_max_lines(formatter, options.max_lines, |formatter| {
+        for hunk in &hunks {
+            writeln!(
+                formatter.labeled("hunk_header"),
+                "@@ -{},{} +{},{} @@",
+                to_line_number(hunk.left_line_range.clone()),
+                hunk.left_line_range.len(),
+                to_line_number(hunk.right_line_range.clone()),
+                hunk.right_line_range.len()
+            )?;
+            for (line_type, tokens) in &hunk.lines {
+                let (label, sigil) = match line_type {
+                    DiffLineType::Context => ("context", " "),
+                    DiffLineType::Removed => ("removed", "-"),
+                    DiffLineType::Added => ("added", "+"),
+                };
+                formatter.with_label(label, |formatter| {
+                    write!(formatter, "{sigil}")?;
+                    show_diff_line_tokens(formatter, tokens)
+                })?;
+                let (_, content) = tokens.last().expect("hunk line must not be empty");
+                if !content.ends_with(b"\n") {
+                    write!(formatter, "\n\\ No newline at end of file\n")?;
+                }
             }
         }
-    }
-    Ok(())
+        Ok(())
+    })
 }
 
 fn show_diff_line_tokens(
@@ -1494,6 +1688,85 @@ fn show_diff_line_tokens(
     Ok(())
 }
 
+/// Calls `write_inner()` with a formatter that shows up to `max_lines` lines,
+/// and then prints a notice if the rest was omitted.
+///
+/// Once the limit is reached, writing fails with `LineLimitReached`, which
+/// stops `write_inner()` from formatting the rest of the diff.
+fn with_max_lines(
+    formatter: &mut dyn Formatter,
+    max_lines: Option<usize>,
+    write_inner: impl FnOnce(&mut dyn Formatter) -> io::Result<()>,
+) -> io::Result<()> {
+    let Some(max_lines) = max_lines else {
+        return write_inner(formatter);
+    };
+    let mut truncating = LineLimitFormatter {
+        formatter,
+        remaining_lines: max_lines,
+    };
+    match write_inner(&mut truncating) {
+        Ok(()) => Ok(()),
+        Err(err)
+            if err
+                .get_ref()
+                .is_some_and(|err| err.is::<LineLimitReached>()) =>
+        {
+            writeln!(formatter.labeled("truncated"), "... (more lines truncated)")
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Line limit reached")]
+struct LineLimitReached;
+
+/// Formatter that fails once more than the specified number of lines are
+/// written.
+struct LineLimitFormatter<'a> {
+    formatter: &'a mut dyn Formatter,
+    remaining_lines: usize,
+}
+
+impl Write for LineLimitFormatter<'_> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.remaining_lines == 0 {
+            return Err(io::Error::other(LineLimitReached));
+        }
+        let shown_len = data
+            .split_inclusive(|b| *b == b'\n')
+            .take(self.remaining_lines)
+            .inspect(|line| {
+                if line.ends_with(b"\n") {
+                    self.remaining_lines -= 1;
+                }
+            })
+            .map(|line| line.len())
+            .sum();
+        self.formatter.write_all(&data[..shown_len])?;
+        Ok(shown_len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.formatter.flush()
+    }
+}
+
+impl Formatter for LineLimitFormatter<'_> {
+    fn raw(&mut self) -> io::Result<Box<dyn Write + '_>> {
+        self.formatter.raw()
+    }
+
+    fn push_label(&mut self, label: &str) -> io::Result<()> {
+        self.formatter.push_label(label)
+    }
+
+    fn pop_label(&mut self) -> io::Result<()> {
+        self.formatter.pop_label()
+    }
+}
+
 pub fn show_git_diff(
     formatter: &mut dyn Formatter,
     store: &Store,
@@ -1591,11 +1864,26 @@ pub fn show_git_diff(
     .block_on()
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FileListOptions {
+    /// Whether to terminate each path with NUL instead of newline.
+    pub null_terminated: bool,
+}
+
+impl FileListOptions {
+    fn from_args(args: &DiffFormatArgs) -> Self {
+        FileListOptions {
+            null_terminated: args.null_terminated,
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub fn show_diff_summary(
     formatter: &mut dyn Formatter,
     mut tree_diff: BoxStream<CopiesTreeDiffEntry>,
     path_converter: &RepoPathUiConverter,
+    options: &FileListOptions,
 ) -> Result<(), DiffRenderError> {
     async {
         while let Some(CopiesTreeDiffEntry { path, values }) = tree_diff.next().await {
@@ -1607,15 +1895,29 @@ pub fn show_diff_summary(
                     CopyOperation::Copy => ("copied", "C"),
                     CopyOperation::Rename => ("renamed", "R"),
                 };
-                let path = path_converter.format_copied_path(before_path, after_path);
-                writeln!(formatter.labeled(label), "{sigil} {path}")?;
+                if options.null_terminated {
+                    let before_path = path_converter.format_file_path(before_path);
+                    let after_path = path_converter.format_file_path(after_path);
+                    write!(
+                        formatter.labeled(label),
+                        "{sigil} {before_path}\0{after_path}\0"
+                    )?;
+                } else {
+                    let path = path_converter.format_copied_path(before_path, after_path);
+                    writeln!(formatter.labeled(label), "{sigil} {path}")?;
+                }
             } else {
                 let path = path_converter.format_file_path(after_path);
-                match (before.is_present(), after.is_present()) {
-                    (true, true) => writeln!(formatter.labeled("modified"), "M {path}")?,
-                    (false, true) => writeln!(formatter.labeled("added"), "A {path}")?,
-                    (true, false) => writeln!(formatter.labeled("removed"), "D {path}")?,
+                let (label, sigil) = match (before.is_present(), after.is_present()) {
+                    (true, true) => ("modified", "M"),
+                    (false, true) => ("added", "A"),
+                    (true, false) => ("removed", "D"),
                     (false, false) => unreachable!(),
+                };
+                if options.null_terminated {
+                    write!(formatter.labeled(label), "{sigil} {path}\0")?;
+                } else {
+                    writeln!(formatter.labeled(label), "{sigil} {path}")?;
                 }
             }
         }
@@ -1628,12 +1930,15 @@ pub fn show_diff_summary(
 pub struct DiffStatOptions {
     /// How lines are tokenized and compared.
     pub line_diff: LineDiffOptions,
+    /// Overrides the display width.
+    pub width: Option<usize>,
 }
 
 impl DiffStatOptions {
     fn from_args(args: &DiffFormatArgs) -> Self {
         DiffStatOptions {
             line_diff: LineDiffOptions::from_args(args),
+            width: args.stat_width.map(usize::from),
         }
     }
 }
@@ -1717,6 +2022,7 @@ pub fn show_diff_stat(
     }
     .block_on()?;
 
+    let display_width = options.width.unwrap_or(display_width);
     let number_padding = max_diffs.to_string().len();
     // 4 characters padding for the graph
     let available_width = display_width.saturating_sub(4 + " | ".len() + number_padding);
@@ -1807,12 +2113,14 @@ pub fn show_names(
     formatter: &mut dyn Formatter,
     mut tree_diff: BoxStream<CopiesTreeDiffEntry>,
     path_converter: &RepoPathUiConverter,
+    options: &FileListOptions,
 ) -> io::Result<()> {
+    let terminator = if options.null_terminated { '\0' } else { '\n' };
     async {
         while let Some(CopiesTreeDiffEntry { path, .. }) = tree_diff.next().await {
-            writeln!(
+            write!(
                 formatter,
-                "{}",
+                "{}{terminator}",
                 path_converter.format_file_path(path.target())
             )?;
         }
@@ -655,6 +655,33 @@ impl FormatRecorder {
         }
         flush_data(formatter, self.data.len())
     }
+
+    /// Replays the recorded content, labeling the given byte `ranges` of the
+    /// data with `label`. The ranges must be sorted and must not overlap.
+    pub fn replay_with_labeled_ranges(
+        &self,
+        formatter: &mut dyn Formatter,
+        ranges: &[Range<usize>],
+        label: &str,
+    ) -> io::Result<()> {
+        self.replay_with(formatter, |formatter, range| {
+            let mut pos = range.start;
+            for labeled in ranges
+                .iter()
+                .skip_while(|labeled| labeled.end <= range.start)
+                .take_while(|labeled| labeled.start < range.end)
+            {
+                let start = labeled.start.max(range.start);
+                let end = labeled.end.min(range.end);
+                formatter.write_all(&self.data[pos..start])?;
+                formatter.push_label(label)?;
+                formatter.write_all(&self.data[start..end])?;
+                formatter.pop_label()?;
+                pos = end;
+            }
+            formatter.write_all(&self.data[pos..range.end])
+        })
+    }
 }
 
 impl Write for FormatRecorder {
@@ -1301,6 +1328,33 @@ mod tests {
             @"<< outer1 >>[38;5;1m<< inner1  inner2 >>[39m<< outer2 >>");
     }
 
+    #[test]
+    fn test_format_recorder_labeled_ranges() {
+        let mut recorder = FormatRecorder::new();
+        write!(recorder, "outer1 ").unwrap();
+        recorder.push_label("inner").unwrap();
+        write!(recorder, "inner1").unwrap();
+        recorder.pop_label().unwrap();
+        write!(recorder, " outer2").unwrap();
+
+        // Ranges crossing the label boundaries are split
+        let config = config_from_string(
+            r#"
+        colors.inner = "red"
+        colors.highlight = { bg = "yellow" }
+        "#,
+        );
+        let mut output: Vec<u8> = vec![];
+        let mut formatter = ColorFormatter::for_config(&mut output, &config, false).unwrap();
+        recorder
+            .replay_with_labeled_ranges(&mut formatter, &[0..1, 5..9, 16..20], "highlight")
+            .unwrap();
+        drop(formatter);
+        insta::assert_snapshot!(
+            String::from_utf8(output).unwrap(),
+            @"[48;5;3mo[49muter[48;5;3m1 [49m[38;5;1m[48;5;3min[49mner1[39m ou[48;5;3mter2[49m");
+    }
+
     #[test]
     fn test_raw_format_recorder() {
         // Note: similar to test_format_recorder above
@@ -23,6 +23,7 @@ use std::mem;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::thread;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -41,6 +42,7 @@ use jj_lib::op_store::RefTarget;
 use jj_lib::op_store::RemoteRef;
 use jj_lib::repo::ReadonlyRepo;
 use jj_lib::repo::Repo;
+use jj_lib::settings::GitSettings;
 use jj_lib::store::Store;
 use jj_lib::str_util::StringPattern;
 use jj_lib::workspace::Workspace;
@@ -282,6 +284,38 @@ impl GitSidebandProgressMessageWriter {
 }
 
 type SidebandProgressCallback<'a> = &'a mut dyn FnMut(&[u8]);
+type ProgressCallback<'a> = Box<dyn FnMut(&git::Progress) + 'a>;
+
+/// Runs the network operation `f`, and retries it with exponential backoff
+/// while it fails with a transient error, up to `git.network-retries` times.
+pub fn with_network_retries<T, E: error::Error + 'static>(
+    ui: &Ui,
+    git_settings: &GitSettings,
+    is_transient: impl Fn(&E) -> bool,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let max_retries = git_settings.network_retries;
+    let mut delay = git_settings.network_retry_backoff;
+    let mut retries = 0;
+    loop {
+        match f() {
+            Err(err) if retries < max_retries && is_transient(&err) => {
+                retries += 1;
+                let message = iter::successors(Some(&err as &dyn error::Error), |err| err.source())
+                    .join(": ");
+                writeln!(
+                    ui.warning_default(),
+                    "{message} (retrying in {delay_ms}ms, attempt {retries} of {max_retries})",
+                    delay_ms = delay.as_millis(),
+                )
+                .ok();
+                thread::sleep(delay);
+                delay = delay.saturating_mul(2);
+            }
+            result => return result,
+        }
+    }
+}
 
 pub fn with_remote_git_callbacks<T>(
     ui: &Ui,
@@ -289,16 +323,23 @@ pub fn with_remote_git_callbacks<T>(
     f: impl FnOnce(git::RemoteCallbacks<'_>) -> T,
 ) -> T {
     let mut callbacks = git::RemoteCallbacks::default();
-    let mut progress_callback = None;
+    let mut progress_callback: Option<ProgressCallback> = None;
     if let Some(mut output) = ui.progress_output() {
         let mut progress = Progress::new(Instant::now());
-        progress_callback = Some(move |x: &git::Progress| {
+        progress_callback = Some(Box::new(move |x: &git::Progress| {
             _ = progress.update(Instant::now(), x, &mut output);
-        });
+        }));
+    } else if ui.use_progress_lines() {
+        // Print occasional lines instead of redrawing a bar, e.g. in CI logs.
+        let mut progress = Progress::new_for_lines(Instant::now());
+        let mut output = ui.status();
+        progress_callback = Some(Box::new(move |x: &git::Progress| {
+            _ = progress.update_line(Instant::now(), x, &mut output);
+        }));
     }
     callbacks.progress = progress_callback
         .as_mut()
-        .map(|x| x as &mut dyn FnMut(&git::Progress));
+        .map(|x| x.as_mut() as &mut dyn FnMut(&git::Progress));
     callbacks.sideband_progress = sideband_progress_callback.map(|x| x as &mut dyn FnMut(&[u8]));
     let mut get_ssh_keys = get_ssh_keys; // Coerce to unit fn type
     callbacks.get_ssh_keys = Some(&mut get_ssh_keys);
@@ -355,7 +396,11 @@ pub fn print_git_import_stats(
     Ok(())
 }
 
+/// Interval between progress lines printed when the output isn't a terminal.
+const PROGRESS_LINE_INTERVAL: Duration = Duration::from_secs(10);
+
 pub struct Progress {
+    start: Instant,
     next_print: Instant,
     rate: RateEstimate,
     buffer: String,
@@ -365,6 +410,7 @@ pub struct Progress {
 impl Progress {
     pub fn new(now: Instant) -> Self {
         Self {
+            start: now,
             next_print: now + crate::progress::INITIAL_DELAY,
             rate: RateEstimate::new(),
             buffer: String::new(),
@@ -372,6 +418,65 @@ impl Progress {
         }
     }
 
+    /// Creates progress to be reported by [`Progress::update_line()`].
+    pub fn new_for_lines(now: Instant) -> Self {
+        Self {
+            next_print: now + PROGRESS_LINE_INTERVAL,
+            ..Self::new(now)
+        }
+    }
+
+    /// Prints a line of progress every [`PROGRESS_LINE_INTERVAL`].
+    pub fn update_line<W: std::io::Write + ?Sized>(
+        &mut self,
+        now: Instant,
+        progress: &git::Progress,
+        output: &mut W,
+    ) -> io::Result<()> {
+        if progress.overall == 1.0 {
+            return Ok(());
+        }
+        let rate = progress
+            .bytes_downloaded
+            .and_then(|x| self.rate.update(now, x));
+        if now < self.next_print {
+            return Ok(());
+        }
+        self.next_print = now + PROGRESS_LINE_INTERVAL;
+        self.buffer.clear();
+        self.write_stats(now, progress, rate);
+        writeln!(output, "Fetching: {}", self.buffer.trim_end())?;
+        Ok(())
+    }
+
+    fn write_stats(&mut self, now: Instant, progress: &git::Progress, rate: Option<f32>) {
+        use std::fmt::Write as _;
+
+        write!(self.buffer, "{: >3.0}% ", 100.0 * progress.overall).unwrap();
+        if progress.total_objects > 0 {
+            write!(
+                self.buffer,
+                "{}/{} objects ",
+                progress.received_objects, progress.total_objects
+            )
+            .unwrap();
+        }
+        if let Some(total) = progress.bytes_downloaded {
+            let (scaled, prefix) = binary_prefix(total as f32);
+            write!(self.buffer, "{scaled: >5.1} {prefix}B ").unwrap();
+        }
+        if let Some(estimate) = rate {
+            let (scaled, prefix) = binary_prefix(estimate);
+            write!(self.buffer, "at {scaled: >5.1} {prefix}B/s ").unwrap();
+        }
+        if progress.overall > 0.0 {
+            // Assume the remaining work proceeds at the average speed so far.
+            let elapsed = now.saturating_duration_since(self.start);
+            let remaining = elapsed.mul_f32((1.0 - progress.overall).max(0.0) / progress.overall);
+            write!(self.buffer, "ETA {} ", format_eta(remaining)).unwrap();
+        }
+    }
+
     pub fn update<W: std::io::Write>(
         &mut self,
         now: Instant,
@@ -405,15 +510,7 @@ impl Progress {
         self.buffer.clear();
         write!(self.buffer, "\r").unwrap();
         let control_chars = self.buffer.len();
-        write!(self.buffer, "{: >3.0}% ", 100.0 * progress.overall).unwrap();
-        if let Some(total) = progress.bytes_downloaded {
-            let (scaled, prefix) = binary_prefix(total as f32);
-            write!(self.buffer, "{scaled: >5.1} {prefix}B ").unwrap();
-        }
-        if let Some(estimate) = rate {
-            let (scaled, prefix) = binary_prefix(estimate);
-            write!(self.buffer, "at {scaled: >5.1} {prefix}B/s ").unwrap();
-        }
+        self.write_stats(now, progress, rate);
 
         let bar_width = output
             .term_width()
@@ -431,6 +528,17 @@ impl Progress {
     }
 }
 
+/// Formats `duration` as `M:SS`, or `H:MM:SS` if an hour or longer.
+fn format_eta(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
 fn draw_progress(progress: f32, buffer: &mut String, width: usize) {
     const CHARS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
     const RESOLUTION: usize = CHARS.len() - 1;
@@ -636,10 +744,29 @@ pub fn git_fetch(
     remotes: &[String],
     branch: &[StringPattern],
 ) -> Result<(), CommandError> {
-    let git_settings = tx.settings().git_settings()?;
-
     for remote in remotes {
-        let stats = with_remote_git_callbacks(ui, None, |cb| {
+        let stats = git_fetch_remote(ui, tx, git_repo, remote, branch)?;
+        print_git_import_stats(ui, tx.repo(), &stats, true)?;
+    }
+    warn_if_branches_not_found(
+        ui,
+        tx,
+        branch,
+        &remotes.iter().map(StringPattern::exact).collect_vec(),
+    )
+}
+
+/// Fetches the `branch`es from a single `remote` and imports them.
+pub fn git_fetch_remote(
+    ui: &mut Ui,
+    tx: &mut WorkspaceCommandTransaction,
+    git_repo: &git2::Repository,
+    remote: &str,
+    branch: &[StringPattern],
+) -> Result<GitImportStats, CommandError> {
+    let git_settings = tx.settings().git_settings()?;
+    let stats = with_network_retries(ui, &git_settings, GitFetchError::is_transient, || {
+        with_remote_git_callbacks(ui, None, |cb| {
             git::fetch(
                 tx.repo_mut(),
                 git_repo,
@@ -650,35 +777,29 @@ pub fn git_fetch(
                 None,
             )
         })
-        .map_err(|err| match err {
-            GitFetchError::InvalidBranchPattern => {
-                if branch
-                    .iter()
-                    .any(|pattern| pattern.as_exact().is_some_and(|s| s.contains('*')))
-                {
-                    user_error_with_hint(
-                        "Branch names may not include `*`.",
-                        "Prefix the pattern with `glob:` to expand `*` as a glob",
-                    )
-                } else {
-                    user_error(err)
-                }
+    })
+    .map_err(|err| match err {
+        GitFetchError::InvalidBranchPattern => {
+            if branch
+                .iter()
+                .any(|pattern| pattern.as_exact().is_some_and(|s| s.contains('*')))
+            {
+                user_error_with_hint(
+                    "Branch names may not include `*`.",
+                    "Prefix the pattern with `glob:` to expand `*` as a glob",
+                )
+            } else {
+                user_error(err)
             }
-            GitFetchError::GitImportError(err) => err.into(),
-            GitFetchError::InternalGitError(err) => map_git_error(err),
-            _ => user_error(err),
-        })?;
-        print_git_import_stats(ui, tx.repo(), &stats.import_stats, true)?;
-    }
-    warn_if_branches_not_found(
-        ui,
-        tx,
-        branch,
-        &remotes.iter().map(StringPattern::exact).collect_vec(),
-    )
+        }
+        GitFetchError::GitImportError(err) => err.into(),
+        GitFetchError::InternalGitError(err) => map_git_error(err),
+        _ => user_error(err),
+    })?;
+    Ok(stats.import_stats)
 }
 
-fn warn_if_branches_not_found(
+pub fn warn_if_branches_not_found(
     ui: &mut Ui,
     tx: &WorkspaceCommandTransaction,
     branches: &[StringPattern],
@@ -714,9 +835,60 @@ mod tests {
     use std::path::MAIN_SEPARATOR;
 
     use insta::assert_snapshot;
+    use jj_lib::config::StackedConfig;
 
     use super::*;
 
+    #[test]
+    fn test_with_network_retries() {
+        let mut config = StackedConfig::with_defaults();
+        config.extend_layers(crate::config::default_config_layers());
+        let ui = Ui::with_config(&config).unwrap();
+        let git_settings = GitSettings {
+            network_retries: 2,
+            network_retry_backoff: Duration::ZERO,
+            ..GitSettings::default()
+        };
+        // Simulates a transport that fails the first `failures` attempts.
+        let run = |failures: usize, error: fn() -> git2::Error| {
+            let mut attempts = 0;
+            let result =
+                with_network_retries(&ui, &git_settings, GitFetchError::is_transient, || {
+                    attempts += 1;
+                    if attempts <= failures {
+                        Err(GitFetchError::InternalGitError(error()))
+                    } else {
+                        Ok(())
+                    }
+                });
+            (result.is_ok(), attempts)
+        };
+        let reset = || {
+            git2::Error::new(
+                git2::ErrorCode::GenericError,
+                git2::ErrorClass::Net,
+                "connection reset",
+            )
+        };
+        let timeout =
+            || git2::Error::new(git2::ErrorCode::Timeout, git2::ErrorClass::Net, "timed out");
+        let auth = || {
+            git2::Error::new(
+                git2::ErrorCode::Auth,
+                git2::ErrorClass::Http,
+                "authentication failed",
+            )
+        };
+
+        assert_eq!(run(0, reset), (true, 1));
+        assert_eq!(run(2, reset), (true, 3));
+        assert_eq!(run(2, timeout), (true, 3));
+        // Gives up after the configured number of retries
+        assert_eq!(run(3, reset), (false, 3));
+        // Authentication errors aren't retried
+        assert_eq!(run(1, auth), (false, 1));
+    }
+
     #[test]
     fn test_absolute_git_url() {
         // gix::Url::canonicalize() works even if the path doesn't exist.
@@ -796,6 +968,8 @@ mod tests {
                     current_time,
                     &jj_lib::git::Progress {
                         bytes_downloaded: None,
+                        received_objects: 0,
+                        total_objects: 0,
                         overall,
                     },
                     &mut output,
@@ -805,15 +979,69 @@ mod tests {
         };
         // First output is after the initial delay
         assert_snapshot!(update(crate::progress::INITIAL_DELAY - Duration::from_millis(1), 0.1), @"");
-        assert_snapshot!(update(Duration::from_millis(1), 0.10), @"[?25l\r 10% [█▊                ][K");
+        assert_snapshot!(update(Duration::from_millis(1), 0.10), @"[?25l\r 10% ETA 0:02 [▉        ][K");
         // No updates for the next 30 milliseconds
         assert_snapshot!(update(Duration::from_millis(10), 0.11), @"");
         assert_snapshot!(update(Duration::from_millis(10), 0.12), @"");
         assert_snapshot!(update(Duration::from_millis(10), 0.13), @"");
         // We get an update now that we go over the threshold
-        assert_snapshot!(update(Duration::from_millis(100), 0.30), @" 30% [█████▍            ][K");
+        assert_snapshot!(update(Duration::from_millis(100), 0.30), @" 30% ETA 0:00 [██▊      ][K");
         // Even though we went over by quite a bit, the new threshold is relative to the
         // previous output, so we don't get an update here
         assert_snapshot!(update(Duration::from_millis(30), 0.40), @"");
     }
+
+    #[test]
+    fn test_update_with_objects() {
+        let start = Instant::now();
+        let mut progress = Progress::new(start);
+        let mut update = |duration, received_objects, bytes| -> String {
+            let mut buf = vec![];
+            let mut output = ProgressOutput::for_test(&mut buf, 70);
+            progress
+                .update(
+                    start + duration,
+                    &jj_lib::git::Progress {
+                        bytes_downloaded: Some(bytes),
+                        received_objects,
+                        total_objects: 100,
+                        overall: received_objects as f32 / 100.0,
+                    },
+                    &mut output,
+                )
+                .unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        assert_snapshot!(update(Duration::from_millis(100), 5, 0), @"");
+        assert_snapshot!(
+            update(Duration::from_secs(2), 20, 2 << 20),
+            @"[?25l\r 20% 20/100 objects   2.0 MiB at   1.1 MiB/s ETA 0:08 [██▊           ][K");
+    }
+
+    #[test]
+    fn test_update_line() {
+        let start = Instant::now();
+        let mut progress = Progress::new_for_lines(start);
+        let mut update = |duration, received_objects| -> String {
+            let mut buf = vec![];
+            progress
+                .update_line(
+                    start + duration,
+                    &jj_lib::git::Progress {
+                        bytes_downloaded: None,
+                        received_objects,
+                        total_objects: 100,
+                        overall: received_objects as f32 / 100.0,
+                    },
+                    &mut buf,
+                )
+                .unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        assert_snapshot!(update(Duration::from_secs(1), 10), @"");
+        assert_snapshot!(update(Duration::from_secs(10), 25), @"Fetching:  25% 25/100 objects ETA 0:30");
+        // Lines are printed at most every 10 seconds
+        assert_snapshot!(update(Duration::from_secs(15), 50), @"");
+        assert_snapshot!(update(Duration::from_secs(20), 50), @"Fetching:  50% 50/100 objects ETA 0:20");
+    }
 }
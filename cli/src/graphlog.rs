@@ -16,12 +16,17 @@ use std::hash::Hash;
 use std::io;
 use std::io::Write;
 
+use itertools::Itertools as _;
 use jj_lib::config::ConfigGetError;
 use jj_lib::graph::GraphEdge;
 use jj_lib::graph::GraphEdgeType;
 use jj_lib::settings::UserSettings;
 use renderdag::Ancestor;
+use renderdag::GraphRow;
 use renderdag::GraphRowRenderer;
+use renderdag::LinkLine;
+use renderdag::NodeLine;
+use renderdag::PadLine;
 use renderdag::Renderer;
 
 pub trait GraphLog<K: Clone + Eq + Hash> {
@@ -130,3 +135,265 @@ pub fn get_graphlog<'a, K: Clone + Eq + Hash + 'a>(
         }
     }
 }
+
+/// Creates a graph renderer which uses a single character per column.
+///
+/// If `max_width` is specified, columns beyond that width are collapsed into
+/// an indicator.
+pub fn get_compact_graphlog<'a, K: Clone + Eq + Hash + 'a>(
+    style: GraphStyle,
+    max_width: Option<usize>,
+    formatter: &'a mut dyn Write,
+) -> Box<dyn GraphLog<K> + 'a> {
+    let glyphs = match style {
+        GraphStyle::Ascii | GraphStyle::AsciiLarge => &COMPACT_ASCII_GLYPHS,
+        GraphStyle::Curved => &COMPACT_CURVED_GLYPHS,
+        GraphStyle::Square => &COMPACT_SQUARE_GLYPHS,
+    };
+    let renderer = CompactRenderer {
+        inner: GraphRowRenderer::new(),
+        glyphs,
+        max_width,
+        extra_pad_line: None,
+    };
+    SaplingGraphLog::create(renderer, formatter)
+}
+
+mod glyph {
+    pub(super) const SPACE: usize = 0;
+    pub(super) const HORIZONTAL: usize = 1;
+    pub(super) const PARENT: usize = 2;
+    pub(super) const ANCESTOR: usize = 3;
+    pub(super) const MERGE_LEFT: usize = 4;
+    pub(super) const MERGE_RIGHT: usize = 5;
+    pub(super) const MERGE_BOTH: usize = 6;
+    pub(super) const FORK_LEFT: usize = 7;
+    pub(super) const FORK_RIGHT: usize = 8;
+    pub(super) const FORK_BOTH: usize = 9;
+    pub(super) const JOIN_LEFT: usize = 10;
+    pub(super) const JOIN_RIGHT: usize = 11;
+    pub(super) const JOIN_BOTH: usize = 12;
+    pub(super) const TERMINATION: usize = 13;
+    pub(super) const ELIDED: usize = 14;
+    pub(super) const COUNT: usize = 15;
+}
+
+const COMPACT_ASCII_GLYPHS: [&str; glyph::COUNT] = [
+    " ", "-", "|", ".", "/", "\\", "+", "\\", "/", "+", "|", "|", "|", "~", ">",
+];
+
+const COMPACT_CURVED_GLYPHS: [&str; glyph::COUNT] = [
+    " ", "─", "│", "╷", "╯", "╰", "┴", "╮", "╭", "┬", "┤", "├", "┼", "~", "…",
+];
+
+const COMPACT_SQUARE_GLYPHS: [&str; glyph::COUNT] = [
+    " ", "─", "│", "·", "┘", "└", "┴", "┐", "┌", "┬", "┤", "├", "┼", "~", "…",
+];
+
+/// Renders graph rows with one character per column and no padding between
+/// columns.
+struct CompactRenderer<N> {
+    inner: GraphRowRenderer<N>,
+    glyphs: &'static [&'static str; glyph::COUNT],
+    max_width: Option<usize>,
+    extra_pad_line: Option<String>,
+}
+
+impl<N> CompactRenderer<N> {
+    /// Joins the column glyphs and the message line, collapsing the columns
+    /// that don't fit in the maximum width.
+    fn render_line(&self, mut columns: Vec<&str>, message: Option<&str>) -> String {
+        if let Some(max_width) = self.max_width {
+            if columns.len() > max_width {
+                let elided = columns[max_width.saturating_sub(1)..]
+                    .iter()
+                    .any(|&glyph| glyph != self.glyphs[glyph::SPACE]);
+                if elided {
+                    columns.truncate(max_width.saturating_sub(1));
+                    columns.push(self.glyphs[glyph::ELIDED]);
+                } else {
+                    columns.truncate(max_width);
+                }
+            }
+        }
+        let mut line = columns.concat();
+        if let Some(message) = message {
+            line.push(' ');
+            line.push_str(message);
+        }
+        line.trim_end().to_owned()
+    }
+
+    fn link_glyph(&self, cur: LinkLine, merge: bool) -> &'static str {
+        let glyphs = self.glyphs;
+        if cur.intersects(LinkLine::HORIZONTAL) {
+            if cur.intersects(LinkLine::CHILD)
+                || cur.intersects(LinkLine::ANY_FORK) && cur.intersects(LinkLine::ANY_MERGE)
+                || cur.intersects(LinkLine::ANY_FORK)
+                    && cur.intersects(LinkLine::VERT_PARENT)
+                    && !merge
+            {
+                glyphs[glyph::JOIN_BOTH]
+            } else if cur.intersects(LinkLine::ANY_FORK) {
+                glyphs[glyph::FORK_BOTH]
+            } else if cur.intersects(LinkLine::ANY_MERGE) {
+                glyphs[glyph::MERGE_BOTH]
+            } else {
+                glyphs[glyph::HORIZONTAL]
+            }
+        } else if cur.intersects(LinkLine::VERT_PARENT) && !merge {
+            let left = cur.intersects(LinkLine::LEFT_MERGE | LinkLine::LEFT_FORK);
+            let right = cur.intersects(LinkLine::RIGHT_MERGE | LinkLine::RIGHT_FORK);
+            match (left, right) {
+                (true, true) => glyphs[glyph::JOIN_BOTH],
+                (true, false) => glyphs[glyph::JOIN_LEFT],
+                (false, true) => glyphs[glyph::JOIN_RIGHT],
+                (false, false) => glyphs[glyph::PARENT],
+            }
+        } else if cur.intersects(LinkLine::VERT_PARENT | LinkLine::VERT_ANCESTOR)
+            && !cur.intersects(LinkLine::LEFT_FORK | LinkLine::RIGHT_FORK)
+        {
+            let left = cur.intersects(LinkLine::LEFT_MERGE);
+            let right = cur.intersects(LinkLine::RIGHT_MERGE);
+            match (left, right) {
+                (true, true) => glyphs[glyph::JOIN_BOTH],
+                (true, false) => glyphs[glyph::JOIN_LEFT],
+                (false, true) => glyphs[glyph::JOIN_RIGHT],
+                (false, false) if cur.intersects(LinkLine::VERT_ANCESTOR) => {
+                    glyphs[glyph::ANCESTOR]
+                }
+                (false, false) => glyphs[glyph::PARENT],
+            }
+        } else if cur.intersects(LinkLine::LEFT_FORK)
+            && cur.intersects(LinkLine::LEFT_MERGE | LinkLine::CHILD)
+        {
+            glyphs[glyph::JOIN_LEFT]
+        } else if cur.intersects(LinkLine::RIGHT_FORK)
+            && cur.intersects(LinkLine::RIGHT_MERGE | LinkLine::CHILD)
+        {
+            glyphs[glyph::JOIN_RIGHT]
+        } else if cur.intersects(LinkLine::LEFT_MERGE) && cur.intersects(LinkLine::RIGHT_MERGE) {
+            glyphs[glyph::MERGE_BOTH]
+        } else if cur.intersects(LinkLine::LEFT_FORK) && cur.intersects(LinkLine::RIGHT_FORK) {
+            glyphs[glyph::FORK_BOTH]
+        } else if cur.intersects(LinkLine::LEFT_FORK) {
+            glyphs[glyph::FORK_LEFT]
+        } else if cur.intersects(LinkLine::LEFT_MERGE) {
+            glyphs[glyph::MERGE_LEFT]
+        } else if cur.intersects(LinkLine::RIGHT_FORK) {
+            glyphs[glyph::FORK_RIGHT]
+        } else if cur.intersects(LinkLine::RIGHT_MERGE) {
+            glyphs[glyph::MERGE_RIGHT]
+        } else {
+            glyphs[glyph::SPACE]
+        }
+    }
+
+    fn pad_glyph(&self, pad: PadLine) -> &'static str {
+        match pad {
+            PadLine::Blank => self.glyphs[glyph::SPACE],
+            PadLine::Ancestor => self.glyphs[glyph::ANCESTOR],
+            PadLine::Parent => self.glyphs[glyph::PARENT],
+        }
+    }
+}
+
+impl<N: Clone + Eq> Renderer<N> for CompactRenderer<N> {
+    type Output = String;
+
+    fn width(&self, node: Option<&N>, parents: Option<&Vec<Ancestor<N>>>) -> u64 {
+        let columns = self.inner.width(node, parents);
+        let columns = match self.max_width {
+            Some(max_width) => columns.min(max_width.try_into().unwrap()),
+            None => columns,
+        };
+        columns.saturating_add(1)
+    }
+
+    fn reserve(&mut self, node: N) {
+        self.inner.reserve(node);
+    }
+
+    fn next_row(
+        &mut self,
+        node: N,
+        parents: Vec<Ancestor<N>>,
+        glyph: String,
+        message: String,
+    ) -> String {
+        let GraphRow {
+            glyph: node_glyph,
+            message,
+            merge,
+            node_line,
+            link_line,
+            term_line,
+            pad_lines,
+            ..
+        } = self.inner.next_row(node, parents, glyph, message);
+        let mut message_lines = message.lines();
+        let mut out = String::new();
+
+        if let Some(extra_pad_line) = self.extra_pad_line.take() {
+            out.push_str(&extra_pad_line);
+            out.push('\n');
+        }
+
+        let columns = node_line
+            .iter()
+            .map(|entry| match entry {
+                NodeLine::Node => node_glyph.as_str(),
+                NodeLine::Parent => self.glyphs[glyph::PARENT],
+                NodeLine::Ancestor => self.glyphs[glyph::ANCESTOR],
+                NodeLine::Blank => self.glyphs[glyph::SPACE],
+            })
+            .collect();
+        out.push_str(&self.render_line(columns, message_lines.next()));
+        out.push('\n');
+
+        if let Some(link_line) = link_line {
+            let columns = link_line
+                .iter()
+                .map(|&cur| self.link_glyph(cur, merge))
+                .collect();
+            out.push_str(&self.render_line(columns, message_lines.next()));
+            out.push('\n');
+        }
+
+        let mut need_extra_pad_line = false;
+        if let Some(term_line) = term_line {
+            for term_glyph in [glyph::PARENT, glyph::TERMINATION] {
+                let columns = term_line
+                    .iter()
+                    .zip(&pad_lines)
+                    .map(|(&term, &pad)| {
+                        if term {
+                            self.glyphs[term_glyph]
+                        } else {
+                            self.pad_glyph(pad)
+                        }
+                    })
+                    .collect();
+                out.push_str(&self.render_line(columns, message_lines.next()));
+                out.push('\n');
+            }
+            need_extra_pad_line = true;
+        }
+
+        let pad_columns = pad_lines
+            .iter()
+            .map(|&pad| self.pad_glyph(pad))
+            .collect_vec();
+        for message in message_lines {
+            out.push_str(&self.render_line(pad_columns.clone(), Some(message)));
+            out.push('\n');
+            need_extra_pad_line = false;
+        }
+
+        if need_extra_pad_line {
+            self.extra_pad_line = Some(self.render_line(pad_columns, None));
+        }
+
+        out
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! User-configured commands run around operations.
+//!
+//! Hooks are configured in the `[hooks]` table. The `pre-operation` hook runs
+//! before a command records its operation, and can abort it by exiting with a
+//! non-zero status. The `post-operation` hook runs after the operation has been
+//! recorded and the working copy has been updated. Automatic snapshots of the
+//! working copy don't run hooks.
+//!
+//! Commands which change things outside the repo before recording the
+//! operation, such as `jj git push`, can't be blocked by the `pre-operation`
+//! hook.
+
+use std::io;
+use std::process::Stdio;
+
+use jj_lib::config::ConfigGetResultExt as _;
+use jj_lib::settings::UserSettings;
+
+use crate::command_error::user_error_with_hint;
+use crate::command_error::user_error_with_message;
+use crate::command_error::CommandError;
+use crate::config::CommandNameAndArgs;
+
+/// Point around an operation at which a hook runs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HookPoint {
+    PreOperation,
+    PostOperation,
+}
+
+impl HookPoint {
+    fn name(self) -> &'static str {
+        match self {
+            HookPoint::PreOperation => "pre-operation",
+            HookPoint::PostOperation => "post-operation",
+        }
+    }
+}
+
+/// Runs the hook configured for `point`, if any, with the environment
+/// variables `env` set.
+///
+/// Returns an error if the hook couldn't be run or exited with a non-zero
+/// status.
+pub fn run_hook(
+    settings: &UserSettings,
+    point: HookPoint,
+    env: &[(&str, String)],
+) -> Result<(), CommandError> {
+    let Some(hook) = settings
+        .get::<CommandNameAndArgs>(["hooks", point.name()])
+        .optional()?
+    else {
+        return Ok(());
+    };
+    tracing::info!(?hook, "running {} hook", point.name());
+    let status = hook
+        .to_command()
+        .envs(env.iter().map(|(name, value)| (name, value)))
+        .stdin(Stdio::null())
+        .stdout(io::stderr())
+        .status()
+        .map_err(|err| {
+            user_error_with_message(
+                format!("Failed to run the {} hook '{hook}'", point.name()),
+                err,
+            )
+        })?;
+    if status.success() {
+        return Ok(());
+    }
+    let message = if let Some(exit_code) = status.code() {
+        format!("The {} hook exited with {exit_code}", point.name())
+    } else {
+        format!("The {} hook was terminated by: {status}", point.name())
+    };
+    let outcome = match point {
+        HookPoint::PreOperation => "The operation was not recorded.",
+        HookPoint::PostOperation => "The operation was recorded anyway.",
+    };
+    Err(user_error_with_hint(
+        message,
+        format!(
+            "{outcome} The hook is configured by `hooks.{}`.",
+            point.name()
+        ),
+    ))
+}
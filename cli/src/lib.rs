@@ -39,9 +39,11 @@ pub mod git_util {
     }
 }
 pub mod graphlog;
+pub mod hooks;
 pub mod merge_tools;
 pub mod movement_util;
 pub mod operation_templater;
+pub mod output_format;
 mod progress;
 pub mod revset_util;
 pub mod template_builder;
@@ -365,6 +365,7 @@ pub fn run_mergetool_external(
     Ok((new_tree, partial_resolution_error))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn edit_diff_external(
     editor: &ExternalMergeTool,
     left_tree: &MergedTree,
@@ -373,12 +374,16 @@ pub fn edit_diff_external(
     instructions: Option<&str>,
     base_ignores: Arc<GitIgnoreFile>,
     default_conflict_marker_style: ConflictMarkerStyle,
+    checkout_parallelism: usize,
 ) -> Result<MergedTreeId, DiffEditError> {
     let conflict_marker_style = editor
         .conflict_marker_style
         .unwrap_or(default_conflict_marker_style);
     let options = CheckoutOptions {
         conflict_marker_style,
+        parallelism: checkout_parallelism,
+        git_lfs: false,
+        eol_conversion: false,
     };
 
     let got_output_field = find_all_variables(&editor.edit_args).contains(&"output");
@@ -413,6 +418,7 @@ pub fn edit_diff_external(
 }
 
 /// Generates textual diff by the specified `tool` and writes into `writer`.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_diff(
     ui: &Ui,
     writer: &mut dyn Write,
@@ -421,12 +427,16 @@ pub fn generate_diff(
     matcher: &dyn Matcher,
     tool: &ExternalMergeTool,
     default_conflict_marker_style: ConflictMarkerStyle,
+    checkout_parallelism: usize,
 ) -> Result<(), DiffGenerateError> {
     let conflict_marker_style = tool
         .conflict_marker_style
         .unwrap_or(default_conflict_marker_style);
     let options = CheckoutOptions {
         conflict_marker_style,
+        parallelism: checkout_parallelism,
+        git_lfs: false,
+        eol_conversion: false,
     };
     let store = left_tree.store();
     let diff_wc = check_out_trees(store, left_tree, right_tree, matcher, None, &options)?;
@@ -194,6 +194,7 @@ pub struct DiffEditor {
     base_ignores: Arc<GitIgnoreFile>,
     use_instructions: bool,
     conflict_marker_style: ConflictMarkerStyle,
+    checkout_parallelism: usize,
 }
 
 impl DiffEditor {
@@ -238,6 +239,7 @@ impl DiffEditor {
             base_ignores,
             use_instructions: settings.get_bool("ui.diff-instructions")?,
             conflict_marker_style,
+            checkout_parallelism: settings.get("core.checkout-parallelism")?,
         })
     }
 
@@ -273,6 +275,7 @@ impl DiffEditor {
                     instructions.as_deref(),
                     self.base_ignores.clone(),
                     self.conflict_marker_style,
+                    self.checkout_parallelism,
                 )
             }
         }
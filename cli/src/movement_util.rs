@@ -196,13 +196,27 @@ fn choose_commit<'a>(
     direction: Direction,
     commits: &'a [Commit],
 ) -> Result<&'a Commit, CommandError> {
+    let template = workspace_command.commit_summary_template();
+    if !Ui::can_prompt() {
+        let mut cmd_err = user_error(format!(
+            "Ambiguous {} commit, cannot choose one without a prompt",
+            direction.cmd()
+        ));
+        for commit in commits {
+            cmd_err.add_formatted_hint_with(|formatter| {
+                write!(formatter, "Candidate: ")?;
+                template.format(commit, formatter)
+            });
+        }
+        cmd_err.add_hint("Use `jj new` or `jj edit` to move to one of the candidates");
+        return Err(cmd_err);
+    }
     writeln!(
         ui.stdout(),
         "ambiguous {} commit, choose one to target:",
         direction.cmd()
     )?;
     let mut formatter = ui.stdout_formatter();
-    let template = workspace_command.commit_summary_template();
     let mut choices: Vec<String> = Default::default();
     for (i, commit) in commits.iter().enumerate() {
         write!(formatter, "{}: ", i + 1)?;
@@ -252,7 +266,7 @@ pub(crate) fn move_to_commit(
     // We're editing, just move to the target commit.
     if args.should_edit {
         // We're editing, the target must be rewritable.
-        workspace_command.check_rewritable([target.id()])?;
+        workspace_command.check_rewritable(ui, [target.id()])?;
         let mut tx = workspace_command.start_transaction();
         tx.edit(&target)?;
         tx.finish(
@@ -15,15 +15,18 @@
 use std::any::Any;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::io;
 
 use itertools::Itertools as _;
 use jj_lib::extensions_map::ExtensionsMap;
 use jj_lib::object_id::ObjectId;
+use jj_lib::op_store::OpStoreResult;
 use jj_lib::op_store::OperationId;
 use jj_lib::operation::Operation;
 use jj_lib::repo::RepoLoader;
 use jj_lib::settings::UserSettings;
+use jj_lib::view::View;
 
 use crate::template_builder;
 use crate::template_builder::merge_fn_map;
@@ -294,15 +297,29 @@ fn builtin_operation_methods() -> OperationTemplateBuildMethodFnMap<Operation> {
             Ok(L::wrap_operation_id(out_property))
         },
     );
+    map.insert(
+        "tag",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            let [key_node] = function.expect_exact_arguments()?;
+            let key =
+                template_parser::expect_string_literal_with(key_node, |key, _| Ok(key.to_owned()))?;
+            let out_property = self_property
+                .map(move |op| op.metadata().tags.get(&key).cloned().unwrap_or_default());
+            Ok(L::wrap_string(out_property))
+        },
+    );
     map.insert(
         "tags",
         |_language, _diagnostics, _build_ctx, self_property, function| {
             function.expect_no_arguments()?;
             let out_property = self_property.map(|op| {
                 // TODO: introduce map type
+                // Internal `jj.*` keys are only accessible through tag(key).
                 op.metadata()
                     .tags
                     .iter()
+                    .filter(|(key, _)| !key.starts_with("jj."))
+                    .sorted()
                     .map(|(key, value)| format!("{key}: {value}"))
                     .join("\n")
             });
@@ -348,9 +365,56 @@ fn builtin_operation_methods() -> OperationTemplateBuildMethodFnMap<Operation> {
             Ok(L::wrap_boolean(out_property))
         },
     );
+    map.insert(
+        "has_conflicts",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property =
+                self_property.and_then(|op| Ok(!conflicted_refs_by_merge(&op)?.is_empty()));
+            Ok(L::wrap_boolean(out_property))
+        },
+    );
+    map.insert(
+        "conflicted_refs",
+        |_language, _diagnostics, _build_ctx, self_property, function| {
+            function.expect_no_arguments()?;
+            let out_property = self_property.and_then(|op| Ok(conflicted_refs_by_merge(&op)?));
+            Ok(L::wrap_string_list(out_property))
+        },
+    );
     map
 }
 
+/// Returns the bookmarks which are conflicted in the view of the merge
+/// operation `op`, but in none of its parents.
+fn conflicted_refs_by_merge(op: &Operation) -> OpStoreResult<Vec<String>> {
+    if op.parent_ids().len() < 2 {
+        return Ok(vec![]);
+    }
+    let mut parent_refs = HashSet::new();
+    for parent in op.parents() {
+        parent_refs.extend(conflicted_refs(&parent?.view()?));
+    }
+    let refs = conflicted_refs(&op.view()?)
+        .into_iter()
+        .filter(|name| !parent_refs.contains(name))
+        .collect();
+    Ok(refs)
+}
+
+/// Returns the names of the conflicted local and remote bookmarks in `view`.
+fn conflicted_refs(view: &View) -> Vec<String> {
+    let local_refs = view
+        .local_bookmarks()
+        .filter(|(_, target)| target.has_conflict())
+        .map(|(name, _)| name.to_owned());
+    let remote_refs = view
+        .all_remote_bookmarks()
+        .filter(|(_, remote_ref)| remote_ref.target.has_conflict())
+        .map(|((name, remote), _)| format!("{name}@{remote}"));
+    local_refs.chain(remote_refs).collect()
+}
+
 impl Template for OperationId {
     fn format(&self, formatter: &mut TemplateFormatter) -> io::Result<()> {
         write!(formatter, "{}", self.hex())
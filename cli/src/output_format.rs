@@ -0,0 +1,104 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Machine-readable output of commands selected by `--output-format`.
+
+use std::io::Write as _;
+
+use itertools::Itertools as _;
+use jj_lib::commit::Commit;
+use jj_lib::object_id::ObjectId as _;
+use jj_lib::op_store::RefTarget;
+use serde::Serialize;
+
+use crate::command_error::user_error_with_hint;
+use crate::command_error::CommandError;
+use crate::ui::Ui;
+
+/// Format of the primary output of a command.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// Human-readable text, colored as configured
+    #[default]
+    Text,
+    /// A single JSON document
+    Json,
+}
+
+/// Commands that support `--output-format=json`, by their full command name.
+const JSON_OUTPUT_COMMANDS: &[&str] = &["bookmark list", "operation log", "status"];
+
+/// Returns an error if the command doesn't support the output `format`.
+pub fn check_output_format(command_name: &str, format: OutputFormat) -> Result<(), CommandError> {
+    match format {
+        OutputFormat::Text => Ok(()),
+        OutputFormat::Json if JSON_OUTPUT_COMMANDS.contains(&command_name) => Ok(()),
+        OutputFormat::Json => Err(user_error_with_hint(
+            format!("`jj {command_name}` does not support --output-format=json"),
+            format!(
+                "JSON output is supported by: {}",
+                JSON_OUTPUT_COMMANDS
+                    .iter()
+                    .map(|name| format!("`jj {name}`"))
+                    .join(", ")
+            ),
+        )),
+    }
+}
+
+/// Writes `value` to stdout as a JSON document.
+pub fn write_json(ui: &Ui, value: &impl Serialize) -> Result<(), CommandError> {
+    let mut stdout = ui.stdout();
+    serde_json::to_writer_pretty(&mut stdout, value)
+        .map_err(|err| CommandError::from(std::io::Error::from(err)))?;
+    writeln!(stdout)?;
+    Ok(())
+}
+
+/// Summary of a commit in JSON output.
+#[derive(Clone, Debug, Serialize)]
+pub struct CommitJson {
+    pub change_id: String,
+    pub commit_id: String,
+    pub description: String,
+}
+
+impl CommitJson {
+    pub fn new(commit: &Commit) -> Self {
+        CommitJson {
+            change_id: commit.change_id().reverse_hex(),
+            commit_id: commit.id().hex(),
+            description: commit.description().to_owned(),
+        }
+    }
+}
+
+/// Target of a bookmark or tag in JSON output.
+///
+/// A conflicted target has multiple `added` and one or more `removed`
+/// commits. An absent target has no commits.
+#[derive(Clone, Debug, Serialize)]
+pub struct RefTargetJson {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl RefTargetJson {
+    pub fn new(target: &RefTarget) -> Self {
+        RefTargetJson {
+            added: target.added_ids().map(|id| id.hex()).collect(),
+            removed: target.removed_ids().map(|id| id.hex()).collect(),
+        }
+    }
+}
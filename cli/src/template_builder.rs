@@ -55,6 +55,7 @@ use crate::templater::ReformatTemplate;
 use crate::templater::SeparateTemplate;
 use crate::templater::SizeHint;
 use crate::templater::Template;
+use crate::templater::TemplatePredicate;
 use crate::templater::TemplateProperty;
 use crate::templater::TemplatePropertyError;
 use crate::templater::TemplatePropertyExt as _;
@@ -1692,6 +1693,26 @@ pub fn parse<'a, C: Clone + 'a, L: TemplateLanguage<'a> + ?Sized>(
         .map_err(|err| err.extend_alias_candidates(aliases_map))
 }
 
+/// Parses text, expands aliases, then builds boolean expression evaluation
+/// tree.
+pub fn parse_predicate<'a, C: Clone + 'a, L: TemplateLanguage<'a> + ?Sized>(
+    language: &L,
+    diagnostics: &mut TemplateDiagnostics,
+    template_text: &str,
+    aliases_map: &TemplateAliasesMap,
+    wrap_self: impl Fn(PropertyPlaceholder<C>) -> L::Property,
+) -> TemplateParseResult<TemplatePredicate<'a, C>> {
+    let node = template_parser::parse(template_text, aliases_map)?;
+    let self_placeholder = PropertyPlaceholder::new();
+    let build_ctx = BuildContext {
+        local_variables: HashMap::new(),
+        self_variable: &|| wrap_self(self_placeholder.clone()),
+    };
+    let property = expect_boolean_expression(language, diagnostics, &build_ctx, &node)
+        .map_err(|err| err.extend_alias_candidates(aliases_map))?;
+    Ok(TemplatePredicate::new(property, self_placeholder))
+}
+
 pub fn expect_boolean_expression<'a, L: TemplateLanguage<'a> + ?Sized>(
     language: &L,
     diagnostics: &mut TemplateDiagnostics,
@@ -714,6 +714,31 @@ impl<'a, C: Clone> TemplateRenderer<'a, C> {
     }
 }
 
+/// Adapter that evaluates compiled boolean `property` with the `placeholder`
+/// value set.
+pub struct TemplatePredicate<'a, C> {
+    property: Box<dyn TemplateProperty<Output = bool> + 'a>,
+    placeholder: PropertyPlaceholder<C>,
+}
+
+impl<'a, C: Clone> TemplatePredicate<'a, C> {
+    pub fn new(
+        property: Box<dyn TemplateProperty<Output = bool> + 'a>,
+        placeholder: PropertyPlaceholder<C>,
+    ) -> Self {
+        TemplatePredicate {
+            property,
+            placeholder,
+        }
+    }
+
+    /// Evaluates the predicate for the given `context`.
+    pub fn test(&self, context: &C) -> Result<bool, TemplatePropertyError> {
+        self.placeholder
+            .with_value(context.clone(), || self.property.extract())
+    }
+}
+
 /// Wrapper to pass around `Formatter` and error handler.
 pub struct TemplateFormatter<'a> {
     formatter: &'a mut dyn Formatter,
@@ -479,7 +479,8 @@ pub fn write_wrapped(
 }
 
 pub fn parse_author(author: &str) -> Result<(String, String), &'static str> {
-    let re = regex::Regex::new(r"(?<name>.*?)\s*<(?<email>.+)>$").unwrap();
+    // Angle brackets and line breaks would make the signature ambiguous.
+    let re = regex::Regex::new(r"^(?<name>[^<>\r\n]*?)\s*<(?<email>[^<>\r\n]+)>$").unwrap();
     let captures = re.captures(author).ok_or("Invalid author string")?;
     Ok((captures["name"].to_string(), captures["email"].to_string()))
 }
@@ -1215,4 +1216,16 @@ mod tests {
         let parsed = parse_author(&format!("<{expected_email}>")).unwrap();
         assert_eq!(("".to_string(), expected_email.to_string()), parsed);
     }
+
+    #[test]
+    fn test_parse_author_invalid() {
+        assert!(parse_author("Example").is_err());
+        assert!(parse_author("Example <>").is_err());
+        assert!(parse_author("Example <example@example.com").is_err());
+        assert!(parse_author("Example <example@example.com> trailing").is_err());
+        assert!(parse_author("Example <a> <example@example.com>").is_err());
+        assert!(parse_author("Example <<example@example.com>>").is_err());
+        assert!(parse_author("Exa\nmple <example@example.com>").is_err());
+        assert!(parse_author("Example <example@\nexample.com>").is_err());
+    }
 }
@@ -84,6 +84,10 @@ pub fn format_absolute_timestamp_with(
     Ok(datetime.format_with_items(format.items.iter()).to_string())
 }
 
+pub fn format_rfc3339_timestamp(timestamp: &Timestamp) -> Result<String, TimestampOutOfRange> {
+    Ok(datetime_from_timestamp(timestamp)?.to_rfc3339())
+}
+
 pub fn format_duration(
     from: &Timestamp,
     to: &Timestamp,
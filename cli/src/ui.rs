@@ -394,6 +394,18 @@ impl Ui {
         }
     }
 
+    /// Whether occasional progress lines should be printed for long-running
+    /// operations because continuous feedback can't be displayed on stderr
+    pub fn use_progress_lines(&self) -> bool {
+        match &self.output {
+            UiOutput::Terminal { stderr, .. } => {
+                self.progress_indicator && !self.quiet && !stderr.is_terminal()
+            }
+            UiOutput::Paged { .. } => false,
+            UiOutput::BuiltinPaged { .. } => false,
+        }
+    }
+
     pub fn progress_output(&self) -> Option<ProgressOutput<std::io::Stderr>> {
         self.use_progress_indicator()
             .then(ProgressOutput::for_stderr)
@@ -53,6 +53,11 @@ struct Args {
     #[arg(long)]
     append: Option<String>,
 
+    /// Adds a line with the value of this environment variable to the end of
+    /// the file
+    #[arg(long)]
+    append_env: Option<String>,
+
     /// Write this string to stdout, and ignore stdin.
     #[arg(long)]
     stdout: Option<String>,
@@ -78,6 +83,7 @@ fn main() -> ExitCode {
         assert!(!args.uppercase);
         assert!(!args.lowercase);
         assert!(args.append.is_none());
+        assert!(args.append_env.is_none());
         data
     } else {
         let mut stdout = std::io::stdin()
@@ -104,6 +110,9 @@ fn main() -> ExitCode {
         if let Some(line) = args.append {
             stdout.push_str(&line);
         }
+        if let Some(name) = args.append_env {
+            stdout.push_str(&std::env::var(name).unwrap_or_default());
+        }
         stdout
     };
     print!("{stdout}");
@@ -220,6 +220,22 @@ impl TestEnvironment {
         self.normalize_output(&get_stderr_string(&assert))
     }
 
+    /// Run a `jj` command, check that it failed with code 5 (for storage
+    /// errors), and return its stderr
+    #[must_use]
+    pub fn jj_cmd_storage_error(&self, current_dir: &Path, args: &[&str]) -> String {
+        let assert = self.jj_cmd(current_dir, args).assert().code(5).stdout("");
+        self.normalize_output(&get_stderr_string(&assert))
+    }
+
+    /// Run a `jj` command, check that it failed with code 6 (for conflicts),
+    /// and return its stderr
+    #[must_use]
+    pub fn jj_cmd_conflict_error(&self, current_dir: &Path, args: &[&str]) -> String {
+        let assert = self.jj_cmd(current_dir, args).assert().code(6).stdout("");
+        self.normalize_output(&get_stderr_string(&assert))
+    }
+
     /// Run a `jj` command, check that it failed with code 255, and return its
     /// stderr
     #[must_use]
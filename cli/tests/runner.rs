@@ -31,6 +31,7 @@ mod test_edit_command;
 mod test_evolog_command;
 mod test_file_annotate_command;
 mod test_file_chmod_command;
+mod test_file_log_command;
 mod test_file_show_command;
 mod test_file_track_untrack_commands;
 mod test_fix_command;
@@ -47,6 +48,8 @@ mod test_git_submodule;
 mod test_gitignores;
 mod test_global_opts;
 mod test_help_command;
+#[cfg(unix)]
+mod test_hooks;
 mod test_immutable_commits;
 mod test_init_command;
 mod test_interdiff_command;
@@ -63,6 +66,7 @@ mod test_revset_output;
 mod test_root;
 mod test_shell_completion;
 mod test_show_command;
+mod test_sign_command;
 mod test_simplify_parents_command;
 mod test_sparse_command;
 mod test_split_command;
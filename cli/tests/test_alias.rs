@@ -341,3 +341,82 @@ fn test_alias_in_config_arg() {
         stderr,
         @"Warning: Command aliases cannot be loaded from -R/--repository path or --config/--config-file arguments.");
 }
+
+#[test]
+fn test_command_defaults() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "first"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "second"]);
+    std::fs::write(repo_path.join("file"), "").unwrap();
+
+    test_env.add_config(
+        r#"
+        aliases.l = ["log"]
+        [command-defaults.log]
+        template = 'description.first_line() ++ "\n"'
+        limit = 1
+        no-graph = true
+        "#,
+    );
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log"]);
+    insta::assert_snapshot!(stdout, @"second");
+
+    // Options specified on the command line take precedence
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", "commit_id.short()", "-n2"]);
+    insta::assert_snapshot!(stdout, @"a494d4f21731fa15625b4a98");
+
+    // Defaults apply to the command an alias expands to
+    let stdout = test_env.jj_cmd_success(&repo_path, &["l"]);
+    insta::assert_snapshot!(stdout, @"second");
+
+    // Defaults aren't inserted after "--"
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "--", "file"]);
+    insta::assert_snapshot!(stdout, @"second");
+}
+
+#[test]
+fn test_command_defaults_conflicts() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    std::fs::write(repo_path.join("file"), "ab\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::write(repo_path.join("file"), "a b\n").unwrap();
+
+    test_env.add_config(
+        r#"
+        [command-defaults.diff]
+        ignore-all-space = true
+        "#,
+    );
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file b/file
+    index 81bf396956..b2901ea97c 100644
+    --- a/file
+    +++ b/file
+    ");
+
+    // A default conflicting with a command-line option is ignored
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git", "--ignore-space-change"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file b/file
+    index 81bf396956..b2901ea97c 100644
+    --- a/file
+    +++ b/file
+    @@ -1,1 +1,1 @@
+    -ab
+    +a b
+    ");
+
+    test_env.add_config(
+        r#"
+        [command-defaults."bookmark list"]
+        bogus = true
+        "#,
+    );
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["bookmark", "list"]);
+    insta::assert_snapshot!(stderr, @"Warning: Unknown option 'bogus' in command-defaults for 'bookmark list'");
+}
@@ -56,7 +56,7 @@ fn test_backout() {
     // Backout the commit
     let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["backout", "-r", "@"]);
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @"");
+    insta::assert_snapshot!(stderr, @r#"Backed out 2443ea76b0b1 as royxmykx 6d845ed9 Back out "a""#);
     insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r###"
     ○  6d845ed9fb6a Back out "a"
     │
@@ -73,7 +73,7 @@ fn test_backout() {
     test_env.jj_cmd_ok(&repo_path, &["edit", "@+"]);
     let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["backout", "-r", "@"]);
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @"");
+    insta::assert_snapshot!(stderr, @r#"Backed out 6d845ed9fb6a as znkkpsqq 79555ea9 Back out "Back out "a"""#);
     insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r###"
     ○  79555ea9040b Back out "Back out "a""
     │
@@ -122,7 +122,11 @@ fn test_backout_multiple() {
     let (stdout, stderr) =
         test_env.jj_cmd_ok(&repo_path, &["backout", "-r", "b", "-r", "c", "-r", "e"]);
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @"");
+    insta::assert_snapshot!(stderr, @r#"
+    Backed out 208f8612074a as wqnwkozp 8ff3fbc2 Back out "e"
+    Backed out 413337bbd11f as mouksmqu d31d42e0 Back out "c"
+    Backed out 46cc97af6802 as tqvpomtp 6504c4de Back out "b"
+    "#);
     insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r###"
     ○  6504c4ded177 Back out "b"
     │
@@ -188,6 +192,74 @@ fn test_backout_multiple() {
     "#);
 }
 
+#[test]
+fn test_backout_range() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    create_commit(&test_env, &repo_path, "a", &[], &[("a", "a\n")]);
+    create_commit(&test_env, &repo_path, "b", &["a"], &[("a", "a\nb\n")]);
+    create_commit(&test_env, &repo_path, "c", &["b"], &[("a", "a\nb\nc\n")]);
+
+    // The reverse of the range is applied from the newest commit
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["backout", "-r", "b::c", "-d", "c"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r#"
+    Backed out d06a2382d586 as vruxwmqv 54ca6fef Back out "c"
+    Backed out 46cc97af6802 as nuwvvtmy 1c6e65c9 Back out "b"
+    "#);
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r#"
+    ○  1c6e65c9f2ed Back out "b"
+    │
+    │  This backs out commit 46cc97af6802301d8db381386e8485ff3ff24ae6.
+    ○  54ca6fef7d23 Back out "c"
+    │
+    │  This backs out commit d06a2382d586d9b08078853bb2a5ac1697ba36b6.
+    @  d06a2382d586 c
+    ○  46cc97af6802 b
+    ○  2443ea76b0b1 a
+    ◆  000000000000
+    "#);
+    let stdout = test_env.jj_cmd_success(&repo_path, &["file", "show", "-r", "heads(all())", "a"]);
+    insta::assert_snapshot!(stdout, @"a");
+}
+
+#[test]
+fn test_backout_conflict() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    create_commit(&test_env, &repo_path, "a", &[], &[("a", "a\n")]);
+    create_commit(&test_env, &repo_path, "b", &["a"], &[("a", "b\n")]);
+    create_commit(&test_env, &repo_path, "c", &["b"], &[("a", "c\n")]);
+
+    // Backing out b on top of c conflicts, which is recorded in the new commit
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["backout", "-r", "b", "-d", "c"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r#"
+    Backed out 1188d37ce4c5 as vruxwmqv e1cc69f0 (conflict) Back out "b"
+    New conflicts appeared in these commits:
+      vruxwmqv e1cc69f0 (conflict) Back out "b"
+    To resolve the conflicts, start by updating to it:
+      jj new vruxwmqv
+    Then use `jj resolve`, or edit the conflict markers in the file directly.
+    Once the conflicts are resolved, you may want to inspect the result with `jj diff`.
+    Then run `jj squash` to move the resolution into the conflicted commit.
+    "#);
+    let stdout = test_env.jj_cmd_success(&repo_path, &["file", "show", "-r", "c+", "a"]);
+    insta::assert_snapshot!(stdout, @r"
+    <<<<<<< Conflict 1 of 1
+    %%%%%%% Changes from base to side #1
+    -b
+    +c
+    +++++++ Contents of side #2
+    a
+    >>>>>>> Conflict 1 of 1 ends
+    ");
+}
+
 fn get_log_output(test_env: &TestEnvironment, cwd: &Path) -> String {
     let template = r#"commit_id.short() ++ " " ++ description"#;
     test_env.jj_cmd_success(cwd, &["log", "-T", template])
@@ -124,6 +124,67 @@ fn test_bookmark_empty_name() {
     "###);
 }
 
+#[test]
+fn test_bookmark_create_from_operation() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m=foo"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "foo"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "root()"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "delete", "foo"]);
+    test_env.jj_cmd_ok(&repo_path, &["abandon", "description(foo)"]);
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r"
+    @   f652c32197cf
+    ◆   000000000000
+    ");
+
+    // The bookmark didn't exist at that operation
+    let stderr = test_env.jj_cmd_failure(
+        &repo_path,
+        &["bookmark", "create", "foo", "--from-op", "@----"],
+    );
+    insta::assert_snapshot!(stderr, @"Error: Bookmark foo didn't exist at operation 924775db8ae7");
+
+    // Recreate the deleted bookmark, which brings back the abandoned commit
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &["bookmark", "create", "foo", "--from-op", "@--"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"Created bookmark foo pointing to qpvuntsm 280394c7 foo | (empty) foo");
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r"
+    @   f652c32197cf
+    │ ○  foo 280394c74e61
+    ├─╯
+    ◆   000000000000
+    ");
+
+    // The bookmark exists now
+    let stderr = test_env.jj_cmd_failure(
+        &repo_path,
+        &["bookmark", "create", "foo", "--from-op", "@----"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Error: Bookmark already exists: foo
+    Hint: Use `jj bookmark set` to update it.
+    ");
+
+    // Can't be combined with -r
+    let stderr = test_env.jj_cmd_cli_error(
+        &repo_path,
+        &["bookmark", "create", "bar", "--from-op", "@-", "-r@"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--from-operation <OPERATION>' cannot be used with '--revision <REVSET>'
+
+    Usage: jj bookmark create --from-operation <OPERATION> <NAMES>...
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_bookmark_move() {
     let test_env = TestEnvironment::default();
@@ -1408,6 +1469,59 @@ fn test_bookmark_list() {
     insta::assert_snapshot!(stderr, @r###"
     Hint: Bookmarks marked as deleted will be *deleted permanently* on the remote on the next `jj git push`. Use `jj bookmark forget` to prevent this.
     "###);
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &local_path,
+        &[
+            "bookmark",
+            "list",
+            "--all-remotes",
+            "--output-format=json",
+            "remote-delete",
+            "remote-untrack",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r#"
+    [
+      {
+        "name": "remote-delete",
+        "remote": null,
+        "present": false,
+        "conflict": false,
+        "target": {
+          "added": [],
+          "removed": []
+        }
+      },
+      {
+        "name": "remote-delete",
+        "remote": "origin",
+        "present": true,
+        "conflict": false,
+        "target": {
+          "added": [
+            "203e60eb354463395f342935a715fedb8fdb8b3b"
+          ],
+          "removed": []
+        },
+        "tracked": true
+      },
+      {
+        "name": "remote-untrack",
+        "remote": "origin",
+        "present": true,
+        "conflict": false,
+        "target": {
+          "added": [
+            "71a16b0505cdf2d3c3b91975a7c739be1d761db2"
+          ],
+          "removed": []
+        },
+        "tracked": false
+      }
+    ]
+    "#);
+    insta::assert_snapshot!(stderr, @"Hint: Bookmarks marked as deleted will be *deleted permanently* on the remote on the next `jj git push`. Use `jj bookmark forget` to prevent this.");
 }
 
 #[test]
@@ -1635,6 +1749,55 @@ fn test_bookmark_list_filtered() {
     insta::assert_snapshot!(stderr, @"");
 }
 
+#[test]
+fn test_bookmark_list_sorted() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["new", "root()", "-m", "old"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "zeta", "beta"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "root()", "-m", "new"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "alpha"]);
+    // Rewriting the old commit updates its committer date, but not the author
+    // date
+    test_env.jj_cmd_ok(&repo_path, &["describe", "zeta", "-m", "old rewritten"]);
+
+    let list = |args: &[&str]| {
+        let template = r#"name ++ " " ++ normal_target.description()"#;
+        test_env.jj_cmd_success(
+            &repo_path,
+            &[&["bookmark", "list", "-T", template][..], args].concat(),
+        )
+    };
+    insta::assert_snapshot!(list(&[]), @r"
+    alpha new
+    beta old rewritten
+    zeta old rewritten
+    ");
+    insta::assert_snapshot!(list(&["--reverse"]), @r"
+    zeta old rewritten
+    beta old rewritten
+    alpha new
+    ");
+    // Bookmarks pointing to the same commit are sorted by name
+    insta::assert_snapshot!(list(&["--sort=committer-date"]), @r"
+    alpha new
+    beta old rewritten
+    zeta old rewritten
+    ");
+    insta::assert_snapshot!(list(&["--sort=author-date"]), @r"
+    beta old rewritten
+    zeta old rewritten
+    alpha new
+    ");
+    insta::assert_snapshot!(list(&["--sort=author-date", "--reverse"]), @r"
+    alpha new
+    zeta old rewritten
+    beta old rewritten
+    ");
+}
+
 #[test]
 fn test_bookmark_list_much_remote_divergence() {
     let test_env = TestEnvironment::default();
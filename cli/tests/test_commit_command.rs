@@ -222,6 +222,57 @@ fn test_commit_interactive_with_paths() {
     ");
 }
 
+#[test]
+fn test_commit_interactive_select_all_or_nothing() {
+    let mut test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let workspace_path = test_env.env_root().join("repo");
+
+    std::fs::write(workspace_path.join("file1"), "foo\n").unwrap();
+    std::fs::write(workspace_path.join("file2"), "bar\n").unwrap();
+    let diff_editor = test_env.set_up_fake_diff_editor();
+
+    // Select nothing: the commit is empty and all changes stay in the new
+    // working-copy commit
+    std::fs::write(&diff_editor, "reset file1\0reset file2").unwrap();
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_path, &["commit", "-i", "-m=nothing"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Working copy now at: rlvkpnrz df635c2d (no description set)
+    Parent commit      : qpvuntsm 1d659019 (empty) nothing
+    ");
+    let stdout = test_env.jj_cmd_success(&workspace_path, &["log", "--summary"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  rlvkpnrz test.user@example.com 2001-02-03 08:05:08 df635c2d
+    │  (no description set)
+    │  A file1
+    │  A file2
+    ○  qpvuntsm test.user@example.com 2001-02-03 08:05:08 1d659019
+    │  (empty) nothing
+    ◆  zzzzzzzz root() 00000000
+    ");
+
+    // Select everything: the new working-copy commit is empty
+    test_env.jj_cmd_ok(&workspace_path, &["undo"]);
+    std::fs::write(&diff_editor, "").unwrap();
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_path, &["commit", "-i", "-m=everything"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Working copy now at: mzvwutvl fa84cb01 (empty) (no description set)
+    Parent commit      : qpvuntsm b83f8cb7 everything
+    ");
+    let stdout = test_env.jj_cmd_success(&workspace_path, &["log", "--summary"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  mzvwutvl test.user@example.com 2001-02-03 08:05:11 fa84cb01
+    │  (empty) (no description set)
+    ○  qpvuntsm test.user@example.com 2001-02-03 08:05:11 b83f8cb7
+    │  everything
+    │  A file1
+    │  A file2
+    ◆  zzzzzzzz root() 00000000
+    ");
+}
+
 #[test]
 fn test_commit_with_default_description() {
     let mut test_env = TestEnvironment::default();
@@ -268,6 +268,44 @@ fn test_log_default_without_working_copy() {
     "#);
 }
 
+#[test]
+fn test_log_working_copy_keywords() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "initial"]);
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["workspace", "add", "--name", "second", "../second"],
+    );
+    // Check out the same commit in two workspaces
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["workspace", "add", "--name", "third", "../third"],
+    );
+    test_env.jj_cmd_ok(&test_env.env_root().join("third"), &["edit", "second@"]);
+
+    let template = r#"
+    separate(" ",
+      description.first_line(),
+      if(current_working_copy, "current"),
+      if(is_working_copy, "checked-out"),
+      "[" ++ working_copies ++ "]",
+    ) ++ "\n"
+    "#;
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-r", "all()", "--no-graph", "-T", template],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    checked-out [second@ third@]
+    current checked-out [default@]
+    initial []
+    []
+    ");
+}
+
 #[test]
 fn test_log_builtin_templates() {
     let test_env = TestEnvironment::default();
@@ -675,6 +713,50 @@ fn test_log_git_head() {
     "#);
 }
 
+#[test]
+fn test_log_git_decorations() {
+    let test_env = TestEnvironment::default();
+    let repo_path = test_env.env_root().join("repo");
+    let git_repo = git2::Repository::init(&repo_path).unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["git", "init", "--git-repo=."]);
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m=first"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "main"]);
+    test_env.jj_cmd_ok(&repo_path, &["tag", "create", "v1.0", "-m=release"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m=second"]);
+    test_env.jj_cmd_ok(&repo_path, &["git", "export"]);
+    let main_oid = git_repo.refname_to_id("refs/heads/main").unwrap();
+    git_repo
+        .reference("refs/remotes/origin/main", main_oid, false, "")
+        .unwrap();
+    git_repo
+        .reference_symbolic(
+            "refs/remotes/origin/HEAD",
+            "refs/remotes/origin/main",
+            false,
+            "",
+        )
+        .unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["git", "import"]);
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", "remote_heads"]);
+    insta::assert_snapshot!(stdout, @r"
+    @
+    ○
+    ◆  HEAD@origin
+    │
+    ~
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", "builtin_log_decorated"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  mzvwutvl a9efb78d (empty) second
+    ○  zsuskuln 29a3e81c (HEAD) (empty) (no description set)
+    ◆  qpvuntsm fa15625b (main, main@origin, tag: v1.0, HEAD@origin) (empty) first
+    │
+    ~
+    ");
+}
+
 #[test]
 fn test_log_commit_id_normal_hex() {
     let test_env = TestEnvironment::default();
@@ -884,6 +966,26 @@ fn test_log_contained_in() {
     ◆
     "###);
 
+    // Repeated revsets share the evaluated result, which shouldn't be mixed up
+    // with the other revsets
+    let template = r#"
+    separate(" ",
+      description.first_line(),
+      if(self.contained_in("main::"), "[descendant]"),
+      if(self.contained_in("main::"), "[descendant again]"),
+      if(self.contained_in("::main"), "[ancestor]"),
+    ) ++ "\n"
+    "#;
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-r::", "-T", template]);
+    insta::assert_snapshot!(stdout, @r"
+    @  D
+    │ ○  C [descendant] [descendant again]
+    │ ○  B [descendant] [descendant again] [ancestor]
+    │ ○  A [ancestor]
+    ├─╯
+    ◆  [ancestor]
+    ");
+
     // Suppress error that could be detected earlier
     let stderr = test_env.jj_cmd_failure(
         &repo_path,
@@ -947,6 +1049,65 @@ fn test_log_contained_in() {
     "###);
 }
 
+#[test]
+fn test_log_touches() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::create_dir(repo_path.join("dir")).unwrap();
+    std::fs::write(repo_path.join("dir/file1"), "a").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "add dir/file1"]);
+    std::fs::write(repo_path.join("file2"), "a").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "add file2"]);
+    std::fs::remove_file(repo_path.join("dir/file1")).unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "remove dir/file1"]);
+
+    let template = r#"
+    separate(" ",
+      description.first_line(),
+      if(self.touches("dir"), "[dir]"),
+      if(self.touches("file2 | glob:*.txt"), "[file2]"),
+    ) ++ "\n"
+    "#;
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", template]);
+    insta::assert_snapshot!(stdout, @r"
+    @  remove dir/file1 [dir]
+    ○  add file2 [file2]
+    ○  add dir/file1 [dir]
+    ◆
+    ");
+
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["log", "-T", "self.touches()"]);
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Failed to parse template: Function "touches": Expected 1 arguments
+    Caused by:  --> 1:14
+      |
+    1 | self.touches()
+      |              ^
+      |
+      = Function "touches": Expected 1 arguments
+    "#);
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["log", "-T", r#"self.touches("(")"#]);
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Failed to parse template: In fileset expression
+    Caused by:
+    1:  --> 1:14
+      |
+    1 | self.touches("(")
+      |              ^-^
+      |
+      = In fileset expression
+    2:  --> 1:2
+      |
+    1 | (
+      |  ^---
+      |
+      = expected <expression>
+    Hint: See https://jj-vcs.github.io/jj/latest/filesets/ for filesets syntax, or for how to match file paths.
+    "#);
+}
+
 #[test]
 fn test_short_prefix_in_transaction() {
     let test_env = TestEnvironment::default();
@@ -1292,3 +1453,56 @@ fn test_signature_templates() {
     ~
     "#);
 }
+
+#[test]
+fn test_signature_show() {
+    let test_env = TestEnvironment::default();
+
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "unsigned"]);
+    test_env.add_config("signing.sign-all = true");
+    test_env.add_config("signing.backend = 'test'");
+    test_env.add_config("signing.key = 'test-key'");
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "signed"]);
+
+    // Hidden unless enabled
+    let stdout = test_env.jj_cmd_success(&repo_path, &["show"]);
+    insta::assert_snapshot!(stdout, @r"
+    Commit ID: a66401228bf4e2342823a546821f720162070a3a
+    Change ID: rlvkpnrzqnoowoytxnquwvuryrwnrmlp
+    Author   : Test User <test.user@example.com> (2001-02-03 08:05:09)
+    Committer: Test User <test.user@example.com> (2001-02-03 08:05:09)
+
+        signed
+    ");
+
+    test_env.add_config("ui.show-cryptographic-signatures = true");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["show"]);
+    insta::assert_snapshot!(stdout, @r"
+    Commit ID: a66401228bf4e2342823a546821f720162070a3a
+    Change ID: rlvkpnrzqnoowoytxnquwvuryrwnrmlp
+    Author   : Test User <test.user@example.com> (2001-02-03 08:05:09)
+    Committer: Test User <test.user@example.com> (2001-02-03 08:05:09)
+    Signature: good signature by test-display test-key
+
+        signed
+    ");
+
+    // An unsigned commit is reported as such, not as a bad signature
+    let stdout = test_env.jj_cmd_success(&repo_path, &["show", "@-"]);
+    insta::assert_snapshot!(stdout, @r"
+    Commit ID: 879d5d20fea5930f053e0817033ad4aba924a361
+    Change ID: qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu
+    Author   : Test User <test.user@example.com> (2001-02-03 08:05:08)
+    Committer: Test User <test.user@example.com> (2001-02-03 08:05:08)
+    Signature: (no signature)
+
+        unsigned
+    ");
+
+    let template = r#"signature.status() ++ " " ++ signature.key() ++ "\n""#;
+    let stdout = test_env.jj_cmd_success(&repo_path, &["show", "-T", template]);
+    insta::assert_snapshot!(stdout, @"good test-key");
+}
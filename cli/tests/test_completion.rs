@@ -76,8 +76,11 @@ fn test_bookmark_names() {
     bbb-tracked	x
     --repository	Path to repository to operate on
     --ignore-working-copy	Don't snapshot the working copy, and don't update it
+    --no-snapshot	Don't snapshot the working copy, but still update it
     --ignore-immutable	Allow rewriting immutable commits
     --at-operation	Operation to load the repo at
+    --output-format	Format of the command's primary output
+    --op-meta	Attach custom metadata to the operation (can be repeated)
     --debug	Enable debug logging
     --color	When to colorize output (always, never, debug, auto)
     --quiet	Silence non-primary command output
@@ -432,6 +435,51 @@ fn test_revisions() {
     ");
 }
 
+#[test]
+fn test_tags_and_other_revisions() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let git_repo = {
+        let mut git_repo_path = repo_path.clone();
+        git_repo_path.extend([".jj", "repo", "store", "git"]);
+        git2::Repository::open(git_repo_path).unwrap()
+    };
+
+    test_env.jj_cmd_ok(&repo_path, &["new", "root()", "-mcommit1"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "bookmark1"]);
+    test_env.jj_cmd_ok(&repo_path, &["git", "export"]);
+    let oid = git_repo
+        .find_reference("refs/heads/bookmark1")
+        .unwrap()
+        .target()
+        .unwrap();
+    git_repo.reference("refs/tags/v1.0", oid, true, "").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["git", "import"]);
+
+    let mut test_env = test_env;
+    test_env.add_env_var("COMPLETE", "fish");
+    let test_env = test_env;
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["--", "jj", "tag", "list", "v"]);
+    insta::assert_snapshot!(stdout, @"v1.0	commit1");
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["--", "jj", "unsquash", "-r", ""]);
+    insta::assert_snapshot!(stdout, @"m	(no description set)");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["--", "jj", "workspace", "add", "--revision", ""],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    bookmark1	commit1
+    v1.0	commit1
+    m	(no description set)
+    r	commit1
+    z	(no description set)
+    ");
+}
+
 #[test]
 fn test_operations() {
     let test_env = TestEnvironment::default();
@@ -521,6 +569,8 @@ fn test_config() {
 
     let stdout = test_env.jj_cmd_success(dir, &["--", "jj", "config", "get", "c"]);
     insta::assert_snapshot!(stdout, @r"
+    core.checkout-parallelism	Number of threads used to write files when updating the working copy. 0 means the number of available CPUs.
+    core.eol-conversion	Whether to convert line endings according to the `text` and `eol` attributes in the root .gitattributes file
     core.fsmonitor	Whether to use an external filesystem monitor, useful for large repos
     core.watchman.register_snapshot_trigger	Whether to use triggers to monitor for changes in the background.
     ");
@@ -528,7 +578,10 @@ fn test_config() {
     let stdout = test_env.jj_cmd_success(dir, &["--", "jj", "config", "list", "c"]);
     insta::assert_snapshot!(stdout, @r"
     colors	Mapping from jj formatter labels to colors
+    command-defaults	Default option values for built-in commands, keyed by command name
     core
+    core.checkout-parallelism	Number of threads used to write files when updating the working copy. 0 means the number of available CPUs.
+    core.eol-conversion	Whether to convert line endings according to the `text` and `eol` attributes in the root .gitattributes file
     core.fsmonitor	Whether to use an external filesystem monitor, useful for large repos
     core.watchman
     core.watchman.register_snapshot_trigger	Whether to use triggers to monitor for changes in the background.
@@ -536,6 +589,8 @@ fn test_config() {
 
     let stdout = test_env.jj_cmd_success(dir, &["--", "jj", "log", "--config", "c"]);
     insta::assert_snapshot!(stdout, @r"
+    core.checkout-parallelism=	Number of threads used to write files when updating the working copy. 0 means the number of available CPUs.
+    core.eol-conversion=	Whether to convert line endings according to the `text` and `eol` attributes in the root .gitattributes file
     core.fsmonitor=	Whether to use an external filesystem monitor, useful for large repos
     core.watchman.register_snapshot_trigger=	Whether to use triggers to monitor for changes in the background.
     ");
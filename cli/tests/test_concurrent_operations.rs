@@ -61,6 +61,67 @@ fn test_concurrent_operation_divergence() {
     "###);
 }
 
+#[test]
+fn test_concurrent_operations_conflicted_refs() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "a"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "b"]);
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["bookmark", "create", "foo", "bar", "-r", "@-"],
+    );
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "set", "foo", "bar", "-r", "@"]);
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "bookmark",
+            "set",
+            "foo",
+            "-r=root()",
+            "--allow-backwards",
+            "--at-op=@-",
+        ],
+    );
+
+    let template = r#"separate(" ", id.short(4), has_conflicts, conflicted_refs) ++ "\n""#;
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["op", "log", "--no-graph", "-T", template]);
+    insta::assert_snapshot!(stdout, @r"
+    659f true foo
+    b23f false
+    36c7 false
+    3a09 false
+    d11a false
+    f00f false
+    eac7 false
+    0000 false
+    ");
+    insta::assert_snapshot!(stderr, @r"
+    Concurrent modification detected, resolving automatically.
+    ");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            "-T",
+            "builtin_op_log_conflicts",
+            "--limit",
+            "1",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r#"
+    @    659faac03635 test-username@host.example.com 2001-02-03 04:05:13.000 +07:00 - 2001-02-03 04:05:13.000 +07:00
+    ├─╮  reconcile divergent operations
+    │ │  args: jj op log --no-graph -T 'separate(" ", id.short(4), has_conflicts, conflicted_refs) ++ "\n"'
+    │ │  Conflicted bookmarks: foo
+    "#);
+}
+
 #[test]
 fn test_concurrent_operations_auto_rebase() {
     let test_env = TestEnvironment::default();
@@ -19,6 +19,7 @@ use itertools::Itertools;
 use regex::Regex;
 
 use crate::common::fake_editor_path;
+use crate::common::get_stderr_string;
 use crate::common::to_toml_value;
 use crate::common::TestEnvironment;
 
@@ -341,6 +342,67 @@ fn test_config_layer_override_default() {
     "###);
 }
 
+#[test]
+fn test_config_list_show_source() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let config_key = "merge-tools.vimdiff.program";
+
+    test_env.add_config(format!("{config_key} = 'user'\n"));
+    std::fs::write(
+        repo_path.join(".jj/repo/config.toml"),
+        format!("{config_key} = 'repo'\n"),
+    )
+    .unwrap();
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "config",
+            "list",
+            config_key,
+            "--show-source",
+            "--include-defaults",
+            "--include-overridden",
+        ],
+    );
+    insta::assert_snapshot!(stdout.replace('\\', "/"), @r#"
+    # merge-tools.vimdiff.program = "vim" # default
+    # merge-tools.vimdiff.program = 'user' # user $TEST_ENV/config/config0002.toml
+    merge-tools.vimdiff.program = 'repo' # repo $TEST_ENV/repo/.jj/repo/config.toml
+    "#);
+
+    // The command-line argument wins
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "config",
+            "list",
+            config_key,
+            "--show-source",
+            &format!("--config={config_key}='cli'"),
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"merge-tools.vimdiff.program = 'cli' # cli");
+
+    // The source can also be used in custom templates
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "config",
+            "list",
+            "user",
+            "-T",
+            r#"name ++ " " ++ source ++ "\n""#,
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    user.name env-overrides
+    user.email env-overrides
+    ");
+}
+
 #[test]
 fn test_config_layer_override_env() {
     let mut test_env = TestEnvironment::default();
@@ -559,6 +621,7 @@ fn test_config_set_for_user_directory() {
     Error: Cannot determine config file to edit:
       $TEST_ENV/config/config0001.toml
       $TEST_ENV/config/config0002.toml
+    Hint: Edit one of the files directly, or point `JJ_CONFIG` at a single file.
     ");
 }
 
@@ -921,7 +984,11 @@ fn test_config_get() {
     "#,
     );
 
-    let stdout = test_env.jj_cmd_failure(test_env.env_root(), &["config", "get", "nonexistent"]);
+    let assert = test_env
+        .jj_cmd(test_env.env_root(), &["config", "get", "nonexistent"])
+        .assert()
+        .code(4);
+    let stdout = test_env.normalize_output(&get_stderr_string(&assert));
     insta::assert_snapshot!(stdout, @r"
     Config error: Value not found for nonexistent
     For help, see https://jj-vcs.github.io/jj/latest/config/.
@@ -958,6 +1025,74 @@ fn test_config_get() {
     insta::assert_snapshot!(stdout, @"bar");
 }
 
+#[test]
+fn test_config_get_typed() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config(
+        r#"
+    [table]
+    string = "some value"
+    empty = ""
+    bool = true
+    int = 123
+    list = ["list", "value"]
+    empty-list = []
+    "#,
+    );
+    let get = |name: &str, value_type: &str| {
+        test_env.jj_cmd_success(
+            test_env.env_root(),
+            &["config", "get", name, "--type", value_type],
+        )
+    };
+
+    insta::assert_snapshot!(get("table.string", "string"), @"some value");
+    insta::assert_snapshot!(get("table.empty", "string"), @"");
+    insta::assert_snapshot!(get("table.bool", "bool"), @"true");
+    insta::assert_snapshot!(get("table.int", "int"), @"123");
+    insta::assert_snapshot!(get("table.list", "list"), @r"
+    list
+    value
+    ");
+    insta::assert_snapshot!(get("table.empty-list", "list"), @"");
+
+    let stderr = test_env.jj_cmd_failure(
+        test_env.env_root(),
+        &["config", "get", "table.int", "--type", "bool"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Config error: Invalid type or value for table.int
+    Caused by: Expected a boolean
+    Hint: Check the config file: $TEST_ENV/config/config0002.toml
+    For help, see https://jj-vcs.github.io/jj/latest/config/.
+    ");
+    let stderr = test_env.jj_cmd_failure(
+        test_env.env_root(),
+        &["config", "get", "table.string", "--type", "list"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Config error: Invalid type or value for table.string
+    Caused by: Expected a list
+    Hint: Check the config file: $TEST_ENV/config/config0002.toml
+    For help, see https://jj-vcs.github.io/jj/latest/config/.
+    ");
+
+    // Missing keys are reported with a distinct exit code
+    let assert = test_env
+        .jj_cmd(
+            test_env.env_root(),
+            &["config", "get", "table.missing", "--type", "string"],
+        )
+        .assert()
+        .code(4)
+        .stdout("");
+    let stderr = test_env.normalize_output(&get_stderr_string(&assert));
+    insta::assert_snapshot!(stderr, @r"
+    Config error: Value not found for table.missing
+    For help, see https://jj-vcs.github.io/jj/latest/config/.
+    ");
+}
+
 #[test]
 fn test_config_path_syntax() {
     let test_env = TestEnvironment::default();
@@ -1001,7 +1136,11 @@ fn test_config_path_syntax() {
     insta::assert_snapshot!(stderr, @r###"
     Warning: No matching config key for a.'b()'.x
     "###);
-    let stderr = test_env.jj_cmd_failure(test_env.env_root(), &["config", "get", "a.'b()'.x"]);
+    let assert = test_env
+        .jj_cmd(test_env.env_root(), &["config", "get", "a.'b()'.x"])
+        .assert()
+        .code(4);
+    let stderr = test_env.normalize_output(&get_stderr_string(&assert));
     insta::assert_snapshot!(stderr, @r"
     Config error: Value not found for a.'b()'.x
     For help, see https://jj-vcs.github.io/jj/latest/config/.
@@ -1165,6 +1304,66 @@ fn test_config_conditional_without_home_dir() {
     insta::assert_snapshot!(stdout, @"repo");
 }
 
+#[test]
+fn test_config_conditional_remotes() {
+    let mut test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "work"]);
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "personal"]);
+    let work_path = test_env.env_root().join("work");
+    let personal_path = test_env.env_root().join("personal");
+    test_env.jj_cmd_ok(
+        &work_path,
+        &[
+            "git",
+            "remote",
+            "add",
+            "origin",
+            "https://example.com/work/repo.git",
+        ],
+    );
+    test_env.jj_cmd_ok(
+        &personal_path,
+        &[
+            "git",
+            "remote",
+            "add",
+            "origin",
+            "https://example.com/me/repo.git",
+        ],
+    );
+    // Test with fresh new config file
+    let user_config_path = test_env.env_root().join("config.toml");
+    test_env.set_config_path(&user_config_path);
+    std::fs::write(
+        &user_config_path,
+        indoc! {"
+            foo = 'personal'
+            [[--scope]]
+            --when.remotes = ['*example.com/work/*']
+            foo = 'work'
+        "},
+    )
+    .unwrap();
+
+    let stdout = test_env.jj_cmd_success(test_env.env_root(), &["config", "get", "foo"]);
+    insta::assert_snapshot!(stdout, @"personal");
+    let stdout = test_env.jj_cmd_success(&work_path, &["config", "get", "foo"]);
+    insta::assert_snapshot!(stdout, @"work");
+    let stdout = test_env.jj_cmd_success(&personal_path, &["config", "get", "foo"]);
+    insta::assert_snapshot!(stdout, @"personal");
+
+    std::fs::write(&user_config_path, "--when.remotes = ['[']").unwrap();
+    let stderr = test_env.jj_cmd_failure(&work_path, &["config", "list", "--user"]);
+    insta::assert_snapshot!(stderr, @r"
+    Config error: Invalid type or value for --when
+    Caused by: Pattern syntax error near position 0: invalid range pattern
+    in `remotes`
+
+    Hint: Check the config file: $TEST_ENV/config.toml
+    For help, see https://jj-vcs.github.io/jj/latest/config/.
+    ");
+}
+
 #[test]
 fn test_config_show_paths() {
     let test_env = TestEnvironment::default();
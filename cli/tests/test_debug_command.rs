@@ -89,17 +89,19 @@ fn test_debug_index() {
     test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
     let workspace_path = test_env.env_root().join("repo");
     let stdout = test_env.jj_cmd_success(&workspace_path, &["debug", "index"]);
-    assert_snapshot!(filter_index_stats(&stdout), @r###"
+    assert_snapshot!(filter_index_stats(&stdout), @r"
     Number of commits: 2
     Number of merges: 0
     Max generation number: 1
     Number of heads: 1
     Number of changes: 2
+    Size on disk: 144 bytes
     Stats per level:
       Level 0:
         Number of commits: 2
         Name: [hash]
-    "###
+        Size on disk: 144 bytes
+    "
     );
 }
 
@@ -111,39 +113,67 @@ fn test_debug_reindex() {
     test_env.jj_cmd_ok(&workspace_path, &["new"]);
     test_env.jj_cmd_ok(&workspace_path, &["new"]);
     let stdout = test_env.jj_cmd_success(&workspace_path, &["debug", "index"]);
-    assert_snapshot!(filter_index_stats(&stdout), @r###"
+    assert_snapshot!(filter_index_stats(&stdout), @r"
     Number of commits: 4
     Number of merges: 0
     Max generation number: 3
     Number of heads: 1
     Number of changes: 4
+    Size on disk: 416 bytes
     Stats per level:
       Level 0:
         Number of commits: 3
         Name: [hash]
+        Size on disk: 204 bytes
       Level 1:
         Number of commits: 1
         Name: [hash]
-    "###
+        Size on disk: 212 bytes
+    "
     );
     let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_path, &["debug", "reindex"]);
     assert_snapshot!(stdout, @"");
     insta::assert_snapshot!(stderr, @r###"
     Finished indexing 4 commits.
     "###);
+    // Unused segments are removed
+    let segments_dir = workspace_path.join(".jj/repo/index/segments");
+    assert_eq!(std::fs::read_dir(&segments_dir).unwrap().count(), 1);
     let stdout = test_env.jj_cmd_success(&workspace_path, &["debug", "index"]);
-    assert_snapshot!(filter_index_stats(&stdout), @r###"
+    assert_snapshot!(filter_index_stats(&stdout), @r"
     Number of commits: 4
     Number of merges: 0
     Max generation number: 3
     Number of heads: 1
     Number of changes: 4
+    Size on disk: 264 bytes
     Stats per level:
       Level 0:
         Number of commits: 4
         Name: [hash]
-    "###
+        Size on disk: 264 bytes
+    "
     );
+
+    // Same as "debug reindex"
+    test_env.jj_cmd_ok(&workspace_path, &["new"]);
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_path, &["debug", "index", "--rebuild"]);
+    assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"Finished indexing 5 commits.");
+    let stdout = test_env.jj_cmd_success(&workspace_path, &["debug", "index"]);
+    assert_snapshot!(filter_index_stats(&stdout), @r"
+    Number of commits: 5
+    Number of merges: 0
+    Max generation number: 4
+    Number of heads: 1
+    Number of changes: 5
+    Size on disk: 324 bytes
+    Stats per level:
+      Level 0:
+        Number of commits: 5
+        Name: [hash]
+        Size on disk: 324 bytes
+    ");
 }
 
 #[test]
@@ -240,7 +270,132 @@ fn test_debug_operation_id() {
     );
 }
 
+#[test]
+fn test_debug_operation_binary() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let workspace_path = test_env.env_root().join("repo");
+    let op_store_path = workspace_path.join(".jj/repo/op_store");
+    let op_id = test_env.current_operation_id(&workspace_path);
+
+    // The raw bytes are the same as the stored objects
+    let assert = test_env
+        .jj_cmd(
+            &workspace_path,
+            &["debug", "operation", "--binary", "--display=operation"],
+        )
+        .assert()
+        .success();
+    let op_bytes = std::fs::read(op_store_path.join("operations").join(&op_id)).unwrap();
+    assert_eq!(assert.get_output().stdout, op_bytes);
+    let assert = test_env
+        .jj_cmd(
+            &workspace_path,
+            &["debug", "operation", "--binary", "--display=view"],
+        )
+        .assert()
+        .success();
+    let view_dir = op_store_path.join("views");
+    let view_bytes = std::fs::read_dir(&view_dir)
+        .unwrap()
+        .map(|entry| std::fs::read(entry.unwrap().path()).unwrap())
+        .find(|bytes| *bytes == assert.get_output().stdout);
+    assert!(view_bytes.is_some());
+
+    // The root operation isn't stored
+    let assert = test_env
+        .jj_cmd(
+            &workspace_path,
+            &[
+                "debug",
+                "operation",
+                "--binary",
+                "--display=operation",
+                "000000000000",
+            ],
+        )
+        .assert()
+        .success();
+    assert!(assert.get_output().stdout.is_empty());
+
+    let stderr = test_env.jj_cmd_failure(&workspace_path, &["debug", "operation", "--binary"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: --binary can only dump a single object
+    Hint: Use --display=operation or --display=view
+    ");
+
+    // An operation which can't be decoded can still be dumped
+    std::fs::write(op_store_path.join("operations").join(&op_id), b"garbage").unwrap();
+    let assert = test_env
+        .jj_cmd(
+            &workspace_path,
+            &["debug", "operation", "--binary", "--display=operation"],
+        )
+        .assert()
+        .success();
+    assert_eq!(assert.get_output().stdout, b"garbage");
+    let assert = test_env
+        .jj_cmd(
+            &workspace_path,
+            &[
+                "debug",
+                "operation",
+                "--binary",
+                "--display=operation",
+                &op_id[..12],
+            ],
+        )
+        .assert()
+        .success();
+    assert_eq!(assert.get_output().stdout, b"garbage");
+}
+
+#[test]
+fn test_debug_snapshot_stats() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let workspace_path = test_env.env_root().join("repo");
+    std::fs::write(workspace_path.join("file1"), "a\n").unwrap();
+    std::fs::write(workspace_path.join("file2"), "bc\n").unwrap();
+    let stdout = test_env.jj_cmd_success(&workspace_path, &["debug", "snapshot", "--stats"]);
+    assert_snapshot!(filter_durations(&stdout), @r"
+    Files scanned: 2
+    Files hashed: 2
+    Bytes read: 5
+    Phases:
+      traverse filesystem: [duration]
+      process tree entries: [duration]
+      process deleted tree entries: [duration]
+      process file states: [duration]
+      write tree: [duration]
+    ");
+}
+
+fn filter_durations(text: &str) -> String {
+    let regex = Regex::new(r"(?m)^(  [a-z ]+): .*$").unwrap();
+    regex.replace_all(text, "$1: [duration]").to_string()
+}
+
 fn filter_index_stats(text: &str) -> String {
     let regex = Regex::new(r"    Name: [0-9a-z]+").unwrap();
     regex.replace_all(text, "    Name: [hash]").to_string()
 }
+
+#[test]
+fn test_debug_watchman_disabled() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let workspace_path = test_env.env_root().join("repo");
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_path, &["debug", "watchman", "query"]);
+    assert_snapshot!(stdout, @"");
+    assert_snapshot!(stderr, @r#"
+    Watchman is disabled, nothing to do. Set `core.fsmonitor="watchman"` to enable.
+    "#);
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_path, &["debug", "watchman", "reset"]);
+    assert_snapshot!(stdout, @"");
+    assert_snapshot!(stderr, @r#"
+    Watchman is disabled, nothing to do. Set `core.fsmonitor="watchman"` to enable.
+    "#);
+}
@@ -763,6 +763,65 @@ fn test_describe_author() {
     "#);
 }
 
+#[test]
+fn test_describe_author_preserves_change_id() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "first"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "second"]);
+
+    let template =
+        r#"change_id.short() ++ " " ++ author.name() ++ " <" ++ author.email() ++ ">\n""#;
+    let get_log =
+        || test_env.jj_cmd_success(&repo_path, &["log", "-r::@ ~ root()", "-T", template]);
+    insta::assert_snapshot!(get_log(), @r"
+    @  kkmpptxzrspx Test User <test.user@example.com>
+    ○  qpvuntsmwlqt Test User <test.user@example.com>
+    │
+    ~
+    ");
+
+    // The change id is preserved, and the descendant is rebased
+    let (_stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "describe",
+            "@-",
+            "--no-edit",
+            "--author",
+            "Other <other@example.com>",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Rebased 1 descendant commits
+    Working copy now at: kkmpptxz d9399819 (empty) second
+    Parent commit      : qpvuntsm d0e3debc (empty) first
+    ");
+    insta::assert_snapshot!(get_log(), @r"
+    @  kkmpptxzrspx Test User <test.user@example.com>
+    ○  qpvuntsmwlqt Other <other@example.com>
+    │
+    ~
+    ");
+
+    // Malformed author strings are rejected
+    let stderr = test_env.jj_cmd_cli_error(
+        &repo_path,
+        &[
+            "describe",
+            "--no-edit",
+            "--author",
+            "Other <a> <other@example.com>",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: invalid value 'Other <a> <other@example.com>' for '--author <AUTHOR>': Invalid author string
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_describe_avoids_unc() {
     let mut test_env = TestEnvironment::default();
@@ -488,6 +488,280 @@ fn test_diff_name_only() {
     "###);
 }
 
+#[cfg(unix)]
+#[test]
+fn test_diff_null_terminated() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("deleted"), "d").unwrap();
+    std::fs::write(repo_path.join("old name"), "content\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::remove_file(repo_path.join("deleted")).unwrap();
+    std::fs::rename(repo_path.join("old name"), repo_path.join("new name")).unwrap();
+    std::fs::write(repo_path.join("with\nnewline"), "added").unwrap();
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--name-only", "-z"]);
+    insta::assert_snapshot!(stdout.replace('\0', "<NUL>"), @r"
+    deleted<NUL>new name<NUL>with
+    newline<NUL>
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--name-status", "-z"]);
+    insta::assert_snapshot!(stdout.replace('\0', "<NUL>"), @r"
+    D deleted<NUL>R old name<NUL>new name<NUL>A with
+    newline<NUL>
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["show", "-s", "-z", "-T", "''"]);
+    insta::assert_snapshot!(stdout.replace('\0', "<NUL>"), @r"
+    D deleted<NUL>R old name<NUL>new name<NUL>A with
+    newline<NUL>
+    ");
+
+    // Without -z, the summary is newline-terminated
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--name-status"]);
+    insta::assert_snapshot!(stdout, @r"
+    D deleted
+    R {old name => new name}
+    A with
+    newline
+    ");
+
+    // -z requires a file list format
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["diff", "-z"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the following required arguments were not provided:
+      <--summary|--name-only>
+
+    Usage: jj diff --null-terminated <--summary|--name-only> [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn test_diff_merge_base() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "base\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "base"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "main"]);
+    std::fs::write(repo_path.join("file1"), "main\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "main"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "description(base)", "-m", "side"]);
+    std::fs::write(repo_path.join("file1"), "side\n").unwrap();
+    std::fs::write(repo_path.join("file2"), "side\n").unwrap();
+
+    // Changes since the working copy forked from main
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--merge-base=main", "--git"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index df967b96a5..2299c37978 100644
+    --- a/file1
+    +++ b/file1
+    @@ -1,1 +1,1 @@
+    -base
+    +side
+    diff --git a/file1 b/file2
+    copy from file1
+    copy to file2
+    index df967b96a5..2299c37978 100644
+    --- a/file1
+    +++ b/file2
+    @@ -1,1 +1,1 @@
+    -base
+    +side
+    ");
+
+    // Changes in main since it forked from the working copy
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--merge-base=@", "-r=main", "-s"]);
+    insta::assert_snapshot!(stdout, @"M file1");
+    let stdout =
+        test_env.jj_cmd_success(&repo_path, &["diff", "--merge-base=@", "--to=main", "-s"]);
+    insta::assert_snapshot!(stdout, @"M file1");
+
+    // Several merge bases
+    test_env.jj_cmd_ok(&repo_path, &["new", "main", "@", "-m", "merge1"]);
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["new", "main", "description(side)", "-m", "merge2"],
+    );
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["diff", "--merge-base=description(merge1)"]);
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Revset "fork_point(afd22240c85b | 4833821dba70)" resolved to more than one revision
+    Hint: The revset "fork_point(afd22240c85b | 4833821dba70)" resolved to these revisions:
+      mzvwutvl a4d340b0 side
+      kkmpptxz dd30375a main | main
+    "#);
+}
+
+#[test]
+fn test_diff_reverse() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "a\nb\n").unwrap();
+    std::fs::write(repo_path.join("renamed1"), "x\ny\nz\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::write(repo_path.join("file1"), "a\nc\n").unwrap();
+    std::fs::write(repo_path.join("file2"), "new\n").unwrap();
+    std::fs::rename(repo_path.join("renamed1"), repo_path.join("renamed2")).unwrap();
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--reverse", "--git"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index 0f7bc76605..422c2b7ab3 100644
+    --- a/file1
+    +++ b/file1
+    @@ -1,2 +1,2 @@
+     a
+    -c
+    +b
+    diff --git a/file2 b/file2
+    deleted file mode 100644
+    index 3e757656cf..0000000000
+    --- a/file2
+    +++ /dev/null
+    @@ -1,1 +0,0 @@
+    -new
+    diff --git a/renamed2 b/renamed1
+    rename from renamed2
+    rename to renamed1
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--reverse", "--summary"]);
+    insta::assert_snapshot!(stdout, @r"
+    M file1
+    D file2
+    R {renamed2 => renamed1}
+    ");
+
+    // Composes with path filtering and --color-words
+    let stdout =
+        test_env.jj_cmd_success(&repo_path, &["diff", "--reverse", "--color-words", "file1"]);
+    insta::assert_snapshot!(stdout, @r"
+    Modified regular file file1:
+       1    1: a
+       2    2: cb
+    ");
+
+    // Reversing --from/--to is the same as swapping them
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "diff",
+            "--reverse",
+            "--from",
+            "@-",
+            "--to",
+            "@",
+            "--summary",
+        ],
+    );
+    let swapped = test_env.jj_cmd_success(
+        &repo_path,
+        &["diff", "--from", "@", "--to", "@-", "--summary"],
+    );
+    assert_eq!(stdout, swapped);
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["show", "--reverse", "-T", "''", "--stat"]);
+    insta::assert_snapshot!(stdout, @r"
+    file1                  | 2 +-
+    file2                  | 1 -
+    {renamed2 => renamed1} | 0
+    3 files changed, 1 insertion(+), 2 deletions(-)
+    ");
+}
+
+#[test]
+fn test_diff_find_renames() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("exact1"), "a\nb\nc\nd\n").unwrap();
+    std::fs::write(repo_path.join("modified1"), "e\nf\ng\nh\n").unwrap();
+    std::fs::write(repo_path.join("copied1"), "i\nj\nk\nl\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::remove_file(repo_path.join("exact1")).unwrap();
+    std::fs::write(repo_path.join("exact2"), "a\nb\nc\nd\n").unwrap();
+    // 75% similar to the deleted file
+    std::fs::remove_file(repo_path.join("modified1")).unwrap();
+    std::fs::write(repo_path.join("modified2"), "e\nf\ng\nx\n").unwrap();
+    std::fs::write(repo_path.join("copied1"), "i\nj\nk\nl\nm\n").unwrap();
+    std::fs::write(repo_path.join("copied2"), "i\nj\nk\nl\n").unwrap();
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary", "--find-renames"]);
+    insta::assert_snapshot!(stdout, @r"
+    M copied1
+    A copied2
+    R {exact1 => exact2}
+    R {modified1 => modified2}
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary", "--find-renames=75%"]);
+    insta::assert_snapshot!(stdout, @r"
+    M copied1
+    A copied2
+    R {exact1 => exact2}
+    R {modified1 => modified2}
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary", "--find-renames=76"]);
+    insta::assert_snapshot!(stdout, @r"
+    M copied1
+    A copied2
+    R {exact1 => exact2}
+    D modified1
+    A modified2
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary", "--find-renames=100"]);
+    insta::assert_snapshot!(stdout, @r"
+    M copied1
+    A copied2
+    R {exact1 => exact2}
+    D modified1
+    A modified2
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary", "--find-copies"]);
+    insta::assert_snapshot!(stdout, @r"
+    M copied1
+    C {copied1 => copied2}
+    R {exact1 => exact2}
+    R {modified1 => modified2}
+    ");
+
+    // Threshold from the config
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["diff", "--git", "--config=diff.find-renames=100", "exact2"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/exact1 b/exact2
+    rename from exact1
+    rename to exact2
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-r@", "-T''", "--stat", "--find-renames=75"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @
+    │  copied1                  | 1 +
+    ~  copied2                  | 4 ++++
+       {exact1 => exact2}       | 0
+       {modified1 => modified2} | 2 +-
+       4 files changed, 6 insertions(+), 1 deletion(-)
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["diff", "--find-renames=101"]);
+    insta::assert_snapshot!(stderr, @r#"
+    error: invalid value '101' for '--find-renames[=<N>]': expected a percentage between 0 and 100, got "101"
+
+    For more information, try '--help'.
+    "#);
+}
+
 #[test]
 fn test_diff_bad_args() {
     let test_env = TestEnvironment::default();
@@ -1594,7 +1868,7 @@ fn test_diff_ignore_whitespace() {
     1 file changed, 4 insertions(+), 2 deletions(-)
     "#);
 
-    // Word-level changes are still highlighted
+    // Word-level changes respect the whitespace options
     let stdout = test_env.jj_cmd_success(
         &repo_path,
         &["diff", "--color=always", "--ignore-all-space"],
@@ -1602,11 +1876,11 @@ fn test_diff_ignore_whitespace() {
     insta::assert_snapshot!(stdout, @r#"
     [38;5;3mModified regular file file1:[39m
          [38;5;2m   1[39m: [4m[38;5;2m{[24m[39m
-    [38;5;1m   1[39m [38;5;2m   2[39m: [4m[38;5;2m    [24m[39mfoo {
-    [38;5;1m   2[39m [38;5;2m   3[39m:     [4m[38;5;2m    [24m[39mbar;
-    [38;5;1m   3[39m [38;5;2m   4[39m: [4m[38;5;2m    [24m[39m}
+    [38;5;1m   1[39m [38;5;2m   2[39m:     foo {
+    [38;5;1m   2[39m [38;5;2m   3[39m:         bar;
+    [38;5;1m   3[39m [38;5;2m   4[39m:     }
          [38;5;2m   5[39m: [4m[38;5;2m}[24m[39m
-    [38;5;1m   4[39m [38;5;2m   6[39m: baz {[4m[38;5;2m  [24m[39m}
+    [38;5;1m   4[39m [38;5;2m   6[39m: baz {  }
     "#);
     let stdout = test_env.jj_cmd_success(
         &repo_path,
@@ -1616,13 +1890,128 @@ fn test_diff_ignore_whitespace() {
     [38;5;3mModified regular file file1:[39m
          [38;5;2m   1[39m: [4m[38;5;2m{[24m[39m
     [38;5;1m   1[39m [38;5;2m   2[39m: [4m[38;5;2m    [24m[39mfoo {
-    [38;5;1m   2[39m [38;5;2m   3[39m:     [4m[38;5;2m    [24m[39mbar;
+    [38;5;1m   2[39m [38;5;2m   3[39m:         bar;
          [38;5;2m   4[39m: [4m[38;5;2m    }[24m[39m
     [38;5;1m   3[39m [38;5;2m   5[39m: }
     [38;5;1m   4[39m [38;5;2m   6[39m: baz {[4m[38;5;2m  [24m[39m}
     "#);
 }
 
+#[test]
+fn test_diff_ignore_blank_lines() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(
+        repo_path.join("file1"),
+        indoc! {"
+            a
+            b
+
+            c
+            d
+            e
+            f
+            g
+            h
+        "},
+    )
+    .unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::write(
+        repo_path.join("file1"),
+        indoc! {"
+            a
+
+            b
+            c
+
+            d
+            e
+            f
+            g
+                h
+        "},
+    )
+    .unwrap();
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git", "--ignore-blank-lines"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index 4f0fa33b01..db68c6ab22 100644
+    --- a/file1
+    +++ b/file1
+    @@ -6,4 +7,4 @@
+     e
+     f
+     g
+    -h
+    +    h
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "diff",
+            "--git",
+            "--ignore-blank-lines",
+            "--ignore-all-space",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index 4f0fa33b01..db68c6ab22 100644
+    --- a/file1
+    +++ b/file1
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "diff",
+            "--stat",
+            "--ignore-blank-lines",
+            "--ignore-all-space",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    file1 | 0
+    1 file changed, 0 insertions(+), 0 deletions(-)
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["diff", "--color-words", "--ignore-blank-lines"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    Modified regular file file1:
+        ...
+       6    7: e
+       7    8: f
+       8    9: g
+       9   10:     h
+    ");
+
+    // Indentation-only changes are hidden in `jj log -p` as well
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-r@",
+            "--no-graph",
+            "-T''",
+            "-p",
+            "--git",
+            "--ignore-blank-lines",
+            "--ignore-all-space",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index 4f0fa33b01..db68c6ab22 100644
+    --- a/file1
+    +++ b/file1
+    ");
+}
+
 #[test]
 fn test_diff_skipped_context() {
     let test_env = TestEnvironment::default();
@@ -1896,6 +2285,65 @@ fn test_diff_skipped_context_nondefault() {
     "###);
 }
 
+#[test]
+fn test_diff_context_short_flag() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "a\nb\nc\nd\ne\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::write(repo_path.join("file1"), "a\n  b\nC\nd\ne\n").unwrap();
+
+    // No context, and the indentation-only change is ignored
+    let stdout =
+        test_env.jj_cmd_success(&repo_path, &["diff", "--git", "-U0", "--ignore-all-space"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index 9405325339..b9eb2a6fa2 100644
+    --- a/file1
+    +++ b/file1
+    @@ -3,1 +3,1 @@
+    -c
+    +C
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-r@",
+            "--no-graph",
+            "-T''",
+            "-p",
+            "-U0",
+            "--ignore-all-space",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    Modified regular file file1:
+        ...
+       3    3: cC
+        ...
+    ");
+
+    // Context larger than the file is clamped
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git", "-U100"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index 9405325339..b9eb2a6fa2 100644
+    --- a/file1
+    +++ b/file1
+    @@ -1,5 +1,5 @@
+     a
+    -b
+    -c
+    +  b
+    +C
+     d
+     e
+    ");
+}
+
 #[test]
 fn test_diff_leading_trailing_context() {
     let test_env = TestEnvironment::default();
@@ -2469,6 +2917,67 @@ fn test_diff_stat() {
     "###);
 }
 
+#[test]
+fn test_diff_stat_width() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    // One file dominates the change set, but the others still get a bar
+    std::fs::write(repo_path.join("big"), "line\n".repeat(200)).unwrap();
+    std::fs::write(repo_path.join("small"), "line\n".repeat(2)).unwrap();
+    std::fs::write(repo_path.join("tiny"), "line\n").unwrap();
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--stat"]);
+    insta::assert_snapshot!(stdout, @r"
+    big   | 200 +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+    small |   2 +
+    tiny  |   1 +
+    3 files changed, 203 insertions(+), 0 deletions(-)
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--stat", "--stat-width=30"]);
+    insta::assert_snapshot!(stdout, @r"
+    big   | 200 +++++++++++++++
+    small |   2 +
+    tiny  |   1 +
+    3 files changed, 203 insertions(+), 0 deletions(-)
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--stat", "--stat-width=120"]);
+    insta::assert_snapshot!(stdout, @r"
+    big   | 200 +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+    small |   2 ++
+    tiny  |   1 +
+    3 files changed, 203 insertions(+), 0 deletions(-)
+    ");
+
+    // The width also applies to the stat format set in config
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["diff", "--config=ui.diff.format=stat", "--stat-width=30"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    big   | 200 +++++++++++++++
+    small |   2 +
+    tiny  |   1 +
+    3 files changed, 203 insertions(+), 0 deletions(-)
+    ");
+
+    // The width is ignored if no histogram is shown
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary", "--stat-width=30"]);
+    insta::assert_snapshot!(stdout, @r"
+    A big
+    A small
+    A tiny
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["diff", "--stat", "--stat-width=0"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: invalid value '0' for '--stat-width <WIDTH>': 0 is not in 1..=65535
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_diff_stat_long_name_or_stat() {
     let mut test_env = TestEnvironment::default();
@@ -2638,3 +3147,82 @@ fn test_diff_binary() {
     4 files changed, 6 insertions(+), 6 deletions(-)
     "###);
 }
+
+#[test]
+fn test_diff_max_diff_lines() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "a\nb\nc\n").unwrap();
+    std::fs::write(repo_path.join("file2"), "x\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::write(repo_path.join("file1"), "A\nB\nC").unwrap();
+    std::fs::write(repo_path.join("file2"), "y\n").unwrap();
+
+    // Each file diff is truncated separately
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git", "--max-diff-lines=3"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index de980441c3..870951ade2 100644
+    --- a/file1
+    +++ b/file1
+    @@ -1,3 +1,3 @@
+    -a
+    -b
+    ... (more lines truncated)
+    diff --git a/file2 b/file2
+    index 587be6b4c3..975fbec825 100644
+    --- a/file2
+    +++ b/file2
+    @@ -1,1 +1,1 @@
+    -x
+    +y
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--max-diff-lines=1"]);
+    insta::assert_snapshot!(stdout, @r"
+    Modified regular file file1:
+       1    1: aA
+    ... (more lines truncated)
+    Modified regular file file2:
+       1    1: xy
+    ");
+
+    // The truncated diff should be aligned with the graph
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-r@", "-T''", "-p", "--git", "--max-diff-lines=2"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @
+    │  diff --git a/file1 b/file1
+    ~  index de980441c3..870951ade2 100644
+       --- a/file1
+       +++ b/file1
+       @@ -1,3 +1,3 @@
+       -a
+       ... (more lines truncated)
+       diff --git a/file2 b/file2
+       index 587be6b4c3..975fbec825 100644
+       --- a/file2
+       +++ b/file2
+       @@ -1,1 +1,1 @@
+       -x
+       ... (more lines truncated)
+    ");
+
+    // Diff shorter than the limit isn't truncated
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["diff", "--git", "--max-diff-lines=10", "file2"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file2 b/file2
+    index 587be6b4c3..975fbec825 100644
+    --- a/file2
+    +++ b/file2
+    @@ -1,1 +1,1 @@
+    -x
+    +y
+    ");
+}
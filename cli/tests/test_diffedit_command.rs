@@ -14,6 +14,7 @@
 
 use indoc::indoc;
 
+use crate::common::get_stderr_string;
 use crate::common::TestEnvironment;
 
 #[test]
@@ -732,3 +733,207 @@ fn test_diffedit_restore_descendants() {
     +println!("baz");
     "#);
 }
+
+#[test]
+fn test_diffedit_apply() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "a\nb\nc\n").unwrap();
+    std::fs::write(repo_path.join("file2"), "x\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+
+    let patch = indoc! {"
+        diff --git a/file1 b/file1
+        --- a/file1
+        +++ b/file1
+        @@ -1,3 +1,3 @@
+         a
+        -b
+        +B
+         c
+        diff --git a/file2 b/file2
+        deleted file mode 100644
+        --- a/file2
+        +++ /dev/null
+        @@ -1 +0,0 @@
+        -x
+        diff --git a/file3 b/file3
+        new file mode 100644
+        --- /dev/null
+        +++ b/file3
+        @@ -0,0 +1 @@
+        +new
+    "};
+    std::fs::write(test_env.env_root().join("patch"), patch).unwrap();
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["diffedit", "--apply", "../patch"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Created rlvkpnrz 97591a49 (no description set)
+    Working copy now at: rlvkpnrz 97591a49 (no description set)
+    Parent commit      : qpvuntsm 7bd674c8 (no description set)
+    Added 1 files, modified 1 files, removed 1 files
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    index de980441c3..7be73ce3c1 100644
+    --- a/file1
+    +++ b/file1
+    @@ -1,3 +1,3 @@
+     a
+    -b
+    +B
+     c
+    diff --git a/file2 b/file2
+    deleted file mode 100644
+    index 587be6b4c3..0000000000
+    --- a/file2
+    +++ /dev/null
+    @@ -1,1 +0,0 @@
+    -x
+    diff --git a/file3 b/file3
+    new file mode 100644
+    index 0000000000..3e757656cf
+    --- /dev/null
+    +++ b/file3
+    @@ -0,0 +1,1 @@
+    +new
+    ");
+
+    // Applying the same patch again adds nothing and rejects all hunks
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["diffedit", "--apply", "../patch"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Rejected hunk #1 (@@ -1,3 +1,3 @@) in file1
+    Warning: Cannot apply patch to file2: the file doesn't exist
+    Warning: Cannot add file3: the file already exists
+    Warning: Failed to apply 3 of 3 hunks
+    Nothing changed.
+    ");
+
+    // Mode changes are applied
+    let patch = indoc! {"
+        diff --git a/file1 b/file1
+        old mode 100644
+        new mode 100755
+    "};
+    let (stdout, stderr) =
+        test_env.jj_cmd_stdin_ok(&repo_path, &["diffedit", "--apply", "-"], patch);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Created rlvkpnrz d20eb21e (no description set)
+    Working copy now at: rlvkpnrz d20eb21e (no description set)
+    Parent commit      : qpvuntsm 7bd674c8 (no description set)
+    Added 0 files, modified 1 files, removed 0 files
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git", "--from", "@-", "file1"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    old mode 100644
+    new mode 100755
+    index de980441c3..7be73ce3c1
+    --- a/file1
+    +++ b/file1
+    @@ -1,3 +1,3 @@
+     a
+    -b
+    +B
+     c
+    ");
+
+    // Patches without a usable path are rejected before anything is applied
+    let patch = indoc! {"
+        --- /dev/null
+        +++ /dev/null
+        @@ -0,0 +1 @@
+        +x
+    "};
+    let assert = test_env
+        .jj_cmd_stdin(&repo_path, &["diffedit", "--apply", "-"], patch)
+        .assert()
+        .code(1);
+    let stderr = test_env.normalize_output(&get_stderr_string(&assert));
+    insta::assert_snapshot!(stderr, @r"
+    Error: Invalid patch on line 1: no file path in header
+    ");
+
+    // --apply and --tool can't be combined
+    let stderr = test_env.jj_cmd_cli_error(
+        &repo_path,
+        &[
+            "diffedit",
+            "--apply",
+            "../patch",
+            "--tool",
+            "fake-diff-editor",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--apply <PATH>' cannot be used with '--tool <NAME>'
+
+    Usage: jj diffedit --apply <PATH>
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn test_diffedit_apply_partial() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file"), "1\n2\n3\n4\n5\n6\n7\n8\n9\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    // Shift the lines so the first hunk applies with an offset
+    std::fs::write(repo_path.join("file"), "0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n").unwrap();
+
+    let patch = indoc! {"
+        --- a/file
+        +++ b/file
+        @@ -1,3 +1,3 @@
+         1
+        -2
+        +two
+         3
+        @@ -7,3 +7,3 @@
+         7
+        -eight
+        +8
+         9
+        --- a/missing
+        +++ b/missing
+        @@ -1 +1 @@
+        -a
+        +b
+    "};
+    let (stdout, stderr) =
+        test_env.jj_cmd_stdin_ok(&repo_path, &["diffedit", "--apply", "-"], patch);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Rejected hunk #2 (@@ -7,3 +7,3 @@) in file
+    Warning: Cannot apply patch to missing: the file doesn't exist
+    Warning: Failed to apply 2 of 3 hunks
+    Created rlvkpnrz a8022466 (no description set)
+    Working copy now at: rlvkpnrz a8022466 (no description set)
+    Parent commit      : qpvuntsm f5851664 (no description set)
+    Added 0 files, modified 1 files, removed 0 files
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--git", "--from", "@-"]);
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file b/file
+    index 0719398930..a37a78d5d6 100644
+    --- a/file
+    +++ b/file
+    @@ -1,5 +1,6 @@
+    +0
+     1
+    -2
+    +two
+     3
+     4
+     5
+    ");
+}
@@ -334,12 +334,14 @@ fn test_evolog_with_no_template() {
     - builtin_log_comfortable
     - builtin_log_compact
     - builtin_log_compact_full_description
+    - builtin_log_decorated
     - builtin_log_detailed
     - builtin_log_node
     - builtin_log_node_ascii
     - builtin_log_oneline
     - builtin_op_log_comfortable
     - builtin_op_log_compact
+    - builtin_op_log_conflicts
     - builtin_op_log_node
     - builtin_op_log_node_ascii
     - builtin_op_log_oneline
@@ -372,6 +374,28 @@ fn test_evolog_reversed_no_graph() {
     ");
 }
 
+#[test]
+fn test_evolog_reversed_with_limit() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "a"]);
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "b"]);
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "c"]);
+    // The limit applies to the newest versions, before reversing
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["evolog", "--reversed", "--no-graph", "--limit=2"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    qpvuntsm hidden test.user@example.com 2001-02-03 08:05:09 b4584f54
+    (empty) b
+    qpvuntsm test.user@example.com 2001-02-03 08:05:10 5cb22a87
+    (empty) c
+    ");
+}
+
 #[test]
 fn test_evolog_reverse_with_graph() {
     let test_env = TestEnvironment::default();
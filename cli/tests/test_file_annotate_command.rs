@@ -149,3 +149,72 @@ fn test_annotate_merge_one_sided_conflict_resolution() {
     zsuskuln test.use 2001-02-03 08:05:11    2: new text from new commit 1
     ");
 }
+
+#[test]
+fn test_annotate_template() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file.txt"), "line1\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m=initial"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m=next"]);
+    append_to_file(&repo_path.join("file.txt"), "new text from new commit");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "file",
+            "annotate",
+            "file.txt",
+            "-T",
+            r#"commit_id.short() ++ " " ++ description.first_line()"#,
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    8934c77238e0 initial    1: line1
+    41ae16e649e8 next    2: new text from new commit
+    ");
+}
+
+#[test]
+fn test_annotate_renamed() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(
+        repo_path.join("old.txt"),
+        "line1\nline2\nline3\nline4\nline5\n",
+    )
+    .unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m=initial"]);
+
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m=rename"]);
+    std::fs::remove_file(repo_path.join("old.txt")).unwrap();
+    std::fs::write(
+        repo_path.join("new.txt"),
+        "line1\nline2\nline3\nline4\nline5\nline6\n",
+    )
+    .unwrap();
+
+    // Lines from before the rename are attributed to the initial commit
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "file",
+            "annotate",
+            "new.txt",
+            "-T",
+            "description.first_line()",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    initial    1: line1
+    initial    2: line2
+    initial    3: line3
+    initial    4: line4
+    initial    5: line5
+    rename    6: line6
+    ");
+}
@@ -14,6 +14,9 @@
 
 use std::path::Path;
 
+use jj_lib::file_util::check_symlink_support;
+use jj_lib::file_util::try_symlink;
+
 use crate::common::TestEnvironment;
 
 fn create_commit(
@@ -246,3 +249,56 @@ fn test_chmod_file_dir_deletion_conflicts() {
     >>>>>>> Conflict 1 of 1 ends
     "###);
 }
+
+#[test]
+fn test_chmod_plus_minus_x() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    std::fs::write(repo_path.join("file"), "content\n").unwrap();
+
+    test_env.jj_cmd_ok(&repo_path, &["file", "chmod", "-r=@", "+x", "file"]);
+    let stdout = test_env.jj_cmd_success(&repo_path, &["debug", "tree"]);
+    insta::assert_snapshot!(stdout, @r#"file: Ok(Resolved(Some(File { id: FileId("d95f3ad14dee633a758d2e331151e950dd13e4ed"), executable: true })))"#);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt as _;
+        let mode = std::fs::metadata(repo_path.join("file"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_ne!(mode & 0o111, 0);
+    }
+
+    // `-x` would be parsed as a flag, so it must be passed after `--`
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["file", "chmod", "-x", "file"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: unexpected argument '-x' found
+
+      tip: to pass '-x' as a value, use '-- -x'
+
+    Usage: jj file chmod [OPTIONS] <MODE> <FILESETS>...
+
+    For more information, try '--help'.
+    ");
+
+    test_env.jj_cmd_ok(&repo_path, &["file", "chmod", "-r=@", "--", "-x", "file"]);
+    let stdout = test_env.jj_cmd_success(&repo_path, &["debug", "tree"]);
+    insta::assert_snapshot!(stdout, @r#"file: Ok(Resolved(Some(File { id: FileId("d95f3ad14dee633a758d2e331151e950dd13e4ed"), executable: false })))"#);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt as _;
+        let mode = std::fs::metadata(repo_path.join("file"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o111, 0);
+    }
+
+    // Symlinks don't have an executable bit
+    if check_symlink_support().unwrap() {
+        try_symlink("file", repo_path.join("link")).unwrap();
+        let stderr = test_env.jj_cmd_failure(&repo_path, &["file", "chmod", "+x", "link"]);
+        insta::assert_snapshot!(stderr, @"Error: Found neither a file nor a conflict at 'link'.");
+    }
+}
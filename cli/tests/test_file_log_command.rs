@@ -0,0 +1,103 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use crate::common::TestEnvironment;
+
+fn init_repo_with_rename(test_env: &TestEnvironment) -> std::path::PathBuf {
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("old.txt"), "a\nb\nc\nd\ne\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m=add old"]);
+    std::fs::write(repo_path.join("old.txt"), "a\nb\nc\nd\ne\nf\n").unwrap();
+    std::fs::write(repo_path.join("other.txt"), "other\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m=modify old"]);
+    std::fs::write(repo_path.join("other.txt"), "other 2\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m=modify other"]);
+    std::fs::remove_file(repo_path.join("old.txt")).unwrap();
+    std::fs::write(repo_path.join("new.txt"), "a\nb\nc\nd\ne\nf\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m=rename"]);
+    std::fs::write(repo_path.join("new.txt"), "a\nb\nc\nd\ne\nf\ng\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m=modify new"]);
+    repo_path
+}
+
+fn file_log(test_env: &TestEnvironment, repo_path: &Path, args: &[&str]) -> String {
+    let mut all_args = vec!["file", "log", "-T", "description"];
+    all_args.extend_from_slice(args);
+    test_env.jj_cmd_success(repo_path, &all_args)
+}
+
+#[test]
+fn test_file_log_follow_renames() {
+    let test_env = TestEnvironment::default();
+    let repo_path = init_repo_with_rename(&test_env);
+
+    insta::assert_snapshot!(file_log(&test_env, &repo_path, &["new.txt"]), @r"
+    modify new
+    rename
+    modify old
+    add old
+    ");
+    insta::assert_snapshot!(file_log(&test_env, &repo_path, &["new.txt", "--no-follow"]), @r"
+    modify new
+    rename
+    ");
+    insta::assert_snapshot!(file_log(&test_env, &repo_path, &["new.txt", "-n2"]), @r"
+    modify new
+    rename
+    ");
+    insta::assert_snapshot!(file_log(&test_env, &repo_path, &["old.txt", "-r@--"]), @r"
+    modify old
+    add old
+    ");
+}
+
+#[test]
+fn test_file_log_patch() {
+    let test_env = TestEnvironment::default();
+    let repo_path = init_repo_with_rename(&test_env);
+
+    insta::assert_snapshot!(
+        file_log(&test_env, &repo_path, &["new.txt", "-r@-", "--git"]), @r"
+    rename
+    diff --git a/old.txt b/new.txt
+    rename from old.txt
+    rename to new.txt
+    modify old
+    diff --git a/old.txt b/old.txt
+    index 9405325339..0fdf397db0 100644
+    --- a/old.txt
+    +++ b/old.txt
+    @@ -3,3 +3,4 @@
+     c
+     d
+     e
+    +f
+    add old
+    diff --git a/old.txt b/old.txt
+    new file mode 100644
+    index 0000000000..9405325339
+    --- /dev/null
+    +++ b/old.txt
+    @@ -0,0 +1,5 @@
+    +a
+    +b
+    +c
+    +d
+    +e
+    ");
+}
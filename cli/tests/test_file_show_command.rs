@@ -94,6 +94,63 @@ fn test_show() {
     "###);
 }
 
+#[test]
+fn test_show_conflict_side() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "base\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "side1"]);
+    std::fs::write(repo_path.join("file1"), "side1\n").unwrap();
+    std::fs::write(repo_path.join("file2"), "side1\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "@-", "-m", "side2"]);
+    std::fs::write(repo_path.join("file1"), "side2\n").unwrap();
+    std::fs::write(repo_path.join("file3"), "side2\n").unwrap();
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["new", "description(side1)", "description(side2)"],
+    );
+    std::fs::write(repo_path.join("file2"), "resolved\n").unwrap();
+
+    // Resolved files are printed as usual, and `cat` is an alias of `show`
+    let stdout =
+        test_env.jj_cmd_success(&repo_path, &["file", "cat", "file2", "--conflict-side=2"]);
+    insta::assert_snapshot!(stdout, @"resolved");
+
+    let stdout =
+        test_env.jj_cmd_success(&repo_path, &["file", "show", "file1", "--conflict-side=1"]);
+    insta::assert_snapshot!(stdout, @"side1");
+    let stdout =
+        test_env.jj_cmd_success(&repo_path, &["file", "show", "file1", "--conflict-side=2"]);
+    insta::assert_snapshot!(stdout, @"side2");
+    let stderr =
+        test_env.jj_cmd_failure(&repo_path, &["file", "show", "file1", "--conflict-side=3"]);
+    insta::assert_snapshot!(stderr, @"Error: The conflict at 'file1' has only 2 sides");
+    let stderr =
+        test_env.jj_cmd_cli_error(&repo_path, &["file", "show", "file1", "--conflict-side=0"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: invalid value '0' for '--conflict-side <N>': 0 is not in 1..=4294967295
+
+    For more information, try '--help'.
+    ");
+
+    // A side where the file doesn't exist is skipped
+    test_env.jj_cmd_ok(&repo_path, &["new", "description(side1)-", "-m", "delete"]);
+    std::fs::remove_file(repo_path.join("file1")).unwrap();
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["new", "description(delete)", "description(side1)"],
+    );
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["file", "show", "file1", "--conflict-side=1"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"Warning: Path 'file1' doesn't exist on side #1 of the conflict");
+    let stdout =
+        test_env.jj_cmd_success(&repo_path, &["file", "show", "file1", "--conflict-side=2"]);
+    insta::assert_snapshot!(stdout, @"side1");
+}
+
 #[cfg(unix)]
 #[test]
 fn test_show_symlink() {
@@ -102,6 +102,61 @@ fn test_track_untrack() {
     assert!(!files_after.contains("target"));
 }
 
+#[test]
+fn test_track_untrack_globs() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.add_config(r#"snapshot.auto-track = "none()""#);
+
+    std::fs::create_dir_all(repo_path.join("a").join("b")).unwrap();
+    std::fs::write(repo_path.join("top.log"), "").unwrap();
+    std::fs::write(repo_path.join("a").join("mid.log"), "").unwrap();
+    std::fs::write(repo_path.join("a").join("b").join("deep.log"), "").unwrap();
+    std::fs::write(repo_path.join("a").join("b").join("deep.txt"), "").unwrap();
+
+    // Nested globs can be tracked
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["file", "track", "glob:a/**/*.log"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["file", "list"]);
+    insta::assert_snapshot!(stdout.replace('\\', "/"), @r"
+    a/b/deep.log
+    a/mid.log
+    ");
+
+    // Commit the tracked files, then track the rest
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "logs"]);
+    test_env.jj_cmd_ok(&repo_path, &["file", "track", "glob:**/*"]);
+    let stdout = test_env.jj_cmd_success(&repo_path, &["file", "list"]);
+    insta::assert_snapshot!(stdout.replace('\\', "/"), @r"
+    a/b/deep.log
+    a/b/deep.txt
+    a/mid.log
+    top.log
+    ");
+
+    // Untracking files with a nested glob reports the files that already exist
+    // in the parent commit
+    std::fs::write(repo_path.join(".gitignore"), "*.log\n").unwrap();
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["file", "untrack", "glob:**/*.log"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr.replace('\\', "/"), @r"
+    Warning: The following untracked files exist in the parent commit and will show as deleted in the working-copy commit:
+      a/b/deep.log
+      a/mid.log
+    Hint: Untracking only affects the working-copy commit going forward. The files are not removed from existing commits.
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["file", "list"]);
+    insta::assert_snapshot!(stdout.replace('\\', "/"), @"a/b/deep.txt");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary"]);
+    insta::assert_snapshot!(stdout.replace('\\', "/"), @r"
+    D a/b/deep.log
+    A a/b/deep.txt
+    D a/mid.log
+    ");
+}
+
 #[test]
 fn test_track_untrack_sparse() {
     let test_env = TestEnvironment::default();
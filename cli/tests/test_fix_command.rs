@@ -726,6 +726,51 @@ fn test_deduplication() {
     insta::assert_snapshot!(sorted_lines(repo_path.join("file-fixlog")), @"BAR\nFOO\n");
 }
 
+#[test]
+fn test_fix_tool_receives_path() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let formatter_path = assert_cmd::cargo::cargo_bin("fake-formatter");
+    assert!(formatter_path.is_file());
+    let formatter = to_toml_value(formatter_path.to_str().unwrap());
+    test_env.add_config(format!(
+        r###"
+        [fix.tools.placeholder]
+        command = [{formatter}, "--append", "arg:$path\n"]
+        patterns = ["glob:'**/*.txt'"]
+
+        [fix.tools.env]
+        command = [{formatter}, "--append-env", "JJ_FIX_PATH"]
+        patterns = ["glob:'**/*.txt'"]
+        "###,
+    ));
+    std::fs::create_dir(repo_path.join("dir")).unwrap();
+    std::fs::write(repo_path.join("file.txt"), "content\n").unwrap();
+    std::fs::write(repo_path.join("dir").join("file.txt"), "content\n").unwrap();
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["fix", "-s", "@"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Fixed 1 commits of 1 checked.
+    Working copy now at: qpvuntsm 27f1700d (no description set)
+    Parent commit      : zzzzzzzz 00000000 (empty) (no description set)
+    Added 0 files, modified 2 files, removed 0 files
+    ");
+    let content = test_env.jj_cmd_success(&repo_path, &["file", "show", "file.txt"]);
+    insta::assert_snapshot!(content, @r"
+    content
+    file.txt
+    arg:file.txt
+    ");
+    let content = test_env.jj_cmd_success(&repo_path, &["file", "show", "dir/file.txt"]);
+    insta::assert_snapshot!(content, @r"
+    content
+    dir/file.txt
+    arg:dir/file.txt
+    ");
+}
+
 fn sorted_lines(path: PathBuf) -> String {
     let mut log: Vec<_> = std::fs::read_to_string(path.as_os_str())
         .unwrap()
@@ -523,6 +523,116 @@ fn test_git_clone_at_operation() {
     "###);
 }
 
+#[test]
+fn test_git_clone_colocate_bare() {
+    let test_env = TestEnvironment::default();
+    let git_repo_path = test_env.env_root().join("source");
+    let git_repo = git2::Repository::init(git_repo_path).unwrap();
+    set_up_non_empty_git_repo(&git_repo);
+
+    // --git-layout requires --colocate
+    let stderr = test_env.jj_cmd_cli_error(
+        test_env.env_root(),
+        &["git", "clone", "source", "clone", "--git-layout=bare"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: the following required arguments were not provided:
+      --colocate
+
+    Usage: jj git clone --colocate --git-layout <GIT_LAYOUT> <SOURCE> <DESTINATION>
+
+    For more information, try '--help'.
+    ");
+
+    let (_stdout, stderr) = test_env.jj_cmd_ok(
+        test_env.env_root(),
+        &[
+            "git",
+            "clone",
+            "source",
+            "clone",
+            "--colocate",
+            "--git-layout=bare",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r#"
+    Fetching into new repo in "$TEST_ENV/clone"
+    bookmark: main@origin [new] untracked
+    Setting the revset alias "trunk()" to "main@origin"
+    Working copy now at: uuqppmxq 1f0b881a (empty) (no description set)
+    Parent commit      : mzyxwzks 9f01a0e0 main | message
+    Added 1 files, modified 0 files, removed 0 files
+    "#);
+    let clone_path = test_env.env_root().join("clone");
+    assert!(clone_path.join("file").exists());
+    let jj_git_repo = git2::Repository::open(clone_path.join(".git")).unwrap();
+    assert!(jj_git_repo.is_bare());
+
+    // Bookmarks can be exported to the bare repo
+    test_env.jj_cmd_ok(&clone_path, &["bookmark", "create", "-r@-", "feature"]);
+    assert!(jj_git_repo.find_reference("refs/heads/feature").is_err());
+    test_env.jj_cmd_ok(&clone_path, &["git", "export"]);
+    assert!(jj_git_repo.find_reference("refs/heads/feature").is_ok());
+}
+
+#[test]
+fn test_git_clone_import_git_config() {
+    let test_env = TestEnvironment::default();
+    let git_repo_path = test_env.env_root().join("source");
+    let git_repo = git2::Repository::init(git_repo_path).unwrap();
+    set_up_non_empty_git_repo(&git_repo);
+
+    // Nothing to import
+    let (_stdout, stderr) = test_env.jj_cmd_ok(
+        test_env.env_root(),
+        &["git", "clone", "source", "clone1", "--import-git-config"],
+    );
+    insta::assert_snapshot!(stderr, @r#"
+    Fetching into new repo in "$TEST_ENV/clone1"
+    bookmark: main@origin [new] untracked
+    No Git config to import
+    Setting the revset alias "trunk()" to "main@origin"
+    Working copy now at: sqpuoqvx cad212e1 (empty) (no description set)
+    Parent commit      : mzyxwzks 9f01a0e0 main | message
+    Added 1 files, modified 0 files, removed 0 files
+    "#);
+
+    // The global Git config isn't copied to the repo config
+    std::fs::write(
+        test_env.home_dir().join(".gitconfig"),
+        "[user]\nname = Git User\nemail = git.user@example.com\n[remote]\npushDefault = origin\n",
+    )
+    .unwrap();
+    let (_stdout, stderr) = test_env.jj_cmd_ok(
+        test_env.env_root(),
+        &[
+            "git",
+            "clone",
+            "source",
+            "clone2",
+            "--colocate",
+            "--import-git-config",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r#"
+    Fetching into new repo in "$TEST_ENV/clone2"
+    bookmark: main@origin [new] untracked
+    No Git config to import
+    Setting the revset alias "trunk()" to "main@origin"
+    Working copy now at: uuqppmxq 1f0b881a (empty) (no description set)
+    Parent commit      : mzyxwzks 9f01a0e0 main | message
+    Added 1 files, modified 0 files, removed 0 files
+    "#);
+    let repo_config_path =
+        test_env
+            .env_root()
+            .join(PathBuf::from_iter(["clone2", ".jj", "repo", "config.toml"]));
+    insta::assert_snapshot!(std::fs::read_to_string(repo_config_path).unwrap(), @r#"
+    [revset-aliases]
+    "trunk()" = "main@origin"
+    "#);
+}
+
 #[test]
 fn test_git_clone_with_remote_name() {
     let test_env = TestEnvironment::default();
@@ -309,6 +309,79 @@ fn test_git_colocated_rebase_on_import() {
     "###);
 }
 
+#[test]
+fn test_git_colocated_change_id_trailer() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.change-id-trailer = true");
+    let workspace_root = test_env.env_root().join("repo");
+    let git_repo = git2::Repository::init(&workspace_root).unwrap();
+    test_env.jj_cmd_ok(&workspace_root, &["git", "init", "--git-repo", "."]);
+
+    // The trailer is added to commits with a description
+    std::fs::write(workspace_root.join("file"), "contents").unwrap();
+    test_env.jj_cmd_ok(&workspace_root, &["commit", "-m", "add a file"]);
+    test_env.jj_cmd_ok(&workspace_root, &["bookmark", "create", "master", "-r@-"]);
+    let stdout = test_env.jj_cmd_success(
+        &workspace_root,
+        &["log", "-r@-", "-T", "description", "--no-graph"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    add a file
+
+    Change-Id: qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu
+    ");
+
+    // Commits rewritten by git keep their change id, and show up as divergent
+    let commit1 = git_repo
+        .find_branch("master", git2::BranchType::Local)
+        .unwrap()
+        .get()
+        .peel_to_commit()
+        .unwrap();
+    let message = commit1
+        .message()
+        .unwrap()
+        .replace("add a file", "add a file (amended)");
+    let amended_oid = commit1
+        .amend(None, None, None, None, Some(&message), None)
+        .unwrap();
+    git_repo
+        .branch(
+            "amended",
+            &git_repo.find_commit(amended_oid).unwrap(),
+            false,
+        )
+        .unwrap();
+
+    // Commits with a trailer from elsewhere reuse its change id
+    let signature = commit1.author();
+    git_repo
+        .commit(
+            Some("refs/heads/other"),
+            &signature,
+            &signature,
+            "from elsewhere\n\nChange-Id: kkkkkkkkkkkkkkkkkkkkkkkkkkkkkkkk\n",
+            &commit1.tree().unwrap(),
+            &[&commit1],
+        )
+        .unwrap();
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_root, &["git", "import"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Done importing changes from the underlying Git repo.
+    Nothing changed.
+    ");
+    insta::assert_snapshot!(get_log_output_divergence(&test_env, &workspace_root), @r"
+    @  rlvkpnrzqnoo b1117bf1567a
+    │ ○  kkkkkkkkkkkk 2bc3fc56344f from elsewhere other
+    ├─╯
+    ○  qpvuntsmwlqt 560bea66a3ad add a file master git_head() !divergence!
+    │ ○  qpvuntsmwlqt c2ef568ba295 add a file (amended) amended !divergence!
+    ├─╯
+    ◆  zzzzzzzzzzzz 000000000000
+    ");
+}
+
 #[test]
 fn test_git_colocated_bookmarks() {
     let test_env = TestEnvironment::default();
@@ -757,11 +830,11 @@ fn test_git_colocated_undo_head_move() {
     // HEAD should be moved back
     let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["undo"]);
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @r#"
-    Undid operation: b50ec983d1c1 (2001-02-03 08:05:13) new empty commit
+    insta::assert_snapshot!(stderr, @r"
+    Undid operation: 9a4e89cc80a3 (2001-02-03 08:05:13) new empty commit
     Working copy now at: royxmykx eb08b363 (empty) (no description set)
     Parent commit      : qpvuntsm 230dd059 (empty) (no description set)
-    "#);
+    ");
     insta::assert_snapshot!(
         git_repo.head().unwrap().target().unwrap().to_string(),
         @"230dd059e1b059aefc0da06a2e5a7dbf22362f22");
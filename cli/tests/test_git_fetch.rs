@@ -207,6 +207,72 @@ fn test_git_fetch_all_remotes() {
     "###);
 }
 
+#[test]
+fn test_git_fetch_all_remotes_with_failure() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config("git.auto-local-bookmark = true");
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    add_git_remote(&test_env, &repo_path, "rem1");
+    add_git_remote(&test_env, &repo_path, "rem2");
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["git", "remote", "add", "bad", "../nonexistent"],
+    );
+
+    // The failing remote is reported, but the other remotes are still fetched
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["git", "fetch", "--all-remotes"]);
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Failed to fetch from remote bad: unsupported URL protocol; class=Net (12)
+    bookmark: rem1@rem1 [new] tracked
+    Fetched from rem1: 1 new, 0 updated, 0 deleted bookmarks
+    bookmark: rem2@rem2 [new] tracked
+    Fetched from rem2: 1 new, 0 updated, 0 deleted bookmarks
+    Error: Failed to fetch from 1 remote(s): bad
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&test_env, &repo_path), @r"
+    rem1: qxosxrvv 6a211027 message
+      @rem1: qxosxrvv 6a211027 message
+    rem2: yszkquru 2497a8a0 message
+      @rem2: yszkquru 2497a8a0 message
+    ");
+
+    // Updated and deleted bookmarks are summarized per remote
+    let rem1_repo = git2::Repository::open(test_env.env_root().join("rem1")).unwrap();
+    rem1_repo
+        .find_reference("refs/heads/rem1")
+        .unwrap()
+        .delete()
+        .unwrap();
+    let rem2_repo = git2::Repository::open(test_env.env_root().join("rem2")).unwrap();
+    let rem2_head = rem2_repo
+        .find_reference("refs/heads/rem2")
+        .unwrap()
+        .peel_to_commit()
+        .unwrap();
+    let signature =
+        git2::Signature::new("Some One", "some.one@example.com", &git2::Time::new(0, 0)).unwrap();
+    rem2_repo
+        .commit(
+            Some("refs/heads/rem2"),
+            &signature,
+            &signature,
+            "updated",
+            &rem2_head.tree().unwrap(),
+            &[&rem2_head],
+        )
+        .unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["git", "remote", "remove", "bad"]);
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["git", "fetch", "--all-remotes"]);
+    insta::assert_snapshot!(stderr, @r"
+    bookmark: rem1@rem1 [deleted] untracked
+    Abandoned 1 commits that are no longer reachable.
+    Fetched from rem1: 0 new, 0 updated, 1 deleted bookmarks
+    bookmark: rem2@rem2 [updated] tracked
+    Fetched from rem2: 0 new, 1 updated, 0 deleted bookmarks
+    ");
+}
+
 #[test]
 fn test_git_fetch_multiple_remotes_from_config() {
     let test_env = TestEnvironment::default();
@@ -1015,9 +1081,10 @@ fn test_fetch_undo_what() {
         &["op", "restore", "--what", "repo", &base_operation_id],
     );
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @r#"
+    insta::assert_snapshot!(stderr, @r"
     Restored to operation: eac759b9ab75 (2001-02-03 08:05:07) add workspace 'default'
-    "#);
+    Restored only: commits, bookmarks, working-copy
+    ");
     insta::assert_snapshot!(get_bookmark_output(&test_env, &repo_path), @r###"
     b (deleted)
       @origin: vpupmnsl hidden c7d4bdcb descr_for_b
@@ -1044,9 +1111,10 @@ fn test_fetch_undo_what() {
         ],
     );
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @r#"
+    insta::assert_snapshot!(stderr, @r"
     Restored to operation: eac759b9ab75 (2001-02-03 08:05:07) add workspace 'default'
-    "#);
+    Restored only: remote-tracking
+    ");
     insta::assert_snapshot!(get_bookmark_output(&test_env, &repo_path), @r###"
     newbookmark: qpvuntsm 230dd059 (empty) (no description set)
     "###);
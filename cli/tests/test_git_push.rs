@@ -312,6 +312,96 @@ fn test_git_push_sideways_unexpectedly_moved() {
     "#);
 }
 
+#[test]
+fn test_git_push_rebase_on_reject() {
+    let (test_env, workspace_root) = set_up();
+
+    // Move bookmark1 forward on the remote
+    let origin_path = test_env.env_root().join("origin");
+    test_env.jj_cmd_ok(&origin_path, &["new", "bookmark1", "-m=remote"]);
+    std::fs::write(origin_path.join("remote"), "remote").unwrap();
+    test_env.jj_cmd_ok(&origin_path, &["bookmark", "set", "bookmark1"]);
+    test_env.jj_cmd_ok(&origin_path, &["git", "export"]);
+
+    // Move bookmark1 forward to another commit locally
+    test_env.jj_cmd_ok(&workspace_root, &["new", "bookmark1", "-m=local"]);
+    std::fs::write(workspace_root.join("local"), "local").unwrap();
+    test_env.jj_cmd_ok(&workspace_root, &["bookmark", "set", "bookmark1"]);
+
+    // The local commit is rebased onto the remote bookmark and pushed
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&workspace_root, &["git", "push", "--rebase-on-reject"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Move forward bookmark bookmark1 from d13ecdbda2a2 to 6750425ff51c
+    The remote rejected the push because some bookmarks moved; rebasing onto them.
+    bookmark: bookmark1@origin [updated] tracked
+    Working copy now at: kpqxywon 4b4ed15f bookmark1* | local
+    Parent commit      : rxqvuqun 80284bec bookmark1@origin | remote
+    Added 1 files, modified 0 files, removed 0 files
+    Changes to push to origin:
+      Move forward bookmark bookmark1 from 80284bec6fd9 to 4b4ed15ffa0d
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&test_env, &workspace_root), @r"
+    bookmark1: kpqxywon 4b4ed15f local
+      @origin: kpqxywon 4b4ed15f local
+    bookmark2: rlzusymt 8476341e (empty) description 2
+      @origin: rlzusymt 8476341e (empty) description 2
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &workspace_root,
+        &[
+            "log",
+            "-r",
+            "::bookmark1",
+            "--no-graph",
+            "-T",
+            "description.first_line() ++ \"\\n\"",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    local
+    remote
+    description 1
+    ");
+}
+
+#[test]
+fn test_git_push_rebase_on_reject_conflict() {
+    let (test_env, workspace_root) = set_up();
+
+    // Move bookmark1 forward on the remote
+    let origin_path = test_env.env_root().join("origin");
+    test_env.jj_cmd_ok(&origin_path, &["new", "bookmark1", "-m=remote"]);
+    std::fs::write(origin_path.join("file"), "remote").unwrap();
+    test_env.jj_cmd_ok(&origin_path, &["bookmark", "set", "bookmark1"]);
+    test_env.jj_cmd_ok(&origin_path, &["git", "export"]);
+
+    // Modify the same file locally
+    test_env.jj_cmd_ok(&workspace_root, &["new", "bookmark1", "-m=local"]);
+    std::fs::write(workspace_root.join("file"), "local").unwrap();
+    test_env.jj_cmd_ok(&workspace_root, &["bookmark", "set", "bookmark1"]);
+
+    // The rebase is aborted, and nothing is pushed
+    let stderr =
+        test_env.jj_cmd_conflict_error(&workspace_root, &["git", "push", "--rebase-on-reject"]);
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Move forward bookmark bookmark1 from d13ecdbda2a2 to 0d7eacee91c2
+    The remote rejected the push because some bookmarks moved; rebasing onto them.
+    bookmark: bookmark1@origin [updated] tracked
+    Error: Rebasing bookmark bookmark1 onto bookmark1@origin resulted in conflicts in commit 888c52fa1b79, not pushing
+    Hint: Rebase bookmark bookmark1 onto bookmark1@origin and resolve the conflicts.
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&test_env, &workspace_root), @r"
+    bookmark1: kpqxywon 0d7eacee local
+      @origin (ahead by 1 commits, behind by 1 commits): nqvqymuz 7501402c remote
+    bookmark2: rlzusymt 8476341e (empty) description 2
+      @origin: rlzusymt 8476341e (empty) description 2
+    ");
+}
+
 // This tests whether the push checks that the remote bookmarks are in expected
 // positions.
 #[test]
@@ -1188,6 +1278,127 @@ fn test_git_push_deleted() {
     "###);
 }
 
+#[test]
+fn test_git_push_tags() {
+    let (test_env, workspace_root) = set_up();
+    let origin_git_repo_path = test_env
+        .env_root()
+        .join("origin")
+        .join(".jj")
+        .join("repo")
+        .join("store")
+        .join("git");
+    test_env.jj_cmd_ok(
+        &workspace_root,
+        &["tag", "create", "v1", "-r=bookmark1", "-m=release 1"],
+    );
+    test_env.jj_cmd_ok(&workspace_root, &["tag", "create", "light", "-r=bookmark2"]);
+
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&workspace_root, &["git", "push", "--tags", "--dry-run"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Add tag light to 8476341eb395
+      Add tag v1 to d13ecdbda2a2
+    Dry-run requested, not pushing.
+    ");
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_root, &["git", "push", "--tags"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Add tag light to 8476341eb395
+      Add tag v1 to d13ecdbda2a2
+    ");
+    let origin_git_repo = git2::Repository::open(&origin_git_repo_path).unwrap();
+    let tag = origin_git_repo
+        .find_reference("refs/tags/v1")
+        .unwrap()
+        .peel_to_tag()
+        .unwrap();
+    assert_eq!(tag.message(), Some("release 1\n"));
+    assert!(origin_git_repo.find_reference("refs/tags/light").is_ok());
+
+    // Pushing the same tags again is a no-op
+    test_env.jj_cmd_ok(&workspace_root, &["git", "push", "--tags"]);
+
+    // Tags can't be moved on the remote
+    test_env.jj_cmd_ok(&workspace_root, &["tag", "delete", "v1"]);
+    test_env.jj_cmd_ok(&workspace_root, &["tag", "create", "v1", "-r=bookmark2"]);
+    let stderr = test_env.jj_cmd_failure(&workspace_root, &["git", "push", "--tags"]);
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Add tag light to 8476341eb395
+      Add tag v1 to 8476341eb395
+    Error: The remote rejected some tags: v1
+    Hint: Tags can't be moved on the remote. Delete the tag on the remote, or create a tag with a different name.
+    ");
+}
+
+#[test]
+fn test_git_push_follow_tags() {
+    let (test_env, workspace_root) = set_up();
+    let origin_git_repo_path = test_env
+        .env_root()
+        .join("origin")
+        .join(".jj")
+        .join("repo")
+        .join("store")
+        .join("git");
+    test_env.jj_cmd_ok(
+        &workspace_root,
+        &["tag", "create", "v1", "-r=bookmark1", "-m=release 1"],
+    );
+    test_env.jj_cmd_ok(&workspace_root, &["tag", "create", "light", "-r=bookmark1"]);
+    // Not reachable from the pushed bookmark
+    test_env.jj_cmd_ok(
+        &workspace_root,
+        &["tag", "create", "other", "-r=bookmark2", "-m=other"],
+    );
+    test_env.jj_cmd_ok(&workspace_root, &["new", "bookmark1", "-m=bump"]);
+    test_env.jj_cmd_ok(&workspace_root, &["bookmark", "set", "bookmark1"]);
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "push", "-b=bookmark1", "--follow-tags", "--dry-run"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Move forward bookmark bookmark1 from d13ecdbda2a2 to 33ad2113c620
+    Tags to push to origin:
+      Add tag v1 to d13ecdbda2a2
+    Dry-run requested, not pushing.
+    ");
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "push", "-b=bookmark1", "--follow-tags"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Move forward bookmark bookmark1 from d13ecdbda2a2 to 33ad2113c620
+    Tags to push to origin:
+      Add tag v1 to d13ecdbda2a2
+    ");
+    let origin_git_repo = git2::Repository::open(&origin_git_repo_path).unwrap();
+    assert!(origin_git_repo.find_reference("refs/tags/v1").is_ok());
+    assert!(origin_git_repo.find_reference("refs/tags/light").is_err());
+    assert!(origin_git_repo.find_reference("refs/tags/other").is_err());
+
+    // Tags which the remote already has aren't pushed again
+    test_env.jj_cmd_ok(&workspace_root, &["new", "bookmark1", "-m=bump again"]);
+    test_env.jj_cmd_ok(&workspace_root, &["bookmark", "set", "bookmark1"]);
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "push", "-b=bookmark1", "--follow-tags"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Move forward bookmark bookmark1 from 33ad2113c620 to fec6c55e98f6
+    ");
+}
 #[test]
 fn test_git_push_conflicting_bookmarks() {
     let (test_env, workspace_root) = set_up();
@@ -1562,3 +1773,137 @@ fn get_bookmark_output(test_env: &TestEnvironment, repo_path: &Path) -> String {
     // --quiet to suppress deleted bookmarks hint
     test_env.jj_cmd_success(repo_path, &["bookmark", "list", "--all-remotes", "--quiet"])
 }
+
+#[test]
+fn test_git_push_op_log_since_push() {
+    let (test_env, workspace_root) = set_up();
+    test_env.jj_cmd_ok(&workspace_root, &["new", "bookmark1", "-m", "foo"]);
+    test_env.jj_cmd_ok(&workspace_root, &["bookmark", "set", "bookmark1", "-r@"]);
+    test_env.jj_cmd_ok(&workspace_root, &["git", "push", "-b", "bookmark1"]);
+    test_env.jj_cmd_ok(&workspace_root, &["describe", "-m", "bar"]);
+    test_env.jj_cmd_ok(&workspace_root, &["new"]);
+
+    let template = r#"description ++ "\n""#;
+    let stdout = test_env.jj_cmd_success(
+        &workspace_root,
+        &["op", "log", "-T", template, "--since-push", "bookmark1"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  new empty commit
+    ○  describe commit 0c142ff3a34fffc3760cb5ed44af21d6a1530bc2
+    │
+    ~
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &workspace_root,
+        &[
+            "op",
+            "log",
+            "-T",
+            template,
+            "--since-push=bookmark1",
+            "--no-graph",
+            "--reversed",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    describe commit 0c142ff3a34fffc3760cb5ed44af21d6a1530bc2
+    new empty commit
+    ");
+
+    // The bookmark was never pushed
+    let stderr = test_env.jj_cmd_failure(&workspace_root, &["op", "log", "--since-push=bookmark2"]);
+    insta::assert_snapshot!(stderr, @r#"Error: No push operation found for bookmark "bookmark2""#);
+}
+
+#[test]
+fn test_git_push_named() {
+    let (test_env, workspace_root) = set_up();
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "push", "--named=ci/build=bookmark2", "--dry-run"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Add bookmark ci/build to 8476341eb395
+    Dry-run requested, not pushing.
+    ");
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "push", "--named=ci/build=bookmark2"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Add bookmark ci/build to 8476341eb395
+    ");
+    // No local bookmark is created, and the remote bookmark isn't tracked
+    insta::assert_snapshot!(get_bookmark_output(&test_env, &workspace_root), @r"
+    bookmark1: xtvrqkyv d13ecdbd (empty) description 1
+      @origin: xtvrqkyv d13ecdbd (empty) description 1
+    bookmark2: rlzusymt 8476341e (empty) description 2
+      @origin: rlzusymt 8476341e (empty) description 2
+    ci/build@origin: rlzusymt 8476341e (empty) description 2
+    ");
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "push", "--named=ci/build=bookmark2"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Bookmark ci/build@origin already matches bookmark2
+    Nothing changed.
+    ");
+
+    // Move it
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "push", "--named=ci/build=bookmark1"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Move sideways bookmark ci/build from 8476341eb395 to d13ecdbda2a2
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&workspace_root, &["git", "push", "--named=foo..bar=@"]);
+    insta::assert_snapshot!(stderr, @r#"
+    error: invalid value 'foo..bar=@' for '--named <NAME=REVSET>': invalid bookmark name: "foo..bar"
+
+    For more information, try '--help'.
+    "#);
+    let stderr = test_env.jj_cmd_failure(
+        &workspace_root,
+        &["git", "push", "--named=foo=bookmark1|bookmark2"],
+    );
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Revset "bookmark1|bookmark2" resolved to more than one revision
+    Hint: The revset "bookmark1|bookmark2" resolved to these revisions:
+      rlzusymt 8476341e bookmark2 | (empty) description 2
+      xtvrqkyv d13ecdbd bookmark1 ci/build@origin | (empty) description 1
+    "#);
+    let stderr = test_env.jj_cmd_failure(&workspace_root, &["git", "push", "--named=bookmark1=@-"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: Bookmark bookmark1 already exists locally
+    Hint: Use `jj git push --bookmark bookmark1` to push it.
+    ");
+
+    // Delete it
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&workspace_root, &["git", "push", "--named=ci/build="]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Changes to push to origin:
+      Delete bookmark ci/build from d13ecdbda2a2
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&test_env, &workspace_root), @r"
+    bookmark1: xtvrqkyv d13ecdbd (empty) description 1
+      @origin: xtvrqkyv d13ecdbd (empty) description 1
+    bookmark2: rlzusymt 8476341e (empty) description 2
+      @origin: rlzusymt 8476341e (empty) description 2
+    ");
+}
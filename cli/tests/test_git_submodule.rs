@@ -63,3 +63,127 @@ fn test_gitsubmodule_print_gitmodules() {
 	path:new
     "###);
 }
+
+/// Creates a Git repository at `repo/` whose `main` branch records the
+/// submodules `sub` (cloneable from `sub/`) and `missing` (whose remote doesn't
+/// exist), and initializes a colocated jj workspace in it.
+fn init_repo_with_submodules(test_env: &TestEnvironment) -> std::path::PathBuf {
+    let signature =
+        git2::Signature::new("Some One", "some.one@example.com", &git2::Time::new(0, 0)).unwrap();
+
+    let sub_repo = git2::Repository::init(test_env.env_root().join("sub")).unwrap();
+    let blob_id = sub_repo.blob(b"content\n").unwrap();
+    let mut tree_builder = sub_repo.treebuilder(None).unwrap();
+    tree_builder.insert("file", blob_id, 0o100644).unwrap();
+    let tree = sub_repo.find_tree(tree_builder.write().unwrap()).unwrap();
+    let sub_commit_id = sub_repo
+        .commit(Some("HEAD"), &signature, &signature, "sub", &tree, &[])
+        .unwrap();
+
+    let workspace_root = test_env.env_root().join("repo");
+    let git_repo = git2::Repository::init(&workspace_root).unwrap();
+    let gitmodules = "[submodule \"sub\"]
+	path = sub
+	url = ../sub
+[submodule \"missing\"]
+	path = missing
+	url = ../missing
+";
+    std::fs::write(workspace_root.join(".gitmodules"), gitmodules).unwrap();
+    let gitmodules_id = git_repo.blob(gitmodules.as_bytes()).unwrap();
+    let mut tree_builder = git_repo.treebuilder(None).unwrap();
+    tree_builder
+        .insert(".gitmodules", gitmodules_id, 0o100644)
+        .unwrap();
+    tree_builder.insert("sub", sub_commit_id, 0o160000).unwrap();
+    let missing_commit_id =
+        git2::Oid::from_str("1234567890123456789012345678901234567890").unwrap();
+    tree_builder
+        .insert("missing", missing_commit_id, 0o160000)
+        .unwrap();
+    let tree = git_repo.find_tree(tree_builder.write().unwrap()).unwrap();
+    git_repo
+        .commit(
+            Some("refs/heads/main"),
+            &signature,
+            &signature,
+            "add submodules",
+            &tree,
+            &[],
+        )
+        .unwrap();
+    git_repo.set_head("refs/heads/main").unwrap();
+    test_env.jj_cmd_ok(&workspace_root, &["git", "init", "--git-repo", "."]);
+    workspace_root
+}
+
+#[test]
+fn test_git_submodule_list_and_update() {
+    let test_env = TestEnvironment::default();
+    let workspace_root = init_repo_with_submodules(&test_env);
+
+    let stdout = test_env.jj_cmd_success(&workspace_root, &["git", "submodule", "list"]);
+    insta::assert_snapshot!(stdout, @r"
+    missing 123456789012 ../missing (not checked out)
+    sub cb28ead0e0ac ../sub (not checked out)
+    ");
+
+    // The submodule whose remote is missing is reported, but doesn't prevent
+    // the other submodule from being checked out.
+    let stderr = test_env.jj_cmd_failure(&workspace_root, &["git", "submodule", "update"]);
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Failed to update submodule missing: could not fetch from $TEST_ENV/missing: unsupported URL protocol
+    Checked out submodule sub at cb28ead0e0ac
+    Error: Failed to update 1 of 2 submodules
+    ");
+
+    let stdout = test_env.jj_cmd_success(&workspace_root, &["git", "submodule", "list"]);
+    insta::assert_snapshot!(stdout, @r"
+    missing 123456789012 ../missing (no commit checked out)
+    sub cb28ead0e0ac ../sub
+    ");
+    assert_eq!(
+        std::fs::read_to_string(workspace_root.join("sub").join("file")).unwrap(),
+        "content\n"
+    );
+
+    // The checked out submodule doesn't show up as a change in the superproject
+    let stdout = test_env.jj_cmd_success(&workspace_root, &["diff", "--summary"]);
+    insta::assert_snapshot!(stdout, @"");
+}
+
+#[test]
+fn test_git_submodule_update_configured_remote() {
+    let test_env = TestEnvironment::default();
+    let workspace_root = init_repo_with_submodules(&test_env);
+    test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "remote", "add", "origin", "../origin/repo"],
+    );
+    test_env.jj_cmd_ok(
+        &workspace_root,
+        &["git", "remote", "add", "upstream", "../upstream/repo"],
+    );
+
+    // Relative URLs are resolved against the configured fetch remote
+    let stderr = test_env.jj_cmd_failure(
+        &workspace_root,
+        &["git", "submodule", "update", "--config=git.fetch=upstream"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Failed to update submodule missing: could not fetch from $TEST_ENV/upstream/missing: unsupported URL protocol
+    Warning: Failed to update submodule sub: could not fetch from $TEST_ENV/upstream/sub: unsupported URL protocol
+    Error: Failed to update 2 of 2 submodules
+    ");
+}
+
+#[test]
+fn test_git_submodule_list_no_submodules() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let workspace_root = test_env.env_root().join("repo");
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(&workspace_root, &["git", "submodule", "list"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"No submodules.");
+}
@@ -153,6 +153,49 @@ fn test_ignore_working_copy() {
     "###);
 }
 
+#[test]
+fn test_no_snapshot() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file"), "initial").unwrap();
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", "commit_id"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  b15ef4cdd277d2c63cce6d67c1916f53a36141f7
+    ◆  0000000000000000000000000000000000000000
+    ");
+
+    // Modify the file. With --no-snapshot, we still get the same commit ID.
+    std::fs::write(repo_path.join("file"), "modified").unwrap();
+    let stdout_again =
+        test_env.jj_cmd_success(&repo_path, &["log", "-T", "commit_id", "--no-snapshot"]);
+    assert_eq!(stdout_again, stdout);
+
+    // Unlike --ignore-working-copy, the working copy can still be updated
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["new", "--no-snapshot"]);
+    insta::assert_snapshot!(stderr, @r"
+    Working copy now at: zsuskuln 2eb84ff9 (empty) (no description set)
+    Parent commit      : qpvuntsm b15ef4cd (no description set)
+    ");
+
+    // The modification is picked up by the next snapshot
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "--summary"]);
+    insta::assert_snapshot!(stdout, @"M file");
+
+    let stderr = test_env.jj_cmd_cli_error(
+        &repo_path,
+        &["log", "--no-snapshot", "--ignore-working-copy"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--no-snapshot' cannot be used with '--ignore-working-copy'
+
+    Usage: jj log --no-snapshot [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_repo_arg_with_init() {
     let test_env = TestEnvironment::default();
@@ -372,6 +415,43 @@ fn test_broken_repo_structure() {
     "###);
 }
 
+#[test]
+fn test_exit_codes() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+
+    // Bad revsets and revsets that resolve to nothing are user errors
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["log", "-r", "foo("]);
+    insta::assert_snapshot!(stderr.lines().next().unwrap(), @"Error: Failed to parse revset: Syntax error");
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["edit", "none()"]);
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Revset "none()" didn't resolve to any revisions
+    "#);
+
+    // Invalid command line
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["log", "--no-such-flag"]);
+    insta::assert_snapshot!(stderr.lines().next().unwrap(), @"error: unexpected argument '--no-such-flag' found");
+
+    // Requested config value doesn't exist
+    test_env
+        .jj_cmd(&repo_path, &["config", "get", "no-such-key"])
+        .assert()
+        .code(4);
+
+    // Failed to read the repository
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["op", "log", "--no-graph", "-T", r#"id ++ "\n""#],
+    );
+    let parent_op_id = stdout.lines().nth(1).unwrap();
+    let op_store_path = repo_path.join(".jj").join("repo").join("op_store");
+    std::fs::remove_file(op_store_path.join("operations").join(parent_op_id)).unwrap();
+    let stderr = test_env.jj_cmd_storage_error(&repo_path, &["op", "show", "@-"]);
+    insta::assert_snapshot!(stderr.lines().next().unwrap(), @"Error: Failed to load an operation");
+}
+
 #[test]
 fn test_color_config() {
     let mut test_env = TestEnvironment::default();
@@ -472,6 +552,39 @@ fn test_color_config() {
     ");
 }
 
+#[test]
+fn test_color_op_log() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let op_log = |color: &str| {
+        test_env.jj_cmd_success(
+            &repo_path,
+            &[color, "op", "log", "-n1", "--op-diff", "-T", "id.short()"],
+        )
+    };
+
+    // The output isn't a terminal, so it's colorized only if requested
+    insta::assert_snapshot!(op_log("--color=always"), @r"
+    [1m[38;5;2m@[0m  [38;5;4meac759b9ab75[39m
+    │
+    │  Changed commits:
+    │  ○  [38;5;2m+[39m [1m[38;5;5mq[0m[38;5;8mpvuntsm[39m [1m[38;5;4m2[0m[38;5;8m30dd059[39m [38;5;2m(empty)[39m [38;5;2m(no description set)[39m
+    ");
+    insta::assert_snapshot!(op_log("--color=never"), @r"
+    @  eac759b9ab75
+    │
+    │  Changed commits:
+    │  ○  + qpvuntsm 230dd059 (empty) (no description set)
+    ");
+    insta::assert_snapshot!(op_log("--color=auto"), @r"
+    @  eac759b9ab75
+    │
+    │  Changed commits:
+    │  ○  + qpvuntsm 230dd059 (empty) (no description set)
+    ");
+}
+
 #[test]
 fn test_color_ui_messages() {
     let test_env = TestEnvironment::default();
@@ -557,6 +670,22 @@ fn test_quiet() {
     insta::assert_snapshot!(stderr, @"");
 }
 
+#[test]
+fn test_output_format_unsupported() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["--output-format=json", "log"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: `jj log` does not support --output-format=json
+    Hint: JSON output is supported by: `jj bookmark list`, `jj operation log`, `jj status`
+    ");
+
+    // The text format is accepted by all commands
+    test_env.jj_cmd_ok(&repo_path, &["--output-format=text", "log"]);
+}
+
 #[test]
 fn test_early_args() {
     // Test that help output parses early args
@@ -591,6 +720,25 @@ fn test_early_args() {
     test_env.jj_cmd_success(test_env.env_root(), &["--config=ui.color=always", "help"]);
 }
 
+#[test]
+fn test_no_pager() {
+    let test_env = TestEnvironment::default();
+    test_env.add_config(r#"ui.paginate = "auto""#);
+
+    // --no-pager overrides the config, including --config arguments
+    let stdout = test_env.jj_cmd_success(
+        test_env.env_root(),
+        &[
+            "--no-pager",
+            "--config=ui.paginate=auto",
+            "config",
+            "get",
+            "ui.paginate",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"never");
+}
+
 #[test]
 fn test_config_args() {
     let test_env = TestEnvironment::default();
@@ -935,7 +1083,7 @@ fn test_help() {
     let test_env = TestEnvironment::default();
 
     let stdout = test_env.jj_cmd_success(test_env.env_root(), &["diffedit", "-h"]);
-    insta::assert_snapshot!(stdout, @r###"
+    insta::assert_snapshot!(stdout, @r"
     Touch up the content changes in a revision with a diff editor
 
     Usage: jj diffedit [OPTIONS]
@@ -945,21 +1093,26 @@ fn test_help() {
       -f, --from <REVSET>        Show changes from this revision
       -t, --to <REVSET>          Edit changes in this revision
           --tool <NAME>          Specify diff editor to be used
+          --apply <PATH>         Apply a patch instead of starting a diff editor
           --restore-descendants  Preserve the content (not the diff) when rebasing descendants
       -h, --help                 Print help (see more with '--help')
 
     Global Options:
       -R, --repository <REPOSITORY>      Path to repository to operate on
           --ignore-working-copy          Don't snapshot the working copy, and don't update it
+          --no-snapshot                  Don't snapshot the working copy, but still update it
           --ignore-immutable             Allow rewriting immutable commits
           --at-operation <AT_OPERATION>  Operation to load the repo at [aliases: at-op]
+          --output-format <FORMAT>       Format of the command's primary output [default: text]
+                                         [possible values: text, json]
+          --op-meta <KEY=VALUE>          Attach custom metadata to the operation (can be repeated)
           --debug                        Enable debug logging
           --color <WHEN>                 When to colorize output (always, never, debug, auto)
           --quiet                        Silence non-primary command output
           --no-pager                     Disable the pager
           --config <NAME=VALUE>          Additional configuration options (can be repeated)
           --config-file <PATH>           Additional configuration files (can be repeated)
-    "###);
+    ");
 }
 
 #[test]
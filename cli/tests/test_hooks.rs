@@ -0,0 +1,128 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::TestEnvironment;
+
+#[test]
+fn test_hooks_failing_pre_operation() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let op_id_before = test_env.current_operation_id(&repo_path);
+
+    test_env.add_config(r#"hooks.pre-operation = ["sh", "-c", "echo rejected; exit 3"]"#);
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["describe", "-m", "new"]);
+    insta::assert_snapshot!(stderr, @r"
+    rejected
+    Error: The pre-operation hook exited with 3
+    Hint: The operation was not recorded. The hook is configured by `hooks.pre-operation`.
+    ");
+
+    // The operation wasn't recorded
+    assert_eq!(test_env.current_operation_id(&repo_path), op_id_before);
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-r@",
+            "--no-graph",
+            "-T",
+            "description",
+            "--ignore-working-copy",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"");
+}
+
+#[test]
+fn test_hooks_failing_pre_operation_colocated() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "--colocate", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.add_config(r#"hooks.pre-operation = ["false"]"#);
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["bookmark", "create", "foo"]);
+    insta::assert_snapshot!(stderr, @r"
+    Created 1 bookmarks pointing to qpvuntsm 230dd059 foo | (empty) (no description set)
+    Error: The pre-operation hook exited with 1
+    Hint: The operation was not recorded. The hook is configured by `hooks.pre-operation`.
+    ");
+
+    // Nothing was exported to the Git repo
+    let git_repo = git2::Repository::open(&repo_path).unwrap();
+    assert!(git_repo.find_reference("refs/heads/foo").is_err());
+}
+
+#[test]
+fn test_hooks_environment() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    let script = "echo $0: $JJ_OPERATION_DESCRIPTION $JJ_WORKSPACE $JJ_CHANGE \
+                  ${JJ_OLD_OPERATION:0:12} ${JJ_OPERATION:0:12}";
+    test_env.add_config(format!(
+        r#"
+        hooks.pre-operation = ["bash", "-c", "{script}", "pre"]
+        hooks.post-operation = ["bash", "-c", "{script}", "post"]
+        "#
+    ));
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "new"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    pre: describe commit 230dd059e1b059aefc0da06a2e5a7dbf22362f22 default qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu eac759b9ab75
+    Working copy now at: qpvuntsm 3a4f035e (empty) new
+    Parent commit      : zzzzzzzz 00000000 (empty) (no description set)
+    post: describe commit 230dd059e1b059aefc0da06a2e5a7dbf22362f22 default qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu eac759b9ab75 53cdabf25585
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", "-n2", "-T", "id.short()"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  53cdabf25585
+    ○  eac759b9ab75
+    ");
+
+    // Hooks aren't run if nothing changed
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "new"]);
+    insta::assert_snapshot!(stderr, @"Nothing changed.");
+}
+
+#[test]
+fn test_hooks_failing_post_operation() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.add_config(r#"hooks.post-operation = ["false"]"#);
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["describe", "-m", "new"]);
+    insta::assert_snapshot!(stderr, @r"
+    Working copy now at: qpvuntsm 3a4f035e (empty) new
+    Parent commit      : zzzzzzzz 00000000 (empty) (no description set)
+    Error: The post-operation hook exited with 1
+    Hint: The operation was recorded anyway. The hook is configured by `hooks.post-operation`.
+    ");
+
+    // The operation was recorded anyway
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-r@",
+            "--no-graph",
+            "-T",
+            "description",
+            "--ignore-working-copy",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"new");
+}
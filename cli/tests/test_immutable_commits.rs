@@ -47,11 +47,12 @@ fn test_rewrite_immutable_generic() {
     "#);
     // Cannot rewrite an ancestor of the configured set
     let stderr = test_env.jj_cmd_failure(&repo_path, &["edit", "main-"]);
-    insta::assert_snapshot!(stderr, @r#"
+    insta::assert_snapshot!(stderr, @r"
     Error: Commit b84b821b8a2b is immutable
     Hint: Could not modify commit: qpvuntsm b84b821b a
+    Hint: It is an ancestor of immutable head: kkmpptxz 72e1b68c main | b
     Hint: Pass `--ignore-immutable` or configure the set of immutable commits via `revset-aliases.immutable_heads()`.
-    "#);
+    ");
     // Cannot rewrite the root commit even with an empty set of immutable commits
     test_env.add_config(r#"revset-aliases."immutable_heads()" = "none()""#);
     let stderr = test_env.jj_cmd_failure(&repo_path, &["edit", "root()"]);
@@ -76,11 +77,12 @@ fn test_rewrite_immutable_generic() {
     let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["--ignore-immutable", "edit", "main"]);
     insta::assert_snapshot!(stdout, @r###"
     "###);
-    insta::assert_snapshot!(stderr, @r###"
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Rewriting immutable commits because `--ignore-immutable` was passed
     Working copy now at: kkmpptxz 72e1b68c main | b
     Parent commit      : qpvuntsm b84b821b a
     Added 0 files, modified 1 files, removed 0 files
-    "###);
+    ");
     // ... but not the root commit
     let stderr = test_env.jj_cmd_failure(&repo_path, &["--ignore-immutable", "edit", "root()"]);
     insta::assert_snapshot!(stderr, @r###"
@@ -107,6 +109,79 @@ fn test_rewrite_immutable_generic() {
     "###);
 }
 
+#[test]
+fn test_rewrite_immutable_reports_many_commits() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    for i in 1..=12 {
+        test_env.jj_cmd_ok(&repo_path, &["new", &format!("-m={i}")]);
+    }
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "main"]);
+    test_env.add_config(r#"revset-aliases."immutable_heads()" = "main""#);
+
+    // Only the first commits are listed
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["describe", "-m=x", "::@- ~ root()"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: 12 commits are immutable
+    Hint: Could not modify commit: kmkuslsw 54b763ae (empty) 11
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: kpqxywon 988df5bf (empty) 10
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: znkkpsqq 649400e1 (empty) 9
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: yostqsxw ab844e64 (empty) 8
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: vruxwmqv ce0a69b9 (empty) 7
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: yqosqzyt e64e38e5 (empty) 6
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: royxmykx 95240153 (empty) 5
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: mzvwutvl 22f1fd8c (empty) 4
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: zsuskuln 66432a4e (empty) 3
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: Could not modify commit: kkmpptxz 88c9253c (empty) 2
+    Hint: It is an ancestor of immutable head: wqnwkozp 1c48ea4e main | (empty) 12
+    Hint: ...and 2 more immutable commits
+    Hint: Pass `--ignore-immutable` or configure the set of immutable commits via `revset-aliases.immutable_heads()`.
+    ");
+}
+
+#[test]
+fn test_ignore_immutable_warning() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    std::fs::write(repo_path.join("file"), "a").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m=b"]);
+    std::fs::write(repo_path.join("file"), "b").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "main"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m=c"]);
+    test_env.add_config(r#"revset-aliases."immutable_heads()" = "main""#);
+
+    // No warning if no immutable commits are rewritten
+    let (_stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["--ignore-immutable", "describe", "-m=c2"]);
+    insta::assert_snapshot!(stderr, @r"
+    Working copy now at: zsuskuln 6eb58375 (empty) c2
+    Parent commit      : rlvkpnrz e4957667 main | b
+    ");
+
+    // The warning is printed once even if the command checks several times
+    let (_stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["--ignore-immutable", "unsquash", "-r=main"]);
+    insta::assert_snapshot!(stderr, @r"
+    Warning: `jj unsquash` is deprecated; use `jj diffedit --restore-descendants` or `jj squash` instead
+    Warning: `jj unsquash` will be removed in a future version, and this will be a hard error
+    Warning: Rewriting immutable commits because `--ignore-immutable` was passed
+    Rebased 1 descendant commits
+    Working copy now at: zsuskuln a4ced75e (empty) c2
+    Parent commit      : rlvkpnrz ee92f941 main | b
+    ");
+}
+
 #[test]
 fn test_new_wc_commit_when_wc_immutable() {
     let test_env = TestEnvironment::default();
@@ -217,6 +292,7 @@ fn test_rewrite_immutable_commands() {
     insta::assert_snapshot!(stderr, @r"
     Error: Commit 72e1b68cbcf2 is immutable
     Hint: Could not modify commit: kkmpptxz 72e1b68c b
+    Hint: It is an ancestor of immutable head: mzvwutvl bcab555f main | (conflict) merge
     Hint: Pass `--ignore-immutable` or configure the set of immutable commits via `revset-aliases.immutable_heads()`.
     ");
     // chmod
@@ -263,11 +339,13 @@ fn test_rewrite_immutable_commands() {
     "###);
     // parallelize
     let stderr = test_env.jj_cmd_failure(&repo_path, &["parallelize", "description(b)", "main"]);
-    insta::assert_snapshot!(stderr, @r###"
-    Error: Commit bcab555fc80e is immutable
+    insta::assert_snapshot!(stderr, @r"
+    Error: 2 commits are immutable
     Hint: Could not modify commit: mzvwutvl bcab555f main | (conflict) merge
+    Hint: Could not modify commit: kkmpptxz 72e1b68c b
+    Hint: It is an ancestor of immutable head: mzvwutvl bcab555f main | (conflict) merge
     Hint: Pass `--ignore-immutable` or configure the set of immutable commits via `revset-aliases.immutable_heads()`.
-    "###);
+    ");
     // rebase -s
     let stderr = test_env.jj_cmd_failure(&repo_path, &["rebase", "-s=main", "-d=@"]);
     insta::assert_snapshot!(stderr, @r###"
@@ -277,11 +355,12 @@ fn test_rewrite_immutable_commands() {
     "###);
     // rebase -b
     let stderr = test_env.jj_cmd_failure(&repo_path, &["rebase", "-b=main", "-d=@"]);
-    insta::assert_snapshot!(stderr, @r#"
+    insta::assert_snapshot!(stderr, @r"
     Error: Commit 77cee210cbf5 is immutable
     Hint: Could not modify commit: zsuskuln 77cee210 c
+    Hint: It is an ancestor of immutable head: mzvwutvl bcab555f main | (conflict) merge
     Hint: Pass `--ignore-immutable` or configure the set of immutable commits via `revset-aliases.immutable_heads()`.
-    "#);
+    ");
     // rebase -r
     let stderr = test_env.jj_cmd_failure(&repo_path, &["rebase", "-r=main", "-d=@"]);
     insta::assert_snapshot!(stderr, @r###"
@@ -319,11 +398,14 @@ fn test_rewrite_immutable_commands() {
     "###);
     // squash -r
     let stderr = test_env.jj_cmd_failure(&repo_path, &["squash", "-r=description(b)"]);
-    insta::assert_snapshot!(stderr, @r#"
-    Error: Commit 72e1b68cbcf2 is immutable
+    insta::assert_snapshot!(stderr, @r"
+    Error: 2 commits are immutable
     Hint: Could not modify commit: kkmpptxz 72e1b68c b
+    Hint: It is an ancestor of immutable head: mzvwutvl bcab555f main | (conflict) merge
+    Hint: Could not modify commit: qpvuntsm b84b821b a
+    Hint: It is an ancestor of immutable head: mzvwutvl bcab555f main | (conflict) merge
     Hint: Pass `--ignore-immutable` or configure the set of immutable commits via `revset-aliases.immutable_heads()`.
-    "#);
+    ");
     // squash --from
     let stderr = test_env.jj_cmd_failure(&repo_path, &["squash", "--from=main"]);
     insta::assert_snapshot!(stderr, @r###"
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use indoc::indoc;
+
 use crate::common::get_stdout_string;
 use crate::common::TestEnvironment;
 
@@ -44,12 +46,14 @@ fn test_log_with_no_template() {
     - builtin_log_comfortable
     - builtin_log_compact
     - builtin_log_compact_full_description
+    - builtin_log_decorated
     - builtin_log_detailed
     - builtin_log_node
     - builtin_log_node_ascii
     - builtin_log_oneline
     - builtin_op_log_comfortable
     - builtin_op_log_compact
+    - builtin_op_log_conflicts
     - builtin_op_log_node
     - builtin_op_log_node_ascii
     - builtin_op_log_oneline
@@ -719,6 +723,88 @@ fn test_log_short_shortest_length_parameter() {
     @  230dd059e1b059aefc0da06a2e5a7dbf22362f22|230dd059e1b059aefc0da06a2e5a7dbf22362f22
     ◆  0000000000000000000000000000000000000000|0000000000000000000000000000000000000000
     "###);
+
+    // The same ID rendered with different lengths
+    insta::assert_snapshot!(
+        render(r#"commit_id.shortest() ++ "|" ++ commit_id.shortest(8) ++ "|" ++ change_id.shortest()"#), @r"
+    @  2|230dd059|q
+    ◆  0|00000000|z
+    ");
+}
+
+#[test]
+fn test_log_shortest_configured_min_length() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.add_config(
+        r#"
+        ui.commit-id-length = 12
+        ui.change-id-length = 4
+        "#,
+    );
+
+    // The configured minimum applies to the default templates
+    insta::assert_snapshot!(
+        test_env.jj_cmd_success(&repo_path, &["log", "--revisions=@"]), @r"
+    @  qpvuntsm test.user@example.com 2001-02-03 08:05:07 230dd059e1b0
+    │  (empty) (no description set)
+    ~
+    ");
+
+    // A larger explicit length still wins
+    let render = |template| test_env.jj_cmd_success(&repo_path, &["log", "-T", template]);
+    insta::assert_snapshot!(
+        render(r#"change_id.shortest() ++ "|" ++ commit_id.shortest(16)"#), @r"
+    @  qpvu|230dd059e1b059ae
+    ◆  zzzz|0000000000000000
+    ");
+
+    let stderr = test_env.jj_cmd_failure(
+        &repo_path,
+        &[
+            "log",
+            "--config=ui.commit-id-length=-1",
+            "-T",
+            "commit_id.shortest()",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Failed to parse template: In alias "format_commit_summary_with_refs(commit, refs)"
+    Caused by:
+    1:  --> 1:1
+      |
+    1 | format_commit_summary_with_refs(self, bookmarks)
+      | ^----------------------------------------------^
+      |
+      = In alias "format_commit_summary_with_refs(commit, refs)"
+    2:  --> 2:3
+      |
+    2 |   format_short_change_id_with_hidden_and_divergent_info(commit),
+      |   ^-----------------------------------------------------------^
+      |
+      = In alias "format_short_change_id_with_hidden_and_divergent_info(commit)"
+    3:  --> 3:5
+      |
+    3 |     format_short_change_id(commit.change_id()) ++ " hidden"
+      |     ^----------------------------------------^
+      |
+      = In alias "format_short_change_id(id)"
+    4:  --> 1:1
+      |
+    1 | format_short_id(id)
+      | ^-----------------^
+      |
+      = In alias "format_short_id(id)"
+    5:  --> 1:4
+      |
+    1 | id.shortest(8)
+      |    ^------^
+      |
+      = Failed to get config value
+    6: Invalid type or value for ui.commit-id-length
+    7: invalid value: integer `-1`, expected usize
+    "#);
 }
 
 #[test]
@@ -755,6 +841,70 @@ fn test_log_author_format() {
     );
 }
 
+#[test]
+fn test_log_mailmap() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "describe",
+            "-m",
+            "old",
+            "--author",
+            "Old Name <old@example.com>",
+        ],
+    );
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "new"]);
+
+    let template = r#"description.first_line() ++ ": " ++ author ++ " mine=" ++ mine ++ "\n""#;
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "--no-graph", "-T", template]);
+    insta::assert_snapshot!(stdout, @r"
+    new: Test User <test.user@example.com> mine=true
+    old: Old Name <old@example.com> mine=false
+    :  mine=false
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "--no-graph", "-r", "mine()", "-T", template],
+    );
+    insta::assert_snapshot!(stdout, @"new: Test User <test.user@example.com> mine=true");
+
+    // The old identity is mapped to the user's identity
+    std::fs::write(
+        repo_path.join(".mailmap"),
+        "# Comment\nTest User <test.user@example.com> Old Name <OLD@example.com>\n",
+    )
+    .unwrap();
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "--no-graph", "-T", template]);
+    insta::assert_snapshot!(stdout, @r"
+    new: Test User <test.user@example.com> mine=true
+    old: Test User <test.user@example.com> mine=true
+    :  mine=false
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "--no-graph", "-r", "mine()", "-T", template],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    new: Test User <test.user@example.com> mine=true
+    old: Test User <test.user@example.com> mine=true
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "--no-graph",
+            "-r",
+            "author('Old Name')",
+            "-T",
+            template,
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"");
+}
 #[test]
 fn test_log_divergence() {
     let test_env = TestEnvironment::default();
@@ -814,6 +964,212 @@ fn test_log_reversed() {
     "###);
 }
 
+#[test]
+fn test_log_date_order() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "a"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "root()", "-m", "b"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "description(a)", "-m", "c"]);
+    let template = r#"description.first_line() ++ " " ++ committer.timestamp() ++ "\n""#;
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", template, "--topo-order"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  c 2001-02-03 04:05:10.000 +07:00
+    ○  a 2001-02-03 04:05:08.000 +07:00
+    │ ○  b 2001-02-03 04:05:09.000 +07:00
+    ├─╯
+    ◆   1970-01-01 00:00:00.000 +00:00
+    ");
+    // --date-order implies --no-graph
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", template, "--date-order"]);
+    insta::assert_snapshot!(stdout, @r"
+    c 2001-02-03 04:05:10.000 +07:00
+    b 2001-02-03 04:05:09.000 +07:00
+    a 2001-02-03 04:05:08.000 +07:00
+     1970-01-01 00:00:00.000 +00:00
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-T", template, "--date-order", "--reversed"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+     1970-01-01 00:00:00.000 +00:00
+    a 2001-02-03 04:05:08.000 +07:00
+    b 2001-02-03 04:05:09.000 +07:00
+    c 2001-02-03 04:05:10.000 +07:00
+    ");
+
+    // A revision older than its parent is shown after it
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "new",
+            "description(b)",
+            "-m",
+            "d",
+            "--config=debug.commit-timestamp='2001-02-03T04:05:00+07:00'",
+        ],
+    );
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", template, "--date-order"]);
+    insta::assert_snapshot!(stdout, @r"
+    c 2001-02-03 04:05:10.000 +07:00
+    b 2001-02-03 04:05:09.000 +07:00
+    a 2001-02-03 04:05:08.000 +07:00
+    d 2001-02-03 04:05:00.000 +07:00
+     1970-01-01 00:00:00.000 +00:00
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["log", "--date-order", "--topo-order"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--date-order' cannot be used with '--topo-order'
+
+    Usage: jj log --date-order [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["log", "--date-order", "--no-elide"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--date-order' cannot be used with '--no-elide'
+
+    Usage: jj log --date-order [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn test_log_highlight() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "first commit"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "second commit"]);
+    let template = r#"label("description", description)"#;
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-T", template, "--color=debug", "--highlight=co.m"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    [1m[38;5;2m<<node working_copy::@>>[0m  <<log description description::second >>[38;5;0m[48;5;3m<<log description description highlight::comm>>[39m[49m<<log description description::it>>
+    <<node::○>>  <<log description description::first >>[38;5;0m[48;5;3m<<log description description highlight::comm>>[39m[49m<<log description description::it>>
+    [1m[38;5;14m<<node immutable::◆>>[0m
+    ");
+
+    // Overlapping matches are merged
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            template,
+            "--no-graph",
+            "--color=debug",
+            "--highlight=second c",
+            "--highlight=d com",
+            "--highlight=^f",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    [38;5;0m[48;5;3m<<log description description highlight::second com>>[39m[49m<<log description description::mit>>
+    [38;5;0m[48;5;3m<<log description description highlight::f>>[39m[49m<<log description description::irst commit>>
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            template,
+            "-r@",
+            "--color=always",
+            "--highlight=second",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    [1m[38;5;2m@[0m  [38;5;0m[48;5;3msecond[39m[49m commit
+    │
+    ~
+    ");
+
+    // Without color, the output isn't changed
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-T", template, "-r@", "--highlight=second"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  second commit
+    │
+    ~
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["log", "--highlight=("]);
+    insta::assert_snapshot!(stderr, @r"
+    error: invalid value '(' for '--highlight <PATTERN>': regex parse error:
+        (
+        ^
+    error: unclosed group
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn test_log_multiple_revisions() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "a"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "b"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "c"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "description(a)", "-m", "d"]);
+
+    // The union is shown in topological order regardless of the order of the
+    // arguments
+    let template = r#"description.first_line() ++ "\n""#;
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            template,
+            "-r=description(a)",
+            "-r=description(c)",
+            "-r=description(d)",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  d
+    │ ○  c
+    │ ~  (elided revisions)
+    ├─╯
+    ○  a
+    │
+    ~
+    ");
+
+    // Overlapping revsets don't duplicate revisions
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            template,
+            "--no-graph",
+            "-r=description(b)",
+            "-r=description(a)::description(b)",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    b
+    a
+    ");
+}
+
 #[test]
 fn test_log_filtered_by_path() {
     let test_env = TestEnvironment::default();
@@ -925,6 +1281,146 @@ fn test_log_filtered_by_path() {
     "###);
 }
 
+#[test]
+fn test_log_ancestors_descendants() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "a"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "b"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "c"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "d", "description(a)"]);
+
+    // Defaults to the ancestors of @
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", "description", "--ancestors"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  d
+    ○  a
+    ◆
+    ");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            "description",
+            "--ancestors",
+            "-r=description(c)",
+            "--limit=2",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    ○  c
+    ○  b
+    ");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            "description",
+            "--descendants",
+            "-r=description(a)",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  d
+    │ ○  c
+    │ ○  b
+    ├─╯
+    ○  a
+    │
+    ~
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["log", "--ancestors", "--descendants"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--ancestors' cannot be used with '--descendants'
+
+    Usage: jj log --ancestors [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn test_log_current_branch() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.add_config(r#"revset-aliases."trunk()" = "main""#);
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "a"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "b"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "main", "-r", "@-"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "c"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "feature"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "d"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "e", "main"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "f", "description(d)"]);
+
+    // Shows the revisions from trunk() up to the nearest bookmark and @
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-T", "description", "--current-branch"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  f
+    ○  d
+    ○  c
+    ○  b
+    ◆  a
+    │
+    ~
+    ");
+
+    // Paths are still applied
+    std::fs::write(repo_path.join("file"), "").unwrap();
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-T", "description", "--current-branch", "file"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  f
+    │
+    ~
+    ");
+
+    // Falls back to the default revset if there's no bookmark
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "g", "description(e)"]);
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &["log", "-T", "description", "--current-branch"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  g
+    ○  e
+    │ ○  f
+    │ ○  d
+    │ ○  c
+    │ ○  b
+    ├─╯
+    ◆  a
+    │
+    ~
+    ");
+    insta::assert_snapshot!(stderr, @r"
+    Warning: No bookmark found between the working copy and trunk(), showing the default revset instead.
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["log", "--current-branch", "-r", "@"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--current-branch' cannot be used with '--revisions <REVSETS>'
+
+    Usage: jj log --current-branch [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_log_limit() {
     let test_env = TestEnvironment::default();
@@ -1007,6 +1503,60 @@ fn test_log_limit() {
     "###);
 }
 
+#[test]
+fn test_log_limit_per_branch() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    // A long branch with a bookmark in the middle, and a short side branch.
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "base"]);
+    for name in ["long1", "long2", "long3", "long4", "long5"] {
+        test_env.jj_cmd_ok(&repo_path, &["new", "-m", name]);
+    }
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["bookmark", "create", "-r=description(long2)", "mid"],
+    );
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "short1", "description(base)"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "short2"]);
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-T", "description", "--limit-per-branch=2"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  short2
+    ○  short1
+    │
+    ~
+
+    ○  long5
+    ○  long4
+    ~  (elided revisions)
+    ○  long2
+    ○  long1
+    │
+    ~
+    ");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            "description",
+            "--limit-per-branch=1",
+            "--no-graph",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    short2
+    long5
+    long2
+    ");
+}
+
 #[test]
 fn test_log_warn_path_might_be_revset() {
     let test_env = TestEnvironment::default();
@@ -1426,6 +1976,207 @@ fn test_log_word_wrap() {
     "###);
 }
 
+#[test]
+fn test_log_compact_graph() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let render = |args: &[&str], columns: u32| {
+        let assert = test_env
+            .jj_cmd(&repo_path, args)
+            .env("COLUMNS", columns.to_string())
+            .assert()
+            .success()
+            .stderr("");
+        get_stdout_string(&assert)
+    };
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "base"]);
+    for name in ["a", "b", "c"] {
+        test_env.jj_cmd_ok(
+            &repo_path,
+            &["new", "-m", name, "description(exact:\"base\n\")"],
+        );
+    }
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "new",
+            "-m",
+            "merge",
+            "description(exact:\"a\n\")",
+            "description(exact:\"b\n\")",
+            "description(exact:\"c\n\")",
+        ],
+    );
+
+    let template = r#"description.first_line() ++ "\n""#;
+    insta::assert_snapshot!(render(&["log", "-T", template], 80), @r"
+    @      merge
+    ├─┬─╮
+    │ │ ○  c
+    │ ○ │  b
+    │ ├─╯
+    ○ │  a
+    ├─╯
+    ○  base
+    ◆
+    ");
+    insta::assert_snapshot!(render(&["log", "-T", template, "--compact-graph"], 80), @r"
+    @   merge
+    ├┬╮
+    ││○ c
+    │○│ b
+    │├╯
+    ○│ a
+    ├╯
+    ○ base
+    ◆
+    ");
+    insta::assert_snapshot!(
+        render(&["log", "-T", template, "--config=ui.log-compact-graph=true"], 80), @r"
+    @   merge
+    ├┬╮
+    ││○ c
+    │○│ b
+    │├╯
+    ○│ a
+    ├╯
+    ○ base
+    ◆
+    ");
+    insta::assert_snapshot!(
+        render(&["log", "-T", template, "--config=ui.graph.style=ascii", "--compact-graph"], 80),
+        @r"
+    @   merge
+    |+\
+    ||o c
+    |o| b
+    ||/
+    o| a
+    |/
+    o base
+    +
+    ");
+
+    // Columns beyond the limit are collapsed
+    insta::assert_snapshot!(render(&["log", "-T", template, "--graph-width=2"], 80), @r"
+    @  merge
+    ├…
+    │… c
+    │… b
+    │…
+    ○│ a
+    ├╯
+    ○ base
+    ◆
+    ");
+
+    // Graph width should be subtracted from the term width
+    let template = r#""0 1 2 3 4 5 6 7 8 9""#;
+    insta::assert_snapshot!(
+        render(
+            &["log", "-T", template, "-r=@", "--graph-width=2"],
+            10,
+        ),
+        @r"
+    @ 0 1 2 3 4 5 6 7 8 9
+    │
+    ~
+    ");
+    insta::assert_snapshot!(
+        render(
+            &[
+                "log",
+                "-T",
+                template,
+                "-r=@",
+                "--graph-width=2",
+                "--config=ui.log-word-wrap=true",
+            ],
+            10,
+        ),
+        @r"
+    @ 0 1 2 3
+    │ 4 5 6 7
+    ~ 8 9
+    ");
+}
+
+#[test]
+fn test_log_template_file() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "first"]);
+
+    std::fs::write(
+        test_env.env_root().join("template"),
+        indoc! {r#"
+            change_id.short(8)
+            ++ " "
+            ++ description.first_line()
+            ++ "\n"
+        "#},
+    )
+    .unwrap();
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "--no-graph", "--template-file=../template"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    qpvuntsm first
+    zzzzzzzz
+    ");
+
+    // "-" reads the template from stdin
+    let (stdout, _stderr) = test_env.jj_cmd_stdin_ok(
+        &repo_path,
+        &["log", "--no-graph", "-r@", "--template-file=-"],
+        r#"commit_id.short(8) ++ "\n""#,
+    );
+    insta::assert_snapshot!(stdout, @"fa15625b");
+
+    // Parse errors are reported with the file name and the line number
+    std::fs::write(
+        test_env.env_root().join("template"),
+        indoc! {r#"
+            change_id.short(8)
+            ++ description.bad_method()
+        "#},
+    )
+    .unwrap();
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["log", "--template-file=../template"]);
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Failed to parse template: Method "bad_method" doesn't exist for type "String"
+    Caused by:  --> 2:16
+      |
+    2 | ++ description.bad_method()
+      |                ^--------^
+      |
+      = Method "bad_method" doesn't exist for type "String"
+    Hint: The template was read from file ../template
+    "#);
+
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["log", "--template-file=../missing"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: Failed to read template file ../missing
+    Caused by: No such file or directory (os error 2)
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(
+        &repo_path,
+        &["log", "-T=description", "--template-file=../template"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--template <TEMPLATE>' cannot be used with '--template-file <PATH>'
+
+    Usage: jj log --template <TEMPLATE> [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_log_diff_stat_width() {
     let test_env = TestEnvironment::default();
@@ -1471,6 +2222,14 @@ fn test_log_diff_stat_width() {
     ◆  zzzzzzzz root() 00000000
        0 files changed, 0 insertions(+), 0 deletions(-)
     "###);
+
+    // The width can be overridden
+    insta::assert_snapshot!(render(&["log", "--stat", "--stat-width=20", "-r@"], 30), @r"
+    @  rlvkpnrz test.user@example.com 2001-02-03 08:05:09 287520bf
+    │  (no description set)
+    ~  file2 | 100 +++++
+       1 file changed, 100 insertions(+), 0 deletions(-)
+    ");
 }
 
 #[test]
@@ -1578,6 +2337,115 @@ fn test_elided() {
     "###);
 }
 
+#[test]
+fn test_log_elide_options() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "a"]);
+    for description in ["b", "c", "d", "e", "f"] {
+        test_env.jj_cmd_ok(&repo_path, &["new", "-m", description]);
+    }
+    let revset = "description(exact:'a\n') | description(exact:'b\n') | @";
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["log", "-T", r#"description ++ "\n""#, "-r", revset],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  f
+    │
+    ~  (elided revisions)
+    ○  b
+    │
+    ○  a
+    │
+    ~
+    ");
+
+    // The number of elided revisions is shown on the marker
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            r#"description ++ "\n""#,
+            "-r",
+            revset,
+            "--elide-summary",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  f
+    │
+    ~  (3 elided revisions)
+    ○  b
+    │
+    ○  a
+    │
+    ~
+    ");
+
+    // --elide-summary implies synthetic elided nodes
+    test_env.add_config("ui.log-synthetic-elided-nodes = false");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            r#"description ++ "\n""#,
+            "-r",
+            "@ | root()",
+            "--elide-summary",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  f
+    │
+    ~  (5 elided revisions)
+    ◆
+    ");
+
+    // The connecting revisions are shown dimmed
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-T",
+            r#"description ++ "\n""#,
+            "-r",
+            revset,
+            "--no-elide",
+            "--color=always",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    [1m[38;5;2m@[0m  f
+    │
+    ○  [38;5;8me[39m
+    │  [38;5;8m[39m
+    ○  [38;5;8md[39m
+    │  [38;5;8m[39m
+    ○  [38;5;8mc[39m
+    │  [38;5;8m[39m
+    ○  b
+    │
+    ○  a
+    │
+    ~
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["log", "--no-elide", "--no-graph"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--no-elide' cannot be used with '--no-graph'
+
+    Usage: jj log --no-elide [FILESETS]...
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_log_with_custom_symbols() {
     // Test that elided commits are shown as synthetic nodes.
@@ -355,9 +355,12 @@ fn test_next_fails_on_bookmarking_children_no_stdin() {
     // Try to advance the working copy commit.
     let assert = test_env.jj_cmd(&repo_path, &["next"]).assert().code(1);
     let stderr = test_env.normalize_output(&get_stderr_string(&assert));
-    insta::assert_snapshot!(stderr,@r###"
-    Error: Cannot prompt for input since the output is not connected to a terminal
-    "###);
+    insta::assert_snapshot!(stderr,@r"
+    Error: Ambiguous next commit, cannot choose one without a prompt
+    Hint: Candidate: zsuskuln 5f24490d (empty) third
+    Hint: Candidate: rlvkpnrz 9ed53a4a (empty) second
+    Hint: Use `jj new` or `jj edit` to move to one of the candidates
+    ");
 }
 
 #[test]
@@ -802,7 +805,16 @@ fn test_next_conflict() {
     ○  qpvuntsmwlqt first
     ◆  zzzzzzzzzzzz
     "###);
-    test_env.jj_cmd_ok(&repo_path, &["next", "--conflict"]);
+    // The conflict is reported when reaching it
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["next", "--conflict"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Working copy now at: vruxwmqv 72f5de93 (conflict) (empty) (no description set)
+    Parent commit      : kkmpptxz 23a08622 (conflict) third
+    Added 0 files, modified 1 files, removed 0 files
+    There are unresolved conflicts at these paths:
+    content.txt    2-sided conflict
+    ");
     insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r###"
     @  vruxwmqvtpmx conflict
     ×  kkmpptxzrspx conflict third
@@ -139,12 +139,14 @@ fn test_op_log_with_no_template() {
     - builtin_log_comfortable
     - builtin_log_compact
     - builtin_log_compact_full_description
+    - builtin_log_decorated
     - builtin_log_detailed
     - builtin_log_node
     - builtin_log_node_ascii
     - builtin_log_oneline
     - builtin_op_log_comfortable
     - builtin_op_log_compact
+    - builtin_op_log_conflicts
     - builtin_op_log_node
     - builtin_op_log_node_ascii
     - builtin_op_log_oneline
@@ -167,6 +169,349 @@ fn test_op_log_limit() {
     "###);
 }
 
+#[test]
+fn test_op_log_after_op() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    for i in 1..=4 {
+        test_env.jj_cmd_ok(&repo_path, &["describe", "-m", &format!("description {i}")]);
+    }
+
+    // Page through the operations
+    let template = r#"id.short() ++ " " ++ description ++ "\n""#;
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["op", "log", "--no-graph", "-T", template, "-n2"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    f6d8174e0d5e describe commit a9ae1ccd8adbcdb65cc72fc72f50207973ae7dc8
+    9169c02d5514 describe commit 34b9ff4e7ddc5bd2553251a479b80da2c2412b29
+    ");
+    let last_op_id = stdout.lines().last().unwrap().split(' ').next().unwrap();
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["op", "log", "-T", template, "-n2", "--after-op", last_op_id],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    ○  dfdb1e021863 describe commit d13ecdbda2a2e7471a29db2bed6f08ca28f91264
+    ○  fe4fd49ec647 describe commit 230dd059e1b059aefc0da06a2e5a7dbf22362f22
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            "--output-format=json",
+            "-n1",
+            "--after-op",
+            last_op_id,
+        ],
+    );
+    assert!(stdout.contains("describe commit"), "{stdout}");
+
+    // Nothing is listed after the root operation
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["op", "log", "-T", template, "--after-op", "000000000000"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["op", "log", "--after-op", "deadbeef"]);
+    insta::assert_snapshot!(stderr, @r#"Error: No operation ID matching "deadbeef""#);
+}
+
+#[test]
+fn test_op_log_highlight() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            "--no-graph",
+            "-T",
+            r#"description ++ "\n""#,
+            "--color=always",
+            "--highlight=work[a-z]+",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"add [38;5;0m[48;5;3mworkspace[39m[49m 'default'");
+}
+
+#[test]
+fn test_op_log_merge_base() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "description 0"]);
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["describe", "-m", "description 1", "--at-op", "@-"],
+    );
+    let (stdout, _stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &["op", "log", "--no-graph", "-T", r#"id.short() ++ "\n""#],
+    );
+    let op_ids = stdout.lines().collect_vec();
+    let [_merge_op_id, op_id1, op_id2, base_op_id, ..] = op_ids.as_slice() else {
+        panic!("unexpected operations: {op_ids:?}");
+    };
+
+    let template = r#"id.short() ++ " " ++ description ++ "\n""#;
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["op", "log", "-T", template, "--merge-base", op_id1, op_id2],
+    );
+    assert!(stdout.contains(base_op_id));
+    insta::assert_snapshot!(stdout, @r"
+    ○  eac759b9ab75 add workspace 'default'
+    │
+    ~
+    ");
+
+    // An operation is the merge base of itself and its descendants
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            "--no-graph",
+            "-T",
+            template,
+            "--merge-base",
+            op_id2,
+            "@",
+        ],
+    );
+    assert!(stdout.starts_with(op_id2));
+    insta::assert_snapshot!(stdout, @"9a4a4e20243c describe commit 230dd059e1b059aefc0da06a2e5a7dbf22362f22");
+}
+
+#[test]
+fn test_op_log_filter() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    std::fs::write(repo_path.join("file"), "a\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "second"]);
+    std::fs::write(repo_path.join("file"), "b\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "third"]);
+
+    // Filtered-out operations are elided from the graph
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            r#"-Tdescription ++ "\n""#,
+            "--filter",
+            "!snapshot",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  describe commit 914a93af78b3036ca22c962fc99be931bcc356f0
+    ○  new empty commit
+    ○  add workspace 'default'
+    ○
+    ");
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            r#"-Tdescription ++ "\n""#,
+            "--no-graph",
+            "--filter",
+            "!snapshot",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    describe commit 914a93af78b3036ca22c962fc99be931bcc356f0
+    new empty commit
+    add workspace 'default'
+    ");
+
+    // The default filter applies unless overridden
+    test_env.add_config(r#"op-log.default-filter = "snapshot""#);
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", r#"-Tdescription ++ "\n""#]);
+    insta::assert_snapshot!(stdout, @r"
+    ○  snapshot working copy
+    ○  snapshot working copy
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            r#"-Tdescription ++ "\n""#,
+            "--filter",
+            "!snapshot",
+            "-n1",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"@  describe commit 914a93af78b3036ca22c962fc99be931bcc356f0");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            r#"-Tdescription ++ "\n""#,
+            "--no-filter",
+            "--no-graph",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    describe commit 914a93af78b3036ca22c962fc99be931bcc356f0
+    snapshot working copy
+    new empty commit
+    snapshot working copy
+    add workspace 'default'
+    ");
+
+    // The filter must evaluate to a Boolean
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["op", "log", "--filter", "time"]);
+    insta::assert_snapshot!(stderr, @r#"
+    Error: Failed to parse template: Expected expression of type "Boolean", but actual type is "TimestampRange"
+    Caused by:  --> 1:1
+      |
+    1 | time
+      | ^--^
+      |
+      = Expected expression of type "Boolean", but actual type is "TimestampRange"
+    "#);
+}
+
+#[test]
+fn test_op_log_patch() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "first"]);
+    std::fs::write(repo_path.join("file"), "a\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "foo"]);
+
+    // The patch is shown beneath each operation, respecting --limit
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["op", "log", "-Tdescription", "-p", "--git", "-n2"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    @  create bookmark foo pointing to commit eb3a3cd1a9d0a8141a424885f73768d4a0a39799
+    │
+    │  Changed local bookmarks:
+    │  foo:
+    │  + qpvuntsm eb3a3cd1 foo | first
+    │  - (absent)
+    ○  snapshot working copy
+    │
+    │  Changed commits:
+    │  ○  + qpvuntsm eb3a3cd1 first
+    │     - qpvuntsm hidden fa15625b (empty) first
+    │     diff --git a/file b/file
+    │     new file mode 100644
+    │     index 0000000000..7898192261
+    │     --- /dev/null
+    │     +++ b/file
+    │     @@ -0,0 +1,1 @@
+    │     +a
+    ");
+
+    // The root operation has no changes to show
+    let template = r#"id.short() ++ " (" ++ description ++ ")\n""#;
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            "-T",
+            template,
+            "-p",
+            "--no-graph",
+            "--reversed",
+            "-n2",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    000000000000 ()
+    eac759b9ab75 (add workspace 'default')
+
+    Changed commits:
+    + qpvuntsm 230dd059 (empty) (no description set)
+    ");
+}
+
+#[test]
+fn test_op_log_json() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", "--output-format=json"]);
+    insta::assert_snapshot!(stdout, @r#"
+    [
+      {
+        "id": "eac759b9ab75793fd3da96e60939fb48f2cd2b2a9c1f13ffe723cf620f3005b8d3e7e923634a07ea39513e4f2f360c87b9ad5d331cf90d7a844864b83b72eba1",
+        "parents": [
+          "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+        ],
+        "start_time": "2001-02-03T04:05:07+07:00",
+        "end_time": "2001-02-03T04:05:07+07:00",
+        "description": "add workspace 'default'",
+        "hostname": "host.example.com",
+        "username": "test-username",
+        "is_snapshot": false,
+        "tags": {},
+        "current": true
+      },
+      {
+        "id": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        "parents": [],
+        "start_time": "1970-01-01T00:00:00+00:00",
+        "end_time": "1970-01-01T00:00:00+00:00",
+        "description": "",
+        "hostname": "",
+        "username": "",
+        "is_snapshot": false,
+        "tags": {},
+        "current": false
+      }
+    ]
+    "#);
+
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "op",
+            "log",
+            "--output-format=json",
+            "--reversed",
+            "--limit=1",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r#"
+    [
+      {
+        "id": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        "parents": [],
+        "start_time": "1970-01-01T00:00:00+00:00",
+        "end_time": "1970-01-01T00:00:00+00:00",
+        "description": "",
+        "hostname": "",
+        "username": "",
+        "is_snapshot": false,
+        "tags": {},
+        "current": false
+      }
+    ]
+    "#);
+}
+
 #[test]
 fn test_op_log_no_graph() {
     let test_env = TestEnvironment::default();
@@ -364,6 +709,39 @@ fn test_op_log_builtin_templates() {
     ");
 }
 
+#[test]
+fn test_op_log_time_column() {
+    let mut test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "description 0"]);
+    test_env.add_config(
+        r#"
+        templates.op_log = 'id.short() ++ " " ++ description.first_line() ++ "\n"'
+        templates.op_log_time_column = 'if(root, "-", time.end().format("%H:%M:%S"))'
+        "#,
+    );
+
+    // The column is right-aligned within the content width
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  d009cfc04993 describe commit 230dd059e1b059aefc0da06a2e5a7dbf22362f22                    04:05:08
+    ○  eac759b9ab75 add workspace 'default'                                                     04:05:07
+    ○  000000000000                                                                                    -
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", "--no-graph"]);
+    insta::assert_snapshot!(stdout, @r"
+    d009cfc04993 describe commit 230dd059e1b059aefc0da06a2e5a7dbf22362f22                       04:05:08
+    eac759b9ab75 add workspace 'default'                                                        04:05:07
+    000000000000                                                                                       -
+    ");
+
+    // Lines too long to fit the column are separated by a space
+    test_env.add_env_var("COLUMNS", "20");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", "-n1"]);
+    insta::assert_snapshot!(stdout, @"@  d009cfc04993 describe commit 230dd059e1b059aefc0da06a2e5a7dbf22362f22 04:05:08");
+}
+
 #[test]
 fn test_op_log_word_wrap() {
     let test_env = TestEnvironment::default();
@@ -581,30 +959,30 @@ fn test_op_abandon_ancestors() {
     Abandoned 1 operations and reparented 1 descendant operations.
     "###);
     insta::assert_snapshot!(
-        test_env.jj_cmd_success(&repo_path, &["debug", "local-working-copy", "--ignore-working-copy"]), @r###"
-    Current operation: OperationId("0699d720d0cecd80fb7d765c45955708c61b12feb1d7ed9ff2777ae719471f04ffed3c1dc24efdbf94bdb74426065d6fa9a4f0862a89db2c8c8e359eefc45462")
+        test_env.jj_cmd_success(&repo_path, &["debug", "local-working-copy", "--ignore-working-copy"]), @r#"
+    Current operation: OperationId("4d6693fca381a14519f9f045f312381b833234821b73f82ad4a5a15018629d39d45d68a24a91e68c73cc2a24f2ca92d76ab10f1a017a834530bee9dbf4a95642")
     Current tree: Merge(Resolved(TreeId("4b825dc642cb6eb9a060e54bf8d69288fbee4904")))
-    "###);
-    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["op", "log"]), @r###"
-    @  0699d720d0ce test-username@host.example.com 2001-02-03 04:05:21.000 +07:00 - 2001-02-03 04:05:21.000 +07:00
+    "#);
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["op", "log"]), @r"
+    @  4d6693fca381 test-username@host.example.com 2001-02-03 04:05:21.000 +07:00 - 2001-02-03 04:05:21.000 +07:00
     │  undo operation d92d0753399f732e438bdd88fa7e5214cba2a310d120ec1714028a514c7116bcf04b4a0b26c04dbecf0a917f1d4c8eb05571b8816dd98b0502aaf321e92500b3
     │  args: jj undo
     ○  8545e0137524 test-username@host.example.com 2001-02-03 04:05:09.000 +07:00 - 2001-02-03 04:05:09.000 +07:00
     │  commit 81a4ef3dd421f3184289df1c58bd3a16ea1e3d8e
     │  args: jj commit -m 'commit 2'
     ○  000000000000 root()
-    "###);
+    ");
 
     // Abandon empty range.
     let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["op", "abandon", "@-..@-"]);
     insta::assert_snapshot!(stderr, @r###"
     Nothing changed.
     "###);
-    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["op", "log", "-n1"]), @r###"
-    @  0699d720d0ce test-username@host.example.com 2001-02-03 04:05:21.000 +07:00 - 2001-02-03 04:05:21.000 +07:00
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["op", "log", "-n1"]), @r"
+    @  4d6693fca381 test-username@host.example.com 2001-02-03 04:05:21.000 +07:00 - 2001-02-03 04:05:21.000 +07:00
     │  undo operation d92d0753399f732e438bdd88fa7e5214cba2a310d120ec1714028a514c7116bcf04b4a0b26c04dbecf0a917f1d4c8eb05571b8816dd98b0502aaf321e92500b3
     │  args: jj undo
-    "###);
+    ");
 }
 
 #[test]
@@ -862,7 +1240,7 @@ fn test_op_summary_diff_template() {
     );
     insta::assert_snapshot!(&stdout, @r"
     From operation: [38;5;4m000000000000[39m [38;5;2mroot()[39m
-      To operation: [38;5;4me3792fce5b1f[39m ([38;5;6m2001-02-03 08:05:09[39m) undo operation ac20a4ff47914da9a2e43677b94455b86383bfb9227374d6531ecee85b9ff9230eeb96416a24bb27e7477aa18d50c01810e97c6a008b5c584224650846f4c05b
+      To operation: [38;5;4m4c24850324be[39m ([38;5;6m2001-02-03 08:05:09[39m) undo operation ac20a4ff47914da9a2e43677b94455b86383bfb9227374d6531ecee85b9ff9230eeb96416a24bb27e7477aa18d50c01810e97c6a008b5c584224650846f4c05b
 
     Changed commits:
     ○  [38;5;2m+[39m [1m[38;5;5mq[0m[38;5;8mpvuntsm[39m [1m[38;5;4m2[0m[38;5;8m30dd059[39m [38;5;2m(empty)[39m [38;5;2m(no description set)[39m
@@ -873,7 +1251,7 @@ fn test_op_summary_diff_template() {
     let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["op", "undo", "--color=debug"]);
     insta::assert_snapshot!(&stdout, @"");
     insta::assert_snapshot!(&stderr, @r#"
-    Undid operation: [38;5;4m<<operation id short::2301f6e6ec31>>[39m<<operation:: (>>[38;5;6m<<operation time end local format::2001-02-03 08:05:11>>[39m<<operation::) >><<operation description first_line::new empty commit>>
+    Undid operation: [38;5;4m<<operation id short::0ec7ae9d9081>>[39m<<operation:: (>>[38;5;6m<<operation time end local format::2001-02-03 08:05:11>>[39m<<operation::) >><<operation description first_line::new empty commit>>
     "#);
     let stdout = test_env.jj_cmd_success(
         &repo_path,
@@ -889,7 +1267,7 @@ fn test_op_summary_diff_template() {
     );
     insta::assert_snapshot!(&stdout, @r"
     From operation: [38;5;4m<<operation id short::000000000000>>[39m<<operation:: >>[38;5;2m<<operation root::root()>>[39m
-      To operation: [38;5;4m<<operation id short::d208ae1b4e3c>>[39m<<operation:: (>>[38;5;6m<<operation time end local format::2001-02-03 08:05:12>>[39m<<operation::) >><<operation description first_line::undo operation 2301f6e6ec31931a9b0a594742d6035a44c05250d1707f7f8678e888b11a98773ef07bf0e8008a5bccddf7114da4a35d1a1b1f7efa37c1e6c80d6bdb8f0d7a90>>
+      To operation: [38;5;4m<<operation id short::58e9ef4104a9>>[39m<<operation:: (>>[38;5;6m<<operation time end local format::2001-02-03 08:05:12>>[39m<<operation::) >><<operation description first_line::undo operation 0ec7ae9d908126cc3575fc7c79d22d02ac519be93d416202fbbba13098932dbf207c5b3d4d7a76bb344c5efcff98917b0ef17fce15c31f1d303ba7f8433caa6e>>
 
     Changed commits:
     ○  [38;5;2m<<diff added::+>>[39m [1m[38;5;5m<<change_id shortest prefix::q>>[0m[38;5;8m<<change_id shortest rest::pvuntsm>>[39m [1m[38;5;4m<<commit_id shortest prefix::2>>[0m[38;5;8m<<commit_id shortest rest::30dd059>>[39m [38;5;2m<<empty::(empty)>>[39m [38;5;2m<<empty description placeholder::(no description set)>>[39m
@@ -1278,9 +1656,9 @@ fn test_op_diff() {
     Parent commit      : wvuyspvk 358b82d6 bookmark-1 | (empty) new commit
     "#);
     let stdout = test_env.jj_cmd_success(&repo_path, &["op", "diff"]);
-    insta::assert_snapshot!(&stdout, @r#"
+    insta::assert_snapshot!(&stdout, @r"
     From operation: e07e94fbdd09 (2001-02-03 08:05:32) delete bookmark bookmark-2
-      To operation: 203fe2a7ed9e (2001-02-03 08:05:34) push all tracked bookmarks to git remote origin
+      To operation: 5bd0a89e54dc (2001-02-03 08:05:34) push all tracked bookmarks to git remote origin
 
     Changed commits:
     ○  + oupztwtk 2f0718a0 (empty) (no description set)
@@ -1292,7 +1670,7 @@ fn test_op_diff() {
     bookmark-2@origin:
     + untracked (absent)
     - tracked qzxslznx d487febd Commit 5
-    "#);
+    ");
 }
 
 #[test]
@@ -1931,8 +2309,8 @@ fn test_op_show() {
     Parent commit      : xznxytkn eb6c2b21 bookmark-1 | (empty) new commit
     "#);
     let stdout = test_env.jj_cmd_success(&repo_path, &["op", "show"]);
-    insta::assert_snapshot!(&stdout, @r#"
-    cd1704964f59 test-username@host.example.com 2001-02-03 04:05:30.000 +07:00 - 2001-02-03 04:05:30.000 +07:00
+    insta::assert_snapshot!(&stdout, @r"
+    ceb0fc121410 test-username@host.example.com 2001-02-03 04:05:30.000 +07:00 - 2001-02-03 04:05:30.000 +07:00
     push all tracked bookmarks to git remote origin
     args: jj git push --tracked
 
@@ -1946,7 +2324,38 @@ fn test_op_show() {
     bookmark-2@origin:
     + untracked (absent)
     - tracked qzxslznx d487febd Commit 5
-    "#);
+    ");
+}
+
+#[test]
+fn test_op_snapshot() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    // Nothing to snapshot
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["op", "snapshot"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"Nothing changed.");
+
+    // The snapshot is recorded as a new operation
+    std::fs::write(repo_path.join("file"), "a\n").unwrap();
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["op", "snapshot"]);
+    insta::assert_snapshot!(stdout, @"7cf1dcc57635");
+    insta::assert_snapshot!(stderr, @"");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", "-n2"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  7cf1dcc57635 test-username@host.example.com 2001-02-03 04:05:09.000 +07:00 - 2001-02-03 04:05:09.000 +07:00
+    │  snapshot working copy
+    │  args: jj op snapshot
+    ○  eac759b9ab75 test-username@host.example.com 2001-02-03 04:05:07.000 +07:00 - 2001-02-03 04:05:07.000 +07:00
+    │  add workspace 'default'
+    ");
+
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["op", "snapshot", "--ignore-working-copy"]);
+    insta::assert_snapshot!(stderr, @"Error: Cannot snapshot the working copy with --ignore-working-copy or --no-snapshot");
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["op", "snapshot", "--at-op=@-"]);
+    insta::assert_snapshot!(stderr, @"Error: Cannot snapshot the working copy at a non-head operation");
 }
 
 #[test]
@@ -2233,3 +2642,61 @@ fn get_log_output(test_env: &TestEnvironment, repo_path: &Path, op_id: &str) ->
         &["log", "-T", "commit_id", "--at-op", op_id, "-r", "all()"],
     )
 }
+
+#[test]
+fn test_op_meta() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let template = r#"description ++ " [" ++ self.tag("ci.job") ++ "]\n""#;
+
+    // The metadata is recorded in both the snapshot and the command operation
+    std::fs::write(repo_path.join("file"), "a\n").unwrap();
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "--op-meta",
+            "ci.job=42",
+            "--op-meta",
+            "ci_host=runner",
+            "new",
+        ],
+    );
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", "-T", template, "-n3"]);
+    insta::assert_snapshot!(stdout, @r"
+    @  new empty commit [42]
+    ○  snapshot working copy [42]
+    ○  add workspace 'default' []
+    ");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &["op", "log", "-T", r#"self.tag("ci_host")"#, "-n1"],
+    );
+    insta::assert_snapshot!(stdout, @"@  runner");
+
+    // Invalid and reserved keys are rejected
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["--op-meta", "foo", "new"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: invalid value 'foo' for '--op-meta <KEY=VALUE>': Expected KEY=VALUE
+
+    For more information, try '--help'.
+    ");
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["--op-meta", "a b=c", "new"]);
+    insta::assert_snapshot!(stderr, @r#"
+    error: invalid value 'a b=c' for '--op-meta <KEY=VALUE>': Invalid key "a b"
+
+    For more information, try '--help'.
+    "#);
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["--op-meta", "args=x", "new"]);
+    insta::assert_snapshot!(stderr, @r#"
+    error: invalid value 'args=x' for '--op-meta <KEY=VALUE>': Key "args" is reserved for internal use
+
+    For more information, try '--help'.
+    "#);
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["--op-meta", "jj.foo=x", "new"]);
+    insta::assert_snapshot!(stderr, @r#"
+    error: invalid value 'jj.foo=x' for '--op-meta <KEY=VALUE>': Key "jj.foo" is reserved for internal use
+
+    For more information, try '--help'.
+    "#);
+}
@@ -39,62 +39,62 @@ fn test_rebase_invalid() {
 
     // Missing destination
     let stderr = test_env.jj_cmd_cli_error(&repo_path, &["rebase"]);
-    insta::assert_snapshot!(stderr, @r###"
+    insta::assert_snapshot!(stderr, @r"
     error: the following required arguments were not provided:
-      <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>>
+      <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
 
-    Usage: jj rebase <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>>
+    Usage: jj rebase <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
 
     For more information, try '--help'.
-    "###);
+    ");
 
     // Both -r and -s
     let stderr =
         test_env.jj_cmd_cli_error(&repo_path, &["rebase", "-r", "a", "-s", "a", "-d", "b"]);
-    insta::assert_snapshot!(stderr, @r###"
+    insta::assert_snapshot!(stderr, @r"
     error: the argument '--revisions <REVSETS>' cannot be used with '--source <REVSETS>'
 
-    Usage: jj rebase --revisions <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>>
+    Usage: jj rebase --revisions <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
 
     For more information, try '--help'.
-    "###);
+    ");
 
     // Both -b and -s
     let stderr =
         test_env.jj_cmd_cli_error(&repo_path, &["rebase", "-b", "a", "-s", "a", "-d", "b"]);
-    insta::assert_snapshot!(stderr, @r###"
+    insta::assert_snapshot!(stderr, @r"
     error: the argument '--branch <REVSETS>' cannot be used with '--source <REVSETS>'
 
-    Usage: jj rebase --branch <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>>
+    Usage: jj rebase --branch <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
 
     For more information, try '--help'.
-    "###);
+    ");
 
     // Both -d and --after
     let stderr = test_env.jj_cmd_cli_error(
         &repo_path,
         &["rebase", "-r", "a", "-d", "b", "--after", "b"],
     );
-    insta::assert_snapshot!(stderr, @r###"
+    insta::assert_snapshot!(stderr, @r"
     error: the argument '--destination <REVSETS>' cannot be used with '--insert-after <REVSETS>'
 
-    Usage: jj rebase --revisions <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>>
+    Usage: jj rebase --revisions <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
 
     For more information, try '--help'.
-    "###);
+    ");
 
     // Both -d and --before
     let stderr = test_env.jj_cmd_cli_error(
         &repo_path,
         &["rebase", "-r", "a", "-d", "b", "--before", "b"],
     );
-    insta::assert_snapshot!(stderr, @r###"
+    insta::assert_snapshot!(stderr, @r"
     error: the argument '--destination <REVSETS>' cannot be used with '--insert-before <REVSETS>'
 
-    Usage: jj rebase --revisions <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>>
+    Usage: jj rebase --revisions <REVSETS> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
 
     For more information, try '--help'.
-    "###);
+    ");
 
     // Rebase onto self with -r
     let stderr = test_env.jj_cmd_failure(&repo_path, &["rebase", "-r", "a", "-d", "a"]);
@@ -2580,12 +2580,13 @@ fn test_rebase_skip_emptied() {
 
     let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["rebase", "-d=b", "--skip-emptied"]);
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @r#"
+    insta::assert_snapshot!(stderr, @r"
     Rebased 2 commits onto destination
-    Abandoned 1 newly emptied commits
+    Abandoned 1 newly emptied commits:
+      royxmykx 4f915f5e will become empty
     Working copy now at: yostqsxw bc4222f2 (empty) also already empty
     Parent commit      : vruxwmqv 6b41ecb2 (empty) already empty
-    "#);
+    ");
 
     // The parent commit became empty and was dropped, but the already empty commits
     // were kept
@@ -2619,13 +2620,14 @@ fn test_rebase_skip_emptied() {
         ],
     );
     insta::assert_snapshot!(stdout, @"");
-    insta::assert_snapshot!(stderr, @r#"
+    insta::assert_snapshot!(stderr, @r"
     Rebased 2 descendant commits
-    Abandoned 1 newly emptied commits
+    Abandoned 1 newly emptied commits:
+      royxmykx 4f915f5e will become empty
     Working copy now at: yostqsxw 74149b9b (empty) also already empty
     Parent commit      : vruxwmqv 3bdb2801 (empty) already empty
     Added 0 files, modified 0 files, removed 1 files
-    "#);
+    ");
 
     // Rebasing a single commit which becomes empty abandons that commit, whilst its
     // already empty descendants were kept
@@ -2639,6 +2641,213 @@ fn test_rebase_skip_emptied() {
     "#);
 }
 
+#[test]
+fn test_rebase_skip_emptied_undescribed() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    test_env.add_config(r#"ui.default-description = "TODO: describe\n""#);
+    let repo_path = test_env.env_root().join("repo");
+
+    create_commit(&test_env, &repo_path, "a", &[]);
+    create_commit(&test_env, &repo_path, "b", &["a"]);
+    // Three commits which will become empty, with different descriptions
+    test_env.jj_cmd_ok(&repo_path, &["new", "a", "-m", "kept: meaningful"]);
+    test_env.jj_cmd_ok(&repo_path, &["restore", "--from=b"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "a", "-m", "TODO: describe"]);
+    test_env.jj_cmd_ok(&repo_path, &["restore", "--from=b"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "a", "-m", ""]);
+    test_env.jj_cmd_ok(&repo_path, &["restore", "--from=b"]);
+    // An already empty commit without description
+    test_env.jj_cmd_ok(&repo_path, &["new", "a"]);
+
+    // Test the setup
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["log", "-T", "description"]), @r"
+    @
+    │ ○
+    ├─╯
+    │ ○  TODO: describe
+    ├─╯
+    │ ○  kept: meaningful
+    ├─╯
+    │ ○  b
+    ├─╯
+    ○  a
+    ◆
+    ");
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &["rebase", "-r=a+ ~ b", "-d=b", "--skip-emptied-undescribed"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Rebased 2 commits onto destination
+    Abandoned 2 newly emptied commits:
+      vruxwmqv 368dca0a TODO: describe
+      znkkpsqq 5fe63b18 (no description set)
+    Working copy now at: kmkuslsw f3d46bfb (empty) (no description set)
+    Parent commit      : zsuskuln 1394f625 b | b
+    Added 1 files, modified 0 files, removed 0 files
+    ");
+
+    // Only the emptied commits without a meaningful description were abandoned
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["log", "-T", "description"]), @r"
+    @
+    │ ○  kept: meaningful
+    ├─╯
+    ○  b
+    ○  a
+    ◆
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(
+        &repo_path,
+        &[
+            "rebase",
+            "-d=b",
+            "--skip-emptied-undescribed",
+            "--skip-emptied",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--skip-emptied-undescribed' cannot be used with '--skip-emptied'
+
+    Usage: jj rebase --skip-emptied-undescribed <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn test_rebase_onto_each() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    create_commit(&test_env, &repo_path, "a", &[]);
+    create_commit(&test_env, &repo_path, "release-1", &["a"]);
+    create_commit(&test_env, &repo_path, "release-2", &["a"]);
+    create_commit(&test_env, &repo_path, "f1", &["a"]);
+    create_commit(&test_env, &repo_path, "f2", &["f1"]);
+    let template = r#"description.first_line() ++ surround(" [", "]", bookmarks)"#;
+    let setup_opid = test_env.current_operation_id(&repo_path);
+
+    // Test the setup
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["log", "-T", template]), @r"
+    @  f2 [f2]
+    ○  f1 [f1]
+    │ ○  release-2 [release-2]
+    ├─╯
+    │ ○  release-1 [release-1]
+    ├─╯
+    ○  a [a]
+    ◆
+    ");
+
+    // The commits of the branch are copied onto each destination
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "rebase",
+            "-b=f2",
+            "--onto-each=release-1",
+            "--onto-each=release-2",
+            "--bookmark-prefix=test-",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Duplicated 2 commits onto zsuskuln a4d025d4 release-1 | release-1
+      Tip: rsllmpnm 74f8c047 test-release-1 | f2
+    Duplicated 2 commits onto royxmykx 529894d9 release-2 | release-2
+      Tip: pkpnqqml 15f2d784 test-release-2 | f2
+    ");
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["log", "-T", template]), @r"
+    @  f2 [f2]
+    ○  f1 [f1]
+    │ ○  f2 [test-release-2]
+    │ ○  f1
+    │ ○  release-2 [release-2]
+    ├─╯
+    │ ○  f2 [test-release-1]
+    │ ○  f1
+    │ ○  release-1 [release-1]
+    ├─╯
+    ○  a [a]
+    ◆
+    ");
+
+    // Running it again fails since the bookmarks exist
+    let stderr = test_env.jj_cmd_failure(
+        &repo_path,
+        &[
+            "rebase",
+            "-b=f2",
+            "--onto-each=release-1",
+            "--bookmark-prefix=test-",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Error: Bookmark already exists: test-release-1
+    Hint: Use a different --bookmark-prefix.
+    ");
+
+    // With -r, only the given revisions are copied
+    test_env.jj_cmd_ok(&repo_path, &["op", "restore", &setup_opid]);
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &["rebase", "-r=f2", "--onto-each=all:release-1 | release-2"],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Duplicated 1 commits onto royxmykx 529894d9 release-2 | release-2
+      Tip: xznxytkn f4c1e7c5 f2
+    Duplicated 1 commits onto zsuskuln a4d025d4 release-1 | release-1
+      Tip: smwtzssm 923e16e7 f2
+    ");
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["log", "-T", template]), @r"
+    @  f2 [f2]
+    ○  f1 [f1]
+    │ ○  f2
+    │ ○  release-2 [release-2]
+    ├─╯
+    │ ○  f2
+    │ ○  release-1 [release-1]
+    ├─╯
+    ○  a [a]
+    ◆
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(
+        &repo_path,
+        &["rebase", "--onto-each=release-1", "-d=release-2"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--onto-each <REVSETS>' cannot be used with '--destination <REVSETS>'
+
+    Usage: jj rebase <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
+
+    For more information, try '--help'.
+    ");
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["rebase", "-d=a", "--bookmark-prefix=x"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the argument '--destination <REVSETS>' cannot be used with '--bookmark-prefix <PREFIX>'
+
+    Usage: jj rebase <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
+
+    For more information, try '--help'.
+    ");
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["rebase", "-r=a", "--bookmark-prefix=x"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the following required arguments were not provided:
+      <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
+
+    Usage: jj rebase --revisions <REVSETS> --bookmark-prefix <PREFIX> <--destination <REVSETS>|--insert-after <REVSETS>|--insert-before <REVSETS>|--onto-each <REVSETS>>
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn test_rebase_skip_emptied_descendants() {
     let test_env = TestEnvironment::default();
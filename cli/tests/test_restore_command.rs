@@ -439,6 +439,34 @@ fn test_restore_interactive() {
     ");
 }
 
+#[test]
+fn test_restore_interactive_no_change() {
+    let mut test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    create_commit(&test_env, &repo_path, "a", &[], &[("file1", "a1\n")]);
+    create_commit(
+        &test_env,
+        &repo_path,
+        "b",
+        &["a"],
+        &[("file1", "b1\n"), ("file2", "b2\n")],
+    );
+
+    // Deselect all hunks
+    let diff_editor = test_env.set_up_fake_diff_editor();
+    let diff_script = ["reset file1", "reset file2"].join("\0");
+    std::fs::write(diff_editor, diff_script).unwrap();
+    let op_log_before = test_env.jj_cmd_success(&repo_path, &["op", "log", "-n1", "-Tid"]);
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["restore", "-i", "--from=@-"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"Nothing changed.");
+    // No operation was recorded
+    let op_log_after = test_env.jj_cmd_success(&repo_path, &["op", "log", "-n1", "-Tid"]);
+    assert_eq!(op_log_before, op_log_after);
+}
+
 #[test]
 fn test_restore_interactive_merge() {
     let mut test_env = TestEnvironment::default();
@@ -601,6 +629,35 @@ fn create_commit(
     test_env.jj_cmd_ok(repo_path, &["bookmark", "create", name]);
 }
 
+#[test]
+fn test_restore_merge_base() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "base\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "base"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "main"]);
+    std::fs::write(repo_path.join("file1"), "main\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "main"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "description(base)", "-m", "side 1"]);
+    std::fs::write(repo_path.join("file2"), "side\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "side 2"]);
+    std::fs::write(repo_path.join("file1"), "side\n").unwrap();
+
+    // Undo the changes of the whole side branch
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["restore", "--merge-base=main"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Created royxmykx 7bd6c04c side 2
+    Working copy now at: royxmykx 7bd6c04c side 2
+    Parent commit      : mzvwutvl cbe54a03 side 1
+    Added 0 files, modified 1 files, removed 1 files
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["diff", "-s", "--from=description(base)"]);
+    insta::assert_snapshot!(stdout, @"");
+}
+
 fn get_log_output(test_env: &TestEnvironment, repo_path: &Path) -> String {
     test_env.jj_cmd_success(repo_path, &["log", "-T", "bookmarks"])
 }
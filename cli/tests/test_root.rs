@@ -40,3 +40,44 @@ fn test_root_outside_a_repo() {
     Error: There is no jj repo in "."
     "###);
 }
+
+#[test]
+fn test_root_git() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    test_env.jj_cmd_ok(
+        test_env.env_root(),
+        &["git", "init", "--colocate", "colocated"],
+    );
+    let repo_path = test_env.env_root().join("repo");
+    let colocated_path = test_env.env_root().join("colocated");
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["root", "--git"]);
+    insta::assert_snapshot!(stdout, @"$TEST_ENV/repo/.jj/repo/store/git");
+    let stdout = test_env.jj_cmd_success(&colocated_path, &["workspace", "root", "--git"]);
+    insta::assert_snapshot!(stdout, @"$TEST_ENV/colocated/.git");
+}
+
+#[test]
+fn test_root_git_non_git_backend() {
+    let test_env = TestEnvironment::default();
+    let test_workspace = TestWorkspace::init_with_backend(TestRepoBackend::Local);
+    let root = test_workspace.workspace.workspace_root();
+    let stderr = test_env.jj_cmd_failure(root, &["root", "--git"]);
+    insta::assert_snapshot!(stderr, @"Error: The repo is not backed by a git repo");
+}
+
+#[test]
+fn test_root_without_snapshot() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    // The working copy isn't loaded, so a broken working-copy state doesn't
+    // prevent finding the roots
+    std::fs::write(repo_path.join(".jj/working_copy/checkout"), "garbage").unwrap();
+    let stdout = test_env.jj_cmd_success(&repo_path, &["root"]);
+    insta::assert_snapshot!(stdout, @"$TEST_ENV/repo");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["root", "--git"]);
+    insta::assert_snapshot!(stdout, @"$TEST_ENV/repo/.jj/repo/store/git");
+}
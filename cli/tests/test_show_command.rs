@@ -229,6 +229,35 @@ fn test_show_basic() {
     "#);
 }
 
+#[test]
+fn test_show_summary_merge() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "foo\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "root()"]);
+    std::fs::write(repo_path.join("file2"), "foo\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "all:root()+", "-m", "merge"]);
+    std::fs::write(repo_path.join("file1"), "bar\n").unwrap();
+    std::fs::write(repo_path.join("file3"), "foo\n").unwrap();
+
+    // All parents are listed, and files are summarized without the patch
+    let stdout = test_env.jj_cmd_success(&repo_path, &["show", "--summary"]);
+    insta::assert_snapshot!(stdout, @r"
+    Commit ID: 15ec2faf20594247536b617791f4fb12ac10aced
+    Change ID: kkmpptxzrspxrzommnulwmwkkqwworpl
+    Parents  : 5fdbb76e371a 0e15949eed93
+    Author   : Test User <test.user@example.com> (2001-02-03 08:05:09)
+    Committer: Test User <test.user@example.com> (2001-02-03 08:05:10)
+
+        merge
+
+    M file1
+    A file3
+    ");
+}
+
 #[test]
 fn test_show_with_template() {
     let test_env = TestEnvironment::default();
@@ -258,12 +287,14 @@ fn test_show_with_no_template() {
     - builtin_log_comfortable
     - builtin_log_compact
     - builtin_log_compact_full_description
+    - builtin_log_decorated
     - builtin_log_detailed
     - builtin_log_node
     - builtin_log_node_ascii
     - builtin_log_oneline
     - builtin_op_log_comfortable
     - builtin_op_log_compact
+    - builtin_op_log_conflicts
     - builtin_op_log_node
     - builtin_op_log_node_ascii
     - builtin_op_log_oneline
@@ -0,0 +1,200 @@
+// Copyright 2025 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use crate::common::TestEnvironment;
+
+fn get_log_output(test_env: &TestEnvironment, repo_path: &Path) -> String {
+    let template = r#"
+    separate(" ",
+      description.first_line(),
+      bookmarks,
+      if(signature,
+        signature.status() ++ " signature " ++ signature.key(),
+        "unsigned",
+      ),
+    ) ++ "\n"
+    "#;
+    test_env.jj_cmd_success(repo_path, &["log", "-r", "all()", "-T", template])
+}
+
+fn init_repo(test_env: &TestEnvironment) -> std::path::PathBuf {
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    test_env.add_config("signing.backend = 'test'");
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "one"]);
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "two"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "b2", "-r", "@-"]);
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "three"]);
+    repo_path
+}
+
+#[test]
+fn test_sign() {
+    let test_env = TestEnvironment::default();
+    let repo_path = init_repo(&test_env);
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r"
+    @  three unsigned
+    ○  two b2 unsigned
+    ○  one unsigned
+    ◆  unsigned
+    ");
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["sign", "-r", "description(one)"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Signed 1 commits:
+      qpvuntsm e3ef5444 (empty) one
+    Rebased 2 descendant commits
+    Working copy now at: kkmpptxz d82b5213 (empty) three
+    Parent commit      : rlvkpnrz 427f1384 b2 | (empty) two
+    ");
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r"
+    @  three unsigned
+    ○  two b2 unsigned
+    ○  one good signature
+    ◆  unsigned
+    ");
+
+    // Already signed commits are signed again, here with another key
+    let (_stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "sign",
+            "-r",
+            "description(one)|description(two)",
+            "--key",
+            "other",
+        ],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Signed 2 commits:
+      qpvuntsm 98d250bf (empty) one
+      rlvkpnrz 7ef2f92d b2 | (empty) two
+    Rebased 1 descendant commits
+    Working copy now at: kkmpptxz f31d797f (empty) three
+    Parent commit      : rlvkpnrz 7ef2f92d b2 | (empty) two
+    ");
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r"
+    @  three unsigned
+    ○  two b2 good signature other
+    ○  one good signature other
+    ◆  unsigned
+    ");
+}
+
+#[test]
+fn test_sign_not_authored_by_user() {
+    let test_env = TestEnvironment::default();
+    let repo_path = init_repo(&test_env);
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "describe",
+            "-r",
+            "description(one)",
+            "--author",
+            "Someone <someone@example.com>",
+            "--no-edit",
+        ],
+    );
+
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["sign", "-r", "description(one)"]);
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Signed 1 commits not authored by you
+    Signed 1 commits:
+      qpvuntsm 143c87bc (empty) one
+    Rebased 2 descendant commits
+    Working copy now at: kkmpptxz 74f7657d (empty) three
+    Parent commit      : rlvkpnrz ad43f9bf b2 | (empty) two
+    ");
+}
+
+#[test]
+fn test_sign_immutable() {
+    let test_env = TestEnvironment::default();
+    let repo_path = init_repo(&test_env);
+    test_env.add_config(r#"revset-aliases."immutable_heads()" = "b2""#);
+
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["sign", "-r", "description(one)"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: Commit 876f4b7e04b6 is immutable
+    Hint: Could not modify commit: qpvuntsm 876f4b7e (empty) one
+    Hint: It is an ancestor of immutable head: rlvkpnrz b0e11728 b2 | (empty) two
+    Hint: Pass `--ignore-immutable` or configure the set of immutable commits via `revset-aliases.immutable_heads()`.
+    ");
+
+    let (_stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &["sign", "-r", "description(one)", "--ignore-immutable"],
+    );
+    insta::assert_snapshot!(stderr, @r"
+    Warning: Rewriting immutable commits because `--ignore-immutable` was passed
+    Signed 1 commits:
+      qpvuntsm e3ef5444 (empty) one
+    Rebased 2 descendant commits
+    Working copy now at: kkmpptxz d82b5213 (empty) three
+    Parent commit      : rlvkpnrz 427f1384 b2 | (empty) two
+    ");
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r"
+    @  three unsigned
+    ◆  two b2 unsigned
+    ◆  one good signature
+    ◆  unsigned
+    ");
+}
+
+#[test]
+fn test_sign_without_backend() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["sign"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: No signing backend is configured
+    Hint: Set `signing.backend` to the backend to sign with
+    ");
+}
+
+#[test]
+fn test_unsign() {
+    let test_env = TestEnvironment::default();
+    let repo_path = init_repo(&test_env);
+    test_env.jj_cmd_ok(
+        &repo_path,
+        &["sign", "-r", "description(one)|description(two)"],
+    );
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["unsign", "-r", "description(one)"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Unsigned 1 commits:
+      qpvuntsm cb05440c (empty) one
+    Rebased 2 descendant commits
+    Working copy now at: kkmpptxz 78bd1aa1 (empty) three
+    Parent commit      : rlvkpnrz 7b6a1e41 b2 | (empty) two
+    ");
+    insta::assert_snapshot!(get_log_output(&test_env, &repo_path), @r"
+    @  three unsigned
+    ○  two b2 good signature
+    ○  one unsigned
+    ◆  unsigned
+    ");
+
+    // Unsigned revisions are skipped
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["unsign", "-r", "description(one)"]);
+    insta::assert_snapshot!(stderr, @"No signed revisions to unsign.");
+}
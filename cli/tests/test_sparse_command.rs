@@ -121,6 +121,27 @@ fn test_sparse_manage_patterns() {
     assert!(repo_path.join("file2").exists());
     assert!(!repo_path.join("file3").exists());
 
+    // Can list which files are present on disk
+    let stdout = test_env.jj_cmd_success(&sub_dir, &["sparse", "list", "--materialized"]);
+    insta::assert_snapshot!(stdout, @r"
+    excluded ../file1
+    present  ../file2
+    excluded ../file3
+    ");
+
+    // Files deleted from disk are reported as missing
+    std::fs::remove_file(repo_path.join("file2")).unwrap();
+    let stdout = test_env.jj_cmd_success(
+        &sub_dir,
+        &["sparse", "list", "--materialized", "--ignore-working-copy"],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    excluded ../file1
+    missing  ../file2
+    excluded ../file3
+    ");
+    std::fs::write(repo_path.join("file2"), "contents").unwrap();
+
     // Can reset back to all files
     let (stdout, stderr) = test_env.jj_cmd_ok(&sub_dir, &["sparse", "reset"]);
     insta::assert_snapshot!(stdout, @"");
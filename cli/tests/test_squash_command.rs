@@ -268,7 +268,6 @@ fn test_squash_partial() {
     "###);
 
     // We get a warning if we pass a positional argument that looks like a revset
-    test_env.jj_cmd_ok(&repo_path, &["undo"]);
     let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["squash", "b"]);
     insta::assert_snapshot!(stderr, @r###"
     Warning: The argument "b" is being interpreted as a path. To specify a revset, pass -r "b" instead.
@@ -1221,6 +1220,70 @@ fn test_squash_use_destination_message_and_message_mutual_exclusion() {
     "###);
 }
 
+#[test]
+fn test_squash_dry_run() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file1"), "a\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "parent"]);
+    std::fs::write(repo_path.join("file1"), "b\n").unwrap();
+    std::fs::write(repo_path.join("file2"), "b\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "child"]);
+    let log_before = get_log_output(&test_env, &repo_path);
+    let op_log_template = &["op", "log", "-n1", "--no-graph", "-T", "id.short()"];
+    let op_before = test_env.jj_cmd_success(&repo_path, op_log_template);
+
+    // Both descriptions are non-empty, but no editor is opened
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["squash", "--dry-run"]);
+    insta::assert_snapshot!(stdout, @r"
+    Added regular file file1:
+            1: b
+    Added regular file file2:
+            1: b
+    ");
+    insta::assert_snapshot!(stderr, @"Would squash into: qpvuntsm f1ae064a parent");
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "squash",
+            "--dry-run",
+            "file2",
+            "--config=ui.diff.format=git",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @r"
+    diff --git a/file1 b/file1
+    new file mode 100644
+    index 0000000000..7898192261
+    --- /dev/null
+    +++ b/file1
+    @@ -0,0 +1,1 @@
+    +a
+    diff --git a/file2 b/file2
+    new file mode 100644
+    index 0000000000..6178079822
+    --- /dev/null
+    +++ b/file2
+    @@ -0,0 +1,1 @@
+    +b
+    ");
+    insta::assert_snapshot!(stderr, @"Would squash into: qpvuntsm fef019bf parent");
+
+    // Nothing was changed
+    assert_eq!(get_log_output(&test_env, &repo_path), log_before);
+    assert_eq!(
+        test_env.jj_cmd_success(&repo_path, op_log_template),
+        op_before
+    );
+
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["squash", "--dry-run", "nonexistent"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"Nothing would change.");
+}
+
 fn get_description(test_env: &TestEnvironment, repo_path: &Path, rev: &str) -> String {
     test_env.jj_cmd_success(
         repo_path,
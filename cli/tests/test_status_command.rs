@@ -137,6 +137,96 @@ fn test_status_filtered() {
     "###);
 }
 
+#[test]
+fn test_status_no_snapshot() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file_1"), "file_1").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["status"]);
+    std::fs::write(repo_path.join("file_2"), "file_2").unwrap();
+
+    // The change to file_2 hasn't been snapshotted yet
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["status", "--no-snapshot"]);
+    insta::assert_snapshot!(stdout, @r"
+    Working copy changes:
+    A file_1
+    Working copy : qpvuntsm d5e151b6 (no description set)
+    Parent commit: zzzzzzzz 00000000 (empty) (no description set)
+    ");
+    insta::assert_snapshot!(stderr, @"Warning: The working copy was not snapshotted, the status may be out of date");
+
+    // The working-copy commit wasn't updated
+    let stdout = test_env.jj_cmd_success(&repo_path, &["file", "list", "--ignore-working-copy"]);
+    insta::assert_snapshot!(stdout, @"file_1");
+}
+
+#[test]
+fn test_status_types() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("modified"), "base\n").unwrap();
+    std::fs::write(repo_path.join("deleted"), "base\n").unwrap();
+    std::fs::write(repo_path.join("conflicted"), "base\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new"]);
+    std::fs::write(repo_path.join("conflicted"), "left\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "@-"]);
+    std::fs::write(repo_path.join("conflicted"), "right\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "all:@-+"]);
+    std::fs::write(repo_path.join("modified"), "changed\n").unwrap();
+    std::fs::remove_file(repo_path.join("deleted")).unwrap();
+    std::fs::write(repo_path.join("added"), "added\n").unwrap();
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["status"]);
+    insta::assert_snapshot!(stdout, @r"
+    Working copy changes:
+    A added
+    D deleted
+    M modified
+    There are unresolved conflicts at these paths:
+    conflicted    2-sided conflict
+    Working copy : zsuskuln 71e647cc (conflict) (no description set)
+    Parent commit: kkmpptxz f9169b6e (no description set)
+    Parent commit: rlvkpnrz c884a789 (no description set)
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["status", "--types=added,deleted"]);
+    insta::assert_snapshot!(stdout, @r"
+    Working copy changes:
+    A added
+    D deleted
+    Working copy : zsuskuln 71e647cc (conflict) (no description set)
+    Parent commit: kkmpptxz f9169b6e (no description set)
+    Parent commit: rlvkpnrz c884a789 (no description set)
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["status", "--types", "modified"]);
+    insta::assert_snapshot!(stdout, @r"
+    Working copy changes:
+    M modified
+    Working copy : zsuskuln 71e647cc (conflict) (no description set)
+    Parent commit: kkmpptxz f9169b6e (no description set)
+    Parent commit: rlvkpnrz c884a789 (no description set)
+    ");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["status", "--types", "conflicted"]);
+    insta::assert_snapshot!(stdout, @r"
+    There are unresolved conflicts at these paths:
+    conflicted    2-sided conflict
+    Working copy : zsuskuln 71e647cc (conflict) (no description set)
+    Parent commit: kkmpptxz f9169b6e (no description set)
+    Parent commit: rlvkpnrz c884a789 (no description set)
+    ");
+
+    let stderr = test_env.jj_cmd_cli_error(&repo_path, &["status", "--types", "renamed"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: invalid value 'renamed' for '--types <TYPES>'
+      [possible values: added, modified, deleted, conflicted]
+
+    For more information, try '--help'.
+    ");
+}
+
 // See <https://github.com/jj-vcs/jj/issues/3108>
 // See <https://github.com/jj-vcs/jj/issues/4147>
 #[test]
@@ -411,7 +501,7 @@ fn test_status_untracked_files() {
     Parent commit: qpvuntsm 203bfea9 (no description set)
     ");
 
-    test_env.jj_cmd_success(&repo_path, &["file", "untrack", "initially-untracked-file"]);
+    test_env.jj_cmd_ok(&repo_path, &["file", "untrack", "initially-untracked-file"]);
     let stdout = test_env.jj_cmd_success(&repo_path, &["status"]);
     insta::assert_snapshot!(stdout, @r"
     Working copy changes:
@@ -434,3 +524,185 @@ fn test_status_untracked_files() {
     Parent commit: mzvwutvl 16169825 (no description set)
     ");
 }
+
+#[test]
+fn test_status_json() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("modified"), "base").unwrap();
+    std::fs::write(repo_path.join("removed"), "base").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "base"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "-r@-", "main"]);
+    std::fs::write(repo_path.join("modified"), "changed").unwrap();
+    std::fs::remove_file(repo_path.join("removed")).unwrap();
+    std::fs::write(repo_path.join("added"), "new").unwrap();
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["status", "--output-format=json"]);
+    insta::assert_snapshot!(stdout, @r#"
+    {
+      "version": 1,
+      "working_copy": {
+        "change_id": "rlvkpnrzqnoowoytxnquwvuryrwnrmlp",
+        "commit_id": "30945ead326a1a081fda10c180def5b101fd3ac7",
+        "description": ""
+      },
+      "parents": [
+        {
+          "change_id": "qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu",
+          "commit_id": "4159d4baf6a0063c66e402e2f0b999a1cc1f91a1",
+          "description": "base\n"
+        }
+      ],
+      "changes": [
+        {
+          "path": "added",
+          "status": "added"
+        },
+        {
+          "path": "modified",
+          "status": "modified"
+        },
+        {
+          "path": "removed",
+          "status": "removed"
+        }
+      ],
+      "untracked": [],
+      "conflicts": [],
+      "conflict_count": 0,
+      "bookmarks": [
+        "main"
+      ],
+      "conflicted_bookmarks": [],
+      "conflicted_remote_bookmarks": []
+    }
+    "#);
+
+    // Paths restrict the reported changes
+    let stdout = test_env.jj_cmd_success(&repo_path, &["st", "--output-format=json", "added"]);
+    insta::assert_snapshot!(stdout, @r#"
+    {
+      "version": 1,
+      "working_copy": {
+        "change_id": "rlvkpnrzqnoowoytxnquwvuryrwnrmlp",
+        "commit_id": "30945ead326a1a081fda10c180def5b101fd3ac7",
+        "description": ""
+      },
+      "parents": [
+        {
+          "change_id": "qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu",
+          "commit_id": "4159d4baf6a0063c66e402e2f0b999a1cc1f91a1",
+          "description": "base\n"
+        }
+      ],
+      "changes": [
+        {
+          "path": "added",
+          "status": "added"
+        }
+      ],
+      "untracked": [],
+      "conflicts": [],
+      "conflict_count": 0,
+      "bookmarks": [
+        "main"
+      ],
+      "conflicted_bookmarks": [],
+      "conflicted_remote_bookmarks": []
+    }
+    "#);
+
+    // --json is a shorthand for --output-format=json
+    let stdout = test_env.jj_cmd_success(&repo_path, &["status", "--json"]);
+    insta::assert_snapshot!(stdout, @r#"
+    {
+      "version": 1,
+      "working_copy": {
+        "change_id": "rlvkpnrzqnoowoytxnquwvuryrwnrmlp",
+        "commit_id": "30945ead326a1a081fda10c180def5b101fd3ac7",
+        "description": ""
+      },
+      "parents": [
+        {
+          "change_id": "qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu",
+          "commit_id": "4159d4baf6a0063c66e402e2f0b999a1cc1f91a1",
+          "description": "base\n"
+        }
+      ],
+      "changes": [
+        {
+          "path": "added",
+          "status": "added"
+        },
+        {
+          "path": "modified",
+          "status": "modified"
+        },
+        {
+          "path": "removed",
+          "status": "removed"
+        }
+      ],
+      "untracked": [],
+      "conflicts": [],
+      "conflict_count": 0,
+      "bookmarks": [
+        "main"
+      ],
+      "conflicted_bookmarks": [],
+      "conflicted_remote_bookmarks": []
+    }
+    "#);
+}
+
+#[test]
+fn test_status_json_conflicts() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    std::fs::write(repo_path.join("file"), "base\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m=left"]);
+    std::fs::write(repo_path.join("file"), "left\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m=right", "@-"]);
+    std::fs::write(repo_path.join("file"), "right\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["new", "all:@-+"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "wip"]);
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["status", "--json"]);
+    insta::assert_snapshot!(stdout, @r#"
+    {
+      "version": 1,
+      "working_copy": {
+        "change_id": "zsuskulnrvyrovkzqrwmxqlsskqntxvp",
+        "commit_id": "be97b23883ede999ac9f2cec6ab5417359521351",
+        "description": ""
+      },
+      "parents": [
+        {
+          "change_id": "kkmpptxzrspxrzommnulwmwkkqwworpl",
+          "commit_id": "10105034ea117a4dca0449e5947c90d1f9b21b79",
+          "description": "right\n"
+        },
+        {
+          "change_id": "rlvkpnrzqnoowoytxnquwvuryrwnrmlp",
+          "commit_id": "c4937521e5f26b150fdc9b2c7c68cb0411990d47",
+          "description": "left\n"
+        }
+      ],
+      "changes": [],
+      "untracked": [],
+      "conflicts": [
+        "file"
+      ],
+      "conflict_count": 1,
+      "bookmarks": [
+        "wip"
+      ],
+      "conflicted_bookmarks": [],
+      "conflicted_remote_bookmarks": []
+    }
+    "#);
+}
@@ -117,3 +117,61 @@ fn test_tag_list() {
     added_targets: commit2
     "###);
 }
+
+#[test]
+fn test_tag_create_delete() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let git_repo = {
+        let mut git_repo_path = repo_path.clone();
+        git_repo_path.extend([".jj", "repo", "store", "git"]);
+        git2::Repository::open(git_repo_path).unwrap()
+    };
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-mcommit1"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-mcommit2"]);
+
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["tag", "create", "light", "-r@-"]);
+    insta::assert_snapshot!(stderr, @"Created lightweight tag light pointing to qpvuntsm caf975d0 (empty) commit1");
+    let (_stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["tag", "create", "v1.0", "-m", "Release 1.0"]);
+    insta::assert_snapshot!(stderr, @r"
+    Created annotated tag v1.0 pointing to kkmpptxz d0a19ea4 (empty) commit2
+    Warning: The working-copy commit in workspace 'default' became immutable, so a new commit has been created on top of it.
+    Working copy now at: mzvwutvl d91a3a3c (empty) (no description set)
+    Parent commit      : kkmpptxz d0a19ea4 (empty) commit2
+    ");
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["tag", "list"]), @r"
+    light: qpvuntsm caf975d0 (empty) commit1
+    v1.0: kkmpptxz d0a19ea4 (empty) commit2
+    ");
+
+    // The tags are created in the underlying Git repo
+    let light_ref = git_repo.find_reference("refs/tags/light").unwrap();
+    assert!(light_ref.peel_to_tag().is_err());
+    let tag = git_repo
+        .find_reference("refs/tags/v1.0")
+        .unwrap()
+        .peel_to_tag()
+        .unwrap();
+    assert_eq!(tag.message(), Some("Release 1.0\n"));
+    assert_eq!(tag.tagger().unwrap().email(), Some("test.user@example.com"));
+
+    // Tags can't be moved
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["tag", "create", "light"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: Tag already exists: light
+    Hint: Tags can't be moved. Use `jj tag delete` to delete it first.
+    ");
+
+    // Importing refs doesn't change the tags
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["git", "import"]);
+    insta::assert_snapshot!(stderr, @"Nothing changed.");
+
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["tag", "delete", "glob:*"]);
+    insta::assert_snapshot!(stderr, @"Deleted 2 tags.");
+    insta::assert_snapshot!(test_env.jj_cmd_success(&repo_path, &["tag", "list"]), @"");
+    assert!(git_repo.find_reference("refs/tags/light").is_err());
+    let stderr = test_env.jj_cmd_failure(&repo_path, &["tag", "delete", "light"]);
+    insta::assert_snapshot!(stderr, @"Error: No such tag: light");
+}
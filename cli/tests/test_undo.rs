@@ -46,6 +46,134 @@ fn test_undo_rewrite_with_child() {
     "###);
 }
 
+#[test]
+fn test_undo_dry_run() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "initial"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "foo"]);
+    let op_id_hex = test_env.current_operation_id(&repo_path);
+    let ops_dir = repo_path.join(".jj/repo/op_store/operations");
+    let count_ops = || std::fs::read_dir(&ops_dir).unwrap().count();
+    let op_count = count_ops();
+
+    // The changes are shown, but no operation is recorded
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["undo", "--dry-run"]);
+    insta::assert_snapshot!(stdout, @r"
+    Changed local bookmarks:
+    foo:
+    + (absent)
+    - qpvuntsm 68e11012 (empty) initial
+    ");
+    insta::assert_snapshot!(stderr, @"Would undo operation: b6240d3af32c (2001-02-03 08:05:09) create bookmark foo pointing to commit 68e1101283b0a6c4694f92fab85bcd90f0c83652");
+    assert_eq!(test_env.current_operation_id(&repo_path), op_id_hex);
+    // Nor written to the operation store
+    assert_eq!(count_ops(), op_count);
+
+    // Undoing an operation whose changes were already reverted does nothing
+    test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["undo", "--dry-run", &op_id_hex]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Would undo operation: b6240d3af32c (2001-02-03 08:05:09) create bookmark foo pointing to commit 68e1101283b0a6c4694f92fab85bcd90f0c83652
+    Nothing would change.
+    ");
+
+    // Conflicts are reported
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "foo"]);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "second"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "set", "foo"]);
+    let set_op_id_hex = test_env.current_operation_id(&repo_path);
+    test_env.jj_cmd_ok(&repo_path, &["new", "-m", "third"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "set", "foo"]);
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["undo", "--dry-run", &set_op_id_hex]);
+    insta::assert_snapshot!(stdout, @r"
+    Changed local bookmarks:
+    foo:
+    + (added) wqnwkozp 2c296aca foo?? | (empty) third
+    + (added) qpvuntsm 68e11012 foo?? | (empty) initial
+    + (removed) znkkpsqq c81544e4 (empty) second
+    - wqnwkozp 2c296aca foo?? | (empty) third
+    ");
+    insta::assert_snapshot!(stderr, @r"
+    Would undo operation: 940c44fab4ec (2001-02-03 08:05:17) point bookmark foo to commit c81544e4d9ae9582c9fb1222d961ad470d2c81fb
+    Warning: The undo would make these bookmarks conflicted: foo
+    ");
+}
+
+#[test]
+fn test_undo_redo() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let get_description = || {
+        test_env.jj_cmd_success(
+            &repo_path,
+            &["log", "--no-graph", "-r@", "-Tdescription.first_line()"],
+        )
+    };
+
+    insta::assert_snapshot!(test_env.jj_cmd_failure(&repo_path, &["redo"]), @r"
+    Error: Nothing to redo
+    Hint: Only operations undone by `jj undo` can be redone
+    ");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "A"]);
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "B"]);
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "C"]);
+
+    // Repeated undo steps back through the history
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    insta::assert_snapshot!(stderr, @r"
+    Undid operation: c27c7975d7bb (2001-02-03 08:05:11) describe commit 124bf9d78bedd700329978469ac06ce0655e9dff
+    Working copy now at: qpvuntsm 124bf9d7 (empty) B
+    Parent commit      : zzzzzzzz 00000000 (empty) (no description set)
+    ");
+    assert_eq!(get_description(), "B");
+    test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    assert_eq!(get_description(), "A");
+
+    // Repeated redo reapplies the undone operations
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["redo"]);
+    insta::assert_snapshot!(stderr, @r"
+    Redid operation: f025bf0f3c35 (2001-02-03 08:05:10) describe commit 5ef24e4bf2bea1601be5b120d2bcabdccd69a815
+    Working copy now at: qpvuntsm 124bf9d7 (empty) B
+    Parent commit      : zzzzzzzz 00000000 (empty) (no description set)
+    ");
+    assert_eq!(get_description(), "B");
+
+    // Undo after redo undoes the redone operation, which can then be redone
+    test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    assert_eq!(get_description(), "A");
+    test_env.jj_cmd_ok(&repo_path, &["redo"]);
+    assert_eq!(get_description(), "B");
+    test_env.jj_cmd_ok(&repo_path, &["redo"]);
+    assert_eq!(get_description(), "C");
+    insta::assert_snapshot!(test_env.jj_cmd_failure(&repo_path, &["redo"]), @r"
+    Error: Nothing to redo
+    Hint: Only operations undone by `jj undo` can be redone
+    ");
+
+    // A new operation clears the redo history
+    test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    assert_eq!(get_description(), "B");
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "D"]);
+    insta::assert_snapshot!(test_env.jj_cmd_failure(&repo_path, &["redo"]), @r"
+    Error: Nothing to redo
+    Hint: Only operations undone by `jj undo` can be redone
+    ");
+    test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    assert_eq!(get_description(), "B");
+    test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    assert_eq!(get_description(), "A");
+
+    // An explicitly specified undo operation is undone as is
+    test_env.jj_cmd_ok(&repo_path, &["undo", "@"]);
+    assert_eq!(get_description(), "B");
+}
+
 #[test]
 fn test_git_push_undo() {
     let test_env = TestEnvironment::default();
@@ -363,7 +491,7 @@ fn test_bookmark_track_untrack_undo() {
       @origin: qpvuntsm 8da1cfc8 (empty) commit
     "###);
 
-    test_env.jj_cmd_ok(&repo_path, &["undo"]);
+    test_env.jj_cmd_ok(&repo_path, &["redo"]);
     insta::assert_snapshot!(get_bookmark_output(&test_env, &repo_path), @r###"
     feature1: qpvuntsm 8da1cfc8 (empty) commit
     feature1@origin: qpvuntsm 8da1cfc8 (empty) commit
@@ -389,3 +517,32 @@ fn get_bookmark_output(test_env: &TestEnvironment, repo_path: &Path) -> String {
     // --quiet to suppress deleted bookmarks hint
     test_env.jj_cmd_success(repo_path, &["bookmark", "list", "--all-remotes", "--quiet"])
 }
+
+#[test]
+fn test_undo_what_bookmarks() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+
+    test_env.jj_cmd_ok(&repo_path, &["describe", "-m", "first"]);
+    test_env.jj_cmd_ok(&repo_path, &["bookmark", "create", "foo"]);
+    test_env.jj_cmd_ok(&repo_path, &["abandon", "@"]);
+    insta::assert_snapshot!(get_bookmark_output(&test_env, &repo_path), @"");
+
+    // Only the bookmark is restored; the working copy stays on the new commit
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["undo", "--what", "bookmarks"]);
+    insta::assert_snapshot!(stderr, @r"
+    Undid operation: 3dadb9cc1353 (2001-02-03 08:05:10) abandon commit fa15625b4a986997697639dfc2844138900c79f2
+    Restored only: bookmarks
+    Working copy now at: royxmykx f37b4afd (empty) (no description set)
+    Parent commit      : zzzzzzzz 00000000 (empty) (no description set)
+    ");
+    insta::assert_snapshot!(get_bookmark_output(&test_env, &repo_path), @"foo: qpvuntsm fa15625b (empty) first");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["log", "-T", "description ++ bookmarks"]);
+    insta::assert_snapshot!(stdout, @r"
+    @
+    │ ○  first
+    ├─╯  foo
+    ◆
+    ");
+}
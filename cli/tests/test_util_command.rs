@@ -96,6 +96,54 @@ fn test_gc_operation_log() {
     "#);
 }
 
+#[test]
+fn test_gc_dry_run() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let initial_op = test_env.current_operation_id(&repo_path);
+
+    // Nothing is unreachable yet
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["util", "gc", "--dry-run", "--expire=now"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Would remove 0 operations and 0 views (0 B)
+    Would remove 0 commits, 0 trees, and 0 blobs (approximately 0 B)
+    ");
+
+    // Create a commit, then make it unreachable by restoring the initial
+    // operation and removing the operations that referenced it.
+    std::fs::write(repo_path.join("file"), "a change\n").unwrap();
+    test_env.jj_cmd_ok(&repo_path, &["commit", "-m", "a change"]);
+    test_env.jj_cmd_ok(&repo_path, &["operation", "restore", &initial_op]);
+    test_env.jj_cmd_ok(&repo_path, &["operation", "abandon", "..@-"]);
+    test_env.jj_cmd_ok(&repo_path, &["debug", "reindex"]);
+
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["util", "gc", "--dry-run", "--expire=now"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    Would remove 4 operations and 2 views (1.3 KiB)
+    Would remove 3 commits, 1 tree, and 1 blob (approximately 590 B)
+    ");
+
+    // Objects newer than the default threshold are kept
+    let (_stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["util", "gc", "--dry-run"]);
+    insta::assert_snapshot!(stderr, @r"
+    Would remove 0 operations and 0 views (0 B)
+    Would remove 0 commits, 0 trees, and 0 blobs (approximately 0 B)
+    ");
+
+    // Nothing was removed
+    let (_stdout, stderr) =
+        test_env.jj_cmd_ok(&repo_path, &["util", "gc", "--dry-run", "--expire=now"]);
+    insta::assert_snapshot!(stderr, @r"
+    Would remove 4 operations and 2 views (1.3 KiB)
+    Would remove 3 commits, 1 tree, and 1 blob (approximately 590 B)
+    ");
+}
+
 #[test]
 fn test_shell_completions() {
     #[track_caller]
@@ -134,6 +182,43 @@ fn test_util_exec() {
     assert!(err.is_empty());
 }
 
+#[cfg(unix)]
+#[test]
+fn test_util_exec_env_vars() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "repo"]);
+    let repo_path = test_env.env_root().join("repo");
+    let script = r#"echo "$JJ_ROOT $JJ_WORKSPACE $JJ_OPERATION $JJ_CHANGE $JJ_COMMIT""#;
+
+    let stdout = test_env.jj_cmd_success(&repo_path, &["util", "exec", "--", "sh", "-c", script]);
+    insta::assert_snapshot!(stdout, @"$TEST_ENV/repo default eac759b9ab75793fd3da96e60939fb48f2cd2b2a9c1f13ffe723cf620f3005b8d3e7e923634a07ea39513e4f2f360c87b9ad5d331cf90d7a844864b83b72eba1 qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu 230dd059e1b059aefc0da06a2e5a7dbf22362f22");
+    let stdout = test_env.jj_cmd_success(&repo_path, &["op", "log", "-n1", "--no-graph", "-T=id"]);
+    insta::assert_snapshot!(stdout, @"eac759b9ab75793fd3da96e60939fb48f2cd2b2a9c1f13ffe723cf620f3005b8d3e7e923634a07ea39513e4f2f360c87b9ad5d331cf90d7a844864b83b72eba1");
+    let stdout = test_env.jj_cmd_success(
+        &repo_path,
+        &[
+            "log",
+            "-r@",
+            "--no-graph",
+            "-T",
+            r#"change_id ++ " " ++ commit_id"#,
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu 230dd059e1b059aefc0da06a2e5a7dbf22362f22");
+
+    // The variables are also set for aliases
+    test_env.add_config(r#"aliases.env = ["util", "exec", "--", "sh", "-c", "echo $JJ_CHANGE"]"#);
+    let stdout = test_env.jj_cmd_success(&repo_path, &["env"]);
+    insta::assert_snapshot!(stdout, @"qpvuntsmwlqtpsluzzsnyyzlmlwvmlnu");
+
+    // Outside of a workspace, the variables are unset
+    let stdout = test_env.jj_cmd_success(
+        test_env.env_root(),
+        &["util", "exec", "--", "sh", "-c", "echo \"[$JJ_ROOT]\""],
+    );
+    insta::assert_snapshot!(stdout, @"[]");
+}
+
 #[test]
 fn test_util_exec_fail() {
     let test_env = TestEnvironment::default();
@@ -143,3 +228,19 @@ fn test_util_exec_fail() {
     );
     insta::assert_snapshot!(strip_last_line(&err), @"Error: Failed to execute external command 'missing-program'");
 }
+
+#[test]
+fn test_util_markdown_help_include_hidden() {
+    let test_env = TestEnvironment::default();
+    let stdout = test_env.jj_cmd_success(test_env.env_root(), &["util", "markdown-help"]);
+    assert!(!stdout.contains("## `jj debug`"));
+    assert!(!stdout.contains("## `jj branch`"));
+
+    let stdout = test_env.jj_cmd_success(
+        test_env.env_root(),
+        &["util", "markdown-help", "--include-hidden"],
+    );
+    assert!(stdout.contains("## `jj debug`"));
+    assert!(stdout.contains("## `jj debug fileset`"));
+    assert!(stdout.contains("## `jj branch`"));
+}
@@ -39,6 +39,8 @@ fn test_snapshot_large_file() {
         This will increase the maximum file size allowed for new files, in this repository only.
       - Run `jj --config snapshot.max-new-file-size=13 st`
         This will increase the maximum file size allowed for new files, for this command only.
+      - Run `jj file track --include-large <path>`
+        This will track the given files regardless of their size.
     ");
 
     // test with a larger file using 'KB' human-readable syntax
@@ -56,6 +58,8 @@ fn test_snapshot_large_file() {
         This will increase the maximum file size allowed for new files, in this repository only.
       - Run `jj --config snapshot.max-new-file-size=11264 st`
         This will increase the maximum file size allowed for new files, for this command only.
+      - Run `jj file track --include-large <path>`
+        This will track the given files regardless of their size.
     ");
 
     // test invalid configuration
@@ -87,6 +91,26 @@ fn test_snapshot_large_file() {
     large
     ");
     insta::assert_snapshot!(stderr, @"");
+
+    // The limit can be bypassed for specific files with `jj file track`
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &repo_path,
+        &[
+            "file",
+            "track",
+            "--include-large",
+            "large",
+            "--config=snapshot.auto-track='none()'",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"");
+    let (stdout, stderr) = test_env.jj_cmd_ok(&repo_path, &["file", "list"]);
+    insta::assert_snapshot!(stdout, @r"
+    empty
+    large
+    ");
+    insta::assert_snapshot!(stderr, @"");
 }
 
 #[test]
@@ -114,6 +138,8 @@ fn test_snapshot_large_file_restore() {
         This will increase the maximum file size allowed for new files, in this repository only.
       - Run `jj --config snapshot.max-new-file-size=13 st`
         This will increase the maximum file size allowed for new files, for this command only.
+      - Run `jj file track --include-large <path>`
+        This will track the given files regardless of their size.
     Created kkmpptxz e3eb7e81 (no description set)
     Working copy now at: kkmpptxz e3eb7e81 (no description set)
     Parent commit      : zzzzzzzz 00000000 (empty) (no description set)
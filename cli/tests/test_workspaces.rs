@@ -1019,6 +1019,127 @@ fn test_workspaces_forget_multi_transaction() {
     "###);
 }
 
+#[test]
+fn test_workspaces_forget_all_stale() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "main"]);
+    let main_path = test_env.env_root().join("main");
+    test_env.jj_cmd_ok(&main_path, &["workspace", "add", "../second"]);
+    test_env.jj_cmd_ok(&main_path, &["workspace", "add", "../third"]);
+
+    // Nothing is stale with a large enough threshold
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &main_path,
+        &[
+            "workspace",
+            "forget",
+            "--all-stale",
+            "--config=workspace.stale-after-days=1000000",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @"No stale workspaces found.");
+
+    // Test commits are timestamped in 2001, so they're all old enough. A
+    // confirmation is required when not running interactively.
+    let stderr = test_env.jj_cmd_failure(&main_path, &["workspace", "forget", "--all-stale"]);
+    insta::assert_snapshot!(stderr, @r"
+    The following workspaces will be forgotten:
+      second: uuqppmxq 57d63245 (empty) (no description set)
+      third: pmmvwywv 44a7931a (empty) (no description set)
+    Error: Refusing to forget workspaces without confirmation
+    Hint: Use --yes to forget them non-interactively.
+    ");
+
+    // Declining the prompt doesn't forget anything
+    let (stdout, stderr) =
+        test_env.jj_cmd_stdin_ok(&main_path, &["workspace", "forget", "--all-stale"], "n\n");
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    The following workspaces will be forgotten:
+      second: uuqppmxq 57d63245 (empty) (no description set)
+      third: pmmvwywv 44a7931a (empty) (no description set)
+    Forget these workspaces? (yN): Nothing changed.
+    ");
+
+    // The current workspace is kept even though it's stale too
+    let (stdout, stderr) =
+        test_env.jj_cmd_ok(&main_path, &["workspace", "forget", "--all-stale", "--yes"]);
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    The following workspaces will be forgotten:
+      second: uuqppmxq 57d63245 (empty) (no description set)
+      third: pmmvwywv 44a7931a (empty) (no description set)
+    ");
+    let stdout = test_env.jj_cmd_success(&main_path, &["workspace", "list"]);
+    insta::assert_snapshot!(stdout, @"default: qpvuntsm 230dd059 (empty) (no description set)");
+
+    // --yes only makes sense with --all-stale
+    let stderr = test_env.jj_cmd_cli_error(&main_path, &["workspace", "forget", "--yes"]);
+    insta::assert_snapshot!(stderr, @r"
+    error: the following required arguments were not provided:
+      --all-stale
+
+    Usage: jj workspace forget --all-stale --yes [WORKSPACES]...
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn test_workspaces_forget_all_stale_deleted_dir() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "main"]);
+    let main_path = test_env.env_root().join("main");
+    test_env.jj_cmd_ok(&main_path, &["workspace", "add", "../second"]);
+    test_env.jj_cmd_ok(&main_path, &["workspace", "add", "../third"]);
+    std::fs::remove_dir_all(test_env.env_root().join("second")).unwrap();
+
+    // A workspace whose directory was deleted is stale regardless of its age
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &main_path,
+        &[
+            "workspace",
+            "forget",
+            "--all-stale",
+            "--yes",
+            "--config=workspace.stale-after-days=1000000",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    The following workspaces will be forgotten:
+      second: uuqppmxq 57d63245 (empty) (no description set)
+    ");
+    let stdout = test_env.jj_cmd_success(&main_path, &["workspace", "list"]);
+    insta::assert_snapshot!(stdout, @r"
+    default: qpvuntsm 230dd059 (empty) (no description set)
+    third: pmmvwywv 44a7931a (empty) (no description set)
+    ");
+
+    // The recorded directory follows the workspace when it's renamed
+    test_env.jj_cmd_ok(
+        &test_env.env_root().join("third"),
+        &["workspace", "rename", "fourth"],
+    );
+    std::fs::remove_dir_all(test_env.env_root().join("third")).unwrap();
+    let (stdout, stderr) = test_env.jj_cmd_ok(
+        &main_path,
+        &[
+            "workspace",
+            "forget",
+            "--all-stale",
+            "--yes",
+            "--config=workspace.stale-after-days=1000000",
+        ],
+    );
+    insta::assert_snapshot!(stdout, @"");
+    insta::assert_snapshot!(stderr, @r"
+    The following workspaces will be forgotten:
+      fourth: pmmvwywv 44a7931a (empty) (no description set)
+    ");
+}
+
 #[test]
 fn test_workspaces_forget_abandon_commits() {
     let test_env = TestEnvironment::default();
@@ -1267,6 +1388,45 @@ fn test_workspaces_rename_workspace() {
     "###);
 }
 
+#[test]
+fn test_workspaces_rename_workspace_by_old_name() {
+    let test_env = TestEnvironment::default();
+    test_env.jj_cmd_ok(test_env.env_root(), &["git", "init", "main"]);
+    let main_path = test_env.env_root().join("main");
+    test_env.jj_cmd_ok(
+        &main_path,
+        &["workspace", "add", "--name", "second", "../secondary"],
+    );
+    let secondary_path = test_env.env_root().join("secondary");
+
+    // Cannot rename another workspace, since its metadata lives on its own disk
+    let stderr = test_env.jj_cmd_failure(&main_path, &["workspace", "rename", "second", "third"]);
+    insta::assert_snapshot!(stderr, @r"
+    Error: Cannot rename workspace 'second' from another workspace
+    Hint: Run this command from within the 'second' workspace.
+    ");
+    let stderr = test_env.jj_cmd_failure(&main_path, &["workspace", "rename", "nope", "third"]);
+    insta::assert_snapshot!(stderr, @"Error: No such workspace: nope");
+
+    test_env.jj_cmd_ok(&secondary_path, &["workspace", "rename", "second", "third"]);
+
+    // `@` still resolves to the same working-copy commit in the renamed
+    // workspace, and the other workspace can refer to it by the new name.
+    let template = r#"commit_id.short() ++ " " ++ working_copies ++ "\n""#;
+    let stdout = test_env.jj_cmd_success(
+        &secondary_path,
+        &["log", "--no-graph", "-r@", "-T", template],
+    );
+    insta::assert_snapshot!(stdout, @"57d63245a308 third@");
+    let stdout = test_env.jj_cmd_success(
+        &main_path,
+        &["log", "--no-graph", "-rthird@", "-T", template],
+    );
+    insta::assert_snapshot!(stdout, @"57d63245a308 third@");
+    let stderr = test_env.jj_cmd_failure(&main_path, &["log", "-rsecond@"]);
+    insta::assert_snapshot!(stderr, @r#"Error: Workspace "second" doesn't have a working-copy commit"#);
+}
+
 fn get_log_output(test_env: &TestEnvironment, cwd: &Path) -> String {
     let template = r#"
     separate(" ",
@@ -0,0 +1,61 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use jj_lib::backend::Backend as _;
+use jj_lib::backend::TreeId;
+use jj_lib::git_backend::GitBackend;
+use jj_lib::repo::Repo as _;
+use jj_lib::repo_path::RepoPath;
+use jj_lib::repo_path::RepoPathBuf;
+use pollster::FutureExt as _;
+use testutils::create_single_tree;
+use testutils::TestRepo;
+use testutils::TestRepoBackend;
+
+/// Creates `count` distinct root trees in a Git-backed repo.
+fn synthetic_trees(count: usize) -> (TestRepo, Vec<TreeId>) {
+    let test_repo = TestRepo::init_with_backend(TestRepoBackend::Git);
+    let path = RepoPathBuf::from_internal_string("file");
+    let tree_ids = (0..count)
+        .map(|i| {
+            let contents = format!("contents {i}\n");
+            let tree = create_single_tree(&test_repo.repo, &[(&path, &contents)]);
+            tree.id().clone()
+        })
+        .collect();
+    (test_repo, tree_ids)
+}
+
+fn bench_read_trees(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_read_trees");
+    for count in [100, 1000] {
+        let (test_repo, tree_ids) = synthetic_trees(count);
+        let backend: &GitBackend = test_repo
+            .repo
+            .store()
+            .backend_impl()
+            .downcast_ref()
+            .unwrap();
+        // One backend call per tree, as the store used to do
+        group.bench_function(BenchmarkId::new("one_by_one", count), |b| {
+            b.iter(|| {
+                for id in &tree_ids {
+                    backend.read_tree(RepoPath::root(), id).block_on().unwrap();
+                }
+            });
+        });
+        // A single backend call for all trees
+        group.bench_function(BenchmarkId::new("batched", count), |b| {
+            b.iter(|| {
+                backend
+                    .read_trees(RepoPath::root(), &tree_ids)
+                    .block_on()
+                    .unwrap();
+            });
+        });
+    }
+}
+
+criterion_group!(benches, bench_read_trees);
+criterion_main!(benches);
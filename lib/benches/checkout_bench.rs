@@ -0,0 +1,64 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use jj_lib::commit::Commit;
+use jj_lib::repo::Repo as _;
+use jj_lib::repo_path::RepoPathBuf;
+use jj_lib::working_copy::CheckoutOptions;
+use testutils::commit_with_tree;
+use testutils::create_tree;
+use testutils::TestWorkspace;
+
+/// Creates a workspace and two commits which both contain `count` files spread
+/// over nested directories, with different contents.
+fn synthetic_commits(count: usize) -> (TestWorkspace, Commit, Commit) {
+    let test_workspace = TestWorkspace::init();
+    let repo = &test_workspace.repo;
+    let paths = (0..count)
+        .map(|i| {
+            let path = format!("dir{}/sub{}/file{i}", i % 10, i % 100);
+            RepoPathBuf::from_internal_string(path)
+        })
+        .collect::<Vec<_>>();
+    let create_commit = |contents: &str| {
+        let path_contents = paths
+            .iter()
+            .map(|path| (path.as_ref(), contents))
+            .collect::<Vec<_>>();
+        let tree = create_tree(repo, &path_contents);
+        commit_with_tree(repo.store(), tree.id())
+    };
+    let commit1 = create_commit("old contents\n");
+    let commit2 = create_commit("new contents\n");
+    (test_workspace, commit1, commit2)
+}
+
+fn bench_checkout(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_checkout");
+    group.sample_size(10);
+    for count in [1000, 10000] {
+        let label = format!("{}k", count / 1000);
+        let (mut test_workspace, commit1, commit2) = synthetic_commits(count);
+        let op_id = test_workspace.repo.op_id().clone();
+        // 1 writes files sequentially, 0 uses as many threads as CPUs.
+        for (name, parallelism) in [("sequential", 1), ("parallel", 0)] {
+            let options = CheckoutOptions {
+                parallelism,
+                ..CheckoutOptions::empty_for_test()
+            };
+            group.bench_function(BenchmarkId::new(name, &label), |b| {
+                let ws = &mut test_workspace.workspace;
+                b.iter(|| {
+                    ws.check_out(op_id.clone(), None, &commit1, &options)
+                        .unwrap();
+                    ws.check_out(op_id.clone(), None, &commit2, &options)
+                        .unwrap();
+                });
+            });
+        }
+    }
+}
+
+criterion_group!(benches, bench_checkout);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use jj_lib::commit::Commit;
+use jj_lib::id_prefix::IdPrefixContext;
+use jj_lib::revset::RevsetExpression;
+use testutils::write_random_commit;
+use testutils::TestRepo;
+
+/// Creates `count` commits, as if they were rendered by `jj log`.
+fn synthetic_commits(count: usize) -> (TestRepo, Vec<Commit>) {
+    let mut test_repo = TestRepo::init();
+    let mut tx = test_repo.repo.start_transaction();
+    let commits = (0..count)
+        .map(|_| write_random_commit(tx.repo_mut()))
+        .collect();
+    test_repo.repo = tx.commit("test").unwrap();
+    (test_repo, commits)
+}
+
+fn bench_shortest_prefix_len(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_shortest_prefix_len");
+    for count in [1000, 10000] {
+        let (test_repo, commits) = synthetic_commits(count);
+        let repo = test_repo.repo.as_ref();
+        let new_context =
+            || IdPrefixContext::default().disambiguate_within(RevsetExpression::all());
+        // The disambiguation index is loaded once per command
+        group.bench_function(BenchmarkId::new("load_index", count), |b| {
+            b.iter(|| {
+                let context = new_context();
+                context.populate(repo).unwrap();
+            });
+        });
+        // and then shortest prefixes of all displayed commits are calculated.
+        let context = new_context();
+        let index = context.populate(repo).unwrap();
+        group.bench_function(BenchmarkId::new("all_commits", count), |b| {
+            b.iter(|| {
+                for commit in &commits {
+                    index.shortest_commit_prefix_len(repo, commit.id());
+                    index.shortest_change_prefix_len(repo, commit.change_id());
+                }
+            });
+        });
+    }
+}
+
+criterion_group!(benches, bench_shortest_prefix_len);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use jj_lib::fsmonitor::FsmonitorSettings;
+use jj_lib::repo_path::RepoPathBuf;
+use jj_lib::working_copy::SnapshotOptions;
+use testutils::write_working_copy_file;
+use testutils::TestWorkspace;
+
+/// Creates a workspace with `count` files spread over nested directories, and
+/// snapshots it once so that all files are tracked.
+fn synthetic_workspace(count: usize) -> (TestWorkspace, Vec<RepoPathBuf>) {
+    let mut test_workspace = TestWorkspace::init();
+    let workspace_root = test_workspace.workspace.workspace_root().to_owned();
+    let paths = (0..count)
+        .map(|i| {
+            let path = format!("dir{}/sub{}/file{i}", i % 10, i % 100);
+            RepoPathBuf::from_internal_string(path)
+        })
+        .collect::<Vec<_>>();
+    for path in &paths {
+        write_working_copy_file(&workspace_root, path, "contents\n");
+    }
+    test_workspace.snapshot().unwrap();
+    (test_workspace, paths)
+}
+
+fn bench_snapshot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_snapshot");
+    group.sample_size(10);
+    for count in [1000, 10000] {
+        let label = format!("{}k", count / 1000);
+        let (mut test_workspace, paths) = synthetic_workspace(count);
+        group.bench_function(BenchmarkId::new("full_scan", &label), |b| {
+            let options = SnapshotOptions::empty_for_test();
+            b.iter(|| test_workspace.snapshot_with_options(&options).unwrap());
+        });
+        // Simulates a filesystem monitor reporting a single changed file, which
+        // is the common case when snapshotting in a large working copy.
+        group.bench_function(BenchmarkId::new("fsmonitor", &label), |b| {
+            let changed_files = vec![PathBuf::from(paths[0].as_internal_file_string())];
+            let options = SnapshotOptions {
+                fsmonitor_settings: FsmonitorSettings::Test { changed_files },
+                ..SnapshotOptions::empty_for_test()
+            };
+            b.iter(|| test_workspace.snapshot_with_options(&options).unwrap());
+        });
+    }
+}
+
+criterion_group!(benches, bench_snapshot);
+criterion_main!(benches);
@@ -36,6 +36,7 @@ use crate::conflicts::materialize_merge_result_to_bytes;
 use crate::conflicts::materialize_tree_value;
 use crate::conflicts::ConflictMarkerStyle;
 use crate::conflicts::MaterializedTreeValue;
+use crate::copies::find_copy_source;
 use crate::diff::Diff;
 use crate::diff::DiffHunkKind;
 use crate::fileset::FilesetExpression;
@@ -44,6 +45,7 @@ use crate::graph::GraphEdgeType;
 use crate::merged_tree::MergedTree;
 use crate::repo::Repo;
 use crate::repo_path::RepoPath;
+use crate::repo_path::RepoPathBuf;
 use crate::revset::ResolvedRevsetExpression;
 use crate::revset::RevsetEvaluationError;
 use crate::revset::RevsetExpression;
@@ -147,12 +149,31 @@ impl Source {
 /// original file.
 type OriginalLineMap = Vec<Option<CommitId>>;
 
+/// Lines which originated in a commit that renamed (or copied) the file from
+/// another path. These lines may have originated earlier in the history of
+/// the source path.
+#[derive(Clone, Debug)]
+struct RenamedSource {
+    /// Parent commit in which the file existed at the source path.
+    parent: Commit,
+    /// Path of the file in the parent commit.
+    source_path: RepoPathBuf,
+    /// File content at the renaming commit.
+    text: BString,
+    /// Mapping of line numbers in `text` to the original file, sorted by the
+    /// line numbers in `text`.
+    line_map: Vec<(usize, usize)>,
+}
+
 /// Get line by line annotations for a specific file path in the repo.
 ///
 /// The `domain` expression narrows the range of ancestors to search. It will be
 /// intersected as `domain & ::starting_commit & files(file_path)`. The
 /// `starting_commit` is assumed to be included in the `domain`.
 ///
+/// If the file was renamed or copied from another path, and the backend
+/// records it as such, the history of the source path is followed too.
+///
 /// If the file is not found, returns empty results.
 pub fn get_annotation_for_file(
     repo: &dyn Repo,
@@ -161,7 +182,7 @@ pub fn get_annotation_for_file(
     file_path: &RepoPath,
 ) -> Result<FileAnnotation, RevsetEvaluationError> {
     let source = Source::load(starting_commit, file_path)?;
-    compute_file_annotation(repo, starting_commit.id(), domain, file_path, source)
+    compute_file_annotation(repo, starting_commit.id(), domain, file_path, source, true)
 }
 
 /// Get line by line annotations for a specific file path starting with the
@@ -170,7 +191,9 @@ pub fn get_annotation_for_file(
 /// The file content at the `starting_commit` is set to `starting_text`. This is
 /// typically one of the file contents in the conflict or merged-parent tree.
 ///
-/// See [`get_annotation_for_file()`] for the other arguments.
+/// Unlike [`get_annotation_for_file()`], renames aren't followed, so all
+/// returned commits have the file at `file_path`. See
+/// [`get_annotation_for_file()`] for the other arguments.
 pub fn get_annotation_with_file_content(
     repo: &dyn Repo,
     starting_commit_id: &CommitId,
@@ -179,7 +202,7 @@ pub fn get_annotation_with_file_content(
     starting_text: impl Into<Vec<u8>>,
 ) -> Result<FileAnnotation, RevsetEvaluationError> {
     let source = Source::new(BString::new(starting_text.into()));
-    compute_file_annotation(repo, starting_commit_id, domain, file_path, source)
+    compute_file_annotation(repo, starting_commit_id, domain, file_path, source, false)
 }
 
 fn compute_file_annotation(
@@ -188,10 +211,50 @@ fn compute_file_annotation(
     domain: &Rc<ResolvedRevsetExpression>,
     file_path: &RepoPath,
     mut source: Source,
+    follow_renames: bool,
 ) -> Result<FileAnnotation, RevsetEvaluationError> {
     source.fill_line_map();
     let text = source.text.clone();
-    let line_map = process_commits(repo, starting_commit_id, source, domain, file_path)?;
+    let mut renamed_sources = follow_renames.then(Vec::new);
+    let mut line_map = process_commits(
+        repo,
+        starting_commit_id,
+        source,
+        domain,
+        file_path,
+        renamed_sources.as_mut(),
+    )?;
+    for renamed in renamed_sources.into_iter().flatten() {
+        let parent_source = Source::load(&renamed.parent, &renamed.source_path)?;
+        let parent_annotation = compute_file_annotation(
+            repo,
+            renamed.parent.id(),
+            domain,
+            &renamed.source_path,
+            parent_source,
+            true,
+        )?;
+        // Lines which are unchanged since the rename originated in the
+        // history of the source path.
+        let mut renamed_lines = renamed.line_map.iter().copied().peekable();
+        copy_same_lines_with(
+            &renamed.text,
+            &parent_annotation.text,
+            |current_start, parent_start, count| {
+                renamed_lines
+                    .peeking_take_while(|&(cur, _)| cur < current_start)
+                    .for_each(drop);
+                while let Some((current, original)) =
+                    renamed_lines.next_if(|&(cur, _)| cur < current_start + count)
+                {
+                    let parent = parent_start + (current - current_start);
+                    if let Some(commit_id) = &parent_annotation.line_map[parent] {
+                        line_map[original] = Some(commit_id.clone());
+                    }
+                }
+            },
+        );
+    }
     Ok(FileAnnotation { line_map, text })
 }
 
@@ -204,6 +267,7 @@ fn process_commits(
     starting_source: Source,
     domain: &Rc<ResolvedRevsetExpression>,
     file_name: &RepoPath,
+    mut renamed_sources: Option<&mut Vec<RenamedSource>>,
 ) -> Result<OriginalLineMap, RevsetEvaluationError> {
     let predicate = RevsetFilterPredicate::File(FilesetExpression::file_path(file_name.to_owned()));
     // TODO: If the domain isn't a contiguous range, changes masked out by it
@@ -228,6 +292,7 @@ fn process_commits(
             &mut commit_source_map,
             &commit_id,
             &edge_list,
+            renamed_sources.as_deref_mut(),
         )?;
         if commit_source_map.is_empty() {
             // No more lines to propagate to ancestors.
@@ -247,6 +312,7 @@ fn process_commit(
     commit_source_map: &mut CommitSourceMap,
     current_commit_id: &CommitId,
     edges: &[GraphEdge<CommitId>],
+    renamed_sources: Option<&mut Vec<RenamedSource>>,
 ) -> Result<(), BackendError> {
     let Some(mut current_source) = commit_source_map.remove(current_commit_id) else {
         return Ok(());
@@ -305,10 +371,26 @@ fn process_commit(
     // Once we've looked at all parents of a commit, any leftover lines must be
     // original to the current commit, so we save this information in
     // original_line_map.
-    for (_, original_line_number) in current_source.line_map {
+    for &(_, original_line_number) in &current_source.line_map {
         original_line_map[original_line_number] = Some(current_commit_id.clone());
     }
 
+    // If the file was renamed in the current commit, the leftover lines may
+    // have originated in the history of the source path.
+    if let Some(renamed_sources) = renamed_sources {
+        if !current_source.line_map.is_empty() {
+            let current_commit = repo.store().get_commit(current_commit_id)?;
+            if let Some((parent, source_path)) = find_copy_source(&current_commit, file_name)? {
+                renamed_sources.push(RenamedSource {
+                    parent,
+                    source_path,
+                    text: current_source.text,
+                    line_map: current_source.line_map,
+                });
+            }
+        }
+    }
+
     Ok(())
 }
 
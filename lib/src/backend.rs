@@ -391,6 +391,16 @@ pub fn make_root_commit(root_change_id: ChangeId, empty_tree_id: TreeId) -> Comm
     }
 }
 
+/// Objects that garbage collection would remove.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GcStats {
+    pub commits: usize,
+    pub trees: usize,
+    pub blobs: usize,
+    /// Approximate number of bytes the objects take up.
+    pub bytes: u64,
+}
+
 /// Defines the interface for commit backends.
 #[async_trait]
 pub trait Backend: Send + Sync + Debug {
@@ -435,6 +445,17 @@ pub trait Backend: Send + Sync + Debug {
 
     async fn read_tree(&self, path: &RepoPath, id: &TreeId) -> BackendResult<Tree>;
 
+    /// Reads several trees at the same path. The returned trees are in the
+    /// same order as `ids`. Backends that can look up many objects at once
+    /// should override this; the default reads the trees one by one.
+    async fn read_trees(&self, path: &RepoPath, ids: &[TreeId]) -> BackendResult<Vec<Tree>> {
+        let mut trees = Vec::with_capacity(ids.len());
+        for id in ids {
+            trees.push(self.read_tree(path, id).await?);
+        }
+        Ok(trees)
+    }
+
     async fn write_tree(&self, path: &RepoPath, contents: &Tree) -> BackendResult<TreeId>;
 
     // Not async because it would force `MergedTree::value()` to be async. We don't
@@ -445,6 +466,16 @@ pub trait Backend: Send + Sync + Debug {
 
     async fn read_commit(&self, id: &CommitId) -> BackendResult<Commit>;
 
+    /// Reads several commits. The returned commits are in the same order as
+    /// `ids`. The default implementation reads the commits one by one.
+    async fn read_commits(&self, ids: &[CommitId]) -> BackendResult<Vec<Commit>> {
+        let mut commits = Vec::with_capacity(ids.len());
+        for id in ids {
+            commits.push(self.read_commit(id).await?);
+        }
+        Ok(commits)
+    }
+
     /// Writes a commit and returns its ID and the commit itself. The commit
     /// should contain the data that was actually written, which may differ
     /// from the data passed in. For example, the backend may change the
@@ -489,4 +520,11 @@ pub trait Backend: Send + Sync + Debug {
     /// objects created after `keep_newer` will be preserved. This mitigates a
     /// risk of deleting new commits created concurrently by another process.
     fn gc(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<()>;
+
+    /// Reports the objects `gc()` would remove, without removing anything.
+    fn gc_dry_run(&self, _index: &dyn Index, _keep_newer: SystemTime) -> BackendResult<GcStats> {
+        Err(BackendError::Unsupported(
+            "This backend doesn't support gc dry runs".to_owned(),
+        ))
+    }
 }
@@ -22,8 +22,6 @@ use std::hash::Hash;
 use std::hash::Hasher;
 use std::sync::Arc;
 
-use itertools::Itertools;
-
 use crate::backend;
 use crate::backend::BackendResult;
 use crate::backend::ChangeId;
@@ -119,7 +117,7 @@ impl Commit {
     /// Return the parent tree, merging the parent trees if there are multiple
     /// parents.
     pub fn parent_tree(&self, repo: &dyn Repo) -> BackendResult<MergedTree> {
-        let parents: Vec<_> = self.parents().try_collect()?;
+        let parents = self.store.get_commits(self.parent_ids())?;
         merge_commit_trees(repo, &parents)
     }
 
@@ -186,11 +184,7 @@ pub(crate) fn is_backend_commit_empty(
     if let [parent_id] = &*commit.parents {
         return Ok(commit.root_tree == *store.get_commit(parent_id)?.tree_id());
     }
-    let parents: Vec<_> = commit
-        .parents
-        .iter()
-        .map(|id| store.get_commit(id))
-        .try_collect()?;
+    let parents = store.get_commits(&commit.parents)?;
     let parent_tree = merge_commit_trees(repo, &parents)?;
     Ok(commit.root_tree == parent_tree.id())
 }
@@ -210,7 +204,7 @@ where
 
 /// Wrapper to sort `Commit` by committer timestamp.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub(crate) struct CommitByCommitterTimestamp(pub Commit);
+pub struct CommitByCommitterTimestamp(pub Commit);
 
 impl Ord for CommitByCommitterTimestamp {
     fn cmp(&self, other: &Self) -> Ordering {
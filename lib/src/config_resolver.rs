@@ -44,6 +44,8 @@ pub struct ConfigResolutionContext<'a> {
     pub home_dir: Option<&'a Path>,
     /// Repository path, which is usually `<workspace_root>/.jj/repo`.
     pub repo_path: Option<&'a Path>,
+    /// URLs of the remotes configured in the repository.
+    pub remote_urls: &'a [String],
 }
 
 /// Conditions to enable the parent table.
@@ -58,9 +60,28 @@ pub struct ConfigResolutionContext<'a> {
 struct ScopeCondition {
     /// Paths to match the repository path prefix.
     pub repositories: Option<Vec<PathBuf>>,
+    /// Glob patterns to match the repository's remote URLs.
+    #[serde(deserialize_with = "deserialize_glob_patterns")]
+    pub remotes: Option<Vec<glob::Pattern>>,
     // TODO: maybe add "workspaces"?
 }
 
+fn deserialize_glob_patterns<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<glob::Pattern>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(patterns) = Option::<Vec<String>>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    patterns
+        .iter()
+        .map(|pattern| glob::Pattern::new(pattern).map_err(serde::de::Error::custom))
+        .try_collect()
+        .map(Some)
+}
+
 impl ScopeCondition {
     fn from_value(
         value: ConfigValue,
@@ -85,6 +106,7 @@ impl ScopeCondition {
 
     fn matches(&self, context: &ConfigResolutionContext) -> bool {
         matches_path_prefix(self.repositories.as_deref(), context.repo_path)
+            && matches_any_url(self.remotes.as_deref(), context.remote_urls)
     }
 }
 
@@ -106,6 +128,15 @@ fn matches_path_prefix(candidates: Option<&[PathBuf]>, actual: Option<&Path>) ->
     }
 }
 
+fn matches_any_url(candidates: Option<&[glob::Pattern]>, actual: &[String]) -> bool {
+    match candidates {
+        Some(candidates) => candidates
+            .iter()
+            .any(|pattern| actual.iter().any(|url| pattern.matches(url))),
+        None => true, // no constraints
+    }
+}
+
 /// Evaluates condition for each layer and scope, flattens scoped tables.
 /// Returns new config that only contains enabled layers and tables.
 pub fn resolve(
@@ -406,11 +437,13 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: None,
+            remote_urls: &[],
         };
         assert!(condition.matches(&context));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new("/foo")),
+            remote_urls: &[],
         };
         assert!(condition.matches(&context));
     }
@@ -419,31 +452,37 @@ mod tests {
     fn test_condition_repo_path() {
         let condition = ScopeCondition {
             repositories: Some(["/foo", "/bar"].map(PathBuf::from).into()),
+            ..Default::default()
         };
 
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: None,
+            remote_urls: &[],
         };
         assert!(!condition.matches(&context));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new("/foo")),
+            remote_urls: &[],
         };
         assert!(condition.matches(&context));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new("/fooo")),
+            remote_urls: &[],
         };
         assert!(!condition.matches(&context));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new("/foo/baz")),
+            remote_urls: &[],
         };
         assert!(condition.matches(&context));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new("/bar")),
+            remote_urls: &[],
         };
         assert!(condition.matches(&context));
     }
@@ -452,26 +491,31 @@ mod tests {
     fn test_condition_repo_path_windows() {
         let condition = ScopeCondition {
             repositories: Some(["c:/foo", r"d:\bar/baz"].map(PathBuf::from).into()),
+            ..Default::default()
         };
 
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new(r"c:\foo")),
+            remote_urls: &[],
         };
         assert_eq!(condition.matches(&context), cfg!(windows));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new(r"c:\foo\baz")),
+            remote_urls: &[],
         };
         assert_eq!(condition.matches(&context), cfg!(windows));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new(r"d:\foo")),
+            remote_urls: &[],
         };
         assert!(!condition.matches(&context));
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: Some(Path::new(r"d:/bar\baz")),
+            remote_urls: &[],
         };
         assert_eq!(condition.matches(&context), cfg!(windows));
     }
@@ -489,6 +533,7 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: None,
+            remote_urls: &[],
         };
         let resolved_config = resolve(&source_config, &context).unwrap();
         assert_eq!(resolved_config.layers().len(), 2);
@@ -525,6 +570,7 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: None,
             repo_path: None,
+            remote_urls: &[],
         };
         let resolved_config = resolve(&source_config, &context).unwrap();
         assert_eq!(resolved_config.layers().len(), 7);
@@ -563,6 +609,7 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: Some(Path::new("/home/dir")),
             repo_path: None,
+            remote_urls: &[],
         };
         let resolved_config = resolve(&source_config, &context).unwrap();
         assert_eq!(resolved_config.layers().len(), 1);
@@ -571,6 +618,7 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: Some(Path::new("/home/dir")),
             repo_path: Some(Path::new("/foo/.jj/repo")),
+            remote_urls: &[],
         };
         let resolved_config = resolve(&source_config, &context).unwrap();
         assert_eq!(resolved_config.layers().len(), 3);
@@ -581,6 +629,7 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: Some(Path::new("/home/dir")),
             repo_path: Some(Path::new("/bar/.jj/repo")),
+            remote_urls: &[],
         };
         let resolved_config = resolve(&source_config, &context).unwrap();
         assert_eq!(resolved_config.layers().len(), 2);
@@ -590,6 +639,7 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: Some(Path::new("/home/dir")),
             repo_path: Some(Path::new("/home/dir/baz/.jj/repo")),
+            remote_urls: &[],
         };
         let resolved_config = resolve(&source_config, &context).unwrap();
         assert_eq!(resolved_config.layers().len(), 2);
@@ -597,6 +647,58 @@ mod tests {
         insta::assert_snapshot!(resolved_config.layers()[1].data, @"a = 'a #1 baz'");
     }
 
+    #[test]
+    fn test_resolve_remotes() {
+        let mut source_config = StackedConfig::empty();
+        source_config.add_layer(new_user_layer(indoc! {"
+            a = 'a #0'
+            [[--scope]]
+            --when.remotes = ['*github.com/work/*']
+            a = 'a #0.1 work'
+            [[--scope]]
+            --when.remotes = ['*github.com/work/*']
+            --when.repositories = ['/foo']
+            a = 'a #0.2 work&foo'
+            [[--scope]]
+            --when.remotes = []
+            a = 'a #0.3 none'
+        "}));
+
+        let context = ConfigResolutionContext {
+            home_dir: None,
+            repo_path: Some(Path::new("/foo/.jj/repo")),
+            remote_urls: &[],
+        };
+        let resolved_config = resolve(&source_config, &context).unwrap();
+        assert_eq!(resolved_config.layers().len(), 1);
+        insta::assert_snapshot!(resolved_config.layers()[0].data, @"a = 'a #0'");
+
+        let remote_urls = [
+            "https://example.com/repo.git".to_owned(),
+            "git@github.com/work/repo.git".to_owned(),
+        ];
+        let context = ConfigResolutionContext {
+            home_dir: None,
+            repo_path: Some(Path::new("/bar/.jj/repo")),
+            remote_urls: &remote_urls,
+        };
+        let resolved_config = resolve(&source_config, &context).unwrap();
+        assert_eq!(resolved_config.layers().len(), 2);
+        insta::assert_snapshot!(resolved_config.layers()[0].data, @"a = 'a #0'");
+        insta::assert_snapshot!(resolved_config.layers()[1].data, @"a = 'a #0.1 work'");
+
+        let context = ConfigResolutionContext {
+            home_dir: None,
+            repo_path: Some(Path::new("/foo/.jj/repo")),
+            remote_urls: &remote_urls,
+        };
+        let resolved_config = resolve(&source_config, &context).unwrap();
+        assert_eq!(resolved_config.layers().len(), 3);
+        insta::assert_snapshot!(resolved_config.layers()[0].data, @"a = 'a #0'");
+        insta::assert_snapshot!(resolved_config.layers()[1].data, @"a = 'a #0.1 work'");
+        insta::assert_snapshot!(resolved_config.layers()[2].data, @"a = 'a #0.2 work&foo'");
+    }
+
     #[test]
     fn test_resolve_invalid_condition() {
         let new_config = |text: &str| {
@@ -607,11 +709,16 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: Some(Path::new("/home/dir")),
             repo_path: Some(Path::new("/foo/.jj/repo")),
+            remote_urls: &[],
         };
         assert_matches!(
             resolve(&new_config("--when.repositories = 0"), &context),
             Err(ConfigGetError::Type { .. })
         );
+        assert_matches!(
+            resolve(&new_config("--when.remotes = ['[']"), &context),
+            Err(ConfigGetError::Type { .. })
+        );
     }
 
     #[test]
@@ -624,6 +731,7 @@ mod tests {
         let context = ConfigResolutionContext {
             home_dir: Some(Path::new("/home/dir")),
             repo_path: Some(Path::new("/foo/.jj/repo")),
+            remote_urls: &[],
         };
         assert_matches!(
             resolve(&new_config("[--scope]"), &context),
@@ -15,20 +15,31 @@
 //! Code for working with copies and renames.
 
 use std::collections::HashMap;
+use std::io::Read as _;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 
+use futures::executor::block_on_stream;
 use futures::Stream;
 
+use crate::backend::BackendError;
 use crate::backend::BackendResult;
+use crate::backend::CommitId;
 use crate::backend::CopyRecord;
+use crate::backend::FileId;
+use crate::backend::TreeValue;
+use crate::commit::Commit;
+use crate::matchers::EverythingMatcher;
+use crate::matchers::Matcher;
 use crate::merge::MergedTreeValue;
 use crate::merged_tree::MergedTree;
 use crate::merged_tree::TreeDiffStream;
 use crate::repo_path::RepoPath;
 use crate::repo_path::RepoPathBuf;
+use crate::store::Store;
 
 /// A collection of CopyRecords.
 #[derive(Default, Debug)]
@@ -214,3 +225,216 @@ impl Stream for CopiesTreeDiffStream<'_> {
         Poll::Ready(None)
     }
 }
+
+/// Returns the parent commit and the path the file at `path` was renamed or
+/// copied from in `commit`, if the file was added in `commit` and the backend
+/// recorded its source.
+pub fn find_copy_source(
+    commit: &Commit,
+    path: &RepoPath,
+) -> BackendResult<Option<(Commit, RepoPathBuf)>> {
+    let store = commit.store();
+    for parent in commit.parents() {
+        let parent = parent?;
+        if parent.tree()?.path_value(path)?.is_present() {
+            // Not added in this commit
+            return Ok(None);
+        }
+        let paths = [path.to_owned()];
+        let records = store.get_copy_records(Some(&paths), parent.id(), commit.id())?;
+        for record in block_on_stream(records) {
+            let record = record?;
+            if *record.target == *path && *record.source != *path {
+                return Ok(Some((parent, record.source)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Options for detecting copies and renames by comparing file contents.
+///
+/// If neither threshold is set, copies and renames recorded by the backend
+/// should be used instead.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CopyDetectionOptions {
+    /// Minimum similarity, in percent, for a deleted file to be detected as
+    /// the source of an added file.
+    pub rename_threshold: Option<u8>,
+    /// Minimum similarity, in percent, for a modified or deleted file to be
+    /// detected as the source of a copy.
+    pub copy_threshold: Option<u8>,
+}
+
+impl CopyDetectionOptions {
+    /// Returns true if copies or renames should be detected.
+    pub fn is_enabled(&self) -> bool {
+        self.rename_threshold.is_some() || self.copy_threshold.is_some()
+    }
+}
+
+/// Maximum number of source/target pairs whose contents are compared. Beyond
+/// that, only identical files are detected as copies.
+const MAX_SIMILARITY_PAIRS: usize = 1000 * 1000;
+
+/// Returns how similar the contents `a` and `b` are, in percent.
+///
+/// The similarity is the size of the lines the files have in common, relative
+/// to the size of the larger file. Only identical contents are 100% similar.
+pub fn similarity(a: &[u8], b: &[u8]) -> u8 {
+    if a == b {
+        return 100;
+    }
+    let mut line_counts: HashMap<&[u8], usize> = HashMap::new();
+    for line in a.split_inclusive(|&b| b == b'\n') {
+        *line_counts.entry(line).or_default() += 1;
+    }
+    let mut common_size = 0;
+    for line in b.split_inclusive(|&b| b == b'\n') {
+        if let Some(count) = line_counts.get_mut(line).filter(|count| **count > 0) {
+            *count -= 1;
+            common_size += line.len();
+        }
+    }
+    let max_size = a.len().max(b.len());
+    (common_size * 100 / max_size).min(99) as u8
+}
+
+/// Detects files added between the `root` and `head` commits which were
+/// renamed or copied from other files, by comparing file contents.
+///
+/// Only added files matching the `matcher` are considered as targets. Any
+/// deleted file may be the source of a rename, and if `copy_threshold` is set,
+/// any modified or deleted file may be the source of a copy. Each added file
+/// gets the most similar source above the threshold.
+pub fn detect_copies(
+    store: &Arc<Store>,
+    root: &CommitId,
+    head: &CommitId,
+    matcher: &dyn Matcher,
+    options: &CopyDetectionOptions,
+) -> BackendResult<Vec<CopyRecord>> {
+    if !options.is_enabled() {
+        return Ok(vec![]);
+    }
+    // Detecting copies implies detecting renames
+    let rename_threshold = options.rename_threshold.or(options.copy_threshold);
+    let copy_threshold = options.copy_threshold;
+
+    let root_tree = store.get_commit(root)?.tree()?;
+    let head_tree = store.get_commit(head)?.tree()?;
+    let mut targets = vec![];
+    // (path, file id, deleted)
+    let mut sources = vec![];
+    for entry in block_on_stream(root_tree.diff_stream(&head_tree, &EverythingMatcher)) {
+        let (before, after) = entry.values?;
+        let before_id = as_file_id(&before);
+        let after_id = as_file_id(&after);
+        match (before_id, after_id) {
+            (None, Some(id)) if before.is_absent() && matcher.matches(&entry.path) => {
+                targets.push((entry.path, id.clone()));
+            }
+            (Some(id), None) if after.is_absent() => {
+                sources.push((entry.path, id.clone(), true));
+            }
+            (Some(id), Some(_)) if copy_threshold.is_some() => {
+                sources.push((entry.path, id.clone(), false));
+            }
+            _ => {}
+        }
+    }
+    if targets.is_empty() || sources.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let compare_contents = targets.len() * sources.len() <= MAX_SIMILARITY_PAIRS;
+    let mut contents: HashMap<FileId, Arc<[u8]>> = HashMap::new();
+    let mut read_file = |path: &RepoPath, id: &FileId| -> BackendResult<Arc<[u8]>> {
+        if let Some(content) = contents.get(id) {
+            return Ok(content.clone());
+        }
+        let mut content = vec![];
+        store
+            .read_file(path, id)?
+            .read_to_end(&mut content)
+            .map_err(|err| BackendError::ReadFile {
+                path: path.to_owned(),
+                id: id.clone(),
+                source: err.into(),
+            })?;
+        let content: Arc<[u8]> = content.into();
+        contents.insert(id.clone(), content.clone());
+        Ok(content)
+    };
+
+    let mut renamed_sources = vec![false; sources.len()];
+    let mut records = vec![];
+    for (target, target_id) in targets {
+        let target_content = if compare_contents {
+            Some(read_file(&target, &target_id)?)
+        } else {
+            None
+        };
+        let mut best: Option<(usize, u8)> = None;
+        for (index, (source, source_id, deleted)) in sources.iter().enumerate() {
+            // A deleted file can only be renamed once. Further uses are copies.
+            let threshold = if *deleted && !renamed_sources[index] {
+                rename_threshold
+            } else {
+                copy_threshold
+            };
+            let Some(threshold) = threshold else {
+                continue;
+            };
+            let score = if *source_id == target_id {
+                100
+            } else if let Some(target_content) = &target_content {
+                similarity(&read_file(source, source_id)?, target_content)
+            } else {
+                continue;
+            };
+            if score >= threshold && best.map_or(true, |(_, best_score)| score > best_score) {
+                best = Some((index, score));
+            }
+        }
+        if let Some((index, _)) = best {
+            let (source, source_id, deleted) = &sources[index];
+            if *deleted {
+                renamed_sources[index] = true;
+            }
+            records.push(CopyRecord {
+                target,
+                target_commit: head.clone(),
+                source: source.clone(),
+                source_file: source_id.clone(),
+                source_commit: root.clone(),
+            });
+        }
+    }
+    Ok(records)
+}
+
+fn as_file_id(value: &MergedTreeValue) -> Option<&FileId> {
+    match value.as_normal() {
+        Some(TreeValue::File { id, .. }) => Some(id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity(b"", b""), 100);
+        assert_eq!(similarity(b"a\nb\n", b"a\nb\n"), 100);
+        assert_eq!(similarity(b"a\nb\n", b"c\nd\n"), 0);
+        assert_eq!(similarity(b"a\nb\nc\nd\n", b"a\nb\nc\nx\n"), 75);
+        assert_eq!(similarity(b"a\nb\n", b"a\nb\nc\nd\n"), 50);
+        // Lines are counted as many times as they appear in both files
+        assert_eq!(similarity(b"a\na\nb\n", b"a\nb\nb\n"), 66);
+        // Identical lines in a different order
+        assert_eq!(similarity(b"a\nb\n", b"b\na\n"), 99);
+    }
+}
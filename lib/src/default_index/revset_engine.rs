@@ -1185,6 +1185,28 @@ fn build_predicate_fn(
                 Ok(pattern.matches(&commit.author().email))
             })
         }
+        RevsetFilterPredicate::MailmapAuthorName(mailmap, pattern) => {
+            let mailmap = mailmap.clone();
+            let pattern = pattern.clone();
+            box_pure_predicate_fn(move |index, pos| {
+                let entry = index.entry_by_pos(pos);
+                let commit = store.get_commit(&entry.commit_id())?;
+                let author = commit.author();
+                let (name, _) = mailmap.resolve(&author.name, &author.email);
+                Ok(pattern.matches(name))
+            })
+        }
+        RevsetFilterPredicate::MailmapAuthorEmail(mailmap, pattern) => {
+            let mailmap = mailmap.clone();
+            let pattern = pattern.clone();
+            box_pure_predicate_fn(move |index, pos| {
+                let entry = index.entry_by_pos(pos);
+                let commit = store.get_commit(&entry.commit_id())?;
+                let author = commit.author();
+                let (_, email) = mailmap.resolve(&author.name, &author.email);
+                Ok(pattern.matches(email))
+            })
+        }
         RevsetFilterPredicate::AuthorDate(expression) => {
             let expression = *expression;
             box_pure_predicate_fn(move |index, pos| {
@@ -1264,7 +1286,7 @@ fn has_diff_from_parent(
     commit: &Commit,
     matcher: &dyn Matcher,
 ) -> BackendResult<bool> {
-    let parents: Vec<_> = commit.parents().try_collect()?;
+    let parents = store.get_commits(commit.parent_ids())?;
     if let [parent] = parents.as_slice() {
         // Fast path: no need to load the root tree
         let unchanged = commit.tree_id() == parent.tree_id();
@@ -1302,7 +1324,7 @@ fn matches_diff_from_parent(
     text_pattern: &StringPattern,
     files_matcher: &dyn Matcher,
 ) -> BackendResult<bool> {
-    let parents: Vec<_> = commit.parents().try_collect()?;
+    let parents = store.get_commits(commit.parent_ids())?;
     // Conflict resolution is expensive, try that only for matched files.
     let from_tree = rewrite::merge_commit_trees_no_resolve_without_repo(store, &index, &parents)?;
     let to_tree = commit.tree()?;
@@ -86,6 +86,8 @@ pub enum DefaultIndexStoreError {
     },
     #[error(transparent)]
     OpStore(#[from] OpStoreError),
+    #[error(transparent)]
+    Path(#[from] PathError),
 }
 
 #[derive(Debug)]
@@ -133,6 +135,49 @@ impl DefaultIndexStore {
         Ok(())
     }
 
+    /// Rebuilds index for the given `operation` from scratch.
+    ///
+    /// Unlike `reinit()`, existing index segments are removed only after the
+    /// new index is saved. Processes reading the index concurrently will either
+    /// load the old segments or build the index by themselves.
+    pub fn rebuild_index_at_operation(
+        &self,
+        operation: &Operation,
+        store: &Arc<Store>,
+    ) -> Result<DefaultReadonlyIndex, DefaultIndexStoreError> {
+        self.ensure_base_dirs()?;
+        // Remove all operation links so the new index won't be built on top
+        // of the existing segments.
+        file_util::remove_dir_contents(&self.operations_dir())?;
+        let index_segment = self.build_index_segments_at_operation(operation, store)?;
+        let used_names: HashSet<String> = index_segment
+            .as_composite()
+            .ancestor_index_segments()
+            .filter_map(|segment| segment.name())
+            .collect();
+        // Remove unused segments to save disk space. If raced, the other
+        // process may have to rebuild the index.
+        let segments_dir = self.segments_dir();
+        for entry in segments_dir.read_dir().context(&segments_dir)? {
+            let entry = entry.context(&segments_dir)?;
+            let path = entry.path();
+            // Skip temporary files which may be being written by the other
+            // process.
+            if entry.file_name().to_str().is_some_and(|name| {
+                name.len() == SEGMENT_FILE_NAME_LENGTH && !used_names.contains(name)
+            }) {
+                fs::remove_file(&path).context(&path)?;
+            }
+        }
+        Ok(DefaultReadonlyIndex::from_segment(index_segment))
+    }
+
+    /// Returns the size of the index segment file of the given name.
+    pub fn segment_file_size(&self, name: &str) -> io::Result<u64> {
+        let metadata = self.segments_dir().join(name).metadata()?;
+        Ok(metadata.len())
+    }
+
     fn ensure_base_dirs(&self) -> Result<(), PathError> {
         for dir in [self.operations_dir(), self.segments_dir()] {
             file_util::create_or_reuse_dir(&dir).context(&dir)?;
@@ -832,6 +832,30 @@ impl<'input> Diff<'input> {
         self.compact_unchanged_regions();
     }
 
+    /// Merges changed regions that consist only of whitespace on all sides
+    /// into the surrounding unchanged regions.
+    ///
+    /// When applied to a line diff, changes of blank lines are ignored. The
+    /// resulting matching hunks may have different contents on each side.
+    pub fn ignore_blank_changes(&mut self) {
+        let mut new_unchanged_ranges = vec![self.unchanged_regions[0].clone()];
+        for current in &self.unchanged_regions[1..] {
+            let previous = new_unchanged_ranges.last_mut().unwrap();
+            if self
+                .hunk_between(previous, current)
+                .all(|content| content.iter().all(|b| b.is_ascii_whitespace()))
+            {
+                previous.base.end = current.base.end;
+                for (prev, cur) in iter::zip(&mut previous.others, &current.others) {
+                    prev.end = cur.end;
+                }
+            } else {
+                new_unchanged_ranges.push(current.clone());
+            }
+        }
+        self.unchanged_regions = new_unchanged_ranges;
+    }
+
     fn compact_unchanged_regions(&mut self) {
         let mut compacted = vec![];
         let mut maybe_previous: Option<UnchangedRange> = None;
@@ -1549,6 +1573,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_diff_ignore_blank_changes() {
+        fn diff(inputs: [&str; 2]) -> Vec<DiffHunk<'_>> {
+            let mut diff = Diff::by_line(inputs);
+            diff.ignore_blank_changes();
+            diff.hunks().collect()
+        }
+
+        assert_eq!(diff(["", "\n"]), vec![DiffHunk::matching(["", "\n"])]);
+        assert_eq!(
+            diff(["a\nb\n", "a\n\n \nb\n"]),
+            vec![DiffHunk::matching(["a\nb\n", "a\n\n \nb\n"])]
+        );
+        assert_eq!(
+            diff(["a\n\nb\n", "a\nb\nc\n"]),
+            vec![
+                DiffHunk::matching(["a\n\nb\n", "a\nb\n"]),
+                DiffHunk::different(["", "c\n"]),
+            ]
+        );
+        // Changes mixing blank and non-blank lines are kept
+        assert_eq!(
+            diff(["a\nb\n", "a\n\nc\n"]),
+            vec![
+                DiffHunk::matching(["a\n"].repeat(2)),
+                DiffHunk::different(["b\n", "\nc\n"]),
+            ]
+        );
+    }
+
     #[test]
     fn test_diff_hunk_iterator() {
         let diff = Diff::by_word(["a b c", "a XX c", "a b "]);
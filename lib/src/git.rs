@@ -33,8 +33,10 @@ use thiserror::Error;
 use crate::backend::BackendError;
 use crate::backend::BackendResult;
 use crate::backend::CommitId;
+use crate::backend::Signature;
 use crate::backend::TreeValue;
 use crate::commit::Commit;
+use crate::git_backend::signature_to_git;
 use crate::git_backend::GitBackend;
 use crate::index::Index;
 use crate::merged_tree::MergedTree;
@@ -99,6 +101,19 @@ pub fn parse_git_ref(ref_name: &str) -> Option<RefName> {
     }
 }
 
+/// Returns the remote name if `ref_name` is the `HEAD` ref of a remote, such as
+/// `refs/remotes/origin/HEAD`.
+///
+/// Remote `HEAD` refs aren't remote-tracking branches, but they are recorded
+/// in the view's Git refs to show which branch the remote considers the
+/// default.
+pub fn parse_git_remote_head_ref(ref_name: &str) -> Option<&str> {
+    ref_name
+        .strip_prefix("refs/remotes/")?
+        .strip_suffix("/HEAD")
+        .filter(|remote| !remote.is_empty() && !remote.contains('/'))
+}
+
 fn to_git_ref_name(parsed_ref: &RefName) -> Option<String> {
     match parsed_ref {
         RefName::LocalBranch(branch) => {
@@ -409,7 +424,9 @@ fn diff_refs_to_import(
         .iter()
         .filter_map(|(full_name, target)| {
             // TODO: or clean up invalid ref in case it was stored due to historical bug?
-            let ref_name = parse_git_ref(full_name).expect("stored git ref should be parsable");
+            let ref_name = parse_git_ref(full_name)
+                .or_else(|| remote_head_ref_name(full_name))
+                .expect("stored git ref should be parsable");
             git_ref_filter(&ref_name).then_some((full_name.as_ref(), target))
         })
         .collect();
@@ -458,7 +475,21 @@ fn diff_refs_to_import(
             continue;
         };
         let Some(ref_name) = parse_git_ref(full_name) else {
-            // Skip other refs (such as notes) and symbolic refs.
+            // Remote HEAD refs are recorded as Git refs only. Skip other refs
+            // (such as notes) and symbolic refs.
+            if let Some(ref_name) = remote_head_ref_name(full_name) {
+                if !git_ref_filter(&ref_name) {
+                    continue;
+                }
+                let old_git_target = known_git_refs.remove(full_name).flatten();
+                let Some(id) = resolve_git_ref_to_commit_id(&git_ref, old_git_target) else {
+                    continue;
+                };
+                let new_target = RefTarget::normal(id);
+                if new_target != *old_git_target {
+                    changed_git_refs.push((full_name.to_owned(), new_target));
+                }
+            }
             continue;
         };
         if !git_ref_filter(&ref_name) {
@@ -506,6 +537,17 @@ fn diff_refs_to_import(
     })
 }
 
+/// Ref name used to filter the `HEAD` ref of a remote.
+///
+/// It's treated as a remote branch named `HEAD` so that fetching all branches
+/// of a remote also updates its `HEAD`.
+fn remote_head_ref_name(full_name: &str) -> Option<RefName> {
+    parse_git_remote_head_ref(full_name).map(|remote| RefName::RemoteBranch {
+        branch: "HEAD".to_owned(),
+        remote: remote.to_owned(),
+    })
+}
+
 fn default_remote_ref_state_for(ref_name: &RefName, git_settings: &GitSettings) -> RemoteRefState {
     match ref_name {
         // LocalBranch means Git-tracking branch
@@ -815,6 +857,8 @@ fn diff_refs_to_export(
     let known_git_refs = view
         .git_refs()
         .iter()
+        // Remote HEAD refs are never exported.
+        .filter(|(full_name, _)| parse_git_remote_head_ref(full_name).is_none())
         .map(|(full_name, target)| {
             let ref_name = parse_git_ref(full_name).expect("stored git ref should be parsable");
             (ref_name, target)
@@ -879,6 +923,102 @@ fn diff_refs_to_export(
     }
 }
 
+/// Error that may occur while creating or deleting a tag.
+#[derive(Debug, Error)]
+pub enum GitTagError {
+    #[error("Tag already exists: {0}")]
+    AlreadyExists(String),
+    #[error("No such tag: {0}")]
+    NotFound(String),
+    #[error("Failed to update tag {name}")]
+    FailedToUpdate {
+        name: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("The repo is not backed by a Git repo")]
+    UnexpectedBackend,
+}
+
+/// Creates a tag pointing to `target` in both the underlying Git repo and the
+/// view.
+///
+/// If `annotation` is specified, an annotated tag object is created with the
+/// given message and tagger. Otherwise, a lightweight tag is created. Tags are
+/// immutable, so an existing tag is never overwritten.
+pub fn create_tag(
+    mut_repo: &mut MutableRepo,
+    name: &str,
+    target: &CommitId,
+    annotation: Option<(&str, &Signature)>,
+) -> Result<(), GitTagError> {
+    let git_repo = get_git_repo(mut_repo.store()).ok_or(GitTagError::UnexpectedBackend)?;
+    if mut_repo.get_tag(name).is_present() {
+        return Err(GitTagError::AlreadyExists(name.to_owned()));
+    }
+    let to_update_error =
+        |source: Box<dyn std::error::Error + Send + Sync>| GitTagError::FailedToUpdate {
+            name: name.to_owned(),
+            source,
+        };
+    let git_ref_name = format!("refs/tags/{name}");
+    if git_repo
+        .try_find_reference(&git_ref_name)
+        .ok()
+        .flatten()
+        .is_some()
+    {
+        return Err(GitTagError::AlreadyExists(name.to_owned()));
+    }
+    let target_oid = gix::ObjectId::from_bytes_or_panic(target.as_bytes());
+    let constraint = gix::refs::transaction::PreviousValue::MustNotExist;
+    if let Some((message, tagger)) = annotation {
+        let mut message = message.to_owned();
+        if !message.is_empty() && !message.ends_with('\n') {
+            message.push('\n');
+        }
+        git_repo
+            .tag(
+                name,
+                target_oid,
+                gix::object::Kind::Commit,
+                Some(signature_to_git(tagger)),
+                message,
+                constraint,
+            )
+            .map_err(|err| to_update_error(err.into()))?;
+    } else {
+        git_repo
+            .tag_reference(name, target_oid, constraint)
+            .map_err(|err| to_update_error(err.into()))?;
+    }
+    // The Git ref is recorded with the peeled target, like imported tags.
+    let new_target = RefTarget::normal(target.clone());
+    mut_repo.set_git_ref_target(&git_ref_name, new_target.clone());
+    mut_repo.set_tag_target(name, new_target);
+    Ok(())
+}
+
+/// Deletes a tag from both the underlying Git repo and the view.
+pub fn delete_tag(mut_repo: &mut MutableRepo, name: &str) -> Result<(), GitTagError> {
+    let git_repo = get_git_repo(mut_repo.store()).ok_or(GitTagError::UnexpectedBackend)?;
+    if mut_repo.get_tag(name).is_absent() {
+        return Err(GitTagError::NotFound(name.to_owned()));
+    }
+    let git_ref_name = format!("refs/tags/{name}");
+    if let Some(git_ref) = git_repo.try_find_reference(&git_ref_name).ok().flatten() {
+        git_ref
+            .delete()
+            .map_err(|err| GitTagError::FailedToUpdate {
+                name: name.to_owned(),
+                source: err.into(),
+            })?;
+    }
+    mut_repo.set_git_ref_target(&git_ref_name, RefTarget::absent());
+    mut_repo.set_tag_target(name, RefTarget::absent());
+    Ok(())
+}
+
 fn delete_git_ref(
     git_repo: &gix::Repository,
     git_ref_name: &str,
@@ -1377,6 +1517,14 @@ pub enum GitFetchError {
     InternalGitError(#[from] git2::Error),
 }
 
+impl GitFetchError {
+    /// Returns true if the fetch failed due to a transient network error and
+    /// may succeed if retried.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, GitFetchError::InternalGitError(err) if is_transient_network_error(err))
+    }
+}
+
 fn fetch_options(
     callbacks: RemoteCallbacks<'_>,
     depth: Option<NonZeroU32>,
@@ -1586,6 +1734,32 @@ pub enum GitPushError {
     InternalGitError(#[from] git2::Error),
 }
 
+impl GitPushError {
+    /// Returns true if the push failed due to a transient network error and
+    /// may succeed if retried. Rejected updates are never transient.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, GitPushError::InternalGitError(err) if is_transient_network_error(err))
+    }
+}
+
+/// Returns true if the `err` is likely caused by a transient network failure,
+/// such as a timeout or a reset connection.
+///
+/// Authentication and certificate errors, and rejected updates aren't
+/// transient.
+pub fn is_transient_network_error(err: &git2::Error) -> bool {
+    match err.code() {
+        git2::ErrorCode::Auth | git2::ErrorCode::Certificate | git2::ErrorCode::NotFastForward => {
+            false
+        }
+        git2::ErrorCode::Timeout | git2::ErrorCode::Eof => true,
+        _ => matches!(
+            err.class(),
+            git2::ErrorClass::Net | git2::ErrorClass::Os | git2::ErrorClass::Ssh
+        ),
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct GitBranchPushTargets {
     pub branch_updates: Vec<(String, BookmarkPushUpdate)>,
@@ -1675,6 +1849,111 @@ pub fn push_updates(
     )
 }
 
+/// Lists the names of the tags advertised by the remote.
+pub fn list_remote_tags(
+    git_repo: &git2::Repository,
+    remote_name: &str,
+    callbacks: RemoteCallbacks<'_>,
+) -> Result<Vec<String>, GitPushError> {
+    if remote_name == REMOTE_NAME_FOR_LOCAL_GIT_REPO {
+        return Err(GitPushError::RemoteReservedForLocalGitRepo);
+    }
+    let mut remote = git_repo.find_remote(remote_name).map_err(|err| {
+        if is_remote_not_found_err(&err) {
+            GitPushError::NoSuchRemote(remote_name.to_string())
+        } else {
+            GitPushError::InternalGitError(err)
+        }
+    })?;
+    let mut proxy_options = git2::ProxyOptions::new();
+    proxy_options.auto();
+    let connection = remote.connect_auth(
+        git2::Direction::Push,
+        Some(callbacks.into_git()),
+        Some(proxy_options),
+    )?;
+    let tag_names = connection
+        .list()?
+        .iter()
+        .filter_map(|head| head.name().strip_prefix("refs/tags/"))
+        // Skip the peeled entries of annotated tags
+        .filter(|name| !name.ends_with("^{}"))
+        .map(|name| name.to_owned())
+        .collect();
+    Ok(tag_names)
+}
+
+/// Pushes the specified local tags without updating the repo view.
+///
+/// Tags are never force-pushed, so the remote rejects tags which already exist
+/// there with a different target.
+pub fn push_tags(
+    git_repo: &git2::Repository,
+    remote_name: &str,
+    tag_names: &[String],
+    callbacks: RemoteCallbacks<'_>,
+) -> Result<(), GitPushError> {
+    if remote_name == REMOTE_NAME_FOR_LOCAL_GIT_REPO {
+        return Err(GitPushError::RemoteReservedForLocalGitRepo);
+    }
+    let mut remote = git_repo.find_remote(remote_name).map_err(|err| {
+        if is_remote_not_found_err(&err) {
+            GitPushError::NoSuchRemote(remote_name.to_string())
+        } else {
+            GitPushError::InternalGitError(err)
+        }
+    })?;
+    let refspecs = tag_names
+        .iter()
+        .map(|name| format!("refs/tags/{name}:refs/tags/{name}"))
+        .collect_vec();
+    let mut existing_refs = vec![];
+    let mut rejected_refs = vec![];
+    let push_result = {
+        let mut push_options = git2::PushOptions::new();
+        let mut proxy_options = git2::ProxyOptions::new();
+        proxy_options.auto();
+        push_options.proxy_options(proxy_options);
+        let mut callbacks = callbacks.into_git();
+        callbacks.push_negotiation(|updates| {
+            // Tags which already exist on the remote must not be moved.
+            for update in updates {
+                if !update.src().is_zero() && update.src() != update.dst() {
+                    let dst_refname = update
+                        .dst_refname()
+                        .expect("Expect reference name to be valid UTF-8");
+                    existing_refs.push(dst_refname.to_owned());
+                }
+            }
+            if existing_refs.is_empty() {
+                Ok(())
+            } else {
+                Err(git2::Error::from_str("tags already exist on the remote"))
+            }
+        });
+        callbacks.push_update_reference(|refname, status| {
+            // The status is Some if the ref update was rejected
+            if status.is_some() {
+                rejected_refs.push(refname.to_owned());
+            }
+            Ok(())
+        });
+        push_options.remote_callbacks(callbacks);
+        remote.push(&refspecs, Some(&mut push_options))
+    };
+    if !existing_refs.is_empty() {
+        existing_refs.sort();
+        return Err(GitPushError::RefUpdateRejected(existing_refs));
+    }
+    push_result?;
+    if rejected_refs.is_empty() {
+        Ok(())
+    } else {
+        rejected_refs.sort();
+        Err(GitPushError::RefUpdateRejected(rejected_refs))
+    }
+}
+
 fn push_refs(
     repo: &dyn Repo,
     git_repo: &git2::Repository,
@@ -1879,6 +2158,8 @@ impl<'a> RemoteCallbacks<'a> {
                 progress_cb(&Progress {
                     bytes_downloaded: (progress.received_objects() < progress.total_objects())
                         .then(|| progress.received_bytes() as u64),
+                    received_objects: progress.received_objects(),
+                    total_objects: progress.total_objects(),
                     overall: (progress.indexed_objects() + progress.indexed_deltas()) as f32
                         / (progress.total_objects() + progress.total_deltas()) as f32,
                 });
@@ -1971,6 +2252,10 @@ impl<'a> RemoteCallbacks<'a> {
 pub struct Progress {
     /// `Some` iff data transfer is currently in progress
     pub bytes_downloaded: Option<u64>,
+    /// Number of objects received so far
+    pub received_objects: usize,
+    /// Total number of objects to receive, or 0 if unknown
+    pub total_objects: usize,
     pub overall: f32,
 }
 
@@ -1980,6 +2265,32 @@ struct PartialSubmoduleConfig {
     url: Option<String>,
 }
 
+/// Fetches all branches and tags from `url` into the repository of a checked
+/// out submodule.
+///
+/// This is used to make the commit recorded for the submodule available
+/// locally.
+pub fn fetch_submodule(
+    submodule_repo: &git2::Repository,
+    url: &str,
+    callbacks: RemoteCallbacks<'_>,
+) -> Result<(), git2::Error> {
+    let mut remote = submodule_repo.remote_anonymous(url)?;
+    let mut proxy_options = git2::ProxyOptions::new();
+    proxy_options.auto();
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options.proxy_options(proxy_options);
+    fetch_options.remote_callbacks(callbacks.into_git());
+    remote.fetch(
+        &[
+            "+refs/heads/*:refs/remotes/origin/*",
+            "+refs/tags/*:refs/tags/*",
+        ],
+        Some(&mut fetch_options),
+        None,
+    )
+}
+
 /// Represents configuration from a submodule, e.g. in .gitmodules
 /// This doesn't include all possible fields, only the ones we care about
 #[derive(Debug, PartialEq, Eq)]
@@ -23,10 +23,12 @@ use std::fs;
 use std::io;
 use std::io::Cursor;
 use std::io::Read;
+use std::iter;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
 use std::process::ExitStatus;
+use std::slice;
 use std::str;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -59,6 +61,7 @@ use crate::backend::ConflictId;
 use crate::backend::ConflictTerm;
 use crate::backend::CopyRecord;
 use crate::backend::FileId;
+use crate::backend::GcStats;
 use crate::backend::MergedTreeId;
 use crate::backend::MillisSinceEpoch;
 use crate::backend::SecureSig;
@@ -69,8 +72,10 @@ use crate::backend::Timestamp;
 use crate::backend::Tree;
 use crate::backend::TreeId;
 use crate::backend::TreeValue;
+use crate::config::ConfigGetError;
 use crate::file_util::IoResultExt as _;
 use crate::file_util::PathError;
+use crate::hex_util;
 use crate::index::Index;
 use crate::lock::FileLock;
 use crate::merge::Merge;
@@ -101,6 +106,8 @@ pub enum GitBackendInitError {
     #[error("Failed to open git repository")]
     OpenRepository(#[source] gix::open::Error),
     #[error(transparent)]
+    Config(ConfigGetError),
+    #[error(transparent)]
     Path(PathError),
 }
 
@@ -115,6 +122,8 @@ pub enum GitBackendLoadError {
     #[error("Failed to open git repository")]
     OpenRepository(#[source] gix::open::Error),
     #[error(transparent)]
+    Config(ConfigGetError),
+    #[error(transparent)]
     Path(PathError),
 }
 
@@ -159,6 +168,9 @@ pub struct GitBackend {
     empty_tree_id: TreeId,
     extra_metadata_store: TableStore,
     cached_extra_metadata: Mutex<Option<Arc<ReadonlyTable>>>,
+    /// Whether the change id is recorded in (and restored from) a `Change-Id`
+    /// trailer of the commit message.
+    change_id_trailer: bool,
 }
 
 impl GitBackend {
@@ -166,7 +178,11 @@ impl GitBackend {
         "git"
     }
 
-    fn new(base_repo: gix::ThreadSafeRepository, extra_metadata_store: TableStore) -> Self {
+    fn new(
+        base_repo: gix::ThreadSafeRepository,
+        extra_metadata_store: TableStore,
+        change_id_trailer: bool,
+    ) -> Self {
         let repo = Mutex::new(base_repo.to_thread_local());
         let root_commit_id = CommitId::from_bytes(&[0; HASH_LENGTH]);
         let root_change_id = ChangeId::from_bytes(&[0; CHANGE_ID_LENGTH]);
@@ -179,6 +195,7 @@ impl GitBackend {
             empty_tree_id,
             extra_metadata_store,
             cached_extra_metadata: Mutex::new(None),
+            change_id_trailer,
         }
     }
 
@@ -194,7 +211,7 @@ impl GitBackend {
             gix_open_opts_from_settings(settings),
         )
         .map_err(GitBackendInitError::InitRepository)?;
-        Self::init_with_repo(store_path, git_repo_path, git_repo)
+        Self::init_with_repo(settings, store_path, git_repo_path, git_repo)
     }
 
     /// Initializes backend by creating a new Git repo at the specified
@@ -218,7 +235,7 @@ impl GitBackend {
         )
         .map_err(GitBackendInitError::InitRepository)?;
         let git_repo_path = workspace_root.join(".git");
-        Self::init_with_repo(store_path, &git_repo_path, git_repo)
+        Self::init_with_repo(settings, store_path, &git_repo_path, git_repo)
     }
 
     /// Initializes backend with an existing Git repo at the specified path.
@@ -238,10 +255,11 @@ impl GitBackend {
             gix_open_opts_from_settings(settings),
         )
         .map_err(GitBackendInitError::OpenRepository)?;
-        Self::init_with_repo(store_path, git_repo_path, git_repo)
+        Self::init_with_repo(settings, store_path, git_repo_path, git_repo)
     }
 
     fn init_with_repo(
+        settings: &UserSettings,
         store_path: &Path,
         git_repo_path: &Path,
         git_repo: gix::ThreadSafeRepository,
@@ -270,8 +288,15 @@ impl GitBackend {
                 .context(&target_path)
                 .map_err(GitBackendInitError::Path)?;
         };
+        let change_id_trailer = settings
+            .get_bool("git.change-id-trailer")
+            .map_err(GitBackendInitError::Config)?;
         let extra_metadata_store = TableStore::init(extra_path, HASH_LENGTH);
-        Ok(GitBackend::new(git_repo, extra_metadata_store))
+        Ok(GitBackend::new(
+            git_repo,
+            extra_metadata_store,
+            change_id_trailer,
+        ))
     }
 
     pub fn load(
@@ -293,8 +318,15 @@ impl GitBackend {
             gix_open_opts_from_settings(settings),
         )
         .map_err(GitBackendLoadError::OpenRepository)?;
+        let change_id_trailer = settings
+            .get_bool("git.change-id-trailer")
+            .map_err(GitBackendLoadError::Config)?;
         let extra_metadata_store = TableStore::load(store_path.join("extra"), HASH_LENGTH);
-        Ok(GitBackend::new(repo, extra_metadata_store))
+        Ok(GitBackend::new(
+            repo,
+            extra_metadata_store,
+            change_id_trailer,
+        ))
     }
 
     fn lock_git_repo(&self) -> MutexGuard<'_, gix::Repository> {
@@ -321,6 +353,131 @@ impl GitBackend {
         self.base_repo.work_dir()
     }
 
+    /// Commits that `gc()` keeps reachable by `refs/jj/keep` refs.
+    fn heads_for_gc(&self, index: &dyn Index) -> BackendResult<HashSet<CommitId>> {
+        let heads = index
+            .all_heads_for_gc()
+            .map_err(|err| BackendError::Other(err.into()))?
+            .filter(|id| *id != self.root_commit_id)
+            .collect();
+        Ok(heads)
+    }
+
+    fn read_tree_from_repo(
+        &self,
+        locked_repo: &gix::Repository,
+        id: &TreeId,
+    ) -> BackendResult<Tree> {
+        if id == &self.empty_tree_id {
+            return Ok(Tree::default());
+        }
+        let git_tree_id = validate_git_object_id(id)?;
+        let git_tree = locked_repo
+            .find_object(git_tree_id)
+            .map_err(|err| map_not_found_err(err, id))?
+            .try_into_tree()
+            .map_err(|err| to_read_object_err(err, id))?;
+        let mut tree = Tree::default();
+        for entry in git_tree.iter() {
+            let entry = entry.map_err(|err| to_read_object_err(err, id))?;
+            let name =
+                str::from_utf8(entry.filename()).map_err(|err| to_invalid_utf8_err(err, id))?;
+            let (name, value) = match entry.mode().kind() {
+                gix::object::tree::EntryKind::Tree => {
+                    let id = TreeId::from_bytes(entry.oid().as_bytes());
+                    (name, TreeValue::Tree(id))
+                }
+                gix::object::tree::EntryKind::Blob => {
+                    let id = FileId::from_bytes(entry.oid().as_bytes());
+                    if let Some(basename) = name.strip_suffix(CONFLICT_SUFFIX) {
+                        (
+                            basename,
+                            TreeValue::Conflict(ConflictId::from_bytes(entry.oid().as_bytes())),
+                        )
+                    } else {
+                        (
+                            name,
+                            TreeValue::File {
+                                id,
+                                executable: false,
+                            },
+                        )
+                    }
+                }
+                gix::object::tree::EntryKind::BlobExecutable => {
+                    let id = FileId::from_bytes(entry.oid().as_bytes());
+                    (
+                        name,
+                        TreeValue::File {
+                            id,
+                            executable: true,
+                        },
+                    )
+                }
+                gix::object::tree::EntryKind::Link => {
+                    let id = SymlinkId::from_bytes(entry.oid().as_bytes());
+                    (name, TreeValue::Symlink(id))
+                }
+                gix::object::tree::EntryKind::Commit => {
+                    let id = CommitId::from_bytes(entry.oid().as_bytes());
+                    (name, TreeValue::GitSubmodule(id))
+                }
+            };
+            tree.set(RepoPathComponentBuf::from(name), value);
+        }
+        Ok(tree)
+    }
+
+    /// Reads the Git part of the commit. The jj-specific extras are filled in
+    /// by `read_commit_extras()`.
+    fn read_commit_from_repo(
+        &self,
+        locked_repo: &gix::Repository,
+        shallow_commits: Option<&gix::shallow::Commits>,
+        id: &CommitId,
+    ) -> BackendResult<Commit> {
+        if *id == self.root_commit_id {
+            return Ok(make_root_commit(
+                self.root_change_id().clone(),
+                self.empty_tree_id.clone(),
+            ));
+        }
+        let git_commit_id = validate_git_object_id(id)?;
+        let git_object = locked_repo
+            .find_object(git_commit_id)
+            .map_err(|err| map_not_found_err(err, id))?;
+        let is_shallow = shallow_commits.is_some_and(|shallow| shallow.contains(&git_object.id));
+        let mut commit = commit_from_git_without_root_parent(
+            id,
+            &git_object,
+            false,
+            is_shallow,
+            self.change_id_trailer,
+        )?;
+        if commit.parents.is_empty() {
+            commit.parents.push(self.root_commit_id.clone());
+        };
+        Ok(commit)
+    }
+
+    fn read_commit_extras(&self, id: &CommitId, commit: &mut Commit) -> BackendResult<()> {
+        let table = self.cached_extra_metadata_table()?;
+        if let Some(extras) = table.get_value(id.as_bytes()) {
+            deserialize_extras(commit, extras);
+        } else {
+            // TODO: Remove this hack and map to ObjectNotFound error if we're sure that
+            // there are no reachable ancestor commits without extras metadata. Git commits
+            // imported by jj < 0.8.0 might not have extras (#924).
+            // https://github.com/jj-vcs/jj/issues/2343
+            tracing::info!("unimported Git commit found");
+            self.import_head_commits([id])?;
+            let table = self.cached_extra_metadata_table()?;
+            let extras = table.get_value(id.as_bytes()).unwrap();
+            deserialize_extras(commit, extras);
+        }
+        Ok(())
+    }
+
     fn cached_extra_metadata_table(&self) -> BackendResult<Arc<ReadonlyTable>> {
         let mut locked_head = self.cached_extra_metadata.lock().unwrap();
         match locked_head.as_ref() {
@@ -405,6 +562,7 @@ impl GitBackend {
             &table_lock,
             &head_ids,
             uses_tree_conflict_format,
+            self.change_id_trailer,
         )?;
         self.save_extra_metadata_table(mut_table, &table_lock)
     }
@@ -520,6 +678,7 @@ fn commit_from_git_without_root_parent(
     git_object: &gix::Object,
     uses_tree_conflict_format: bool,
     is_shallow: bool,
+    change_id_trailer: bool,
 ) -> BackendResult<Commit> {
     let commit = git_object
         .try_to_commit_ref()
@@ -565,6 +724,12 @@ fn commit_from_git_without_root_parent(
     // nothing.
     // TODO: what should we do with commit.encoding?
     let description = String::from_utf8_lossy(commit.message).into_owned();
+    // A change id recorded in the message takes precedence over the one derived
+    // from the commit id, so that commits rewritten by other tools keep it.
+    let change_id = change_id_trailer
+        .then(|| change_id_from_trailer(&description))
+        .flatten()
+        .unwrap_or(change_id);
     let author = signature_from_git(commit.author());
     let committer = signature_from_git(commit.committer());
 
@@ -603,6 +768,57 @@ fn commit_from_git_without_root_parent(
 
 const EMPTY_STRING_PLACEHOLDER: &str = "JJ_EMPTY_STRING";
 
+const CHANGE_ID_TRAILER: &str = "Change-Id";
+
+fn is_trailer_line(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(key, _)| {
+        !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+/// Parses the value of a `Change-Id` trailer written by jj. Other values, such
+/// as Gerrit's `I<hex>` ids, are ignored.
+fn parse_change_id_trailer(line: &str) -> Option<ChangeId> {
+    let (key, value) = line.split_once(':')?;
+    if key != CHANGE_ID_TRAILER {
+        return None;
+    }
+    let hex = hex_util::to_forward_hex(value.trim())?;
+    let change_id = ChangeId::try_from_hex(&hex).ok()?;
+    (change_id.as_bytes().len() == CHANGE_ID_LENGTH).then_some(change_id)
+}
+
+/// Returns the change id recorded in the trailers (the last paragraph) of the
+/// commit `message`.
+fn change_id_from_trailer(message: &str) -> Option<ChangeId> {
+    let (_, last_paragraph) = message.trim_end().rsplit_once("\n\n")?;
+    if !last_paragraph.lines().all(is_trailer_line) {
+        return None;
+    }
+    last_paragraph
+        .lines()
+        .rev()
+        .find_map(parse_change_id_trailer)
+}
+
+/// Adds a `Change-Id` trailer with the `change_id` to the `description`,
+/// replacing the one previously written by jj if any.
+fn set_change_id_trailer(description: &str, change_id: &ChangeId) -> String {
+    let trailer = format!("{CHANGE_ID_TRAILER}: {}", change_id.reverse_hex());
+    let body = description.trim_end_matches('\n');
+    match body.rsplit_once("\n\n") {
+        Some((head, last_paragraph)) if last_paragraph.lines().all(is_trailer_line) => {
+            let trailers = last_paragraph
+                .lines()
+                .filter(|line| parse_change_id_trailer(line).is_none())
+                .chain([trailer.as_str()])
+                .join("\n");
+            format!("{head}\n\n{trailers}\n")
+        }
+        _ => format!("{body}\n\n{trailer}\n"),
+    }
+}
+
 fn signature_from_git(signature: gix::actor::SignatureRef) -> Signature {
     let name = signature.name;
     let name = if name != EMPTY_STRING_PLACEHOLDER {
@@ -628,7 +844,7 @@ fn signature_from_git(signature: gix::actor::SignatureRef) -> Signature {
     }
 }
 
-fn signature_to_git(signature: &Signature) -> gix::actor::SignatureRef<'_> {
+pub(crate) fn signature_to_git(signature: &Signature) -> gix::actor::SignatureRef<'_> {
     // git does not support empty names or emails
     let name = if !signature.name.is_empty() {
         &signature.name
@@ -734,13 +950,13 @@ fn to_ref_deletion(git_ref: gix::refs::Reference) -> gix::refs::transaction::Ref
 
 /// Recreates `refs/jj/keep` refs for the `new_heads`, and removes the other
 /// unreachable and non-head refs.
-fn recreate_no_gc_refs(
+/// Calculates diff between existing no-gc refs and new heads, and returns the
+/// no-gc refs to be deleted.
+fn collect_no_gc_refs_to_delete(
     git_repo: &gix::Repository,
-    new_heads: impl IntoIterator<Item = CommitId>,
+    new_heads: &HashSet<CommitId>,
     keep_newer: SystemTime,
-) -> BackendResult<()> {
-    // Calculate diff between existing no-gc refs and new heads.
-    let new_heads: HashSet<CommitId> = new_heads.into_iter().collect();
+) -> BackendResult<Vec<gix::refs::Reference>> {
     let mut no_gc_refs_to_keep_count: usize = 0;
     let mut no_gc_refs_to_delete: Vec<gix::refs::Reference> = Vec::new();
     let git_references = git_repo
@@ -789,6 +1005,15 @@ fn recreate_no_gc_refs(
         no_gc_refs_to_delete_count = no_gc_refs_to_delete.len(),
         "collected reachable refs"
     );
+    Ok(no_gc_refs_to_delete)
+}
+
+fn recreate_no_gc_refs(
+    git_repo: &gix::Repository,
+    new_heads: &HashSet<CommitId>,
+    keep_newer: SystemTime,
+) -> BackendResult<()> {
+    let no_gc_refs_to_delete = collect_no_gc_refs_to_delete(git_repo, new_heads, keep_newer)?;
 
     // It's slow to delete packed refs one by one, so update refs all at once.
     let ref_edits = itertools::chain(
@@ -802,6 +1027,82 @@ fn recreate_no_gc_refs(
     Ok(())
 }
 
+/// Collects ids of the Git objects reachable from `roots`. Commit entries in
+/// trees (submodules) aren't followed.
+fn collect_reachable_git_objects(
+    git_repo: &gix::Repository,
+    roots: Vec<gix::ObjectId>,
+) -> BackendResult<HashSet<gix::ObjectId>> {
+    let to_err = |err: Box<dyn std::error::Error + Send + Sync>| BackendError::Other(err);
+    let mut reachable = HashSet::new();
+    let mut pending = roots;
+    while let Some(id) = pending.pop() {
+        if !reachable.insert(id) {
+            continue;
+        }
+        // Objects can be missing in shallow clones
+        let Some(object) = git_repo
+            .try_find_object(id)
+            .map_err(|err| to_err(err.into()))?
+        else {
+            continue;
+        };
+        match object.kind {
+            gix::object::Kind::Commit => {
+                let commit = object.into_commit();
+                let tree_id = commit.tree_id().map_err(|err| to_err(err.into()))?;
+                pending.push(tree_id.detach());
+                pending.extend(commit.parent_ids().map(|id| id.detach()));
+            }
+            gix::object::Kind::Tree => {
+                for entry in object.into_tree().iter() {
+                    let entry = entry.map_err(|err| to_err(err.into()))?;
+                    match entry.mode().kind() {
+                        gix::object::tree::EntryKind::Tree => pending.push(entry.oid().to_owned()),
+                        gix::object::tree::EntryKind::Commit => {}
+                        _ => {
+                            reachable.insert(entry.oid().to_owned());
+                        }
+                    }
+                }
+            }
+            gix::object::Kind::Tag => {
+                let tag = object.into_tag();
+                let target_id = tag.target_id().map_err(|err| to_err(err.into()))?;
+                pending.push(target_id.detach());
+            }
+            gix::object::Kind::Blob => {}
+        }
+    }
+    Ok(reachable)
+}
+
+/// Counts objects in the Git object database that aren't in `reachable`.
+fn count_unreachable_git_objects(
+    git_repo: &gix::Repository,
+    reachable: &HashSet<gix::ObjectId>,
+) -> BackendResult<GcStats> {
+    let to_err = |err: Box<dyn std::error::Error + Send + Sync>| BackendError::Other(err);
+    let mut stats = GcStats::default();
+    // The same object may be stored in more than one pack
+    let mut seen = HashSet::new();
+    for id in git_repo.objects.iter().map_err(|err| to_err(err.into()))? {
+        let id = id.map_err(|err| to_err(err.into()))?;
+        if reachable.contains(&id) || !seen.insert(id) {
+            continue;
+        }
+        let header = git_repo.find_header(id).map_err(|err| to_err(err.into()))?;
+        match header.kind() {
+            gix::object::Kind::Commit => stats.commits += 1,
+            gix::object::Kind::Tree => stats.trees += 1,
+            gix::object::Kind::Blob => stats.blobs += 1,
+            gix::object::Kind::Tag => continue,
+        }
+        stats.bytes += header.size();
+    }
+    Ok(stats)
+}
+
 fn run_git_gc(git_dir: &Path) -> Result<(), GitGcError> {
     let mut git = Command::new("git");
     git.arg("--git-dir=."); // turn off discovery
@@ -866,6 +1167,7 @@ fn import_extra_metadata_entries_from_heads(
     _table_lock: &FileLock,
     head_ids: &HashSet<&CommitId>,
     uses_tree_conflict_format: bool,
+    change_id_trailer: bool,
 ) -> BackendResult<()> {
     let shallow_commits = git_repo
         .shallow_commits()
@@ -891,6 +1193,7 @@ fn import_extra_metadata_entries_from_heads(
             &git_object,
             uses_tree_conflict_format,
             is_shallow,
+            change_id_trailer,
         )?;
         mut_table.add_entry(id.to_bytes(), serialize_extras(&commit));
         work_ids.extend(
@@ -992,66 +1295,15 @@ impl Backend for GitBackend {
     }
 
     async fn read_tree(&self, _path: &RepoPath, id: &TreeId) -> BackendResult<Tree> {
-        if id == &self.empty_tree_id {
-            return Ok(Tree::default());
-        }
-        let git_tree_id = validate_git_object_id(id)?;
+        let locked_repo = self.lock_git_repo();
+        self.read_tree_from_repo(&locked_repo, id)
+    }
 
+    async fn read_trees(&self, _path: &RepoPath, ids: &[TreeId]) -> BackendResult<Vec<Tree>> {
         let locked_repo = self.lock_git_repo();
-        let git_tree = locked_repo
-            .find_object(git_tree_id)
-            .map_err(|err| map_not_found_err(err, id))?
-            .try_into_tree()
-            .map_err(|err| to_read_object_err(err, id))?;
-        let mut tree = Tree::default();
-        for entry in git_tree.iter() {
-            let entry = entry.map_err(|err| to_read_object_err(err, id))?;
-            let name =
-                str::from_utf8(entry.filename()).map_err(|err| to_invalid_utf8_err(err, id))?;
-            let (name, value) = match entry.mode().kind() {
-                gix::object::tree::EntryKind::Tree => {
-                    let id = TreeId::from_bytes(entry.oid().as_bytes());
-                    (name, TreeValue::Tree(id))
-                }
-                gix::object::tree::EntryKind::Blob => {
-                    let id = FileId::from_bytes(entry.oid().as_bytes());
-                    if let Some(basename) = name.strip_suffix(CONFLICT_SUFFIX) {
-                        (
-                            basename,
-                            TreeValue::Conflict(ConflictId::from_bytes(entry.oid().as_bytes())),
-                        )
-                    } else {
-                        (
-                            name,
-                            TreeValue::File {
-                                id,
-                                executable: false,
-                            },
-                        )
-                    }
-                }
-                gix::object::tree::EntryKind::BlobExecutable => {
-                    let id = FileId::from_bytes(entry.oid().as_bytes());
-                    (
-                        name,
-                        TreeValue::File {
-                            id,
-                            executable: true,
-                        },
-                    )
-                }
-                gix::object::tree::EntryKind::Link => {
-                    let id = SymlinkId::from_bytes(entry.oid().as_bytes());
-                    (name, TreeValue::Symlink(id))
-                }
-                gix::object::tree::EntryKind::Commit => {
-                    let id = CommitId::from_bytes(entry.oid().as_bytes());
-                    (name, TreeValue::GitSubmodule(id))
-                }
-            };
-            tree.set(RepoPathComponentBuf::from(name), value);
-        }
-        Ok(tree)
+        ids.iter()
+            .map(|id| self.read_tree_from_repo(&locked_repo, id))
+            .collect()
     }
 
     async fn write_tree(&self, _path: &RepoPath, contents: &Tree) -> BackendResult<TreeId> {
@@ -1147,45 +1399,26 @@ impl Backend for GitBackend {
 
     #[tracing::instrument(skip(self))]
     async fn read_commit(&self, id: &CommitId) -> BackendResult<Commit> {
-        if *id == self.root_commit_id {
-            return Ok(make_root_commit(
-                self.root_change_id().clone(),
-                self.empty_tree_id.clone(),
-            ));
-        }
-        let git_commit_id = validate_git_object_id(id)?;
+        self.read_commits(slice::from_ref(id))
+            .await
+            .map(|mut commits| commits.pop().unwrap())
+    }
 
-        let mut commit = {
+    #[tracing::instrument(skip_all, fields(count = ids.len()))]
+    async fn read_commits(&self, ids: &[CommitId]) -> BackendResult<Vec<Commit>> {
+        let mut commits: Vec<Commit> = {
             let locked_repo = self.lock_git_repo();
-            let git_object = locked_repo
-                .find_object(git_commit_id)
-                .map_err(|err| map_not_found_err(err, id))?;
-            let is_shallow = locked_repo
-                .shallow_commits()
-                .ok()
-                .flatten()
-                .is_some_and(|shallow| shallow.contains(&git_object.id));
-            commit_from_git_without_root_parent(id, &git_object, false, is_shallow)?
+            let shallow_commits = locked_repo.shallow_commits().ok().flatten();
+            ids.iter()
+                .map(|id| self.read_commit_from_repo(&locked_repo, shallow_commits.as_ref(), id))
+                .try_collect()?
         };
-        if commit.parents.is_empty() {
-            commit.parents.push(self.root_commit_id.clone());
-        };
-
-        let table = self.cached_extra_metadata_table()?;
-        if let Some(extras) = table.get_value(id.as_bytes()) {
-            deserialize_extras(&mut commit, extras);
-        } else {
-            // TODO: Remove this hack and map to ObjectNotFound error if we're sure that
-            // there are no reachable ancestor commits without extras metadata. Git commits
-            // imported by jj < 0.8.0 might not have extras (#924).
-            // https://github.com/jj-vcs/jj/issues/2343
-            tracing::info!("unimported Git commit found");
-            self.import_head_commits([id])?;
-            let table = self.cached_extra_metadata_table()?;
-            let extras = table.get_value(id.as_bytes()).unwrap();
-            deserialize_extras(&mut commit, extras);
+        for (id, commit) in iter::zip(ids, &mut commits) {
+            if *id != self.root_commit_id {
+                self.read_commit_extras(id, commit)?;
+            }
         }
-        Ok(commit)
+        Ok(commits)
     }
 
     async fn write_commit(
@@ -1194,6 +1427,10 @@ impl Backend for GitBackend {
         mut sign_with: Option<&mut SigningFn>,
     ) -> BackendResult<(CommitId, Commit)> {
         assert!(contents.secure_sig.is_none(), "commit.secure_sig was set");
+        if self.change_id_trailer && !contents.description.is_empty() {
+            contents.description =
+                set_change_id_trailer(&contents.description, &contents.change_id);
+        }
 
         let locked_repo = self.lock_git_repo();
         let git_tree_id = match &contents.root_tree {
@@ -1382,11 +1619,8 @@ impl Backend for GitBackend {
     #[tracing::instrument(skip(self, index))]
     fn gc(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<()> {
         let git_repo = self.lock_git_repo();
-        let new_heads = index
-            .all_heads_for_gc()
-            .map_err(|err| BackendError::Other(err.into()))?
-            .filter(|id| *id != self.root_commit_id);
-        recreate_no_gc_refs(&git_repo, new_heads, keep_newer)?;
+        let new_heads = self.heads_for_gc(index)?;
+        recreate_no_gc_refs(&git_repo, &new_heads, keep_newer)?;
         // TODO: remove unreachable entries from extras table if segment file
         // mtime <= keep_newer? (it won't be consistent with no-gc refs
         // preserved by the keep_newer timestamp though)
@@ -1398,6 +1632,44 @@ impl Backend for GitBackend {
         git_repo.refs.force_refresh_packed_buffer().ok();
         Ok(())
     }
+
+    #[tracing::instrument(skip(self, index))]
+    fn gc_dry_run(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<GcStats> {
+        let git_repo = self.lock_git_repo();
+        let new_heads = self.heads_for_gc(index)?;
+        let no_gc_refs_to_delete = collect_no_gc_refs_to_delete(&git_repo, &new_heads, keep_newer)?;
+        let deleted_ref_names: HashSet<_> = no_gc_refs_to_delete
+            .iter()
+            .map(|git_ref| git_ref.name.as_bstr())
+            .collect();
+
+        // Objects stay reachable from the remaining refs and the no-gc refs
+        // that gc() would create for the new heads.
+        let mut roots = new_heads
+            .iter()
+            .map(|id| gix::ObjectId::from_bytes_or_panic(id.as_bytes()))
+            .collect_vec();
+        let git_references = git_repo
+            .references()
+            .map_err(|err| BackendError::Other(err.into()))?;
+        for git_ref in git_references
+            .all()
+            .map_err(|err| BackendError::Other(err.into()))?
+        {
+            let git_ref = git_ref.map_err(BackendError::Other)?;
+            if deleted_ref_names.contains(git_ref.name().as_bstr()) {
+                continue;
+            }
+            if let Some(id) = git_ref.try_id() {
+                roots.push(id.detach());
+            }
+        }
+        if let Ok(head_id) = git_repo.head_id() {
+            roots.push(head_id.detach());
+        }
+        let reachable = collect_reachable_git_objects(&git_repo, roots)?;
+        count_unreachable_git_objects(&git_repo, &reachable)
+    }
 }
 
 /// Write a tree conflict as a special tree with `.jjconflict-base-N` and
@@ -2202,6 +2474,56 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn change_id_trailer_round_trip() {
+        let change_id = ChangeId::from_hex("0123456789abcdef0123456789abcdef");
+        let other_change_id = ChangeId::from_hex("fedcba9876543210fedcba9876543210");
+
+        // Paragraph without trailers
+        let message = set_change_id_trailer("subject\n\nbody\n", &change_id);
+        insta::assert_snapshot!(message, @r"
+        subject
+
+        body
+
+        Change-Id: zyxwvutsrqponmlkzyxwvutsrqponmlk
+        ");
+        assert_eq!(change_id_from_trailer(&message), Some(change_id.clone()));
+
+        // Existing trailers are kept, and the old change id is replaced
+        let message = set_change_id_trailer(
+            "subject\n\nSigned-off-by: Someone\nChange-Id: klmnopqrstuvwxyzklmnopqrstuvwxyz\n",
+            &change_id,
+        );
+        insta::assert_snapshot!(message, @r"
+        subject
+
+        Signed-off-by: Someone
+        Change-Id: zyxwvutsrqponmlkzyxwvutsrqponmlk
+        ");
+        assert_eq!(set_change_id_trailer(&message, &change_id), message);
+        assert_eq!(
+            change_id_from_trailer(&set_change_id_trailer(&message, &other_change_id)),
+            Some(other_change_id)
+        );
+
+        // Gerrit change ids and trailer-like subjects are ignored
+        assert_eq!(
+            change_id_from_trailer("subject\n\nChange-Id: I0123456789abcdef\n"),
+            None
+        );
+        assert_eq!(
+            change_id_from_trailer("Change-Id: zyxwvutsrqponmlkzyxwvutsrqponmlk\n"),
+            None
+        );
+        assert_eq!(
+            change_id_from_trailer(
+                "subject\n\nnot a trailer\nChange-Id: zyxwvutsrqponmlkzyxwvutsrqponmlk\n"
+            ),
+            None
+        );
+    }
+
     fn git_id(commit_id: &CommitId) -> Oid {
         Oid::from_bytes(commit_id.as_bytes()).unwrap()
     }
@@ -0,0 +1,147 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal support for [Git LFS](https://git-lfs.com/) in the working copy.
+//!
+//! The repository only ever stores LFS pointer files. On checkout, pointers
+//! are replaced by the real contents using `git lfs smudge`, and on snapshot,
+//! files tracked by LFS are converted back to pointers using `git lfs clean`.
+//! Tracked files are determined by [`GitAttributes::is_lfs()`].
+//!
+//! [`GitAttributes::is_lfs()`]: crate::gitattributes::GitAttributes::is_lfs
+
+use std::io;
+use std::io::Read as _;
+use std::io::Write as _;
+use std::path::Path;
+use std::process::Command;
+use std::process::Stdio;
+
+use thiserror::Error;
+
+use crate::repo_path::RepoPath;
+
+/// First line of every LFS pointer file.
+const POINTER_VERSION_LINE: &[u8] = b"version https://git-lfs.github.com/spec/v1\n";
+
+/// LFS pointer files are small. Larger files are never considered pointers.
+const MAX_POINTER_SIZE: usize = 1024;
+
+/// Error running a Git LFS filter.
+#[derive(Debug, Error)]
+pub enum GitLfsError {
+    /// Failed to run the `git lfs` command.
+    #[error("Failed to run git lfs")]
+    Io(#[from] io::Error),
+    /// The `git lfs` command exited with an error.
+    #[error("git lfs {filter} failed: {stderr}")]
+    Failed {
+        /// Name of the filter which failed.
+        filter: &'static str,
+        /// Error output of the command.
+        stderr: String,
+    },
+}
+
+/// Returns true if `content` looks like a Git LFS pointer file.
+pub fn is_lfs_pointer(content: &[u8]) -> bool {
+    content.len() <= MAX_POINTER_SIZE
+        && content.starts_with(POINTER_VERSION_LINE)
+        && content
+            .split(|&b| b == b'\n')
+            .any(|line| line.starts_with(b"oid sha256:"))
+}
+
+/// Replaces the LFS `pointer` of the file at `path` with the real contents by
+/// running `git lfs smudge` in the working copy.
+pub fn smudge(
+    working_copy_path: &Path,
+    path: &RepoPath,
+    pointer: &[u8],
+) -> Result<Vec<u8>, GitLfsError> {
+    run_filter(working_copy_path, "smudge", path, pointer)
+}
+
+/// Converts the `content` of the file at `path` to an LFS pointer by running
+/// `git lfs clean` in the working copy. This also stores the contents in the
+/// local LFS object store.
+pub fn clean(
+    working_copy_path: &Path,
+    path: &RepoPath,
+    content: &[u8],
+) -> Result<Vec<u8>, GitLfsError> {
+    run_filter(working_copy_path, "clean", path, content)
+}
+
+fn run_filter(
+    working_copy_path: &Path,
+    filter: &'static str,
+    path: &RepoPath,
+    input: &[u8],
+) -> Result<Vec<u8>, GitLfsError> {
+    let mut child = Command::new("git")
+        .args(["lfs", filter, "--", path.as_internal_file_string()])
+        .current_dir(working_copy_path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+    // Write the input while reading the output so that neither side blocks on
+    // a full pipe.
+    let output = std::thread::scope(|scope| {
+        let writer = scope.spawn(move || stdin.write_all(input));
+        let mut output = vec![];
+        let read_result = stdout.read_to_end(&mut output);
+        let write_result = writer.join().unwrap();
+        read_result?;
+        // The filter may exit without consuming the whole input.
+        match write_result {
+            Err(err) if err.kind() != io::ErrorKind::BrokenPipe => Err(err),
+            _ => Ok(output),
+        }
+    })?;
+    let status = child.wait_with_output()?;
+    if !status.status.success() {
+        return Err(GitLfsError::Failed {
+            filter,
+            stderr: String::from_utf8_lossy(&status.stderr).trim().to_owned(),
+        });
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINTER: &[u8] = b"version https://git-lfs.github.com/spec/v1
+oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393
+size 12345
+";
+
+    #[test]
+    fn test_is_lfs_pointer() {
+        assert!(is_lfs_pointer(POINTER));
+        assert!(!is_lfs_pointer(b""));
+        assert!(!is_lfs_pointer(
+            b"version https://git-lfs.github.com/spec/v1\n"
+        ));
+        assert!(!is_lfs_pointer(b"some other content\n"));
+        let mut large = POINTER.to_vec();
+        large.resize(MAX_POINTER_SIZE + 1, b'x');
+        assert!(!is_lfs_pointer(&large));
+    }
+}
@@ -0,0 +1,351 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Subset of Git's `.gitattributes` used by the working copy: line-ending
+//! conversion (`text`, `eol`, `binary`) and LFS filters (`filter=lfs`).
+
+use std::borrow::Cow;
+use std::io;
+use std::path::Path;
+
+use ignore::gitignore;
+
+use crate::gitignore::GitIgnoreError;
+use crate::repo_path::RepoPath;
+
+/// State of an attribute for a path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AttributeState {
+    /// The attribute is set, e.g. `text`.
+    Set,
+    /// The attribute is unset, e.g. `-text`.
+    Unset,
+    /// The attribute is set to a value, e.g. `eol=crlf`.
+    Value(String),
+}
+
+/// Line ending used for a text file in the working copy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Eol {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    Crlf,
+}
+
+impl Eol {
+    /// Line ending native to the platform.
+    pub fn native() -> Self {
+        if cfg!(windows) {
+            Eol::Crlf
+        } else {
+            Eol::Lf
+        }
+    }
+}
+
+/// How the line endings of a file are converted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EolConversion {
+    /// The file is left untouched.
+    None,
+    /// The file is stored with LF line endings, and checked out with the given
+    /// line endings.
+    Text(Eol),
+    /// Like `Text`, but only if the contents look like text.
+    Auto(Eol),
+}
+
+impl EolConversion {
+    /// Returns the line ending to check out `content` with, or `None` if the
+    /// contents shouldn't be converted.
+    pub fn eol_for(self, content: &[u8]) -> Option<Eol> {
+        match self {
+            EolConversion::None => None,
+            EolConversion::Text(eol) => Some(eol),
+            EolConversion::Auto(eol) => looks_like_text(content).then_some(eol),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AttributesRule {
+    matcher: gitignore::Gitignore,
+    attributes: Vec<(String, AttributeState)>,
+}
+
+/// Parsed `.gitattributes` file.
+///
+/// Only the file at the root of the working copy is supported. Patterns are
+/// matched like `.gitignore` patterns, and later lines override earlier ones.
+#[derive(Debug, Default)]
+pub struct GitAttributes {
+    rules: Vec<AttributesRule>,
+}
+
+impl GitAttributes {
+    /// Creates attributes which don't apply to any path.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Loads the `.gitattributes` file at the root of the working copy, if
+    /// any.
+    pub fn load(working_copy_path: &Path) -> Result<Self, GitIgnoreError> {
+        let path = working_copy_path.join(".gitattributes");
+        match std::fs::read(&path) {
+            Ok(content) => Self::parse(&path, &content),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::empty()),
+            Err(err) => Err(GitIgnoreError::ReadFile { path, source: err }),
+        }
+    }
+
+    /// Parses the contents of a `.gitattributes` file. `path` is only used
+    /// for error reporting.
+    pub fn parse(path: &Path, content: &[u8]) -> Result<Self, GitIgnoreError> {
+        let mut rules = vec![];
+        for (i, line) in content.split(|&b| b == b'\n').enumerate() {
+            let line = std::str::from_utf8(line).map_err(|err| GitIgnoreError::InvalidUtf8 {
+                path: path.to_path_buf(),
+                line_num_for_display: i + 1,
+                line: String::from_utf8_lossy(line).to_string(),
+                source: err,
+            })?;
+            let mut fields = line.split_whitespace();
+            let Some(pattern) = fields.next() else {
+                continue;
+            };
+            // Comments, macro definitions, and negated patterns which Git
+            // rejects
+            if pattern.starts_with('#') || pattern.starts_with('[') || pattern.starts_with('!') {
+                continue;
+            }
+            let mut attributes = vec![];
+            for field in fields {
+                if field == "binary" {
+                    // Built-in macro
+                    for name in ["diff", "merge", "text"] {
+                        attributes.push((name.to_owned(), AttributeState::Unset));
+                    }
+                } else if let Some(name) = field.strip_prefix('-') {
+                    attributes.push((name.to_owned(), AttributeState::Unset));
+                } else if let Some(name) = field.strip_prefix('!') {
+                    // Unspecified. Treated as unset since we don't support
+                    // nested files.
+                    attributes.push((name.to_owned(), AttributeState::Unset));
+                } else if let Some((name, value)) = field.split_once('=') {
+                    attributes.push((name.to_owned(), AttributeState::Value(value.to_owned())));
+                } else {
+                    attributes.push((field.to_owned(), AttributeState::Set));
+                }
+            }
+            let mut builder = gitignore::GitignoreBuilder::new("");
+            builder
+                .add_line(None, pattern)
+                .map_err(|err| GitIgnoreError::Underlying {
+                    path: path.to_path_buf(),
+                    source: err,
+                })?;
+            let matcher = builder.build().map_err(|err| GitIgnoreError::Underlying {
+                path: path.to_path_buf(),
+                source: err,
+            })?;
+            rules.push(AttributesRule {
+                matcher,
+                attributes,
+            });
+        }
+        Ok(GitAttributes { rules })
+    }
+
+    /// Returns true if no attributes are specified.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the state of the attribute `name` for the file at `path`, or
+    /// `None` if it's unspecified.
+    pub fn get(&self, path: &RepoPath, name: &str) -> Option<&AttributeState> {
+        let path = path.as_internal_file_string();
+        self.rules
+            .iter()
+            .rev()
+            .filter(|rule| rule.matcher.matched(path, false).is_ignore())
+            .find_map(|rule| {
+                rule.attributes
+                    .iter()
+                    .rev()
+                    .find(|(attribute, _)| attribute == name)
+                    .map(|(_, state)| state)
+            })
+    }
+
+    /// Returns true if the file at `path` is tracked by Git LFS.
+    pub fn is_lfs(&self, path: &RepoPath) -> bool {
+        matches!(self.get(path, "filter"), Some(AttributeState::Value(value)) if value == "lfs")
+    }
+
+    /// Returns how the line endings of the file at `path` are converted.
+    pub fn eol_conversion(&self, path: &RepoPath) -> EolConversion {
+        let eol = match self.get(path, "eol") {
+            Some(AttributeState::Value(value)) if value == "crlf" => Some(Eol::Crlf),
+            Some(AttributeState::Value(value)) if value == "lf" => Some(Eol::Lf),
+            _ => None,
+        };
+        match self.get(path, "text") {
+            Some(AttributeState::Set) => EolConversion::Text(eol.unwrap_or_else(Eol::native)),
+            Some(AttributeState::Unset) => EolConversion::None,
+            Some(AttributeState::Value(value)) if value == "auto" => {
+                EolConversion::Auto(eol.unwrap_or_else(Eol::native))
+            }
+            // Setting `eol` implies `text`.
+            _ => eol.map_or(EolConversion::None, EolConversion::Text),
+        }
+    }
+}
+
+/// Returns true if `content` doesn't look like binary data.
+pub fn looks_like_text(content: &[u8]) -> bool {
+    // Same heuristic as Git: binary files contain NUL bytes near the start.
+    const FIRST_FEW_BYTES: usize = 8000;
+    !content[..content.len().min(FIRST_FEW_BYTES)].contains(&0)
+}
+
+/// Converts CRLF line endings in `content` to LF.
+pub fn convert_to_lf(content: &[u8]) -> Cow<'_, [u8]> {
+    if !content.windows(2).any(|pair| pair == b"\r\n") {
+        return Cow::Borrowed(content);
+    }
+    let mut converted = Vec::with_capacity(content.len());
+    let mut bytes = content.iter().peekable();
+    while let Some(&b) = bytes.next() {
+        if b == b'\r' && bytes.peek() == Some(&&b'\n') {
+            continue;
+        }
+        converted.push(b);
+    }
+    Cow::Owned(converted)
+}
+
+/// Converts LF line endings in `content` to CRLF. Existing CRLF line endings
+/// are kept as is.
+pub fn convert_to_crlf(content: &[u8]) -> Cow<'_, [u8]> {
+    if !content.contains(&b'\n') {
+        return Cow::Borrowed(content);
+    }
+    let mut converted = Vec::with_capacity(content.len() + content.len() / 16);
+    let mut prev = None;
+    for &b in content {
+        if b == b'\n' && prev != Some(b'\r') {
+            converted.push(b'\r');
+        }
+        converted.push(b);
+        prev = Some(b);
+    }
+    Cow::Owned(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> GitAttributes {
+        GitAttributes::parse(Path::new(".gitattributes"), content.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_gitattributes_get() {
+        let attributes = parse(
+            "\
+# Comment
+*.txt text
+*.bat text eol=crlf
+*.png binary
+special.txt -text
+dir/*.txt foo=bar
+",
+        );
+        let get = |path, name| attributes.get(RepoPath::from_internal_string(path), name);
+        assert_eq!(get("a.txt", "text"), Some(&AttributeState::Set));
+        assert_eq!(get("sub/a.txt", "text"), Some(&AttributeState::Set));
+        assert_eq!(get("special.txt", "text"), Some(&AttributeState::Unset));
+        assert_eq!(get("sub/special.txt", "text"), Some(&AttributeState::Unset));
+        assert_eq!(
+            get("a.bat", "eol"),
+            Some(&AttributeState::Value("crlf".to_owned()))
+        );
+        assert_eq!(get("a.png", "text"), Some(&AttributeState::Unset));
+        assert_eq!(get("a.png", "diff"), Some(&AttributeState::Unset));
+        assert_eq!(
+            get("dir/a.txt", "foo"),
+            Some(&AttributeState::Value("bar".to_owned()))
+        );
+        assert_eq!(get("dir/a.txt", "text"), Some(&AttributeState::Set));
+        assert_eq!(get("other/a.txt", "foo"), None);
+        assert_eq!(get("a.rs", "text"), None);
+    }
+
+    #[test]
+    fn test_gitattributes_eol_conversion() {
+        let attributes = parse(
+            "\
+* text=auto
+*.sh eol=lf
+*.bat text eol=crlf
+*.png binary
+",
+        );
+        let conversion = |path| attributes.eol_conversion(RepoPath::from_internal_string(path));
+        assert_eq!(conversion("a.rs"), EolConversion::Auto(Eol::native()));
+        assert_eq!(conversion("a.sh"), EolConversion::Auto(Eol::Lf));
+        assert_eq!(conversion("a.bat"), EolConversion::Text(Eol::Crlf));
+        assert_eq!(conversion("a.png"), EolConversion::None);
+        assert_eq!(
+            GitAttributes::empty().eol_conversion(RepoPath::from_internal_string("a.rs")),
+            EolConversion::None
+        );
+        assert_eq!(
+            parse("*.sh eol=lf").eol_conversion(RepoPath::from_internal_string("a.sh")),
+            EolConversion::Text(Eol::Lf)
+        );
+    }
+
+    #[test]
+    fn test_gitattributes_is_lfs() {
+        let attributes = parse(
+            "\
+*.bin filter=lfs diff=lfs merge=lfs -text
+/assets/*.png filter=lfs diff=lfs merge=lfs -text
+assets/small.png -filter
+",
+        );
+        let is_lfs = |path| attributes.is_lfs(RepoPath::from_internal_string(path));
+        assert!(is_lfs("file.bin"));
+        assert!(is_lfs("dir/file.bin"));
+        assert!(!is_lfs("file.txt"));
+        assert!(is_lfs("assets/image.png"));
+        assert!(!is_lfs("assets/small.png"));
+        assert!(!is_lfs("other/image.png"));
+    }
+
+    #[test]
+    fn test_convert_eol() {
+        assert_eq!(convert_to_lf(b"a\r\nb\nc\r"), &b"a\nb\nc\r"[..]);
+        assert_eq!(convert_to_lf(b"a\nb"), &b"a\nb"[..]);
+        assert_eq!(convert_to_crlf(b"a\nb\r\nc"), &b"a\r\nb\r\nc"[..]);
+        assert_eq!(convert_to_crlf(b"abc"), &b"abc"[..]);
+        assert!(looks_like_text(b"a\nb"));
+        assert!(!looks_like_text(b"a\0b"));
+    }
+}
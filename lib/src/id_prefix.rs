@@ -16,6 +16,7 @@
 
 use std::iter;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -54,6 +55,11 @@ struct Indexes {
     commit_change_ids: Vec<(CommitId, ChangeId)>,
     commit_index: IdIndex<CommitId, u32, 4>,
     change_index: IdIndex<ChangeId, u32, 4>,
+    // Shortest unique prefix lengths of the commit/change IDs, in the same
+    // order as `commit_change_ids`. Computed upfront because the IDs in the
+    // disambiguation set are usually the ones to be displayed.
+    commit_prefix_lens: Vec<u8>,
+    change_prefix_lens: Vec<u8>,
 }
 
 impl DisambiguationData {
@@ -77,10 +83,22 @@ impl DisambiguationData {
                 commit_index.insert(commit_id, i);
                 change_index.insert(change_id, i);
             }
+            let commit_index = commit_index.build();
+            let change_index = change_index.build();
+            let mut commit_prefix_lens = vec![0; commit_change_ids.len()];
+            for (pointer, len) in commit_index.shortest_unique_prefix_lens(&*commit_change_ids) {
+                commit_prefix_lens[*pointer as usize] = len.try_into().unwrap();
+            }
+            let mut change_prefix_lens = vec![0; commit_change_ids.len()];
+            for (pointer, len) in change_index.shortest_unique_prefix_lens(&*commit_change_ids) {
+                change_prefix_lens[*pointer as usize] = len.try_into().unwrap();
+            }
             Ok(Indexes {
                 commit_change_ids,
-                commit_index: commit_index.build(),
-                change_index: change_index.build(),
+                commit_index,
+                change_index,
+                commit_prefix_lens,
+                change_prefix_lens,
             })
         })
     }
@@ -181,11 +199,11 @@ impl IdPrefixIndex<'_> {
     /// can still be resolved by `resolve_commit_prefix()`.
     pub fn shortest_commit_prefix_len(&self, repo: &dyn Repo, commit_id: &CommitId) -> usize {
         if let Some(indexes) = self.indexes {
-            if let Some(lookup) = indexes
+            if let Some(pointer) = indexes
                 .commit_index
-                .lookup_exact(&*indexes.commit_change_ids, commit_id)
+                .lookup_pointer(&*indexes.commit_change_ids, commit_id)
             {
-                return lookup.shortest_unique_prefix_len();
+                return indexes.commit_prefix_lens[*pointer as usize].into();
             }
         }
         repo.index().shortest_unique_commit_id_prefix_len(commit_id)
@@ -217,11 +235,11 @@ impl IdPrefixIndex<'_> {
     /// can still be resolved by `resolve_change_prefix()`.
     pub fn shortest_change_prefix_len(&self, repo: &dyn Repo, change_id: &ChangeId) -> usize {
         if let Some(indexes) = self.indexes {
-            if let Some(lookup) = indexes
+            if let Some(pointer) = indexes
                 .change_index
-                .lookup_exact(&*indexes.commit_change_ids, change_id)
+                .lookup_pointer(&*indexes.commit_change_ids, change_id)
             {
-                return lookup.shortest_unique_prefix_len();
+                return indexes.change_prefix_lens[*pointer as usize].into();
             }
         }
         repo.shortest_unique_change_id_prefix_len(change_id)
@@ -398,6 +416,16 @@ where
         lookup.has_key().then_some(lookup)
     }
 
+    /// Looks up a pointer associated with the key. If the key has multiple
+    /// pointers, any of them is returned.
+    pub fn lookup_pointer<S>(&self, source: S, key: &K) -> Option<&P>
+    where
+        S: IdIndexSource<P>,
+        S::Entry: IdIndexSourceEntry<K>,
+    {
+        self.lookup_some(source, key).find_pointer()
+    }
+
     fn lookup_some<'i, 'q, S>(&'i self, source: S, key: &'q K) -> IdIndexLookup<'i, 'q, K, P, S, N>
     where
         S: IdIndexSource<P>,
@@ -434,6 +462,49 @@ where
     {
         self.lookup_some(source, key).shortest_unique_prefix_len()
     }
+
+    /// Returns the shortest unique prefix length of every entry, paired with
+    /// the entry pointer.
+    ///
+    /// This walks the index once, which is cheaper than looking up the keys
+    /// one by one with `shortest_unique_prefix_len()`.
+    pub fn shortest_unique_prefix_lens<'i, S>(
+        &'i self,
+        source: S,
+    ) -> impl Iterator<Item = (&'i P, usize)> + 'i
+    where
+        S: IdIndexSource<P> + Copy + 'i,
+        S::Entry: IdIndexSourceEntry<K>,
+    {
+        let index = &self.index;
+        let mut chunk = 0..0;
+        index
+            .iter()
+            .enumerate()
+            .map(move |(pos, (short_key, pointer))| {
+                if pos >= chunk.end {
+                    let count = index[pos..]
+                        .iter()
+                        .take_while(|(s, _)| s == short_key)
+                        .count();
+                    chunk = pos..pos + count;
+                }
+                let len = if chunk.len() == 1 {
+                    // The short key is unique, so the full-length key isn't needed.
+                    neighbor_unique_prefix_len(index, chunk.clone(), short_key).unwrap_or(1)
+                } else {
+                    let key = source.entry_at(pointer).to_key();
+                    let lookup = IdIndexLookup {
+                        index,
+                        source,
+                        key: &key,
+                        pos: chunk.start,
+                    };
+                    lookup.shortest_unique_prefix_len()
+                };
+                (pointer, len)
+            })
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -444,18 +515,23 @@ pub struct IdIndexLookup<'i, 'q, K, P, S, const N: usize> {
     pos: usize, // may be index.len()
 }
 
-impl<K, P, S, const N: usize> IdIndexLookup<'_, '_, K, P, S, N>
+impl<'i, K, P, S, const N: usize> IdIndexLookup<'i, '_, K, P, S, N>
 where
     K: ObjectId + Eq,
     S: IdIndexSource<P>,
     S::Entry: IdIndexSourceEntry<K>,
 {
     fn has_key(&self) -> bool {
+        self.find_pointer().is_some()
+    }
+
+    fn find_pointer(&self) -> Option<&'i P> {
         let short_key = unwrap_as_short_key(self.key.as_bytes());
         self.index[self.pos..]
             .iter()
             .take_while(|(s, _)| s == short_key)
-            .any(|(_, p)| self.source.entry_at(p).to_key() == *self.key)
+            .find(|(_, p)| self.source.entry_at(p).to_key() == *self.key)
+            .map(|(_, p)| p)
     }
 
     pub fn shortest_unique_prefix_len(&self) -> usize {
@@ -463,30 +539,48 @@ where
         // we need to scan all entries in the current chunk, plus left/right neighbors.
         // Typically, current.len() is 1.
         let short_key = unwrap_as_short_key(self.key.as_bytes());
-        let left = self.pos.checked_sub(1).map(|p| &self.index[p]);
-        let (current, right) = {
-            let range = &self.index[self.pos..];
-            let count = range.iter().take_while(|(s, _)| s == short_key).count();
-            (&range[..count], range.get(count))
-        };
+        let count = self.index[self.pos..]
+            .iter()
+            .take_while(|(s, _)| s == short_key)
+            .count();
+        let chunk = self.pos..self.pos + count;
 
         // Left/right neighbors should have unique short keys. For the current chunk,
         // we need to look up full-length keys.
-        let unique_len = |a: &[u8], b: &[u8]| hex_util::common_hex_len(a, b) + 1;
-        let neighbor_lens = left
-            .iter()
-            .chain(&right)
-            .map(|(s, _)| unique_len(s, short_key));
-        let current_lens = current
+        let neighbor_len = neighbor_unique_prefix_len(self.index, chunk.clone(), short_key);
+        let current_lens = self.index[chunk]
             .iter()
             .map(|(_, p)| self.source.entry_at(p).to_key())
             .filter(|key| key != self.key)
-            .map(|key| unique_len(key.as_bytes(), self.key.as_bytes()));
+            .map(|key| unique_prefix_len(key.as_bytes(), self.key.as_bytes()));
         // Even if the key is the only one in the index, we require at least one digit.
-        neighbor_lens.chain(current_lens).max().unwrap_or(1)
+        neighbor_len
+            .into_iter()
+            .chain(current_lens)
+            .max()
+            .unwrap_or(1)
     }
 }
 
+/// Returns the shortest length of a prefix of `short_key` that disambiguates
+/// it from the left/right neighbors of the `chunk` having the same short key.
+fn neighbor_unique_prefix_len<P, const N: usize>(
+    index: &[([u8; N], P)],
+    chunk: Range<usize>,
+    short_key: &[u8; N],
+) -> Option<usize> {
+    let left = chunk.start.checked_sub(1).map(|p| &index[p]);
+    let right = index.get(chunk.end);
+    left.iter()
+        .chain(&right)
+        .map(|(s, _)| unique_prefix_len(s, short_key))
+        .max()
+}
+
+fn unique_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    hex_util::common_hex_len(a, b) + 1
+}
+
 fn unwrap_as_short_key<const N: usize>(key_bytes: &[u8]) -> &[u8; N] {
     let short_slice = key_bytes.get(..N).expect("key too short");
     short_slice.try_into().unwrap()
@@ -738,4 +832,37 @@ mod tests {
             1
         );
     }
+
+    #[test]
+    fn test_id_index_shortest_unique_prefix_lens() {
+        // No crash if empty
+        let source: Vec<(ChangeId, ())> = vec![];
+        let id_index = build_id_index::<_, 1>(&source);
+        assert_eq!(id_index.shortest_unique_prefix_lens(&*source).count(), 0);
+
+        let source = vec![
+            (ChangeId::from_hex("000000"), ()),
+            (ChangeId::from_hex("01ffff"), ()),
+            (ChangeId::from_hex("010000"), ()),
+            (ChangeId::from_hex("01fffe"), ()),
+            (ChangeId::from_hex("acd0"), ()),
+            (ChangeId::from_hex("acd0"), ()), // duplicated key is allowed
+            (ChangeId::from_hex("ffffff"), ()),
+        ];
+        let id_index = build_id_index::<_, 1>(&source);
+        let lens: Vec<_> = id_index
+            .shortest_unique_prefix_lens(&*source)
+            .map(|(Position(i), len)| (*i, len))
+            .sorted()
+            .collect();
+        assert_eq!(
+            lens,
+            [(0, 2), (1, 6), (2, 3), (3, 6), (4, 1), (5, 1), (6, 1)]
+        );
+        // Same as looking up the keys one by one
+        for (i, len) in lens {
+            let (key, ()) = &source[i];
+            assert_eq!(id_index.shortest_unique_prefix_len(&*source, key), len);
+        }
+    }
 }
@@ -62,9 +62,18 @@ pub mod git {
     pub fn is_special_git_remote(_remote: &str) -> bool {
         false
     }
+
+    /// Returns the remote name if `ref_name` is the `HEAD` ref of a remote.
+    ///
+    /// This function always returns None if the "git" feature is not enabled.
+    pub fn parse_git_remote_head_ref(_ref_name: &str) -> Option<&str> {
+        None
+    }
 }
 #[cfg(feature = "git")]
 pub mod git_backend;
+pub mod git_lfs;
+pub mod gitattributes;
 pub mod gitignore;
 pub mod gpg_signing;
 pub mod graph;
@@ -74,6 +83,7 @@ pub mod index;
 pub mod local_backend;
 pub mod local_working_copy;
 pub mod lock;
+pub mod mailmap;
 pub mod matchers;
 pub mod merge;
 pub mod merged_tree;
@@ -82,6 +92,7 @@ pub mod op_heads_store;
 pub mod op_store;
 pub mod op_walk;
 pub mod operation;
+pub mod patch;
 #[allow(missing_docs)]
 pub mod protos;
 pub mod refs;
@@ -45,6 +45,7 @@ use crate::backend::ConflictId;
 use crate::backend::ConflictTerm;
 use crate::backend::CopyRecord;
 use crate::backend::FileId;
+use crate::backend::GcStats;
 use crate::backend::MergedTreeId;
 use crate::backend::MillisSinceEpoch;
 use crate::backend::SecureSig;
@@ -345,6 +346,10 @@ impl Backend for LocalBackend {
     fn gc(&self, _index: &dyn Index, _keep_newer: SystemTime) -> BackendResult<()> {
         Ok(())
     }
+
+    fn gc_dry_run(&self, _index: &dyn Index, _keep_newer: SystemTime) -> BackendResult<GcStats> {
+        Ok(GcStats::default())
+    }
 }
 
 #[allow(unknown_lints)] // XXX FIXME (aseipp): nightly bogons; re-test this occasionally
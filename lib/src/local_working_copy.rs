@@ -35,10 +35,14 @@ use std::os::unix::fs::PermissionsExt;
 use std::path::Path;
 use std::path::PathBuf;
 use std::slice;
+use std::sync::atomic;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
 use std::sync::mpsc::channel;
 use std::sync::mpsc::Sender;
 use std::sync::Arc;
 use std::sync::OnceLock;
+use std::time::Instant;
 use std::time::UNIX_EPOCH;
 
 use either::Either;
@@ -79,6 +83,11 @@ use crate::fsmonitor::watchman;
 use crate::fsmonitor::FsmonitorSettings;
 #[cfg(feature = "watchman")]
 use crate::fsmonitor::WatchmanConfig;
+use crate::git_lfs;
+use crate::gitattributes;
+use crate::gitattributes::Eol;
+use crate::gitattributes::EolConversion;
+use crate::gitattributes::GitAttributes;
 use crate::gitignore::GitIgnoreFile;
 use crate::lock::FileLock;
 use crate::matchers::DifferenceMatcher;
@@ -956,6 +965,8 @@ impl TreeState {
             start_tracking_matcher,
             max_new_file_size,
             conflict_marker_style,
+            git_lfs,
+            eol_conversion,
         } = options;
 
         let sparse_matcher = self.sparse_matcher();
@@ -978,12 +989,20 @@ impl TreeState {
             return Ok((is_dirty, SnapshotStats::default()));
         }
 
+        let git_attributes = if git_lfs || eol_conversion {
+            Some(GitAttributes::load(&self.working_copy_path)?)
+        } else {
+            None
+        };
+
         let (tree_entries_tx, tree_entries_rx) = channel();
         let (file_states_tx, file_states_rx) = channel();
         let (untracked_paths_tx, untracked_paths_rx) = channel();
         let (deleted_files_tx, deleted_files_rx) = channel();
 
-        trace_span!("traverse filesystem").in_scope(|| -> Result<(), SnapshotError> {
+        let mut phase_durations = vec![];
+        let start = Instant::now();
+        let counts = trace_span!("traverse filesystem").in_scope(|| {
             let snapshotter = FileSnapshotter {
                 tree_state: self,
                 current_tree: &self.current_tree()?,
@@ -995,9 +1014,15 @@ impl TreeState {
                 untracked_paths_tx,
                 deleted_files_tx,
                 error: OnceLock::new(),
+                files_scanned: AtomicUsize::new(0),
+                files_hashed: AtomicUsize::new(0),
+                bytes_read: AtomicU64::new(0),
                 progress,
                 max_new_file_size,
                 conflict_marker_style,
+                git_attributes: git_attributes.as_ref(),
+                git_lfs,
+                eol_conversion,
             };
             let directory_to_visit = DirectoryToVisit {
                 dir: RepoPathBuf::root(),
@@ -1013,16 +1038,18 @@ impl TreeState {
             });
             snapshotter.into_result()
         })?;
+        phase_durations.push(("traverse filesystem", start.elapsed()));
 
-        let stats = SnapshotStats {
-            untracked_paths: untracked_paths_rx.into_iter().collect(),
-        };
+        let untracked_paths = untracked_paths_rx.into_iter().collect();
         let mut tree_builder = MergedTreeBuilder::new(self.tree_id.clone());
+        let start = Instant::now();
         trace_span!("process tree entries").in_scope(|| {
             for (path, tree_values) in &tree_entries_rx {
                 tree_builder.set_or_remove(path, tree_values);
             }
         });
+        phase_durations.push(("process tree entries", start.elapsed()));
+        let start = Instant::now();
         let deleted_files = trace_span!("process deleted tree entries").in_scope(|| {
             let deleted_files = HashSet::from_iter(deleted_files_rx);
             is_dirty |= !deleted_files.is_empty();
@@ -1031,6 +1058,8 @@ impl TreeState {
             }
             deleted_files
         });
+        phase_durations.push(("process deleted tree entries", start.elapsed()));
+        let start = Instant::now();
         trace_span!("process file states").in_scope(|| {
             let changed_file_states = file_states_rx
                 .iter()
@@ -1040,11 +1069,21 @@ impl TreeState {
             self.file_states
                 .merge_in(changed_file_states, &deleted_files);
         });
+        phase_durations.push(("process file states", start.elapsed()));
+        let start = Instant::now();
         trace_span!("write tree").in_scope(|| {
             let new_tree_id = tree_builder.write_tree(&self.store).unwrap();
             is_dirty |= new_tree_id != self.tree_id;
             self.tree_id = new_tree_id;
         });
+        phase_durations.push(("write tree", start.elapsed()));
+        let stats = SnapshotStats {
+            untracked_paths,
+            files_scanned: counts.files_scanned,
+            files_hashed: counts.files_hashed,
+            bytes_read: counts.bytes_read,
+            phase_durations,
+        };
         if cfg!(debug_assertions) {
             let tree = self.current_tree().unwrap();
             let tree_paths: HashSet<_> = tree
@@ -1105,6 +1144,13 @@ impl TreeState {
     }
 }
 
+/// Counters collected while traversing the filesystem.
+struct SnapshotCounts {
+    files_scanned: usize,
+    files_hashed: usize,
+    bytes_read: u64,
+}
+
 struct DirectoryToVisit<'a> {
     dir: RepoPathBuf,
     disk_dir: PathBuf,
@@ -1135,9 +1181,15 @@ struct FileSnapshotter<'a> {
     untracked_paths_tx: Sender<(RepoPathBuf, UntrackedReason)>,
     deleted_files_tx: Sender<RepoPathBuf>,
     error: OnceLock<SnapshotError>,
+    files_scanned: AtomicUsize,
+    files_hashed: AtomicUsize,
+    bytes_read: AtomicU64,
     progress: Option<&'a SnapshotProgress<'a>>,
     max_new_file_size: u64,
     conflict_marker_style: ConflictMarkerStyle,
+    git_attributes: Option<&'a GitAttributes>,
+    git_lfs: bool,
+    eol_conversion: bool,
 }
 
 impl FileSnapshotter<'_> {
@@ -1157,10 +1209,14 @@ impl FileSnapshotter<'_> {
     }
 
     /// Extracts the result of the snapshot.
-    fn into_result(self) -> Result<(), SnapshotError> {
+    fn into_result(self) -> Result<SnapshotCounts, SnapshotError> {
         match self.error.into_inner() {
             Some(err) => Err(err),
-            None => Ok(()),
+            None => Ok(SnapshotCounts {
+                files_scanned: self.files_scanned.into_inner(),
+                files_hashed: self.files_hashed.into_inner(),
+                bytes_read: self.bytes_read.into_inner(),
+            }),
         }
     }
 
@@ -1281,7 +1337,11 @@ impl FileSnapshotter<'_> {
                     message: format!("Failed to stat file {}", entry.path().display()),
                     err: err.into(),
                 })?;
-                if maybe_current_file_state.is_none() && metadata.len() > self.max_new_file_size {
+                // Files tracked by LFS are stored as small pointers.
+                if maybe_current_file_state.is_none()
+                    && metadata.len() > self.max_new_file_size
+                    && !self.is_lfs_path(&path)
+                {
                     // Leave the large file untracked
                     let reason = UntrackedReason::FileTooLarge {
                         size: metadata.len(),
@@ -1348,6 +1408,7 @@ impl FileSnapshotter<'_> {
         maybe_current_file_state: Option<&FileState>,
         mut new_file_state: FileState,
     ) -> Result<(), SnapshotError> {
+        self.files_scanned.fetch_add(1, atomic::Ordering::Relaxed);
         let update = self.get_updated_tree_value(
             &path,
             disk_path,
@@ -1425,6 +1486,9 @@ impl FileSnapshotter<'_> {
         if clean {
             Ok(None)
         } else {
+            self.files_hashed.fetch_add(1, atomic::Ordering::Relaxed);
+            self.bytes_read
+                .fetch_add(new_file_state.size, atomic::Ordering::Relaxed);
             let current_tree_values = self.current_tree.path_value(repo_path)?;
             let new_file_type = if !self.tree_state.symlink_support {
                 let mut new_file_type = new_file_state.file_type.clone();
@@ -1467,6 +1531,20 @@ impl FileSnapshotter<'_> {
         &self.tree_state.store
     }
 
+    fn is_lfs_path(&self, path: &RepoPath) -> bool {
+        self.git_lfs
+            && self
+                .git_attributes
+                .is_some_and(|attributes| attributes.is_lfs(path))
+    }
+
+    fn eol_conversion(&self, path: &RepoPath) -> EolConversion {
+        match self.git_attributes {
+            Some(attributes) if self.eol_conversion => attributes.eol_conversion(path),
+            _ => EolConversion::None,
+        }
+    }
+
     async fn write_path_to_store(
         &self,
         repo_path: &RepoPath,
@@ -1544,6 +1622,43 @@ impl FileSnapshotter<'_> {
         path: &RepoPath,
         disk_path: &Path,
     ) -> Result<FileId, SnapshotError> {
+        let eol_conversion = self.eol_conversion(path);
+        if self.is_lfs_path(path) {
+            let content = fs::read(disk_path).map_err(|err| SnapshotError::Other {
+                message: format!("Failed to open file {}", disk_path.display()),
+                err: err.into(),
+            })?;
+            // Pointers are stored as is, e.g. if they couldn't be smudged on
+            // checkout.
+            let pointer = if git_lfs::is_lfs_pointer(&content) {
+                content
+            } else {
+                git_lfs::clean(&self.tree_state.working_copy_path, path, &content).map_err(
+                    |err| SnapshotError::Other {
+                        message: format!(
+                            "Failed to convert {} to a Git LFS pointer",
+                            disk_path.display()
+                        ),
+                        err: err.into(),
+                    },
+                )?
+            };
+            return Ok(self
+                .store()
+                .write_file(path, &mut pointer.as_slice())
+                .await?);
+        } else if eol_conversion != EolConversion::None {
+            let content = fs::read(disk_path).map_err(|err| SnapshotError::Other {
+                message: format!("Failed to open file {}", disk_path.display()),
+                err: err.into(),
+            })?;
+            // Text files are stored with LF line endings.
+            let content = match eol_conversion.eol_for(&content) {
+                Some(_) => gitattributes::convert_to_lf(&content),
+                None => content.as_slice().into(),
+            };
+            return Ok(self.store().write_file(path, &mut content.as_ref()).await?);
+        }
         let mut file = File::open(disk_path).map_err(|err| SnapshotError::Other {
             message: format!("Failed to open file {}", disk_path.display()),
             err: err.into(),
@@ -1582,6 +1697,114 @@ impl FileSnapshotter<'_> {
     }
 }
 
+/// Maximum number of files queued before they are written to disk.
+const CHECKOUT_BATCH_MAX_FILES: usize = 1000;
+/// Maximum total size of contents buffered in memory before the queued files
+/// are written to disk. Regular files aren't buffered.
+const CHECKOUT_BATCH_MAX_BYTES: usize = 64 << 20;
+
+/// Value of a path to be checked out. Regular files are read by the thread
+/// writing them, so their contents are streamed to disk.
+enum CheckoutValue {
+    File { id: FileId, executable: bool },
+    Materialized(MaterializedTreeValue),
+}
+
+impl CheckoutValue {
+    fn is_absent(&self) -> bool {
+        match self {
+            CheckoutValue::File { .. } => false,
+            CheckoutValue::Materialized(value) => value.is_absent(),
+        }
+    }
+}
+
+/// Contents of a file to be written to disk on checkout.
+enum PendingWrite {
+    /// A regular file to be read from the store.
+    StoredFile {
+        id: FileId,
+        executable: bool,
+    },
+    File {
+        contents: Vec<u8>,
+        executable: bool,
+    },
+    Symlink {
+        target: String,
+    },
+    Conflict {
+        data: Vec<u8>,
+        executable: bool,
+        materialized_conflict_data: Option<MaterializedConflictData>,
+    },
+}
+
+impl PendingWrite {
+    /// Returns the number of bytes buffered in memory.
+    fn len(&self) -> usize {
+        match self {
+            PendingWrite::StoredFile { .. } => 0,
+            PendingWrite::File { contents, .. } => contents.len(),
+            PendingWrite::Symlink { target } => target.len(),
+            PendingWrite::Conflict { data, .. } => data.len(),
+        }
+    }
+}
+
+enum PendingFileState {
+    /// The file state is known without writing anything, e.g. for skipped
+    /// paths.
+    Ready(FileState),
+    /// The file is yet to be written to the given disk path.
+    Write(PathBuf, PendingWrite),
+}
+
+/// Creates a thread pool for writing files on checkout. Returns `None` if
+/// files should be written sequentially.
+fn checkout_thread_pool(parallelism: usize) -> Result<Option<rayon::ThreadPool>, CheckoutError> {
+    if parallelism == 1 {
+        return Ok(None);
+    }
+    // Zero lets rayon pick the number of threads based on the available CPUs.
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(parallelism)
+        .thread_name(|i| format!("jj-checkout-{i}"))
+        .build()
+        .map_err(|err| CheckoutError::Other {
+            message: "Failed to create checkout thread pool".to_string(),
+            err: err.into(),
+        })?;
+    Ok(Some(pool))
+}
+
+/// Loads the `.gitattributes` file at the root of `tree`. Conflicted files
+/// are ignored.
+async fn load_git_attributes(
+    store: &Store,
+    tree: &MergedTree,
+) -> Result<GitAttributes, CheckoutError> {
+    let path = RepoPath::from_internal_string(".gitattributes");
+    let Some(Some(TreeValue::File { id, .. })) = tree.path_value(path)?.into_resolved().ok() else {
+        return Ok(GitAttributes::empty());
+    };
+    let mut content = vec![];
+    store
+        .read_file_async(path, &id)
+        .await?
+        .read_to_end(&mut content)
+        .map_err(|err| CheckoutError::Other {
+            message: format!("Failed to read file contents for {path:?}"),
+            err: err.into(),
+        })?;
+    GitAttributes::parse(Path::new(".gitattributes"), &content).map_err(|err| {
+        CheckoutError::Other {
+            message: "Failed to parse .gitattributes".to_string(),
+            err: err.into(),
+        }
+    })
+}
+
 /// Functions to update local-disk files from the store.
 impl TreeState {
     fn write_file(
@@ -1673,6 +1896,98 @@ impl TreeState {
         Ok(())
     }
 
+    fn write_pending(
+        &self,
+        path: &RepoPath,
+        disk_path: &Path,
+        write: PendingWrite,
+        git_attributes: &GitAttributes,
+        git_lfs: bool,
+    ) -> Result<Option<FileState>, CheckoutError> {
+        let file_state = match write {
+            PendingWrite::StoredFile { id, executable } => {
+                let mut reader = match self.store.read_file(path, &id) {
+                    Ok(reader) => reader,
+                    // The old file has already been removed. Leave the path
+                    // deleted as if the file were absent.
+                    Err(BackendError::ReadAccessDenied { .. }) => return Ok(None),
+                    Err(err) => return Err(err.into()),
+                };
+                let eol_conversion = git_attributes.eol_conversion(path);
+                if !git_lfs && eol_conversion == EolConversion::None {
+                    return self
+                        .write_file(disk_path, &mut reader, executable)
+                        .map(Some);
+                }
+                let mut contents = vec![];
+                reader
+                    .read_to_end(&mut contents)
+                    .map_err(|err| CheckoutError::Other {
+                        message: format!("Failed to read file contents for {path:?}"),
+                        err: err.into(),
+                    })?;
+                if git_lfs && git_lfs::is_lfs_pointer(&contents) {
+                    // If the contents aren't available, the pointer is
+                    // written instead, and will be stored as is on snapshot.
+                    if let Ok(smudged) = git_lfs::smudge(&self.working_copy_path, path, &contents) {
+                        contents = smudged;
+                    }
+                } else if let Some(Eol::Crlf) = eol_conversion.eol_for(&contents) {
+                    contents = gitattributes::convert_to_crlf(&contents).into_owned();
+                }
+                self.write_file(disk_path, &mut contents.as_slice(), executable)?
+            }
+            PendingWrite::File {
+                contents,
+                executable,
+            } => self.write_file(disk_path, &mut contents.as_slice(), executable)?,
+            PendingWrite::Symlink { target } => self.write_symlink(disk_path, target)?,
+            PendingWrite::Conflict {
+                data,
+                executable,
+                materialized_conflict_data,
+            } => self.write_conflict(disk_path, data, executable, materialized_conflict_data)?,
+        };
+        Ok(Some(file_state))
+    }
+
+    /// Writes the pending files, in parallel if a thread pool is given.
+    /// Appends the resulting file states in the original order, and the paths
+    /// which couldn't be read to `deleted_files`.
+    fn flush_pending_writes(
+        &self,
+        pool: Option<&rayon::ThreadPool>,
+        pending: Vec<(RepoPathBuf, PendingFileState)>,
+        git_attributes: &GitAttributes,
+        git_lfs: bool,
+        changed_file_states: &mut Vec<(RepoPathBuf, FileState)>,
+        deleted_files: &mut HashSet<RepoPathBuf>,
+    ) -> Result<(), CheckoutError> {
+        let write = |(path, state): (RepoPathBuf, _)| {
+            let file_state = match state {
+                PendingFileState::Ready(file_state) => Some(file_state),
+                PendingFileState::Write(disk_path, write) => {
+                    self.write_pending(&path, &disk_path, write, git_attributes, git_lfs)?
+                }
+            };
+            Ok((path, file_state))
+        };
+        let results: Result<Vec<_>, CheckoutError> = match pool {
+            Some(pool) if pending.len() > 1 => {
+                pool.install(|| pending.into_par_iter().map(write).collect())
+            }
+            _ => pending.into_iter().map(write).collect(),
+        };
+        for (path, file_state) in results? {
+            if let Some(file_state) = file_state {
+                changed_file_states.push((path, file_state));
+            } else {
+                deleted_files.insert(path);
+            }
+        }
+        Ok(())
+    }
+
     pub fn check_out(
         &mut self,
         new_tree: &MergedTree,
@@ -1685,12 +2000,7 @@ impl TreeState {
             other => CheckoutError::InternalBackendError(other),
         })?;
         let stats = self
-            .update(
-                &old_tree,
-                new_tree,
-                self.sparse_matcher().as_ref(),
-                options.conflict_marker_style,
-            )
+            .update(&old_tree, new_tree, self.sparse_matcher().as_ref(), options)
             .block_on()?;
         self.tree_id = new_tree.id();
         Ok(stats)
@@ -1713,20 +2023,10 @@ impl TreeState {
         let removed_matcher = DifferenceMatcher::new(&old_matcher, &new_matcher);
         let empty_tree = MergedTree::resolved(Tree::empty(self.store.clone(), RepoPathBuf::root()));
         let added_stats = self
-            .update(
-                &empty_tree,
-                &tree,
-                &added_matcher,
-                options.conflict_marker_style,
-            )
+            .update(&empty_tree, &tree, &added_matcher, options)
             .block_on()?;
         let removed_stats = self
-            .update(
-                &tree,
-                &empty_tree,
-                &removed_matcher,
-                options.conflict_marker_style,
-            )
+            .update(&tree, &empty_tree, &removed_matcher, options)
             .block_on()?;
         self.sparse_patterns = sparse_patterns;
         assert_eq!(added_stats.updated_files, 0);
@@ -1747,7 +2047,7 @@ impl TreeState {
         old_tree: &MergedTree,
         new_tree: &MergedTree,
         matcher: &dyn Matcher,
-        conflict_marker_style: ConflictMarkerStyle,
+        options: &CheckoutOptions,
     ) -> Result<CheckoutStats, CheckoutError> {
         // TODO: maybe it's better not include the skipped counts in the "intended"
         // counts
@@ -1757,14 +2057,34 @@ impl TreeState {
             removed_files: 0,
             skipped_files: 0,
         };
+        let pool = checkout_thread_pool(options.parallelism)?;
+        let git_attributes = if options.eol_conversion {
+            load_git_attributes(&self.store, new_tree).await?
+        } else {
+            GitAttributes::empty()
+        };
         let mut changed_file_states = Vec::new();
         let mut deleted_files = HashSet::new();
+        // Files to be written are queued in path order, and flushed in batches
+        // to the thread pool.
+        let mut pending = Vec::new();
+        let mut pending_bytes = 0;
         let mut diff_stream = old_tree
             .diff_stream(new_tree, matcher)
             .map(|TreeDiffEntry { path, values }| async {
                 match values {
                     Ok((before, after)) => {
-                        let result = materialize_tree_value(&self.store, &path, after).await;
+                        let result = match after.as_resolved() {
+                            Some(Some(TreeValue::File { id, executable })) => {
+                                Ok(CheckoutValue::File {
+                                    id: id.clone(),
+                                    executable: *executable,
+                                })
+                            }
+                            _ => materialize_tree_value(&self.store, &path, after)
+                                .await
+                                .map(CheckoutValue::Materialized),
+                        };
                         (path, result.map(|value| (before, value)))
                     }
                     Err(err) => (path, Err(err)),
@@ -1789,7 +2109,10 @@ impl TreeState {
             // paths excluded by .gitignore can be marked as such so that
             // newly-"unignored" paths won't be snapshotted automatically.
             if matches!(before.as_normal(), Some(TreeValue::GitSubmodule(_)))
-                && matches!(after, MaterializedTreeValue::GitSubmodule(_))
+                && matches!(
+                    after,
+                    CheckoutValue::Materialized(MaterializedTreeValue::GitSubmodule(_))
+                )
             {
                 eprintln!("ignoring git submodule at {path:?}");
                 // Not updating the file state as if there were no diffs. Leave
@@ -1800,7 +2123,7 @@ impl TreeState {
             // Create parent directories no matter if after.is_present(). This
             // ensures that the path never traverses symlinks.
             let Some(disk_path) = create_parent_dirs(&self.working_copy_path, &path)? else {
-                changed_file_states.push((path, FileState::placeholder()));
+                pending.push((path, PendingFileState::Ready(FileState::placeholder())));
                 stats.skipped_files += 1;
                 continue;
             };
@@ -1808,75 +2131,122 @@ impl TreeState {
             let present_file_deleted = before.is_present() && remove_old_file(&disk_path)?;
             // If not, create temporary file to test the path validity.
             if !present_file_deleted && !can_create_new_file(&disk_path)? {
-                changed_file_states.push((path, FileState::placeholder()));
+                pending.push((path, PendingFileState::Ready(FileState::placeholder())));
                 stats.skipped_files += 1;
                 continue;
             }
 
             // TODO: Check that the file has not changed before overwriting/removing it.
-            let file_state = match after {
-                MaterializedTreeValue::Absent | MaterializedTreeValue::AccessDenied(_) => {
-                    let mut parent_dir = disk_path.parent().unwrap();
-                    loop {
-                        if fs::remove_dir(parent_dir).is_err() {
-                            break;
+            let write = match after {
+                CheckoutValue::File { id, executable } => {
+                    PendingWrite::StoredFile { id, executable }
+                }
+                CheckoutValue::Materialized(value) => match value {
+                    MaterializedTreeValue::Absent | MaterializedTreeValue::AccessDenied(_) => {
+                        // Finish the pending writes first. Otherwise, the parent
+                        // directories of files yet to be written could be removed.
+                        self.flush_pending_writes(
+                            pool.as_ref(),
+                            mem::take(&mut pending),
+                            &git_attributes,
+                            options.git_lfs,
+                            &mut changed_file_states,
+                            &mut deleted_files,
+                        )?;
+                        pending_bytes = 0;
+                        let mut parent_dir = disk_path.parent().unwrap();
+                        loop {
+                            if fs::remove_dir(parent_dir).is_err() {
+                                break;
+                            }
+                            parent_dir = parent_dir.parent().unwrap();
                         }
-                        parent_dir = parent_dir.parent().unwrap();
+                        deleted_files.insert(path);
+                        continue;
                     }
-                    deleted_files.insert(path);
-                    continue;
-                }
-                MaterializedTreeValue::File {
-                    executable,
-                    mut reader,
-                    ..
-                } => self.write_file(&disk_path, &mut reader, executable)?,
-                MaterializedTreeValue::Symlink { id: _, target } => {
-                    if self.symlink_support {
-                        self.write_symlink(&disk_path, target)?
-                    } else {
-                        self.write_file(&disk_path, &mut target.as_bytes(), false)?
+                    MaterializedTreeValue::File { id, executable, .. } => {
+                        PendingWrite::StoredFile { id, executable }
                     }
-                }
-                MaterializedTreeValue::GitSubmodule(_) => {
-                    eprintln!("ignoring git submodule at {path:?}");
-                    FileState::for_gitsubmodule()
-                }
-                MaterializedTreeValue::Tree(_) => {
-                    panic!("unexpected tree entry in diff at {path:?}");
-                }
-                MaterializedTreeValue::FileConflict {
-                    id: _,
-                    contents,
-                    executable,
-                } => {
-                    let conflict_marker_len = choose_materialized_conflict_marker_len(&contents);
-                    let data = materialize_merge_result_to_bytes_with_marker_len(
-                        &contents,
-                        conflict_marker_style,
-                        conflict_marker_len,
-                    )
-                    .into();
-                    let materialized_conflict_data = MaterializedConflictData {
-                        conflict_marker_len: conflict_marker_len.try_into().unwrap_or(u32::MAX),
-                    };
-                    self.write_conflict(
-                        &disk_path,
-                        data,
+                    MaterializedTreeValue::Symlink { id: _, target } => {
+                        if self.symlink_support {
+                            PendingWrite::Symlink { target }
+                        } else {
+                            PendingWrite::File {
+                                contents: target.into_bytes(),
+                                executable: false,
+                            }
+                        }
+                    }
+                    MaterializedTreeValue::GitSubmodule(_) => {
+                        eprintln!("ignoring git submodule at {path:?}");
+                        pending
+                            .push((path, PendingFileState::Ready(FileState::for_gitsubmodule())));
+                        continue;
+                    }
+                    MaterializedTreeValue::Tree(_) => {
+                        panic!("unexpected tree entry in diff at {path:?}");
+                    }
+                    MaterializedTreeValue::FileConflict {
+                        id: _,
+                        contents,
                         executable,
-                        Some(materialized_conflict_data),
-                    )?
-                }
-                MaterializedTreeValue::OtherConflict { id } => {
-                    // Unless all terms are regular files, we can't do much
-                    // better than trying to describe the merge.
-                    let data = id.describe().into_bytes();
-                    let executable = false;
-                    self.write_conflict(&disk_path, data, executable, None)?
-                }
+                    } => {
+                        let conflict_marker_len =
+                            choose_materialized_conflict_marker_len(&contents);
+                        let data = materialize_merge_result_to_bytes_with_marker_len(
+                            &contents,
+                            options.conflict_marker_style,
+                            conflict_marker_len,
+                        )
+                        .into();
+                        let materialized_conflict_data = MaterializedConflictData {
+                            conflict_marker_len: conflict_marker_len.try_into().unwrap_or(u32::MAX),
+                        };
+                        PendingWrite::Conflict {
+                            data,
+                            executable,
+                            materialized_conflict_data: Some(materialized_conflict_data),
+                        }
+                    }
+                    MaterializedTreeValue::OtherConflict { id } => {
+                        // Unless all terms are regular files, we can't do much
+                        // better than trying to describe the merge.
+                        PendingWrite::Conflict {
+                            data: id.describe().into_bytes(),
+                            executable: false,
+                            materialized_conflict_data: None,
+                        }
+                    }
+                },
             };
-            changed_file_states.push((path, file_state));
+            pending_bytes += write.len();
+            pending.push((path, PendingFileState::Write(disk_path, write)));
+            if pending.len() >= CHECKOUT_BATCH_MAX_FILES
+                || pending_bytes >= CHECKOUT_BATCH_MAX_BYTES
+            {
+                self.flush_pending_writes(
+                    pool.as_ref(),
+                    mem::take(&mut pending),
+                    &git_attributes,
+                    options.git_lfs,
+                    &mut changed_file_states,
+                    &mut deleted_files,
+                )?;
+                pending_bytes = 0;
+            }
         }
+        self.flush_pending_writes(
+            pool.as_ref(),
+            pending,
+            &git_attributes,
+            options.git_lfs,
+            &mut changed_file_states,
+            &mut deleted_files,
+        )?;
+        // If any of the writes failed, we've returned early without updating
+        // the file states. The files written so far will then be reported as
+        // modified by the next snapshot, and the old tree can be checked out
+        // again.
         self.file_states
             .merge_in(changed_file_states, &deleted_files);
         Ok(stats)
@@ -0,0 +1,228 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for Git's `.mailmap` files, which map the names and emails recorded
+//! in commits to canonical identities.
+//!
+//! The mailmap is only consulted when displaying or matching commit authors.
+//! Commits are never rewritten.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use crate::backend::Signature;
+
+/// Canonical name and email to use in place of a recorded identity. Either
+/// part may be absent, in which case the recorded value is kept.
+#[derive(Clone, Debug, Default)]
+struct MailmapTarget {
+    name: Option<String>,
+    email: Option<String>,
+}
+
+impl MailmapTarget {
+    fn merge(&mut self, name: Option<&str>, email: Option<&str>) {
+        if let Some(name) = name {
+            self.name = Some(name.to_owned());
+        }
+        if let Some(email) = email {
+            self.email = Some(email.to_owned());
+        }
+    }
+}
+
+/// Mappings for a single commit email.
+#[derive(Clone, Debug, Default)]
+struct MailmapEmailEntry {
+    /// Mapping applied regardless of the commit name.
+    any_name: MailmapTarget,
+    /// Mappings applied only to the given (lowercased) commit name.
+    by_name: BTreeMap<String, MailmapTarget>,
+}
+
+/// Parsed `.mailmap` file.
+///
+/// Lookups are case-insensitive on both names and emails, and later lines
+/// override earlier ones, as in Git.
+#[derive(Clone, Debug, Default)]
+pub struct Mailmap {
+    /// Entries keyed by lowercased commit email.
+    entries: BTreeMap<String, MailmapEmailEntry>,
+}
+
+impl Mailmap {
+    /// Creates an empty mailmap which maps every identity to itself.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Parses the contents of a `.mailmap` file. Malformed lines are ignored.
+    pub fn parse(text: &str) -> Self {
+        let mut mailmap = Self::empty();
+        for line in text.lines() {
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+            let Some((name1, email1, rest)) = parse_name_and_email(line) else {
+                continue;
+            };
+            match parse_name_and_email(rest) {
+                // Proper Name <proper@email> [Commit Name] <commit@email>
+                Some((name2, email2, _)) => mailmap.add(name1, Some(email1), name2, email2),
+                // Proper Name <commit@email>
+                None => mailmap.add(name1, None, None, email1),
+            }
+        }
+        mailmap
+    }
+
+    fn add(
+        &mut self,
+        proper_name: Option<&str>,
+        proper_email: Option<&str>,
+        commit_name: Option<&str>,
+        commit_email: &str,
+    ) {
+        let entry = self.entries.entry(commit_email.to_lowercase()).or_default();
+        let target = match commit_name {
+            Some(name) => entry.by_name.entry(name.to_lowercase()).or_default(),
+            None => &mut entry.any_name,
+        };
+        target.merge(proper_name, proper_email);
+    }
+
+    /// Returns true if the mailmap has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the canonical name and email for the identity recorded as
+    /// `name` and `email`.
+    pub fn resolve<'a>(&'a self, name: &'a str, email: &'a str) -> (&'a str, &'a str) {
+        let Some(entry) = self.entries.get(&email.to_lowercase()) else {
+            return (name, email);
+        };
+        let target = entry
+            .by_name
+            .get(&name.to_lowercase())
+            .unwrap_or(&entry.any_name);
+        (
+            target.name.as_deref().unwrap_or(name),
+            target.email.as_deref().unwrap_or(email),
+        )
+    }
+
+    /// Returns `signature` with its name and email mapped to the canonical
+    /// identity.
+    pub fn resolve_signature<'a>(&self, signature: &'a Signature) -> Cow<'a, Signature> {
+        let (name, email) = self.resolve(&signature.name, &signature.email);
+        if name == signature.name && email == signature.email {
+            Cow::Borrowed(signature)
+        } else {
+            Cow::Owned(Signature {
+                name: name.to_owned(),
+                email: email.to_owned(),
+                timestamp: signature.timestamp,
+            })
+        }
+    }
+}
+
+/// Parses `[Name] <email>` at the start of `text`. Returns the name (if
+/// non-empty), the email, and the remainder of the text.
+fn parse_name_and_email(text: &str) -> Option<(Option<&str>, &str, &str)> {
+    let (name, rest) = text.split_once('<')?;
+    let (email, rest) = rest.split_once('>')?;
+    let name = name.trim();
+    let name = (!name.is_empty()).then_some(name);
+    Some((name, email.trim(), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mailmap_empty() {
+        let mailmap = Mailmap::parse("");
+        assert!(mailmap.is_empty());
+        assert_eq!(
+            mailmap.resolve("Some One", "some.one@example.com"),
+            ("Some One", "some.one@example.com")
+        );
+    }
+
+    #[test]
+    fn test_mailmap_forms() {
+        let mailmap = Mailmap::parse(
+            "\
+# Comment
+Proper Name <commit@example.com>
+<proper@example.com> <old@example.com>
+Other Name <other@example.com> <any@example.com>
+Specific Name <specific@example.com> Commit Name <shared@example.com>
+not a valid line
+",
+        );
+        assert_eq!(
+            mailmap.resolve("Commit Name", "commit@example.com"),
+            ("Proper Name", "commit@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("Old Name", "old@example.com"),
+            ("Old Name", "proper@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("Whoever", "any@example.com"),
+            ("Other Name", "other@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("Commit Name", "shared@example.com"),
+            ("Specific Name", "specific@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("Someone Else", "shared@example.com"),
+            ("Someone Else", "shared@example.com")
+        );
+        assert_eq!(
+            mailmap.resolve("Unknown", "unknown@example.com"),
+            ("Unknown", "unknown@example.com")
+        );
+    }
+
+    #[test]
+    fn test_mailmap_case_insensitive() {
+        let mailmap =
+            Mailmap::parse("Proper Name <proper@example.com> Commit Name <Commit@Example.com>");
+        assert_eq!(
+            mailmap.resolve("commit name", "COMMIT@example.com"),
+            ("Proper Name", "proper@example.com")
+        );
+    }
+
+    #[test]
+    fn test_mailmap_later_lines_override() {
+        let mailmap = Mailmap::parse(
+            "\
+First Name <commit@example.com>
+<proper@example.com> <commit@example.com>
+Second Name <commit@example.com>
+",
+        );
+        assert_eq!(
+            mailmap.resolve("Commit Name", "commit@example.com"),
+            ("Second Name", "proper@example.com")
+        );
+    }
+}
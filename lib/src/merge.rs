@@ -26,13 +26,13 @@ use std::slice;
 use std::sync::Arc;
 
 use itertools::Itertools;
+use pollster::FutureExt as _;
 use smallvec::smallvec_inline;
 use smallvec::SmallVec;
 
 use crate::backend;
 use crate::backend::BackendResult;
 use crate::backend::FileId;
-use crate::backend::TreeId;
 use crate::backend::TreeValue;
 use crate::content_hash::ContentHash;
 use crate::content_hash::DigestUpdate;
@@ -588,6 +588,16 @@ where
         &self,
         store: &Arc<Store>,
         dir: &RepoPath,
+    ) -> BackendResult<Option<Merge<Tree>>> {
+        self.to_tree_merge_async(store, dir).block_on()
+    }
+
+    /// Async version of `to_tree_merge()`. The trees are read from the store
+    /// in a single batch.
+    pub async fn to_tree_merge_async(
+        &self,
+        store: &Arc<Store>,
+        dir: &RepoPath,
     ) -> BackendResult<Option<Merge<Tree>>> {
         let tree_id_merge = self.maybe_map(|term| match borrow_tree_value(term.as_ref()) {
             None => Some(None),
@@ -595,14 +605,20 @@ where
             Some(_) => None,
         });
         if let Some(tree_id_merge) = tree_id_merge {
-            let get_tree = |id: &Option<&TreeId>| -> BackendResult<Tree> {
-                if let Some(id) = id {
-                    store.get_tree(dir.to_owned(), id)
-                } else {
-                    Ok(Tree::empty(store.clone(), dir.to_owned()))
-                }
-            };
-            Ok(Some(tree_id_merge.try_map(get_tree)?))
+            let ids = tree_id_merge
+                .iter()
+                .flatten()
+                .copied()
+                .cloned()
+                .collect_vec();
+            let mut trees = store
+                .get_trees_async(dir.to_owned(), &ids)
+                .await?
+                .into_iter();
+            Ok(Some(tree_id_merge.map(|id| match id {
+                Some(_) => trees.next().unwrap(),
+                None => Tree::empty(store.clone(), dir.to_owned()),
+            })))
         } else {
             Ok(None)
         }
@@ -30,9 +30,7 @@ use std::vec;
 use either::Either;
 use futures::future::BoxFuture;
 use futures::stream::BoxStream;
-use futures::stream::StreamExt;
 use futures::Stream;
-use futures::TryStreamExt;
 use itertools::EitherOrBoth;
 use itertools::Itertools;
 use pollster::FutureExt;
@@ -940,17 +938,6 @@ impl<'matcher> TreeDiffStreamImpl<'matcher> {
         stream
     }
 
-    async fn single_tree(
-        store: &Arc<Store>,
-        dir: RepoPathBuf,
-        value: Option<&TreeValue>,
-    ) -> BackendResult<Tree> {
-        match value {
-            Some(TreeValue::Tree(tree_id)) => store.get_tree_async(dir, tree_id).await,
-            _ => Ok(Tree::empty(store.clone(), dir.clone())),
-        }
-    }
-
     /// Gets the given trees if `values` are trees, otherwise an empty tree.
     async fn trees(
         store: Arc<Store>,
@@ -958,11 +945,8 @@ impl<'matcher> TreeDiffStreamImpl<'matcher> {
         values: MergedTreeValue,
     ) -> BackendResult<Merge<Tree>> {
         if values.is_tree() {
-            let builder: MergeBuilder<Tree> = futures::stream::iter(values.iter())
-                .then(|value| Self::single_tree(&store, dir.clone(), value.as_ref()))
-                .try_collect()
-                .await?;
-            Ok(builder.build())
+            let trees = values.to_tree_merge_async(&store, &dir).await?;
+            Ok(trees.unwrap())
         } else {
             Ok(Merge::resolved(Tree::empty(store, dir)))
         }
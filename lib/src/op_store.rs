@@ -463,6 +463,10 @@ pub enum OpStoreError {
     },
     #[error(transparent)]
     Other(Box<dyn std::error::Error + Send + Sync>),
+    /// A valid operation attempted, but failed because it isn't supported by
+    /// the particular operation store.
+    #[error("{0}")]
+    Unsupported(String),
 }
 
 pub type OpStoreResult<T> = Result<T, OpStoreError>;
@@ -494,8 +498,28 @@ pub trait OpStore: Send + Sync + Debug {
     /// removed. In addition to that, objects created after `keep_newer` will be
     /// preserved. This mitigates a risk of deleting new heads created
     /// concurrently by another process.
-    // TODO: return stats?
     fn gc(&self, head_ids: &[OperationId], keep_newer: SystemTime) -> OpStoreResult<()>;
+
+    /// Reports the operations and views `gc()` would prune, without removing
+    /// anything.
+    fn gc_dry_run(
+        &self,
+        _head_ids: &[OperationId],
+        _keep_newer: SystemTime,
+    ) -> OpStoreResult<OpStoreGcStats> {
+        Err(OpStoreError::Unsupported(
+            "This operation store doesn't support gc dry runs".to_owned(),
+        ))
+    }
+}
+
+/// Operations and views that garbage collection would prune.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OpStoreGcStats {
+    pub operations: usize,
+    pub views: usize,
+    /// Total size of the files on disk.
+    pub bytes: u64,
 }
 
 #[cfg(test)]
@@ -104,6 +104,33 @@ pub fn resolve_op_for_load(
     resolve_single_op(op_store, get_current_op, get_head_ops, op_str)
 }
 
+/// Resolves operation set expression to an operation id without loading a
+/// repo.
+///
+/// Unlike `resolve_op_for_load()`, "@" and operation id prefixes are resolved
+/// without reading the operation itself, so the id of an undecodable operation
+/// can still be obtained. Expressions navigating to parents or children still
+/// have to read the operations in between.
+pub fn resolve_op_id_for_load(
+    repo_loader: &RepoLoader,
+    op_str: &str,
+) -> Result<OperationId, OpsetEvaluationError> {
+    if op_str.ends_with(['-', '+']) {
+        return Ok(resolve_op_for_load(repo_loader, op_str)?.id().clone());
+    }
+    let op_store = repo_loader.op_store();
+    if op_str == "@" {
+        let mut op_head_ids = repo_loader.op_heads_store().get_op_heads()?;
+        return match op_head_ids.len() {
+            0 => Err(OpsetResolutionError::EmptyOperations(op_str.to_owned()).into()),
+            1 => Ok(op_head_ids.pop().unwrap()),
+            // Divergent heads need to be resolved by loading them
+            _ => Ok(resolve_op_for_load(repo_loader, op_str)?.id().clone()),
+        };
+    }
+    resolve_op_id_from_store(op_store, op_str)
+}
+
 /// Resolves operation set expression against the loaded repo.
 ///
 /// The "@" symbol will be resolved to the operation the repo was loaded at.
@@ -170,6 +197,15 @@ fn resolve_single_op_from_store(
     op_store: &Arc<dyn OpStore>,
     op_str: &str,
 ) -> Result<Operation, OpsetEvaluationError> {
+    let op_id = resolve_op_id_from_store(op_store, op_str)?;
+    let data = op_store.read_operation(&op_id)?;
+    Ok(Operation::new(op_store.clone(), op_id, data))
+}
+
+fn resolve_op_id_from_store(
+    op_store: &Arc<dyn OpStore>,
+    op_str: &str,
+) -> Result<OperationId, OpsetEvaluationError> {
     if op_str.is_empty() {
         return Err(OpsetResolutionError::InvalidIdPrefix(op_str.to_owned()).into());
     }
@@ -179,10 +215,7 @@ fn resolve_single_op_from_store(
         PrefixResolution::NoMatch => {
             Err(OpsetResolutionError::NoSuchOperation(op_str.to_owned()).into())
         }
-        PrefixResolution::SingleMatch(op_id) => {
-            let data = op_store.read_operation(&op_id)?;
-            Ok(Operation::new(op_store.clone(), op_id, data))
-        }
+        PrefixResolution::SingleMatch(op_id) => Ok(op_id),
         PrefixResolution::AmbiguousMatch => {
             Err(OpsetResolutionError::AmbiguousIdPrefix(op_str.to_owned()).into())
         }
@@ -243,19 +276,39 @@ impl PartialOrd for OperationByEndTime {
 
 /// Walks `head_ops` and their ancestors in reverse topological order.
 pub fn walk_ancestors(head_ops: &[Operation]) -> impl Iterator<Item = OpStoreResult<Operation>> {
+    walk_ancestors_excluding(head_ops, |_| Ok(false))
+}
+
+/// Walks `head_ops` and their ancestors in reverse topological order, without
+/// emitting or following the operations for which `is_excluded` returns true.
+///
+/// `is_excluded` is called for the parents of an operation before the
+/// operation is emitted.
+pub fn walk_ancestors_excluding(
+    head_ops: &[Operation],
+    mut is_excluded: impl FnMut(&Operation) -> OpStoreResult<bool>,
+) -> impl Iterator<Item = OpStoreResult<Operation>> {
+    let mut included = move |op: Operation| match is_excluded(&op) {
+        Ok(true) => None,
+        Ok(false) => Some(Ok(OperationByEndTime(op))),
+        Err(err) => Some(Err(err)),
+    };
     // Emit the latest head first to stabilize the order.
-    let mut head_ops = head_ops
-        .iter()
-        .cloned()
-        .map(OperationByEndTime)
-        .collect_vec();
-    head_ops.sort_unstable_by(|op1, op2| op1.cmp(op2).reverse());
+    let mut head_ops: Vec<_> = head_ops.iter().cloned().filter_map(&mut included).collect();
+    head_ops.sort_unstable_by(|op1, op2| match (op1, op2) {
+        (Ok(op1), Ok(op2)) => op1.cmp(op2).reverse(),
+        _ => op1.is_ok().cmp(&op2.is_ok()),
+    });
     // Lazily load operations based on timestamp-based heuristic. This works so long
     // as the operation history is mostly linear.
     dag_walk::topo_order_reverse_lazy_ok(
-        head_ops.into_iter().map(Ok),
+        head_ops,
         |OperationByEndTime(op)| op.id().clone(),
-        |OperationByEndTime(op)| op.parents().map_ok(OperationByEndTime).collect_vec(),
+        move |OperationByEndTime(op)| {
+            op.parents()
+                .filter_map(|parent| parent.map_or_else(|err| Some(Err(err)), &mut included))
+                .collect_vec()
+        },
     )
     .map_ok(|OperationByEndTime(op)| op)
 }
@@ -0,0 +1,541 @@
+// Copyright 2024 The Jujutsu Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parsing and applying patches in the unified diff format, as produced by
+//! `jj diff --git` or `git diff`.
+
+use std::fmt;
+
+use bstr::ByteSlice as _;
+use thiserror::Error;
+
+/// Error parsing a patch.
+#[derive(Debug, Error)]
+#[error("Invalid patch on line {line_number}: {message}")]
+pub struct PatchParseError {
+    /// 1-based line number in the patch.
+    pub line_number: usize,
+    /// Description of the problem.
+    pub message: String,
+}
+
+/// Changes to a single file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilePatch {
+    /// Path of the file before the change, or `None` if the file is added.
+    /// Paths are relative to the repository root, with the `a/` prefix
+    /// removed.
+    pub old_path: Option<String>,
+    /// Path of the file after the change, or `None` if the file is deleted.
+    pub new_path: Option<String>,
+    /// Executable bit set by a "new file mode" or "new mode" line, if any.
+    pub executable: Option<bool>,
+    /// Changes to the file contents.
+    pub hunks: Vec<PatchHunk>,
+}
+
+/// A contiguous change in a file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PatchHunk {
+    /// 1-based line number of the hunk in the old file.
+    pub old_start: usize,
+    /// Number of lines of the hunk in the old file.
+    pub old_len: usize,
+    /// 1-based line number of the hunk in the new file.
+    pub new_start: usize,
+    /// Number of lines of the hunk in the new file.
+    pub new_len: usize,
+    /// Lines of the hunk, including newline characters.
+    pub lines: Vec<PatchLine>,
+}
+
+impl PatchHunk {
+    /// Lines expected in the old file.
+    fn old_lines(&self) -> impl Iterator<Item = &[u8]> {
+        self.lines.iter().filter_map(|line| match line {
+            PatchLine::Context(text) | PatchLine::Removed(text) => Some(text.as_slice()),
+            PatchLine::Added(_) => None,
+        })
+    }
+
+    /// Lines replacing the old lines in the new file.
+    fn new_lines(&self) -> impl Iterator<Item = &[u8]> {
+        self.lines.iter().filter_map(|line| match line {
+            PatchLine::Context(text) | PatchLine::Added(text) => Some(text.as_slice()),
+            PatchLine::Removed(_) => None,
+        })
+    }
+}
+
+impl fmt::Display for PatchHunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_len, self.new_start, self.new_len
+        )
+    }
+}
+
+/// A line of a hunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PatchLine {
+    /// Line present in both the old and new file.
+    Context(Vec<u8>),
+    /// Line only present in the old file.
+    Removed(Vec<u8>),
+    /// Line only present in the new file.
+    Added(Vec<u8>),
+}
+
+/// Result of applying hunks to a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatchedContent {
+    /// File contents with the applicable hunks applied.
+    pub content: Vec<u8>,
+    /// Indices of the hunks which couldn't be applied.
+    pub rejected_hunks: Vec<usize>,
+}
+
+/// Parses a patch in the unified diff format. Lines outside of file headers
+/// and hunks, such as commit descriptions, are ignored. Binary patches aren't
+/// supported.
+pub fn parse_patch(text: &[u8]) -> Result<Vec<FilePatch>, PatchParseError> {
+    let mut files: Vec<FilePatch> = vec![];
+    // 1-based line number of the header of each file
+    let mut header_line_numbers: Vec<usize> = vec![];
+    let mut lines = text.split_inclusive(|&b| b == b'\n').enumerate().peekable();
+    // Whether the current file was started by a "diff --git" header, and can
+    // still be completed by "---"/"+++" lines.
+    let mut in_git_header = false;
+    while let Some((index, line)) = lines.next() {
+        let error = |message: &str| PatchParseError {
+            line_number: index + 1,
+            message: message.to_owned(),
+        };
+        let content = line.strip_suffix(b"\n").unwrap_or(line);
+        let content = content.strip_suffix(b"\r").unwrap_or(content);
+        if let Some(rest) = content.strip_prefix(b"diff --git ") {
+            let (old_path, new_path) = parse_git_header_paths(rest);
+            files.push(FilePatch {
+                old_path,
+                new_path,
+                ..FilePatch::default()
+            });
+            header_line_numbers.push(index + 1);
+            in_git_header = true;
+        } else if let Some(rest) = content.strip_prefix(b"--- ") {
+            let old_path = parse_header_path(rest).map_err(|message| error(&message))?;
+            if !in_git_header {
+                files.push(FilePatch::default());
+                header_line_numbers.push(index + 1);
+            }
+            in_git_header = false;
+            let file = files.last_mut().unwrap();
+            file.old_path = old_path;
+            let Some((index, line)) = lines.next() else {
+                return Err(error("missing \"+++\" line"));
+            };
+            let Some(rest) = line
+                .trim_end_with(|c| c == '\n' || c == '\r')
+                .strip_prefix(b"+++ ")
+            else {
+                return Err(PatchParseError {
+                    line_number: index + 1,
+                    message: "expected \"+++\" line".to_owned(),
+                });
+            };
+            file.new_path = parse_header_path(rest).map_err(|message| PatchParseError {
+                line_number: index + 1,
+                message,
+            })?;
+        } else if in_git_header {
+            let file = files.last_mut().unwrap();
+            if let Some(path) = content.strip_prefix(b"rename from ") {
+                file.old_path = Some(path_to_string(path).map_err(|message| error(&message))?);
+            } else if let Some(path) = content.strip_prefix(b"rename to ") {
+                file.new_path = Some(path_to_string(path).map_err(|message| error(&message))?);
+            } else if let Some(mode) = content.strip_prefix(b"new file mode ") {
+                file.old_path = None;
+                file.executable = Some(parse_file_mode(mode).map_err(|message| error(&message))?);
+            } else if let Some(mode) = content.strip_prefix(b"new mode ") {
+                file.executable = Some(parse_file_mode(mode).map_err(|message| error(&message))?);
+            } else if let Some(mode) = content.strip_prefix(b"old mode ") {
+                parse_file_mode(mode).map_err(|message| error(&message))?;
+            } else if content.starts_with(b"deleted file mode ") {
+                file.new_path = None;
+            } else if content.starts_with(b"Binary files ") || content == b"GIT binary patch" {
+                return Err(error("binary patches are not supported"));
+            }
+        } else if let Some(rest) = content.strip_prefix(b"@@ ") {
+            let Some(file) = files.last_mut() else {
+                return Err(error("hunk without file header"));
+            };
+            let mut hunk = parse_hunk_header(rest).ok_or_else(|| error("invalid hunk header"))?;
+            let mut old_remaining = hunk.old_len;
+            let mut new_remaining = hunk.new_len;
+            while old_remaining > 0 || new_remaining > 0 {
+                let Some((index, line)) = lines.next() else {
+                    return Err(error("hunk is shorter than its header says"));
+                };
+                let (kind, text) = match line.split_first() {
+                    Some((kind, text)) => (*kind, text.to_vec()),
+                    // Some tools strip the space of empty context lines
+                    None => (b' ', vec![]),
+                };
+                let (patch_line, remaining) = match kind {
+                    b' ' | b'\n' => {
+                        let text = if kind == b'\n' { b"\n".to_vec() } else { text };
+                        old_remaining = old_remaining.saturating_sub(1);
+                        (PatchLine::Context(text), &mut new_remaining)
+                    }
+                    b'-' => (PatchLine::Removed(text), &mut old_remaining),
+                    b'+' => (PatchLine::Added(text), &mut new_remaining),
+                    _ => {
+                        return Err(PatchParseError {
+                            line_number: index + 1,
+                            message: "unexpected line in hunk".to_owned(),
+                        })
+                    }
+                };
+                if *remaining == 0 {
+                    return Err(PatchParseError {
+                        line_number: index + 1,
+                        message: "hunk is longer than its header says".to_owned(),
+                    });
+                }
+                *remaining -= 1;
+                hunk.lines.push(patch_line);
+                strip_missing_newline(&mut lines, &mut hunk);
+            }
+            strip_missing_newline(&mut lines, &mut hunk);
+            file.hunks.push(hunk);
+        }
+    }
+    for (file, &line_number) in files.iter().zip(&header_line_numbers) {
+        if file.old_path.is_none() && file.new_path.is_none() {
+            return Err(PatchParseError {
+                line_number,
+                message: "no file path in header".to_owned(),
+            });
+        }
+    }
+    Ok(files)
+}
+
+/// Handles the "\ No newline at end of file" marker following a hunk line.
+fn strip_missing_newline<'a>(
+    lines: &mut std::iter::Peekable<impl Iterator<Item = (usize, &'a [u8])>>,
+    hunk: &mut PatchHunk,
+) {
+    if lines.next_if(|(_, line)| line.starts_with(b"\\")).is_some() {
+        if let Some(PatchLine::Context(text) | PatchLine::Removed(text) | PatchLine::Added(text)) =
+            hunk.lines.last_mut()
+        {
+            if text.ends_with(b"\n") {
+                text.pop();
+            }
+        }
+    }
+}
+
+fn parse_git_header_paths(rest: &[u8]) -> (Option<String>, Option<String>) {
+    // "a/<old> b/<new>". Paths with spaces are ambiguous, and are expected to
+    // be specified by "---"/"+++" or "rename" lines too.
+    let text = String::from_utf8_lossy(rest);
+    match text.split_once(" b/") {
+        Some((old, new)) => {
+            let old = old.strip_prefix("a/").unwrap_or(old);
+            (Some(old.to_owned()), Some(new.to_owned()))
+        }
+        None => (None, None),
+    }
+}
+
+fn parse_header_path(rest: &[u8]) -> Result<Option<String>, String> {
+    // Strip timestamp, if any
+    let path = rest.split_str("\t").next().unwrap_or(rest);
+    if path == b"/dev/null" {
+        return Ok(None);
+    }
+    if path.starts_with(b"\"") {
+        return Err("quoted paths are not supported".to_owned());
+    }
+    let path = path
+        .strip_prefix(b"a/")
+        .or_else(|| path.strip_prefix(b"b/"))
+        .unwrap_or(path);
+    path_to_string(path).map(Some)
+}
+
+/// Returns whether the Git file `mode` is executable. Only regular files are
+/// supported.
+fn parse_file_mode(mode: &[u8]) -> Result<bool, String> {
+    match mode {
+        b"100644" => Ok(false),
+        b"100755" => Ok(true),
+        _ => Err(format!("unsupported file mode {}", mode.to_str_lossy())),
+    }
+}
+
+fn path_to_string(path: &[u8]) -> Result<String, String> {
+    String::from_utf8(path.to_vec()).map_err(|_| "path is not valid UTF-8".to_owned())
+}
+
+fn parse_hunk_header(rest: &[u8]) -> Option<PatchHunk> {
+    let rest = std::str::from_utf8(rest).ok()?;
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+    let parse_range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_len) = parse_range(old.strip_prefix('-')?)?;
+    let (new_start, new_len) = parse_range(new.strip_prefix('+')?)?;
+    Some(PatchHunk {
+        old_start,
+        old_len,
+        new_start,
+        new_len,
+        lines: vec![],
+    })
+}
+
+/// Applies the `hunks` to the `content` of a file.
+///
+/// Each hunk is applied where its old lines match the content exactly,
+/// preferring the position closest to the one in its header. Hunks whose old
+/// lines can't be found after the previously applied hunk are rejected.
+pub fn apply_hunks(content: &[u8], hunks: &[PatchHunk]) -> PatchedContent {
+    let lines: Vec<&[u8]> = content.split_inclusive(|&b| b == b'\n').collect();
+    let mut new_content = vec![];
+    let mut rejected_hunks = vec![];
+    // Index of the first line which hasn't been copied to the new content
+    let mut pos = 0;
+    // Difference between the actual and expected positions of the last
+    // applied hunk
+    let mut offset: isize = 0;
+    for (index, hunk) in hunks.iter().enumerate() {
+        let old_lines = hunk.old_lines().collect::<Vec<_>>();
+        // For pure additions, the start is the line after which to insert.
+        let header_start = if old_lines.is_empty() {
+            hunk.old_start
+        } else {
+            hunk.old_start.saturating_sub(1)
+        };
+        let expected = (header_start as isize + offset).max(0) as usize;
+        let matches_at = |start: usize| lines[start..].starts_with(&old_lines);
+        let found = if old_lines.is_empty() {
+            Some(expected.clamp(pos, lines.len()))
+        } else {
+            let last_start = lines.len().checked_sub(old_lines.len());
+            last_start.and_then(|last_start| {
+                (pos..=last_start)
+                    .filter(|&start| matches_at(start))
+                    .min_by_key(|&start| start.abs_diff(expected))
+            })
+        };
+        let Some(start) = found else {
+            rejected_hunks.push(index);
+            continue;
+        };
+        for line in &lines[pos..start] {
+            new_content.extend_from_slice(line);
+        }
+        for line in hunk.new_lines() {
+            new_content.extend_from_slice(line);
+        }
+        pos = start + old_lines.len();
+        offset = start as isize - header_start as isize;
+    }
+    for line in &lines[pos..] {
+        new_content.extend_from_slice(line);
+    }
+    PatchedContent {
+        content: new_content,
+        rejected_hunks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_patch() {
+        let patch = indoc! {b"
+            Some description
+
+            diff --git a/file1 b/file1
+            index 1234567..89abcde 100644
+            --- a/file1
+            +++ b/file1
+            @@ -1,3 +1,3 @@
+             a
+            -b
+            +B
+             c
+            diff --git a/old b/new
+            similarity index 100%
+            rename from old
+            rename to new
+            diff --git a/added b/added
+            new file mode 100755
+            index 0000000..1234567
+            --- /dev/null
+            +++ b/added
+            @@ -0,0 +1 @@
+            +x
+            \\ No newline at end of file
+            --- deleted
+            +++ /dev/null
+            @@ -1 +0,0 @@
+            -y
+        "};
+        let files = parse_patch(patch).unwrap();
+        assert_eq!(files.len(), 4);
+        assert_eq!(files[0].old_path.as_deref(), Some("file1"));
+        assert_eq!(files[0].new_path.as_deref(), Some("file1"));
+        assert_eq!(
+            files[0].hunks,
+            vec![PatchHunk {
+                old_start: 1,
+                old_len: 3,
+                new_start: 1,
+                new_len: 3,
+                lines: vec![
+                    PatchLine::Context(b"a\n".to_vec()),
+                    PatchLine::Removed(b"b\n".to_vec()),
+                    PatchLine::Added(b"B\n".to_vec()),
+                    PatchLine::Context(b"c\n".to_vec()),
+                ],
+            }]
+        );
+        assert_eq!(files[1].old_path.as_deref(), Some("old"));
+        assert_eq!(files[1].new_path.as_deref(), Some("new"));
+        assert!(files[1].hunks.is_empty());
+        assert_eq!(files[2].old_path, None);
+        assert_eq!(files[2].new_path.as_deref(), Some("added"));
+        assert_eq!(files[2].executable, Some(true));
+        assert_eq!(
+            files[2].hunks[0].lines,
+            vec![PatchLine::Added(b"x".to_vec())]
+        );
+        assert_eq!(files[3].old_path.as_deref(), Some("deleted"));
+        assert_eq!(files[3].new_path, None);
+
+        let files = parse_patch(b"diff --git a/f b/f\nold mode 100644\nnew mode 100755\n").unwrap();
+        assert_eq!(files[0].old_path.as_deref(), Some("f"));
+        assert_eq!(files[0].executable, Some(true));
+        assert!(files[0].hunks.is_empty());
+    }
+
+    #[test]
+    fn test_parse_patch_errors() {
+        let err = parse_patch(b"--- a/file\n@@ -1 +1 @@\n").unwrap_err();
+        assert_eq!(err.line_number, 2);
+        let err = parse_patch(b"--- a/file\n+++ b/file\n@@ -1,2 +1,2 @@\n-a\n+b\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid patch on line 3: hunk is shorter than its header says"
+        );
+        let err = parse_patch(b"--- a/file\n+++ b/file\n@@ -1 +1,2 @@\n-a\n-b\n+c\n").unwrap_err();
+        assert_eq!(err.line_number, 5);
+        let err = parse_patch(b"--- /dev/null\n+++ /dev/null\n@@ -0,0 +1 @@\n+a\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid patch on line 1: no file path in header"
+        );
+        let err =
+            parse_patch(b"diff --git \"a/f\\tx\" \"b/f\\tx\"\nnew mode 100755\n").unwrap_err();
+        assert_eq!(err.line_number, 1);
+        let err = parse_patch(b"diff --git a/link b/link\nnew mode 120000\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid patch on line 2: unsupported file mode 120000"
+        );
+        let err = parse_patch(
+            b"diff --git a/bin b/bin\nnew file mode 100644\nindex 0000000..1234567\nBinary \
+              files /dev/null and b/bin differ\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid patch on line 4: binary patches are not supported"
+        );
+        let err = parse_patch(
+            b"diff --git a/bin b/bin\nindex 1234567..89abcde 100644\nGIT binary patch\nliteral \
+              1\nIcmZPo000310RaC2\n\nliteral 0\nKcmV+b0RR6000031\n\n",
+        )
+        .unwrap_err();
+        assert_eq!(err.line_number, 3);
+    }
+
+    #[test]
+    fn test_apply_hunks() {
+        let patch = parse_patch(indoc! {b"
+            --- a/file
+            +++ b/file
+            @@ -1,2 +1,2 @@
+            -1
+            +one
+             2
+            @@ -5,2 +5,3 @@
+             5
+            +5.5
+             6
+            @@ -8,1 +9,1 @@
+            -not there
+            +replacement
+        "})
+        .unwrap();
+        let result = apply_hunks(b"0\n1\n2\n3\n4\n5\n6\n7\n8\n", &patch[0].hunks);
+        assert_eq!(
+            result.content.to_str().unwrap(),
+            "0\none\n2\n3\n4\n5\n5.5\n6\n7\n8\n"
+        );
+        assert_eq!(result.rejected_hunks, vec![2]);
+    }
+
+    #[test]
+    fn test_apply_hunks_add_and_delete() {
+        let patch = parse_patch(indoc! {b"
+            --- /dev/null
+            +++ b/file
+            @@ -0,0 +1,2 @@
+            +a
+            +b
+        "})
+        .unwrap();
+        let result = apply_hunks(b"", &patch[0].hunks);
+        assert_eq!(result.content, b"a\nb\n");
+        assert!(result.rejected_hunks.is_empty());
+
+        let patch = parse_patch(indoc! {b"
+            --- a/file
+            +++ /dev/null
+            @@ -1,2 +0,0 @@
+            -a
+            -b
+        "})
+        .unwrap();
+        let result = apply_hunks(b"a\nb\n", &patch[0].hunks);
+        assert_eq!(result.content, b"");
+        assert!(result.rejected_hunks.is_empty());
+    }
+}
@@ -41,6 +41,7 @@ use crate::graph::GraphNode;
 use crate::hex_util::to_forward_hex;
 use crate::id_prefix::IdPrefixContext;
 use crate::id_prefix::IdPrefixIndex;
+use crate::mailmap::Mailmap;
 use crate::object_id::HexPrefix;
 use crate::object_id::PrefixResolution;
 use crate::op_store::RemoteRefState;
@@ -63,6 +64,7 @@ pub use crate::revset_parser::RevsetParseErrorKind;
 pub use crate::revset_parser::UnaryOp;
 use crate::store::Store;
 use crate::str_util::StringPattern;
+use crate::str_util::StringPatternParseError;
 use crate::time_util::DatePattern;
 use crate::time_util::DatePatternContext;
 
@@ -162,6 +164,12 @@ pub enum RevsetFilterPredicate {
     AuthorName(StringPattern),
     /// Commits with author email matching the pattern.
     AuthorEmail(StringPattern),
+    /// Commits with author name matching the pattern after the author is
+    /// mapped through the mailmap.
+    MailmapAuthorName(Arc<Mailmap>, StringPattern),
+    /// Commits with author email matching the pattern after the author is
+    /// mapped through the mailmap.
+    MailmapAuthorEmail(Arc<Mailmap>, StringPattern),
     /// Commits with author dates matching the given date pattern.
     AuthorDate(DatePattern),
     /// Commits with committer name matching the pattern.
@@ -781,6 +789,27 @@ static BUILTIN_FUNCTION_MAP: Lazy<HashMap<&'static str, RevsetFunction>> = Lazy:
             parse_remote_bookmarks_arguments(diagnostics, function, Some(RemoteRefState::New))
         },
     );
+    map.insert("tracked", |diagnostics, function, _context| {
+        let ([], [opt_arg]) = function.expect_arguments()?;
+        let (bookmark_pattern, remote_pattern) = if let Some(arg) = opt_arg {
+            expect_remote_bookmark_pattern(diagnostics, arg)?
+        } else {
+            (StringPattern::everything(), StringPattern::everything())
+        };
+        Ok(RevsetExpression::remote_bookmarks(
+            bookmark_pattern,
+            remote_pattern,
+            Some(RemoteRefState::Tracking),
+        ))
+    });
+    map.insert("heads_of", |diagnostics, function, _context| {
+        let [remote_arg] = function.expect_exact_arguments()?;
+        let remote_pattern = expect_string_pattern(diagnostics, remote_arg)?;
+        Ok(
+            RevsetExpression::remote_bookmarks(StringPattern::everything(), remote_pattern, None)
+                .heads(),
+        )
+    });
 
     // TODO: Remove in jj 0.28+
     map.insert("branches", map["bookmarks"]);
@@ -842,24 +871,24 @@ static BUILTIN_FUNCTION_MAP: Lazy<HashMap<&'static str, RevsetFunction>> = Lazy:
         let predicate = RevsetFilterPredicate::Subject(pattern);
         Ok(RevsetExpression::filter(predicate))
     });
-    map.insert("author", |diagnostics, function, _context| {
+    map.insert("author", |diagnostics, function, context| {
         let [arg] = function.expect_exact_arguments()?;
         let pattern = expect_string_pattern(diagnostics, arg)?;
-        let name_predicate = RevsetFilterPredicate::AuthorName(pattern.clone());
-        let email_predicate = RevsetFilterPredicate::AuthorEmail(pattern);
+        let name_predicate = author_name_predicate(context, pattern.clone());
+        let email_predicate = author_email_predicate(context, pattern);
         Ok(RevsetExpression::filter(name_predicate)
             .union(&RevsetExpression::filter(email_predicate)))
     });
-    map.insert("author_name", |diagnostics, function, _context| {
+    map.insert("author_name", |diagnostics, function, context| {
         let [arg] = function.expect_exact_arguments()?;
         let pattern = expect_string_pattern(diagnostics, arg)?;
-        let predicate = RevsetFilterPredicate::AuthorName(pattern);
+        let predicate = author_name_predicate(context, pattern);
         Ok(RevsetExpression::filter(predicate))
     });
-    map.insert("author_email", |diagnostics, function, _context| {
+    map.insert("author_email", |diagnostics, function, context| {
         let [arg] = function.expect_exact_arguments()?;
         let pattern = expect_string_pattern(diagnostics, arg)?;
-        let predicate = RevsetFilterPredicate::AuthorEmail(pattern);
+        let predicate = author_email_predicate(context, pattern);
         Ok(RevsetExpression::filter(predicate))
     });
     map.insert("author_date", |diagnostics, function, context| {
@@ -874,8 +903,10 @@ static BUILTIN_FUNCTION_MAP: Lazy<HashMap<&'static str, RevsetFunction>> = Lazy:
         // Email address domains are inherently case‐insensitive, and the local‐parts
         // are generally (although not universally) treated as case‐insensitive too, so
         // we use a case‐insensitive match here.
-        let predicate =
-            RevsetFilterPredicate::AuthorEmail(StringPattern::exact_i(context.user_email));
+        // The user's own email is mapped too, so commits made under an old
+        // email are included.
+        let (_, user_email) = context.mailmap.resolve("", context.user_email);
+        let predicate = author_email_predicate(context, StringPattern::exact_i(user_email));
         Ok(RevsetExpression::filter(predicate))
     });
     map.insert("committer", |diagnostics, function, _context| {
@@ -1023,6 +1054,30 @@ pub fn expect_fileset_expression(
     })
 }
 
+/// Builds a predicate matching the author name, applying the mailmap if any.
+fn author_name_predicate(
+    context: &RevsetParseContext,
+    pattern: StringPattern,
+) -> RevsetFilterPredicate {
+    if context.mailmap.is_empty() {
+        RevsetFilterPredicate::AuthorName(pattern)
+    } else {
+        RevsetFilterPredicate::MailmapAuthorName(context.mailmap.clone(), pattern)
+    }
+}
+
+/// Builds a predicate matching the author email, applying the mailmap if any.
+fn author_email_predicate(
+    context: &RevsetParseContext,
+    pattern: StringPattern,
+) -> RevsetFilterPredicate {
+    if context.mailmap.is_empty() {
+        RevsetFilterPredicate::AuthorEmail(pattern)
+    } else {
+        RevsetFilterPredicate::MailmapAuthorEmail(context.mailmap.clone(), pattern)
+    }
+}
+
 pub fn expect_string_pattern(
     diagnostics: &mut RevsetDiagnostics,
     node: &ExpressionNode,
@@ -1038,6 +1093,31 @@ pub fn expect_string_pattern(
     )
 }
 
+/// Parses a `bookmark@remote` pattern into bookmark and remote patterns.
+///
+/// The pattern is split at the last `@`, and both parts are parsed with the
+/// same pattern kind. If there's no `@`, all remotes are matched.
+fn expect_remote_bookmark_pattern(
+    diagnostics: &mut RevsetDiagnostics,
+    node: &ExpressionNode,
+) -> Result<(StringPattern, StringPattern), RevsetParseError> {
+    revset_parser::expect_pattern_with(
+        diagnostics,
+        "remote bookmark pattern",
+        node,
+        |_diagnostics, value, kind| -> Result<_, StringPatternParseError> {
+            let parse = |value: &str| match kind {
+                Some(kind) => StringPattern::from_str_kind(value, kind),
+                None => Ok(StringPattern::Substring(value.to_owned())),
+            };
+            match value.rsplit_once('@') {
+                Some((bookmark, remote)) => Ok((parse(bookmark)?, parse(remote)?)),
+                None => Ok((parse(value)?, StringPattern::everything())),
+            }
+        },
+    )
+}
+
 pub fn expect_date_pattern(
     diagnostics: &mut RevsetDiagnostics,
     node: &ExpressionNode,
@@ -2658,6 +2738,7 @@ impl RevsetExtensions {
 pub struct RevsetParseContext<'a> {
     aliases_map: &'a RevsetAliasesMap,
     user_email: &'a str,
+    mailmap: Arc<Mailmap>,
     date_pattern_context: DatePatternContext,
     extensions: &'a RevsetExtensions,
     workspace: Option<RevsetWorkspaceContext<'a>>,
@@ -2667,6 +2748,7 @@ impl<'a> RevsetParseContext<'a> {
     pub fn new(
         aliases_map: &'a RevsetAliasesMap,
         user_email: &'a str,
+        mailmap: Arc<Mailmap>,
         date_pattern_context: DatePatternContext,
         extensions: &'a RevsetExtensions,
         workspace: Option<RevsetWorkspaceContext<'a>>,
@@ -2674,6 +2756,7 @@ impl<'a> RevsetParseContext<'a> {
         Self {
             aliases_map,
             user_email,
+            mailmap,
             date_pattern_context,
             extensions,
             workspace,
@@ -2688,6 +2771,11 @@ impl<'a> RevsetParseContext<'a> {
         self.user_email
     }
 
+    /// Mailmap applied to commit authors.
+    pub fn mailmap(&self) -> &Arc<Mailmap> {
+        &self.mailmap
+    }
+
     pub fn date_pattern_context(&self) -> &DatePatternContext {
         &self.date_pattern_context
     }
@@ -2735,6 +2823,7 @@ mod tests {
         let context = RevsetParseContext::new(
             &aliases_map,
             "test.user@example.com",
+            Default::default(),
             chrono::Utc::now().fixed_offset().into(),
             &extensions,
             None,
@@ -2764,6 +2853,7 @@ mod tests {
         let context = RevsetParseContext::new(
             &aliases_map,
             "test.user@example.com",
+            Default::default(),
             chrono::Utc::now().fixed_offset().into(),
             &extensions,
             Some(workspace_ctx),
@@ -2789,6 +2879,7 @@ mod tests {
         let context = RevsetParseContext::new(
             &aliases_map,
             "test.user@example.com",
+            Default::default(),
             chrono::Utc::now().fixed_offset().into(),
             &extensions,
             None,
@@ -3354,6 +3445,63 @@ mod tests {
             @r#"Filter(AuthorEmail(ExactI("test.user@example.com")))"#);
     }
 
+    #[test]
+    fn test_parse_tracked() {
+        let settings = insta_settings();
+        let _guard = settings.bind_to_scope();
+
+        insta::assert_debug_snapshot!(parse(r#"tracked(glob:"*@origin")"#).unwrap(), @r#"
+        CommitRef(
+            RemoteBookmarks {
+                bookmark_pattern: Glob(
+                    Pattern {
+                        original: "*",
+                        tokens: [
+                            AnySequence,
+                        ],
+                        is_recursive: false,
+                    },
+                ),
+                remote_pattern: Glob(
+                    Pattern {
+                        original: "origin",
+                        tokens: [
+                            Char('o'),
+                            Char('r'),
+                            Char('i'),
+                            Char('g'),
+                            Char('i'),
+                            Char('n'),
+                        ],
+                        is_recursive: false,
+                    },
+                ),
+                remote_ref_state: Some(Tracking),
+            },
+        )
+        "#);
+        insta::assert_debug_snapshot!(parse(r#"tracked("foo@bar@baz")"#).unwrap(), @r#"
+        CommitRef(
+            RemoteBookmarks {
+                bookmark_pattern: Substring("foo@bar"),
+                remote_pattern: Substring("baz"),
+                remote_ref_state: Some(Tracking),
+            },
+        )
+        "#);
+        insta::assert_debug_snapshot!(parse("tracked(foo)").unwrap(), @r#"
+        CommitRef(
+            RemoteBookmarks {
+                bookmark_pattern: Substring("foo"),
+                remote_pattern: Substring(""),
+                remote_ref_state: Some(Tracking),
+            },
+        )
+        "#);
+        insta::assert_debug_snapshot!(
+            parse(r#"tracked(regex:"(@origin")"#).unwrap_err().kind(), @r#"Expression("Invalid remote bookmark pattern")"#);
+    }
+
     #[test]
     fn test_parse_revset_keyword_arguments() {
         let settings = insta_settings();
@@ -262,6 +262,11 @@ impl<'repo> CommitRewriter<'repo> {
                 EmptyBehaviour::Keep => false,
                 EmptyBehaviour::AbandonNewlyEmpty => *parent.tree_id() == new_tree_id && !was_empty,
                 EmptyBehaviour::AbandonAllEmpty => *parent.tree_id() == new_tree_id,
+                EmptyBehaviour::AbandonNewlyEmptyUndescribed => {
+                    *parent.tree_id() == new_tree_id
+                        && !was_empty
+                        && self.old_commit.description().trim().is_empty()
+                }
             };
             if should_abandon {
                 self.abandon();
@@ -312,7 +317,16 @@ pub fn rebase_commit_with_options(
         _ => None,
     };
     let new_parents_len = rewriter.new_parents.len();
-    if let Some(builder) = rewriter.rebase_with_empty_behavior(options.empty)? {
+    // Commits with the default description are undescribed, too.
+    let empty = match options.empty {
+        EmptyBehaviour::AbandonNewlyEmptyUndescribed
+            if rewriter.old_commit().description().trim() == options.default_description.trim() =>
+        {
+            EmptyBehaviour::AbandonNewlyEmpty
+        }
+        empty => empty,
+    };
+    if let Some(builder) = rewriter.rebase_with_empty_behavior(empty)? {
         let new_commit = builder.write()?;
         Ok(RebasedCommit::Rewritten(new_commit))
     } else {
@@ -357,6 +371,12 @@ pub enum EmptyBehaviour {
     /// rebase.
     /// Will never skip merge commits with multiple non-empty parents.
     AbandonAllEmpty,
+    /// Like `AbandonNewlyEmpty`, but only skips commits without a meaningful
+    /// description. A description isn't meaningful if it's empty or equal to
+    /// `RebaseOptions::default_description`, ignoring leading and trailing
+    /// whitespace.
+    /// Will never skip merge commits with multiple non-empty parents.
+    AbandonNewlyEmptyUndescribed,
 }
 
 /// Controls the configuration of a rebase.
@@ -372,6 +392,9 @@ pub struct RebaseOptions {
     /// If a merge commit would end up with one parent being an ancestor of the
     /// other, then filter out the ancestor.
     pub simplify_ancestor_merge: bool,
+    /// The description set on commits the user didn't describe. Used by
+    /// `EmptyBehaviour::AbandonNewlyEmptyUndescribed`.
+    pub default_description: String,
 }
 
 /// Configuration for [`MutableRepo::update_rewritten_references()`].
@@ -393,8 +416,8 @@ pub struct MoveCommitsStats {
     /// The number of commits for which rebase was skipped, due to the commit
     /// already being in place.
     pub num_skipped_rebases: u32,
-    /// The number of commits which were abandoned.
-    pub num_abandoned: u32,
+    /// The commits which were abandoned, before rewriting.
+    pub abandoned_commits: Vec<Commit>,
 }
 
 pub enum MoveCommitsTarget {
@@ -734,13 +757,14 @@ pub fn move_commits(
     let mut num_rebased_targets = 0;
     let mut num_rebased_descendants = 0;
     let mut num_skipped_rebases = 0;
-    let mut num_abandoned = 0;
+    let mut abandoned_commits = vec![];
 
     // Always keep empty commits when rebasing descendants.
     let rebase_descendant_options = &RebaseOptions {
         empty: EmptyBehaviour::Keep,
         rewrite_refs: options.rewrite_refs.clone(),
         simplify_ancestor_merge: options.simplify_ancestor_merge,
+        default_description: options.default_description.clone(),
     };
 
     // Rebase each commit onto its new parents in the reverse topological order
@@ -761,7 +785,7 @@ pub fn move_commits(
                 },
             )?;
             if let RebasedCommit::Abandoned { .. } = rebased_commit {
-                num_abandoned += 1;
+                abandoned_commits.push(old_commit.clone());
             } else if is_target_commit {
                 num_rebased_targets += 1;
             } else {
@@ -777,7 +801,7 @@ pub fn move_commits(
         num_rebased_targets,
         num_rebased_descendants,
         num_skipped_rebases,
-        num_abandoned,
+        abandoned_commits,
     })
 }
 
@@ -33,6 +33,7 @@ use crate::backend::Conflict;
 use crate::backend::ConflictId;
 use crate::backend::CopyRecord;
 use crate::backend::FileId;
+use crate::backend::GcStats;
 use crate::backend::SigningFn;
 use crate::backend::SymlinkId;
 use crate::backend::Tree;
@@ -196,4 +197,8 @@ impl Backend for SecretBackend {
     fn gc(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<()> {
         self.inner.gc(index, keep_newer)
     }
+
+    fn gc_dry_run(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<GcStats> {
+        self.inner.gc_dry_run(index, keep_newer)
+    }
 }
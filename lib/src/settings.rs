@@ -17,6 +17,7 @@
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use chrono::DateTime;
 use rand::prelude::*;
@@ -58,6 +59,10 @@ struct UserSettingsData {
 pub struct GitSettings {
     pub auto_local_bookmark: bool,
     pub abandon_unreachable_commits: bool,
+    /// Number of times a network operation is retried on transient errors.
+    pub network_retries: u32,
+    /// Delay before the first retry. Doubled on each subsequent retry.
+    pub network_retry_backoff: Duration,
 }
 
 impl GitSettings {
@@ -65,6 +70,10 @@ impl GitSettings {
         Ok(GitSettings {
             auto_local_bookmark: settings.get_bool("git.auto-local-bookmark")?,
             abandon_unreachable_commits: settings.get_bool("git.abandon-unreachable-commits")?,
+            network_retries: settings.get("git.network-retries")?,
+            network_retry_backoff: Duration::from_millis(
+                settings.get("git.network-retry-backoff")?,
+            ),
         })
     }
 }
@@ -74,6 +83,8 @@ impl Default for GitSettings {
         GitSettings {
             auto_local_bookmark: false,
             abandon_unreachable_commits: true,
+            network_retries: 0,
+            network_retry_backoff: Duration::from_secs(1),
         }
     }
 }
@@ -87,6 +87,12 @@ pub struct SimpleOpStore {
     root_view_id: ViewId,
 }
 
+#[derive(Clone, Copy, Debug)]
+enum GcFileKind {
+    Operation,
+    View,
+}
+
 impl SimpleOpStore {
     pub fn name() -> &'static str {
         "simple_op_store"
@@ -109,6 +115,24 @@ impl SimpleOpStore {
         }
     }
 
+    /// Reads the encoded view object without decoding it. The root view isn't
+    /// stored, so it's empty.
+    pub fn read_raw_view(&self, id: &ViewId) -> OpStoreResult<Vec<u8>> {
+        if *id == self.root_view_id {
+            return Ok(vec![]);
+        }
+        fs::read(self.view_path(id)).map_err(|err| io_to_read_error(err, id))
+    }
+
+    /// Reads the encoded operation object without decoding it. The root
+    /// operation isn't stored, so it's empty.
+    pub fn read_raw_operation(&self, id: &OperationId) -> OpStoreResult<Vec<u8>> {
+        if *id == self.root_operation_id {
+            return Ok(vec![]);
+        }
+        fs::read(self.operation_path(id)).map_err(|err| io_to_read_error(err, id))
+    }
+
     fn view_path(&self, id: &ViewId) -> PathBuf {
         self.path.join("views").join(id.hex())
     }
@@ -116,6 +140,80 @@ impl SimpleOpStore {
     fn operation_path(&self, id: &OperationId) -> PathBuf {
         self.path.join("operations").join(id.hex())
     }
+
+    /// Calls `f` with each operation file and then each view file that isn't
+    /// reachable from `head_ids`. Shared by `gc()` and `gc_dry_run()`.
+    fn for_each_unreachable_file(
+        &self,
+        head_ids: &[OperationId],
+        mut f: impl FnMut(GcFileKind, &fs::DirEntry) -> Result<(), PathError>,
+    ) -> OpStoreResult<()> {
+        let to_op_id = |entry: &fs::DirEntry| -> Option<OperationId> {
+            let name = entry.file_name().into_string().ok()?;
+            OperationId::try_from_hex(&name).ok()
+        };
+        let to_view_id = |entry: &fs::DirEntry| -> Option<ViewId> {
+            let name = entry.file_name().into_string().ok()?;
+            ViewId::try_from_hex(&name).ok()
+        };
+
+        // Reachable objects are resolved without considering the keep_newer
+        // parameter. We could collect ancestors of the "new" operations here,
+        // but more files can be added anyway after that.
+        let read_op = |id: &OperationId| self.read_operation(id).map(|data| (id.clone(), data));
+        let reachable_ops: HashMap<OperationId, Operation> = dag_walk::dfs_ok(
+            head_ids.iter().map(read_op),
+            |(id, _)| id.clone(),
+            |(_, data)| data.parents.iter().map(read_op).collect_vec(),
+        )
+        .try_collect()?;
+        let reachable_views: HashSet<&ViewId> =
+            reachable_ops.values().map(|data| &data.view_id).collect();
+        tracing::info!(
+            reachable_op_count = reachable_ops.len(),
+            reachable_view_count = reachable_views.len(),
+            "collected reachable objects"
+        );
+
+        let mut visit_ops = || -> Result<(), PathError> {
+            let op_dir = self.path.join("operations");
+            for entry in op_dir.read_dir().context(&op_dir)? {
+                let entry = entry.context(&op_dir)?;
+                let Some(id) = to_op_id(&entry) else {
+                    tracing::trace!(?entry, "skipping invalid file name");
+                    continue;
+                };
+                if reachable_ops.contains_key(&id) {
+                    continue;
+                }
+                // If the operation was added after collecting reachable_views,
+                // its view mtime would also be renewed. So there's no need to
+                // update the reachable_views set to preserve the view.
+                f(GcFileKind::Operation, &entry)?;
+            }
+            Ok(())
+        };
+        visit_ops().map_err(|err| OpStoreError::Other(err.into()))?;
+
+        let mut visit_views = || -> Result<(), PathError> {
+            let view_dir = self.path.join("views");
+            for entry in view_dir.read_dir().context(&view_dir)? {
+                let entry = entry.context(&view_dir)?;
+                let Some(id) = to_view_id(&entry) else {
+                    tracing::trace!(?entry, "skipping invalid file name");
+                    continue;
+                };
+                if reachable_views.contains(&id) {
+                    continue;
+                }
+                f(GcFileKind::View, &entry)?;
+            }
+            Ok(())
+        };
+        visit_views().map_err(|err| OpStoreError::Other(err.into()))?;
+
+        Ok(())
+    }
 }
 
 impl OpStore for SimpleOpStore {
@@ -252,21 +350,11 @@ impl OpStore for SimpleOpStore {
 
     #[tracing::instrument(skip(self))]
     fn gc(&self, head_ids: &[OperationId], keep_newer: SystemTime) -> OpStoreResult<()> {
-        let to_op_id = |entry: &fs::DirEntry| -> Option<OperationId> {
-            let name = entry.file_name().into_string().ok()?;
-            OperationId::try_from_hex(&name).ok()
-        };
-        let to_view_id = |entry: &fs::DirEntry| -> Option<ViewId> {
-            let name = entry.file_name().into_string().ok()?;
-            ViewId::try_from_hex(&name).ok()
-        };
         let remove_file_if_not_new = |entry: &fs::DirEntry| -> Result<(), PathError> {
             let path = entry.path();
             // Check timestamp, but there's still TOCTOU problem if an existing
             // file is renewed.
-            let metadata = entry.metadata().context(&path)?;
-            let mtime = metadata.modified().expect("unsupported platform?");
-            if mtime > keep_newer {
+            if is_file_newer(entry, keep_newer)? {
                 tracing::trace!(?path, "not removing");
                 Ok(())
             } else {
@@ -274,66 +362,37 @@ impl OpStore for SimpleOpStore {
                 fs::remove_file(&path).context(&path)
             }
         };
+        self.for_each_unreachable_file(head_ids, |_kind, entry| remove_file_if_not_new(entry))
+    }
 
-        // Reachable objects are resolved without considering the keep_newer
-        // parameter. We could collect ancestors of the "new" operations here,
-        // but more files can be added anyway after that.
-        let read_op = |id: &OperationId| self.read_operation(id).map(|data| (id.clone(), data));
-        let reachable_ops: HashMap<OperationId, Operation> = dag_walk::dfs_ok(
-            head_ids.iter().map(read_op),
-            |(id, _)| id.clone(),
-            |(_, data)| data.parents.iter().map(read_op).collect_vec(),
-        )
-        .try_collect()?;
-        let reachable_views: HashSet<&ViewId> =
-            reachable_ops.values().map(|data| &data.view_id).collect();
-        tracing::info!(
-            reachable_op_count = reachable_ops.len(),
-            reachable_view_count = reachable_views.len(),
-            "collected reachable objects"
-        );
-
-        let prune_ops = || -> Result<(), PathError> {
-            let op_dir = self.path.join("operations");
-            for entry in op_dir.read_dir().context(&op_dir)? {
-                let entry = entry.context(&op_dir)?;
-                let Some(id) = to_op_id(&entry) else {
-                    tracing::trace!(?entry, "skipping invalid file name");
-                    continue;
-                };
-                if reachable_ops.contains_key(&id) {
-                    continue;
-                }
-                // If the operation was added after collecting reachable_views,
-                // its view mtime would also be renewed. So there's no need to
-                // update the reachable_views set to preserve the view.
-                remove_file_if_not_new(&entry)?;
+    #[tracing::instrument(skip(self))]
+    fn gc_dry_run(
+        &self,
+        head_ids: &[OperationId],
+        keep_newer: SystemTime,
+    ) -> OpStoreResult<op_store::OpStoreGcStats> {
+        let mut stats = op_store::OpStoreGcStats::default();
+        self.for_each_unreachable_file(head_ids, |kind, entry| {
+            if is_file_newer(entry, keep_newer)? {
+                return Ok(());
             }
-            Ok(())
-        };
-        prune_ops().map_err(|err| OpStoreError::Other(err.into()))?;
-
-        let prune_views = || -> Result<(), PathError> {
-            let view_dir = self.path.join("views");
-            for entry in view_dir.read_dir().context(&view_dir)? {
-                let entry = entry.context(&view_dir)?;
-                let Some(id) = to_view_id(&entry) else {
-                    tracing::trace!(?entry, "skipping invalid file name");
-                    continue;
-                };
-                if reachable_views.contains(&id) {
-                    continue;
-                }
-                remove_file_if_not_new(&entry)?;
+            match kind {
+                GcFileKind::Operation => stats.operations += 1,
+                GcFileKind::View => stats.views += 1,
             }
+            stats.bytes += entry.metadata().context(entry.path())?.len();
             Ok(())
-        };
-        prune_views().map_err(|err| OpStoreError::Other(err.into()))?;
-
-        Ok(())
+        })?;
+        Ok(stats)
     }
 }
 
+fn is_file_newer(entry: &fs::DirEntry, keep_newer: SystemTime) -> Result<bool, PathError> {
+    let metadata = entry.metadata().context(entry.path())?;
+    let mtime = metadata.modified().expect("unsupported platform?");
+    Ok(mtime > keep_newer)
+}
+
 fn io_to_read_error(err: std::io::Error, id: &impl ObjectId) -> OpStoreError {
     if err.kind() == ErrorKind::NotFound {
         OpStoreError::ObjectNotFound {
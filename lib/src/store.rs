@@ -18,12 +18,14 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::io::Read;
+use std::iter;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::SystemTime;
 
 use clru::CLruCache;
 use futures::stream::BoxStream;
+use itertools::Itertools as _;
 use pollster::FutureExt;
 
 use crate::backend;
@@ -34,6 +36,7 @@ use crate::backend::CommitId;
 use crate::backend::ConflictId;
 use crate::backend::CopyRecord;
 use crate::backend::FileId;
+use crate::backend::GcStats;
 use crate::backend::MergedTreeId;
 use crate::backend::SigningFn;
 use crate::backend::SymlinkId;
@@ -153,6 +156,52 @@ impl Store {
         Ok(data)
     }
 
+    /// Loads several commits. Commits not in the cache are requested from the
+    /// backend in a single batch. The result is in the same order as `ids`.
+    pub fn get_commits(self: &Arc<Self>, ids: &[CommitId]) -> BackendResult<Vec<Commit>> {
+        self.get_commits_async(ids).block_on()
+    }
+
+    pub async fn get_commits_async(
+        self: &Arc<Self>,
+        ids: &[CommitId],
+    ) -> BackendResult<Vec<Commit>> {
+        let datas = self.get_backend_commits(ids).await?;
+        let commits = iter::zip(ids, datas)
+            .map(|(id, data)| Commit::new(self.clone(), id.clone(), data))
+            .collect();
+        Ok(commits)
+    }
+
+    async fn get_backend_commits(
+        &self,
+        ids: &[CommitId],
+    ) -> BackendResult<Vec<Arc<backend::Commit>>> {
+        let mut datas = {
+            let mut locked_cache = self.commit_cache.lock().unwrap();
+            ids.iter()
+                .map(|id| locked_cache.get(id).cloned())
+                .collect_vec()
+        };
+        let missing_ids = iter::zip(ids, &datas)
+            .filter(|(_, data)| data.is_none())
+            .map(|(id, _)| id.clone())
+            .collect_vec();
+        if !missing_ids.is_empty() {
+            let commits = self.backend.read_commits(&missing_ids).await?;
+            let mut locked_cache = self.commit_cache.lock().unwrap();
+            let mut new_datas = iter::zip(missing_ids, commits).map(|(id, commit)| {
+                let data = Arc::new(commit);
+                locked_cache.put(id, data.clone());
+                data
+            });
+            for data in datas.iter_mut().filter(|data| data.is_none()) {
+                *data = new_datas.next();
+            }
+        }
+        Ok(datas.into_iter().map(Option::unwrap).collect())
+    }
+
     pub async fn write_commit(
         self: &Arc<Self>,
         commit: backend::Commit,
@@ -202,6 +251,51 @@ impl Store {
         Ok(data)
     }
 
+    /// Loads several trees in the same directory. Trees not in the cache are
+    /// requested from the backend in a single batch. The result is in the
+    /// same order as `ids`.
+    pub async fn get_trees_async(
+        self: &Arc<Self>,
+        dir: RepoPathBuf,
+        ids: &[TreeId],
+    ) -> BackendResult<Vec<Tree>> {
+        let datas = self.get_backend_trees(&dir, ids).await?;
+        let trees = iter::zip(ids, datas)
+            .map(|(id, data)| Tree::new(self.clone(), dir.clone(), id.clone(), data))
+            .collect();
+        Ok(trees)
+    }
+
+    async fn get_backend_trees(
+        &self,
+        dir: &RepoPath,
+        ids: &[TreeId],
+    ) -> BackendResult<Vec<Arc<backend::Tree>>> {
+        let mut datas = {
+            let mut locked_cache = self.tree_cache.lock().unwrap();
+            ids.iter()
+                .map(|id| locked_cache.get(&(dir.to_owned(), id.clone())).cloned())
+                .collect_vec()
+        };
+        let missing_ids = iter::zip(ids, &datas)
+            .filter(|(_, data)| data.is_none())
+            .map(|(id, _)| id.clone())
+            .collect_vec();
+        if !missing_ids.is_empty() {
+            let trees = self.backend.read_trees(dir, &missing_ids).await?;
+            let mut locked_cache = self.tree_cache.lock().unwrap();
+            let mut new_datas = iter::zip(missing_ids, trees).map(|(id, tree)| {
+                let data = Arc::new(tree);
+                locked_cache.put((dir.to_owned(), id), data.clone());
+                data
+            });
+            for data in datas.iter_mut().filter(|data| data.is_none()) {
+                *data = new_datas.next();
+            }
+        }
+        Ok(datas.into_iter().map(Option::unwrap).collect())
+    }
+
     pub fn get_root_tree(self: &Arc<Self>, id: &MergedTreeId) -> BackendResult<MergedTree> {
         match &id {
             MergedTreeId::Legacy(id) => {
@@ -209,8 +303,10 @@ impl Store {
                 MergedTree::from_legacy_tree(tree)
             }
             MergedTreeId::Merge(ids) => {
-                let trees = ids.try_map(|id| self.get_tree(RepoPathBuf::root(), id))?;
-                Ok(MergedTree::new(trees))
+                let trees = self
+                    .get_trees_async(RepoPathBuf::root(), ids.as_slice())
+                    .block_on()?;
+                Ok(MergedTree::new(Merge::from_vec(trees)))
             }
         }
     }
@@ -291,4 +387,8 @@ impl Store {
     pub fn gc(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<()> {
         self.backend.gc(index, keep_newer)
     }
+
+    pub fn gc_dry_run(&self, index: &dyn Index, keep_newer: SystemTime) -> BackendResult<GcStats> {
+        self.backend.gc_dry_run(index, keep_newer)
+    }
 }
@@ -20,6 +20,7 @@ use std::collections::BTreeMap;
 use std::ffi::OsString;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use itertools::Itertools;
 use thiserror::Error;
@@ -221,6 +222,11 @@ pub struct SnapshotOptions<'a> {
     pub max_new_file_size: u64,
     /// Expected conflict marker style for checking for changed files.
     pub conflict_marker_style: ConflictMarkerStyle,
+    /// Whether to convert files tracked by Git LFS to LFS pointers.
+    pub git_lfs: bool,
+    /// Whether to normalize line endings to LF according to the `text` and
+    /// `eol` attributes in `.gitattributes`.
+    pub eol_conversion: bool,
 }
 
 impl SnapshotOptions<'_> {
@@ -233,6 +239,8 @@ impl SnapshotOptions<'_> {
             start_tracking_matcher: &EverythingMatcher,
             max_new_file_size: u64::MAX,
             conflict_marker_style: ConflictMarkerStyle::default(),
+            git_lfs: false,
+            eol_conversion: false,
         }
     }
 }
@@ -245,6 +253,14 @@ pub type SnapshotProgress<'a> = dyn Fn(&RepoPath) + 'a + Sync;
 pub struct SnapshotStats {
     /// List of new (previously untracked) files which are still untracked.
     pub untracked_paths: BTreeMap<RepoPathBuf, UntrackedReason>,
+    /// Number of files that were checked for changes.
+    pub files_scanned: usize,
+    /// Number of files whose contents were read and written to the store.
+    pub files_hashed: usize,
+    /// Total size in bytes of the files whose contents were read.
+    pub bytes_read: u64,
+    /// Time spent in each phase of the snapshot, in the order they ran.
+    pub phase_durations: Vec<(&'static str, Duration)>,
 }
 
 /// Reason why the new path isn't tracked.
@@ -266,6 +282,14 @@ pub enum UntrackedReason {
 pub struct CheckoutOptions {
     /// Conflict marker style to use when materializing files
     pub conflict_marker_style: ConflictMarkerStyle,
+    /// Number of threads used to write files. Zero means the number of
+    /// available CPUs, and one means files are written sequentially.
+    pub parallelism: usize,
+    /// Whether to replace Git LFS pointers with the real file contents.
+    pub git_lfs: bool,
+    /// Whether to convert line endings according to the `text` and `eol`
+    /// attributes in `.gitattributes`.
+    pub eol_conversion: bool,
 }
 
 impl CheckoutOptions {
@@ -273,6 +297,9 @@ impl CheckoutOptions {
     pub fn empty_for_test() -> Self {
         CheckoutOptions {
             conflict_marker_style: ConflictMarkerStyle::default(),
+            parallelism: 0,
+            git_lfs: false,
+            eol_conversion: false,
         }
     }
 }
@@ -150,6 +150,59 @@ fn init_working_copy(
     Ok((working_copy, repo))
 }
 
+/// Returns the path of the file recording the root of the workspace. The file
+/// name is hex-encoded so any workspace name can be used.
+fn workspace_root_record_path(repo_path: &Path, workspace_id: &WorkspaceId) -> PathBuf {
+    repo_path
+        .join("workspace_roots")
+        .join(hex::encode(workspace_id.as_str()))
+}
+
+/// Records the root directory of the workspace in the repo, so that other
+/// workspaces can tell whether it still exists on disk.
+///
+/// Non-Unicode paths aren't recorded.
+pub fn record_workspace_root(
+    repo_path: &Path,
+    workspace_id: &WorkspaceId,
+    workspace_root: &Path,
+) -> Result<(), PathError> {
+    let Some(root) = workspace_root.to_str() else {
+        return Ok(());
+    };
+    let record_path = workspace_root_record_path(repo_path, workspace_id);
+    let dir = record_path.parent().unwrap();
+    fs::create_dir_all(dir).context(dir)?;
+    fs::write(&record_path, root).context(&record_path)
+}
+
+/// Returns the recorded root directory of the workspace, or `None` if none
+/// was recorded, e.g. because the workspace was created by an older version.
+pub fn get_recorded_workspace_root(
+    repo_path: &Path,
+    workspace_id: &WorkspaceId,
+) -> Result<Option<PathBuf>, PathError> {
+    let record_path = workspace_root_record_path(repo_path, workspace_id);
+    match fs::read_to_string(&record_path) {
+        Ok(root) => Ok(Some(PathBuf::from(root))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).context(&record_path),
+    }
+}
+
+/// Removes the recorded root directory of the workspace, if any.
+pub fn remove_recorded_workspace_root(
+    repo_path: &Path,
+    workspace_id: &WorkspaceId,
+) -> Result<(), PathError> {
+    let record_path = workspace_root_record_path(repo_path, workspace_id);
+    match fs::remove_file(&record_path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err).context(&record_path),
+    }
+}
+
 impl Workspace {
     pub fn new(
         workspace_root: &Path,
@@ -317,6 +370,11 @@ impl Workspace {
             )?;
             let repo_loader = repo.loader().clone();
             let workspace = Workspace::new(workspace_root, repo_dir, working_copy, repo_loader)?;
+            record_workspace_root(
+                &workspace.repo_path,
+                workspace.workspace_id(),
+                &workspace.workspace_root,
+            )?;
             Ok((workspace, repo))
         })()
         .inspect_err(|_err| {
@@ -378,6 +436,11 @@ impl Workspace {
             working_copy,
             repo.loader().clone(),
         )?;
+        record_workspace_root(
+            &workspace.repo_path,
+            workspace.workspace_id(),
+            &workspace.workspace_root,
+        )?;
         Ok((workspace, repo))
     }
 
@@ -131,9 +131,10 @@ fn test_import_refs() {
     let commit4 = empty_git_commit(&git_repo, "refs/heads/feature2", &[&commit2]);
     let commit5 = empty_git_commit(&git_repo, "refs/tags/v1.0", &[&commit1]);
     let commit6 = empty_git_commit(&git_repo, "refs/remotes/origin/feature3", &[&commit1]);
+    // Should only be imported as a Git ref
+    let commit7 = empty_git_commit(&git_repo, "refs/remotes/origin/HEAD", &[&commit2]);
     // Should not be imported
     empty_git_commit(&git_repo, "refs/notes/x", &[&commit2]);
-    empty_git_commit(&git_repo, "refs/remotes/origin/HEAD", &[&commit2]);
 
     git_repo.set_head("refs/heads/main").unwrap();
 
@@ -211,7 +212,7 @@ fn test_import_refs() {
 
     assert_eq!(view.get_tag("v1.0"), &RefTarget::normal(jj_id(&commit5)));
 
-    assert_eq!(view.git_refs().len(), 6);
+    assert_eq!(view.git_refs().len(), 7);
     assert_eq!(
         view.get_git_ref("refs/heads/main"),
         &RefTarget::normal(jj_id(&commit2))
@@ -236,6 +237,10 @@ fn test_import_refs() {
         view.get_git_ref("refs/tags/v1.0"),
         &RefTarget::normal(jj_id(&commit5))
     );
+    assert_eq!(
+        view.get_git_ref("refs/remotes/origin/HEAD"),
+        &RefTarget::normal(jj_id(&commit7))
+    );
     assert_eq!(view.git_head(), &RefTarget::normal(jj_id(&commit2)));
 }
 
@@ -353,6 +358,62 @@ fn test_import_refs_reimport() {
     assert_eq!(view.get_git_ref("refs/heads/feature2"), &commit5_target);
 }
 
+#[test]
+fn test_import_refs_remote_head() {
+    let git_settings = GitSettings::default();
+    let test_repo = TestRepo::init_with_backend(TestRepoBackend::Git);
+    let repo = &test_repo.repo;
+    let git_repo = get_git_repo(repo);
+
+    let commit = empty_git_commit(&git_repo, "refs/remotes/origin/main", &[]);
+    git_repo
+        .reference_symbolic(
+            "refs/remotes/origin/HEAD",
+            "refs/remotes/origin/main",
+            false,
+            "",
+        )
+        .unwrap();
+
+    let mut tx = repo.start_transaction();
+    git::import_refs(tx.repo_mut(), &git_settings).unwrap();
+    tx.repo_mut().rebase_descendants().unwrap();
+    let repo = tx.commit("test").unwrap();
+    let view = repo.view();
+    // The remote HEAD is recorded as a Git ref, but not as a remote bookmark
+    assert_eq!(
+        view.get_git_ref("refs/remotes/origin/HEAD"),
+        &RefTarget::normal(jj_id(&commit))
+    );
+    assert!(view.get_remote_bookmark("HEAD", "origin").is_absent());
+
+    // Exporting doesn't touch the remote HEAD
+    let mut tx = repo.start_transaction();
+    assert!(git::export_refs(tx.repo_mut()).unwrap().is_empty());
+    let repo = tx.commit("test").unwrap();
+    assert_eq!(
+        git_repo
+            .find_reference("refs/remotes/origin/HEAD")
+            .unwrap()
+            .symbolic_target(),
+        Some("refs/remotes/origin/main")
+    );
+
+    // The remote HEAD is removed once it's deleted in Git
+    git_repo
+        .find_reference("refs/remotes/origin/HEAD")
+        .unwrap()
+        .delete()
+        .unwrap();
+    let mut tx = repo.start_transaction();
+    git::import_refs(tx.repo_mut(), &git_settings).unwrap();
+    let repo = tx.commit("test").unwrap();
+    assert!(repo
+        .view()
+        .get_git_ref("refs/remotes/origin/HEAD")
+        .is_absent());
+}
+
 #[test]
 fn test_import_refs_reimport_head_removed() {
     // Test that re-importing refs doesn't cause a deleted head to come back
@@ -2107,6 +2168,71 @@ fn test_export_undo_reexport() {
     );
 }
 
+#[test]
+fn test_create_and_delete_tags() {
+    let test_data = GitRepoData::create();
+    let git_settings = GitSettings::default();
+    let git_repo = test_data.git_repo;
+    let commit = empty_git_commit(&git_repo, "refs/heads/main", &[]);
+    let commit_id = jj_id(&commit);
+    let tagger = Signature {
+        name: "Tagger".to_string(),
+        email: "tagger@example.com".to_string(),
+        timestamp: Timestamp {
+            timestamp: MillisSinceEpoch(0),
+            tz_offset: 0,
+        },
+    };
+
+    let mut tx = test_data.repo.start_transaction();
+    let mut_repo = tx.repo_mut();
+    git::import_refs(mut_repo, &git_settings).unwrap();
+    git::create_tag(mut_repo, "light", &commit_id, None).unwrap();
+    git::create_tag(
+        mut_repo,
+        "annotated",
+        &commit_id,
+        Some(("release", &tagger)),
+    )
+    .unwrap();
+    assert_eq!(
+        mut_repo.get_tag("light"),
+        RefTarget::normal(commit_id.clone())
+    );
+    assert_eq!(
+        mut_repo.get_tag("annotated"),
+        RefTarget::normal(commit_id.clone())
+    );
+
+    // The lightweight tag points directly to the commit, whereas the annotated
+    // tag points to a tag object.
+    let light_ref = git_repo.find_reference("refs/tags/light").unwrap();
+    assert_eq!(light_ref.target(), Some(commit.id()));
+    let annotated_ref = git_repo.find_reference("refs/tags/annotated").unwrap();
+    let tag = annotated_ref.peel_to_tag().unwrap();
+    assert_eq!(tag.target_id(), commit.id());
+    assert_eq!(tag.message(), Some("release\n"));
+    assert_eq!(tag.tagger().unwrap().name(), Some("Tagger"));
+
+    // Tags are never moved
+    assert_matches!(
+        git::create_tag(mut_repo, "light", &commit_id, None),
+        Err(git::GitTagError::AlreadyExists(name)) if name == "light"
+    );
+
+    // Re-importing the refs doesn't change anything
+    let stats = git::import_refs(mut_repo, &git_settings).unwrap();
+    assert!(stats.changed_remote_refs.is_empty());
+
+    git::delete_tag(mut_repo, "annotated").unwrap();
+    assert!(mut_repo.get_tag("annotated").is_absent());
+    assert!(git_repo.find_reference("refs/tags/annotated").is_err());
+    assert_matches!(
+        git::delete_tag(mut_repo, "annotated"),
+        Err(git::GitTagError::NotFound(name)) if name == "annotated"
+    );
+}
+
 #[test]
 fn test_reset_head_to_root() {
     // Create colocated workspace
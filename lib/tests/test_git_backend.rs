@@ -21,8 +21,11 @@ use std::time::Duration;
 use std::time::SystemTime;
 
 use futures::executor::block_on_stream;
+use itertools::Itertools as _;
+use jj_lib::backend::Backend as _;
 use jj_lib::backend::CommitId;
 use jj_lib::backend::CopyRecord;
+use jj_lib::backend::GcStats;
 use jj_lib::commit::Commit;
 use jj_lib::git_backend::GitBackend;
 use jj_lib::repo::ReadonlyRepo;
@@ -32,6 +35,7 @@ use jj_lib::repo_path::RepoPathBuf;
 use jj_lib::store::Store;
 use jj_lib::transaction::Transaction;
 use maplit::hashset;
+use pollster::FutureExt as _;
 use testutils::create_random_commit;
 use testutils::create_tree;
 use testutils::CommitGraphBuilder;
@@ -288,3 +292,101 @@ fn test_copy_detection() {
         HashMap::default(),
     );
 }
+
+#[test]
+fn test_read_commits_and_trees_in_batch() {
+    let test_repo = TestRepo::init_with_backend(TestRepoBackend::Git);
+    let repo = test_repo.repo;
+    let backend = get_git_backend(&repo);
+
+    let mut tx = repo.start_transaction();
+    let commit_a = make_commit(&mut tx, vec![repo.store().root_commit_id().clone()], &[]);
+    let commit_b = make_commit(
+        &mut tx,
+        vec![commit_a.id().clone()],
+        &[(RepoPath::from_internal_string("file"), "b")],
+    );
+    tx.commit("test").unwrap();
+
+    // Batched reads return the same objects in the requested order, including
+    // the virtual root commit and repeated ids.
+    let commit_ids = [
+        commit_b.id().clone(),
+        repo.store().root_commit_id().clone(),
+        commit_a.id().clone(),
+        commit_b.id().clone(),
+    ];
+    let commits = backend.read_commits(&commit_ids).block_on().unwrap();
+    let expected_commits = commit_ids
+        .iter()
+        .map(|id| backend.read_commit(id).block_on().unwrap())
+        .collect_vec();
+    assert_eq!(commits, expected_commits);
+
+    let tree_ids = [
+        commit_b.tree_id().to_merge().into_resolved().unwrap(),
+        repo.store().empty_tree_id().clone(),
+    ];
+    let trees = backend
+        .read_trees(RepoPath::root(), &tree_ids)
+        .block_on()
+        .unwrap();
+    let expected_trees = tree_ids
+        .iter()
+        .map(|id| backend.read_tree(RepoPath::root(), id).block_on().unwrap())
+        .collect_vec();
+    assert_eq!(trees, expected_trees);
+    assert_eq!(trees[0].names().count(), 1);
+
+    // An unknown id fails the whole batch
+    let missing_id = CommitId::from_hex("abcdef0123456789abcdef0123456789abcdef01");
+    assert!(backend
+        .read_commits(&[commit_a.id().clone(), missing_id])
+        .block_on()
+        .is_err());
+}
+
+#[test]
+fn test_gc_dry_run() {
+    let test_repo = TestRepo::init_with_backend(TestRepoBackend::Git);
+    let repo = test_repo.repo;
+    let git_repo_path = get_git_backend(&repo).git_repo_path();
+    let base_index = repo.readonly_index();
+    let root_commit_id = repo.store().root_commit_id().clone();
+
+    let file_path = RepoPath::from_internal_string("file");
+    let mut tx = repo.start_transaction();
+    let commit_a = make_commit(&mut tx, vec![root_commit_id], &[(file_path, "a")]);
+    let commit_b = make_commit(&mut tx, vec![commit_a.id().clone()], &[(file_path, "b")]);
+    let commit_c = make_commit(&mut tx, vec![commit_a.id().clone()], &[(file_path, "c")]);
+    let repo = tx.commit("test").unwrap();
+    let no_gc_refs = collect_no_gc_refs(git_repo_path);
+
+    let now = || SystemTime::now() + Duration::from_secs(1);
+
+    // All reachable
+    let stats = repo.store().gc_dry_run(repo.index(), now()).unwrap();
+    assert_eq!(stats, GcStats::default());
+
+    // C is no longer reachable, and neither are its tree and file
+    let mut mut_index = base_index.start_modification();
+    mut_index.add_commit(&commit_a);
+    mut_index.add_commit(&commit_b);
+    let stats = repo
+        .store()
+        .gc_dry_run(mut_index.as_index(), now())
+        .unwrap();
+    assert_eq!((stats.commits, stats.trees, stats.blobs), (1, 1, 1));
+    assert!(stats.bytes > 0);
+
+    // Empty index, but all kept by file modification time
+    let stats = repo
+        .store()
+        .gc_dry_run(base_index.as_index(), SystemTime::UNIX_EPOCH)
+        .unwrap();
+    assert_eq!(stats, GcStats::default());
+
+    // Nothing was removed
+    assert_eq!(collect_no_gc_refs(git_repo_path), no_gc_refs);
+    assert!(repo.store().get_commit(commit_c.id()).is_ok());
+}
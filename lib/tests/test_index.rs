@@ -379,6 +379,43 @@ fn test_index_commits_hidden_but_referenced() {
     assert!(repo.index().has_id(commit_c.id()));
 }
 
+#[test]
+fn test_rebuild_index_at_operation() {
+    let settings = testutils::user_settings();
+    let test_repo = TestRepo::init();
+    let test_env = &test_repo.env;
+    let repo = &test_repo.repo;
+
+    // Create A and B in separate operations
+    let mut tx = repo.start_transaction();
+    let commit_a = write_random_commit(tx.repo_mut());
+    let repo = tx.commit("test").unwrap();
+    let mut tx = repo.start_transaction();
+    let commit_b = create_random_commit(tx.repo_mut())
+        .set_parents(vec![commit_a.id().clone()])
+        .write()
+        .unwrap();
+    let old_repo = tx.commit("test").unwrap();
+
+    let default_index_store: &DefaultIndexStore =
+        old_repo.index_store().as_any().downcast_ref().unwrap();
+    let index = default_index_store
+        .rebuild_index_at_operation(old_repo.operation(), old_repo.store())
+        .unwrap();
+    let stats = index.as_composite().stats();
+    assert_eq!(stats.num_commits, 1 + 2);
+    assert_eq!(stats.levels.len(), 1);
+
+    // The index loaded before rebuilding should still be usable
+    assert!(old_repo.index().has_id(commit_b.id()));
+
+    let repo = test_env.load_repo_at_head(&settings, test_repo.repo_path());
+    let index = as_readonly_composite(&repo);
+    assert_eq!(index.stats().levels.len(), 1);
+    assert!(index.has_id(commit_a.id()));
+    assert!(index.has_id(commit_b.id()));
+}
+
 #[test]
 fn test_index_commits_incremental() {
     let settings = testutils::user_settings();
@@ -745,6 +745,69 @@ fn test_checkout_discard() {
     assert!(!reloaded_wc.file_states().unwrap().contains_path(file2_path));
 }
 
+#[test_case(1; "sequential")]
+#[test_case(4; "parallel")]
+fn test_checkout_parallelism(parallelism: usize) {
+    // Files should be written identically no matter how many threads are used,
+    // and removing a file shouldn't remove the parent directory of a file that
+    // is yet to be written.
+    let mut test_workspace = TestWorkspace::init();
+    let repo = test_workspace.repo.clone();
+    let workspace_root = test_workspace.workspace.workspace_root().to_owned();
+
+    let added_path = RepoPath::from_internal_string("dir/added");
+    let removed_path = RepoPath::from_internal_string("dir/removed");
+    let paths = (0..100)
+        .map(|i| RepoPathBuf::from_internal_string(format!("sub{}/file{i}", i % 10)))
+        .collect_vec();
+    let mut contents1 = vec![(removed_path, "removed".to_owned())];
+    let mut contents2 = vec![(added_path, "added".to_owned())];
+    for (i, path) in paths.iter().enumerate() {
+        contents1.push((path, format!("old {i}\n")));
+        contents2.push((path, format!("new {i}\n")));
+    }
+    let create = |contents: &[(&RepoPath, String)]| {
+        let path_contents = contents
+            .iter()
+            .map(|(path, text)| (*path, text.as_str()))
+            .collect_vec();
+        create_tree(&repo, &path_contents)
+    };
+    let tree1 = create(&contents1);
+    let tree2 = create(&contents2);
+    let commit1 = commit_with_tree(repo.store(), tree1.id());
+    let commit2 = commit_with_tree(repo.store(), tree2.id());
+
+    let options = CheckoutOptions {
+        parallelism,
+        ..CheckoutOptions::empty_for_test()
+    };
+    let ws = &mut test_workspace.workspace;
+    ws.check_out(repo.op_id().clone(), None, &commit1, &options)
+        .unwrap();
+    let stats = ws
+        .check_out(repo.op_id().clone(), None, &commit2, &options)
+        .unwrap();
+    assert_eq!(
+        stats,
+        CheckoutStats {
+            updated_files: 100,
+            added_files: 1,
+            removed_files: 1,
+            skipped_files: 0,
+        }
+    );
+
+    assert!(!removed_path.to_fs_path_unchecked(&workspace_root).exists());
+    for (path, text) in &contents2 {
+        let disk_path = path.to_fs_path_unchecked(&workspace_root);
+        assert_eq!(std::fs::read_to_string(disk_path).unwrap(), *text);
+    }
+    // The file states should match what was written to disk
+    let new_tree = test_workspace.snapshot().unwrap();
+    assert_eq!(new_tree.id(), tree2.id());
+}
+
 #[test]
 fn test_snapshot_file_directory_transition() {
     let mut test_workspace = TestWorkspace::init();
@@ -2103,6 +2166,158 @@ fn test_fsmonitor() {
     }
 }
 
+#[test]
+fn test_git_lfs_pointer_round_trip() {
+    let mut test_workspace = TestWorkspace::init();
+    let repo = &test_workspace.repo;
+    let workspace_root = test_workspace.workspace.workspace_root().to_owned();
+    let gitattributes = "*.bin filter=lfs diff=lfs merge=lfs -text\n";
+    let pointer_path = RepoPath::from_internal_string("file.bin");
+    let pointer1 = "version https://git-lfs.github.com/spec/v1
+oid sha256:0000000000000000000000000000000000000000000000000000000000000001
+size 123456
+";
+    let pointer2 = "version https://git-lfs.github.com/spec/v1
+oid sha256:0000000000000000000000000000000000000000000000000000000000000002
+size 654321
+";
+    let gitattributes_path = RepoPath::from_internal_string(".gitattributes");
+    let tree1 = create_tree(
+        repo,
+        &[
+            (gitattributes_path, gitattributes),
+            (pointer_path, pointer1),
+        ],
+    );
+    let commit = commit_with_tree(repo.store(), tree1.id());
+    let checkout_options = CheckoutOptions {
+        git_lfs: true,
+        ..CheckoutOptions::empty_for_test()
+    };
+    test_workspace
+        .workspace
+        .check_out(repo.op_id().clone(), None, &commit, &checkout_options)
+        .unwrap();
+
+    // The LFS object isn't available, so the pointer is checked out as is, and
+    // isn't changed by snapshotting.
+    let disk_path = pointer_path.to_fs_path_unchecked(&workspace_root);
+    assert_eq!(std::fs::read_to_string(&disk_path).unwrap(), pointer1);
+    let snapshot_options = SnapshotOptions {
+        git_lfs: true,
+        ..SnapshotOptions::empty_for_test()
+    };
+    let (tree, _stats) = test_workspace
+        .snapshot_with_options(&snapshot_options)
+        .unwrap();
+    assert_eq!(tree.id(), tree1.id());
+
+    // Modified pointers are stored as is too
+    std::fs::write(&disk_path, pointer2).unwrap();
+    let (tree, _stats) = test_workspace
+        .snapshot_with_options(&snapshot_options)
+        .unwrap();
+    let tree2 = create_tree(
+        &test_workspace.repo,
+        &[
+            (gitattributes_path, gitattributes),
+            (pointer_path, pointer2),
+        ],
+    );
+    assert_eq!(tree.id(), tree2.id());
+}
+
+#[test]
+fn test_eol_conversion_round_trip() {
+    let mut test_workspace = TestWorkspace::init();
+    let repo = &test_workspace.repo;
+    let workspace_root = test_workspace.workspace.workspace_root().to_owned();
+    let gitattributes = "*.txt text eol=crlf\n*.bin binary\n";
+    let gitattributes_path = RepoPath::from_internal_string(".gitattributes");
+    let text_path = RepoPath::from_internal_string("file.txt");
+    let binary_path = RepoPath::from_internal_string("file.bin");
+    let other_path = RepoPath::from_internal_string("file.rs");
+    let tree1 = create_tree(
+        repo,
+        &[
+            (gitattributes_path, gitattributes),
+            (text_path, "line 1\nline 2\n"),
+            (binary_path, "binary\r\ndata\n"),
+            (other_path, "fn main() {}\n"),
+        ],
+    );
+    let commit = commit_with_tree(repo.store(), tree1.id());
+    let checkout_options = CheckoutOptions {
+        eol_conversion: true,
+        ..CheckoutOptions::empty_for_test()
+    };
+    test_workspace
+        .workspace
+        .check_out(repo.op_id().clone(), None, &commit, &checkout_options)
+        .unwrap();
+
+    // Text files get CRLF line endings, others are left untouched
+    let read = |path: &RepoPath| std::fs::read(path.to_fs_path_unchecked(&workspace_root)).unwrap();
+    assert_eq!(read(text_path), b"line 1\r\nline 2\r\n");
+    assert_eq!(read(binary_path), b"binary\r\ndata\n");
+    assert_eq!(read(other_path), b"fn main() {}\n");
+
+    // Snapshotting the unchanged files doesn't change the tree
+    let snapshot_options = SnapshotOptions {
+        eol_conversion: true,
+        ..SnapshotOptions::empty_for_test()
+    };
+    let (tree, _stats) = test_workspace
+        .snapshot_with_options(&snapshot_options)
+        .unwrap();
+    assert_eq!(tree.id(), tree1.id());
+
+    // Modified text files are stored with LF line endings, binary files as is
+    std::fs::write(
+        text_path.to_fs_path_unchecked(&workspace_root),
+        "line 1\r\nline 2\r\nline 3\r\n",
+    )
+    .unwrap();
+    std::fs::write(
+        binary_path.to_fs_path_unchecked(&workspace_root),
+        "binary\r\ndata\r\nmore\r\n",
+    )
+    .unwrap();
+    let (tree, _stats) = test_workspace
+        .snapshot_with_options(&snapshot_options)
+        .unwrap();
+    let tree2 = create_tree(
+        &test_workspace.repo,
+        &[
+            (gitattributes_path, gitattributes),
+            (text_path, "line 1\nline 2\nline 3\n"),
+            (binary_path, "binary\r\ndata\r\nmore\r\n"),
+            (other_path, "fn main() {}\n"),
+        ],
+    );
+    assert_eq!(tree.id(), tree2.id());
+
+    // Without conversion, CRLF line endings are stored as is
+    std::fs::write(
+        text_path.to_fs_path_unchecked(&workspace_root),
+        "line 1\r\nline 2\r\n",
+    )
+    .unwrap();
+    let (tree, _stats) = test_workspace
+        .snapshot_with_options(&SnapshotOptions::empty_for_test())
+        .unwrap();
+    let tree3 = create_tree(
+        &test_workspace.repo,
+        &[
+            (gitattributes_path, gitattributes),
+            (text_path, "line 1\r\nline 2\r\n"),
+            (binary_path, "binary\r\ndata\r\nmore\r\n"),
+            (other_path, "fn main() {}\n"),
+        ],
+    );
+    assert_eq!(tree.id(), tree3.id());
+}
+
 #[test]
 fn test_snapshot_max_new_file_size() {
     let mut test_workspace = TestWorkspace::init();
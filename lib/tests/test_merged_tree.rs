@@ -18,6 +18,7 @@ use jj_lib::backend::CommitId;
 use jj_lib::backend::CopyRecord;
 use jj_lib::backend::FileId;
 use jj_lib::backend::MergedTreeId;
+use jj_lib::backend::TreeId;
 use jj_lib::backend::TreeValue;
 use jj_lib::copies::CopiesTreeDiffEntryPath;
 use jj_lib::copies::CopyOperation;
@@ -42,6 +43,7 @@ use jj_lib::repo_path::RepoPathComponent;
 use pollster::FutureExt as _;
 use pretty_assertions::assert_eq;
 use testutils::create_single_tree;
+use testutils::test_backend::TestBackend;
 use testutils::write_file;
 use testutils::TestRepo;
 
@@ -1568,3 +1570,57 @@ fn test_merge_simplify_file_conflict_with_absent() {
     let merged = child1_merged.merge(&parent_merged, &child2_merged).unwrap();
     assert_eq!(merged, expected_merged);
 }
+
+#[test]
+fn test_diff_reads_conflicted_trees_in_batches() {
+    let settings = testutils::user_settings();
+    let test_repo = TestRepo::init();
+    let repo = &test_repo.repo;
+
+    let dir_file_path = RepoPath::from_internal_string("dir/file");
+    let base = create_single_tree(repo, &[(dir_file_path, "base")]);
+    let side1 = create_single_tree(repo, &[(dir_file_path, "side1")]);
+    let side2 = create_single_tree(repo, &[(dir_file_path, "side2")]);
+    let resolved = create_single_tree(repo, &[(dir_file_path, "resolved")]);
+    let conflicted_ids = [side1.id().clone(), base.id().clone(), side2.id().clone()];
+    let resolved_ids = [resolved.id().clone()];
+
+    let count_diff_requests = |use_stream: bool| {
+        // Reload the repo to start with empty store caches
+        let repo = test_repo
+            .env
+            .load_repo_at_head(&settings, test_repo.repo_path());
+        let store = repo.store();
+        let test_backend: &TestBackend = store.backend_impl().downcast_ref().unwrap();
+        let before = test_backend.tree_read_requests();
+        let load_trees = |ids: &[TreeId]| {
+            let trees = store
+                .get_trees_async(RepoPathBuf::root(), ids)
+                .block_on()
+                .unwrap();
+            Merge::from_vec(trees)
+        };
+        let trees1 = load_trees(&conflicted_ids);
+        let trees2 = load_trees(&resolved_ids);
+        let after_root_trees = test_backend.tree_read_requests();
+        let diff: Vec<_> = if use_stream {
+            TreeDiffStreamImpl::new(&trees1, &trees2, &EverythingMatcher, 10)
+                .map(diff_entry_tuple)
+                .collect()
+                .block_on()
+        } else {
+            TreeDiffIterator::new(&trees1, &trees2, &EverythingMatcher)
+                .map(diff_entry_tuple)
+                .collect()
+        };
+        assert_eq!(diff.len(), 1);
+        (
+            after_root_trees - before,
+            test_backend.tree_read_requests() - after_root_trees,
+        )
+    };
+
+    // One request per side for the root trees, and one per side for "dir"
+    assert_eq!(count_diff_requests(false), (2, 2));
+    assert_eq!(count_diff_requests(true), (2, 2));
+}
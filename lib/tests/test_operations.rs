@@ -23,6 +23,7 @@ use jj_lib::backend::CommitId;
 use jj_lib::config::ConfigLayer;
 use jj_lib::config::ConfigSource;
 use jj_lib::object_id::ObjectId;
+use jj_lib::op_store::OpStoreGcStats;
 use jj_lib::op_walk;
 use jj_lib::op_walk::OpsetEvaluationError;
 use jj_lib::op_walk::OpsetResolutionError;
@@ -643,6 +644,8 @@ fn test_gc() {
     assert_eq!(expected_view_entries.len(), 5);
 
     // No heads, but all kept by file modification time
+    let stats = op_store.gc_dry_run(&[], SystemTime::UNIX_EPOCH).unwrap();
+    assert_eq!(stats, OpStoreGcStats::default());
     op_store.gc(&[], SystemTime::UNIX_EPOCH).unwrap();
     assert_eq!(list_dir(&op_dir), expected_op_entries);
     assert_eq!(list_dir(&view_dir), expected_view_entries);
@@ -655,6 +658,13 @@ fn test_gc() {
     assert_eq!(list_dir(&view_dir), expected_view_entries);
 
     // E|F are no longer reachable, but E's view is still reachable
+    let stats = op_store
+        .gc_dry_run(slice::from_ref(repo_d.op_id()), now)
+        .unwrap();
+    assert_eq!((stats.operations, stats.views), (2, 1));
+    assert!(stats.bytes > 0);
+    assert_eq!(list_dir(&op_dir), expected_op_entries);
+    assert_eq!(list_dir(&view_dir), expected_view_entries);
     op_store.gc(slice::from_ref(repo_d.op_id()), now).unwrap();
     expected_op_entries
         .retain(|name| *name != repo_e.op_id().hex() && *name != repo_f.op_id().hex());
@@ -14,6 +14,7 @@
 
 use std::iter;
 use std::path::Path;
+use std::sync::Arc;
 
 use assert_matches::assert_matches;
 use chrono::DateTime;
@@ -29,6 +30,7 @@ use jj_lib::git_backend::GitBackend;
 use jj_lib::graph::reverse_graph;
 use jj_lib::graph::GraphEdge;
 use jj_lib::id_prefix::IdPrefixContext;
+use jj_lib::mailmap::Mailmap;
 use jj_lib::object_id::ObjectId;
 use jj_lib::op_store::RefTarget;
 use jj_lib::op_store::RemoteRef;
@@ -69,7 +71,14 @@ fn resolve_symbol_with_extensions(
 ) -> Result<Vec<CommitId>, RevsetResolutionError> {
     let aliases_map = RevsetAliasesMap::default();
     let now = chrono::Local::now();
-    let context = RevsetParseContext::new(&aliases_map, "", now.into(), extensions, None);
+    let context = RevsetParseContext::new(
+        &aliases_map,
+        "",
+        Default::default(),
+        now.into(),
+        extensions,
+        None,
+    );
     let expression = parse(&mut RevsetDiagnostics::new(), symbol, &context).unwrap();
     assert_matches!(*expression, RevsetExpression::CommitRef(_));
     let symbol_resolver = DefaultSymbolResolver::new(repo, extensions.symbol_resolvers());
@@ -208,6 +217,7 @@ fn test_resolve_symbol_commit_id() {
     let context = RevsetParseContext::new(
         &aliases_map,
         settings.user_email(),
+        Default::default(),
         chrono::Utc::now().fixed_offset().into(),
         &extensions,
         None,
@@ -905,6 +915,14 @@ fn resolve_commit_ids(repo: &dyn Repo, revset_str: &str) -> Vec<CommitId> {
 fn try_resolve_commit_ids(
     repo: &dyn Repo,
     revset_str: &str,
+) -> Result<Vec<CommitId>, RevsetResolutionError> {
+    try_resolve_commit_ids_with_mailmap(repo, revset_str, Default::default())
+}
+
+fn try_resolve_commit_ids_with_mailmap(
+    repo: &dyn Repo,
+    revset_str: &str,
+    mailmap: Arc<Mailmap>,
 ) -> Result<Vec<CommitId>, RevsetResolutionError> {
     let settings = testutils::user_settings();
     let aliases_map = RevsetAliasesMap::default();
@@ -912,6 +930,7 @@ fn try_resolve_commit_ids(
     let context = RevsetParseContext::new(
         &aliases_map,
         settings.user_email(),
+        mailmap,
         chrono::Utc::now().fixed_offset().into(),
         &revset_extensions,
         None,
@@ -947,6 +966,7 @@ fn resolve_commit_ids_in_workspace(
     let context = RevsetParseContext::new(
         &aliases_map,
         settings.user_email(),
+        Default::default(),
         chrono::Utc::now().fixed_offset().into(),
         &extensions,
         Some(workspace_ctx),
@@ -2275,6 +2295,105 @@ fn test_evaluate_expression_remote_bookmarks() {
     );
 }
 
+#[test]
+fn test_evaluate_expression_remote_heads_and_tracked() {
+    let test_repo = TestRepo::init();
+    let repo = &test_repo.repo;
+    let remote_ref = |id: &CommitId, state| RemoteRef {
+        target: RefTarget::normal(id.clone()),
+        state,
+    };
+
+    let mut tx = repo.start_transaction();
+    let mut_repo = tx.repo_mut();
+    let mut graph_builder = CommitGraphBuilder::new(mut_repo);
+    let commit1 = graph_builder.initial_commit();
+    let commit2 = graph_builder.commit_with_parents(&[&commit1]);
+    let commit3 = graph_builder.commit_with_parents(&[&commit1]);
+    let commit4 = graph_builder.commit_with_parents(&[&commit3]);
+    let commit_git_remote = graph_builder.commit_with_parents(&[&commit4]);
+
+    // origin has "main" at commit1 (an ancestor of the other bookmarks), and
+    // "feature" at commit2, which isn't tracked
+    mut_repo.set_remote_bookmark(
+        "main",
+        "origin",
+        remote_ref(commit1.id(), RemoteRefState::Tracking),
+    );
+    mut_repo.set_remote_bookmark(
+        "feature",
+        "origin",
+        remote_ref(commit2.id(), RemoteRefState::New),
+    );
+    mut_repo.set_remote_bookmark(
+        "release",
+        "origin",
+        remote_ref(commit3.id(), RemoteRefState::Tracking),
+    );
+    // upstream has "main" at commit4
+    mut_repo.set_remote_bookmark(
+        "main",
+        "upstream",
+        remote_ref(commit4.id(), RemoteRefState::Tracking),
+    );
+    // Git-tracking bookmarks aren't included
+    mut_repo.set_remote_bookmark(
+        "main",
+        git::REMOTE_NAME_FOR_LOCAL_GIT_REPO,
+        remote_ref(commit_git_remote.id(), RemoteRefState::Tracking),
+    );
+
+    // heads_of() selects the tips of the remote's bookmarks, tracked or not
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "heads_of(origin)"),
+        vec![commit3.id().clone(), commit2.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "heads_of(exact:upstream)"),
+        vec![commit4.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, r#"heads_of(glob:"*")"#),
+        vec![commit4.id().clone(), commit2.id().clone()]
+    );
+    assert_eq!(resolve_commit_ids(mut_repo, "heads_of(git)"), vec![]);
+
+    // tracked() matches "bookmark@remote"
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "tracked()"),
+        vec![
+            commit4.id().clone(),
+            commit3.id().clone(),
+            commit1.id().clone()
+        ]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, r#"tracked(glob:"*@origin")"#),
+        vec![commit3.id().clone(), commit1.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, r#"tracked(exact:"main@upstream")"#),
+        vec![commit4.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "tracked(main)"),
+        vec![commit4.id().clone(), commit1.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, r#"tracked(glob:"feature@*")"#),
+        vec![]
+    );
+
+    // The functions compose with other expressions
+    assert_eq!(
+        resolve_commit_ids(
+            mut_repo,
+            r#"heads_of(upstream) ~ ::tracked(glob:"*@origin")"#
+        ),
+        vec![commit4.id().clone()]
+    );
+}
+
 #[test]
 fn test_evaluate_expression_tags() {
     let test_repo = TestRepo::init();
@@ -3026,6 +3145,65 @@ fn test_evaluate_expression_mine() {
     );
 }
 
+#[test]
+fn test_evaluate_expression_author_mailmap() {
+    let settings = testutils::user_settings();
+    let test_repo = TestRepo::init_with_settings(&settings);
+    let repo = &test_repo.repo;
+
+    let mut tx = repo.start_transaction();
+    let mut_repo = tx.repo_mut();
+
+    let timestamp = Timestamp {
+        timestamp: MillisSinceEpoch(0),
+        tz_offset: 0,
+    };
+    let commit1 = create_random_commit(mut_repo)
+        .set_author(Signature {
+            name: "Old Name".to_string(),
+            email: "old@example.com".to_string(),
+            timestamp,
+        })
+        .write()
+        .unwrap();
+    let commit2 = create_random_commit(mut_repo)
+        .set_parents(vec![commit1.id().clone()])
+        .set_author(Signature {
+            name: "New Name".to_string(),
+            email: settings.user_email().to_owned(),
+            timestamp,
+        })
+        .write()
+        .unwrap();
+    let mailmap = Arc::new(Mailmap::parse(&format!(
+        "New Name <{}> <old@example.com>",
+        settings.user_email()
+    )));
+    let resolve = |revset_str| {
+        try_resolve_commit_ids_with_mailmap(mut_repo, revset_str, mailmap.clone()).unwrap()
+    };
+
+    // Matches against the mapped identity
+    assert_eq!(
+        resolve("author_name(exact:'New Name')"),
+        vec![commit2.id().clone(), commit1.id().clone()]
+    );
+    assert_eq!(resolve("author('Old Name')"), vec![]);
+    assert_eq!(resolve("author_email('old@')"), vec![]);
+    assert_eq!(
+        resolve("mine()"),
+        vec![commit2.id().clone(), commit1.id().clone()]
+    );
+    // Without the mailmap, the recorded identity is matched
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "author('Old Name')"),
+        vec![commit1.id().clone()]
+    );
+    assert_eq!(
+        resolve_commit_ids(mut_repo, "mine()"),
+        vec![commit2.id().clone()]
+    );
+}
 #[test]
 fn test_evaluate_expression_committer() {
     let test_repo = TestRepo::init();
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use itertools::Itertools as _;
 use jj_lib::commit::Commit;
 use jj_lib::matchers::EverythingMatcher;
@@ -24,10 +26,12 @@ use jj_lib::op_store::RemoteRefState;
 use jj_lib::op_store::WorkspaceId;
 use jj_lib::repo::Repo;
 use jj_lib::repo_path::RepoPath;
+use jj_lib::rewrite::move_commits;
 use jj_lib::rewrite::rebase_commit_with_options;
 use jj_lib::rewrite::restore_tree;
 use jj_lib::rewrite::CommitRewriter;
 use jj_lib::rewrite::EmptyBehaviour;
+use jj_lib::rewrite::MoveCommitsTarget;
 use jj_lib::rewrite::RebaseOptions;
 use jj_lib::rewrite::RewriteRefsOptions;
 use maplit::hashmap;
@@ -1693,6 +1697,7 @@ fn test_empty_commit_option(empty_behavior: EmptyBehaviour) {
                 delete_abandoned_bookmarks: false,
             },
             simplify_ancestor_merge: true,
+            ..Default::default()
         },
     );
 
@@ -1728,6 +1733,7 @@ fn test_empty_commit_option(empty_behavior: EmptyBehaviour) {
                 assert_rebased_onto(tx.repo_mut(), &rebase_map, &commit_g, &[new_commit_c.id()]);
             assert_abandoned_with_parent(tx.repo_mut(), &rebase_map, &commit_h, new_commit_g.id())
         }
+        EmptyBehaviour::AbandonNewlyEmptyUndescribed => unreachable!(),
         EmptyBehaviour::AbandonNewlyEmpty => {
             // The commit C isn't empty.
             let new_commit_c =
@@ -1762,6 +1768,82 @@ fn test_empty_commit_option(empty_behavior: EmptyBehaviour) {
     );
 }
 
+#[test]
+fn test_rebase_abandoning_newly_empty_undescribed() {
+    let test_repo = TestRepo::init();
+    let repo = &test_repo.repo;
+
+    // Rebase C, D, E, and F onto B. C, D, and E have the same changes as B,
+    // F was already empty.
+    //
+    // C ("") D (default) E (described) F ("", empty)
+    //  \      |         /             /
+    //   ------A---------------------- B
+    let mut tx = repo.start_transaction();
+    let tree_a = create_tree(repo, &[(RepoPath::from_internal_string("a"), "a")]);
+    let tree_b = create_tree(
+        repo,
+        &[
+            (RepoPath::from_internal_string("a"), "a"),
+            (RepoPath::from_internal_string("b"), "b"),
+        ],
+    );
+    let commit_a = create_random_commit(tx.repo_mut())
+        .set_tree_id(tree_a.id())
+        .write()
+        .unwrap();
+    let mut create_commit = |tree: &MergedTree, description: &str| {
+        create_random_commit(tx.repo_mut())
+            .set_parents(vec![commit_a.id().clone()])
+            .set_tree_id(tree.id())
+            .set_description(description)
+            .write()
+            .unwrap()
+    };
+    let commit_b = create_commit(&tree_b, "B");
+    let commit_c = create_commit(&tree_b, "");
+    let commit_d = create_commit(&tree_b, " default\n");
+    let commit_e = create_commit(&tree_b, "described");
+    let commit_f = create_commit(&tree_a, "");
+
+    let rebase_options = RebaseOptions {
+        empty: EmptyBehaviour::AbandonNewlyEmptyUndescribed,
+        rewrite_refs: RewriteRefsOptions {
+            delete_abandoned_bookmarks: false,
+        },
+        simplify_ancestor_merge: false,
+        default_description: "default".to_owned(),
+    };
+    let stats = move_commits(
+        tx.repo_mut(),
+        &[commit_b.id().clone()],
+        &[],
+        &MoveCommitsTarget::Commits(vec![
+            commit_f.clone(),
+            commit_e.clone(),
+            commit_d.clone(),
+            commit_c.clone(),
+        ]),
+        &rebase_options,
+    )
+    .unwrap();
+    assert_eq!(
+        stats
+            .abandoned_commits
+            .iter()
+            .map(|c| c.id())
+            .collect::<HashSet<_>>(),
+        hashset! {commit_c.id(), commit_d.id()}
+    );
+    assert_eq!(stats.num_rebased_targets, 2);
+    // The abandoned commits are replaced by their new parent
+    let new_parents = tx
+        .repo_mut()
+        .new_parents(&[commit_c.id().clone(), commit_d.id().clone()]);
+    assert_eq!(new_parents, vec![commit_b.id().clone()]);
+    assert_eq!(tx.repo().view().heads().len(), 2);
+}
+
 #[test]
 fn test_rebase_abandoning_empty() {
     let test_repo = TestRepo::init();
@@ -1828,6 +1910,7 @@ fn test_rebase_abandoning_empty() {
             delete_abandoned_bookmarks: false,
         },
         simplify_ancestor_merge: true,
+        ..Default::default()
     };
     let rewriter = CommitRewriter::new(tx.repo_mut(), commit_b, vec![commit_b2.id().clone()]);
     rebase_commit_with_options(rewriter, &rebase_options).unwrap();
@@ -40,6 +40,7 @@ use jj_lib::backend::Conflict;
 use jj_lib::backend::ConflictId;
 use jj_lib::backend::CopyRecord;
 use jj_lib::backend::FileId;
+use jj_lib::backend::GcStats;
 use jj_lib::backend::SecureSig;
 use jj_lib::backend::SigningFn;
 use jj_lib::backend::SymlinkId;
@@ -66,6 +67,9 @@ pub struct TestBackendData {
     files: HashMap<RepoPathBuf, HashMap<FileId, Vec<u8>>>,
     symlinks: HashMap<RepoPathBuf, HashMap<SymlinkId, String>>,
     conflicts: HashMap<RepoPathBuf, HashMap<ConflictId, Conflict>>,
+    // Number of read requests. A batched read counts as a single request.
+    tree_read_requests: usize,
+    commit_read_requests: usize,
 }
 
 #[derive(Clone, Default)]
@@ -140,6 +144,49 @@ impl TestBackend {
     pub fn remove_commit_unchecked(&self, id: &CommitId) {
         self.locked_data().commits.remove(id);
     }
+
+    /// Number of tree read requests made so far. A batched read counts once.
+    pub fn tree_read_requests(&self) -> usize {
+        self.locked_data().tree_read_requests
+    }
+
+    /// Number of commit read requests made so far. A batched read counts once.
+    pub fn commit_read_requests(&self) -> usize {
+        self.locked_data().commit_read_requests
+    }
+
+    fn lookup_tree(data: &TestBackendData, path: &RepoPath, id: &TreeId) -> BackendResult<Tree> {
+        match data
+            .trees
+            .get(path)
+            .and_then(|items| items.get(id))
+            .cloned()
+        {
+            None => Err(BackendError::ObjectNotFound {
+                object_type: "tree".to_string(),
+                hash: id.hex(),
+                source: format!("at path {path:?}").into(),
+            }),
+            Some(tree) => Ok(tree),
+        }
+    }
+
+    fn lookup_commit(&self, data: &TestBackendData, id: &CommitId) -> BackendResult<Commit> {
+        if id == &self.root_commit_id {
+            return Ok(make_root_commit(
+                self.root_change_id.clone(),
+                self.empty_tree_id.clone(),
+            ));
+        }
+        match data.commits.get(id).cloned() {
+            None => Err(BackendError::ObjectNotFound {
+                object_type: "commit".to_string(),
+                hash: id.hex(),
+                source: "".into(),
+            }),
+            Some(commit) => Ok(commit),
+        }
+    }
 }
 
 impl Debug for TestBackend {
@@ -247,20 +294,23 @@ impl Backend for TestBackend {
         if id == &self.empty_tree_id {
             return Ok(Tree::default());
         }
-        match self
-            .locked_data()
-            .trees
-            .get(path)
-            .and_then(|items| items.get(id))
-            .cloned()
-        {
-            None => Err(BackendError::ObjectNotFound {
-                object_type: "tree".to_string(),
-                hash: id.hex(),
-                source: format!("at path {path:?}").into(),
-            }),
-            Some(tree) => Ok(tree),
-        }
+        let mut data = self.locked_data();
+        data.tree_read_requests += 1;
+        Self::lookup_tree(&data, path, id)
+    }
+
+    async fn read_trees(&self, path: &RepoPath, ids: &[TreeId]) -> BackendResult<Vec<Tree>> {
+        let mut data = self.locked_data();
+        data.tree_read_requests += 1;
+        ids.iter()
+            .map(|id| {
+                if id == &self.empty_tree_id {
+                    Ok(Tree::default())
+                } else {
+                    Self::lookup_tree(&data, path, id)
+                }
+            })
+            .collect()
     }
 
     async fn write_tree(&self, path: &RepoPath, contents: &Tree) -> BackendResult<TreeId> {
@@ -301,20 +351,15 @@ impl Backend for TestBackend {
     }
 
     async fn read_commit(&self, id: &CommitId) -> BackendResult<Commit> {
-        if id == &self.root_commit_id {
-            return Ok(make_root_commit(
-                self.root_change_id.clone(),
-                self.empty_tree_id.clone(),
-            ));
-        }
-        match self.locked_data().commits.get(id).cloned() {
-            None => Err(BackendError::ObjectNotFound {
-                object_type: "commit".to_string(),
-                hash: id.hex(),
-                source: "".into(),
-            }),
-            Some(commit) => Ok(commit),
-        }
+        let mut data = self.locked_data();
+        data.commit_read_requests += 1;
+        self.lookup_commit(&data, id)
+    }
+
+    async fn read_commits(&self, ids: &[CommitId]) -> BackendResult<Vec<Commit>> {
+        let mut data = self.locked_data();
+        data.commit_read_requests += 1;
+        ids.iter().map(|id| self.lookup_commit(&data, id)).collect()
     }
 
     async fn write_commit(
@@ -349,4 +394,8 @@ impl Backend for TestBackend {
     fn gc(&self, _index: &dyn Index, _keep_newer: SystemTime) -> BackendResult<()> {
         Ok(())
     }
+
+    fn gc_dry_run(&self, _index: &dyn Index, _keep_newer: SystemTime) -> BackendResult<GcStats> {
+        Ok(GcStats::default())
+    }
 }